@@ -9,7 +9,7 @@ Namepsace to deploy elements into.
 Single entry of rollup names
 */}}
 {{- define "composer.rollupDefinition" }}
-{{ .name }}::{{ .wsRpc }}
+{{ .name }}::{{ if .collector }}{{ .collector }}::{{ end }}{{ .wsRpc }}
 {{- end}}
 
 {{/*
@@ -3,6 +3,10 @@
 mod generated;
 use std::{
     borrow::Cow,
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
     sync::Arc,
 };
 
@@ -32,6 +36,7 @@ use ethers::{
     types::{
         Filter,
         Log,
+        ValueOrArray,
         H256,
     },
 };
@@ -60,6 +65,14 @@ impl BuildError {
         })
     }
 
+    #[must_use]
+    fn duplicate_contract(address: ethers::types::Address, kind: WithdrawalKind) -> Self {
+        Self(BuildErrorKind::DuplicateContract {
+            address,
+            kind,
+        })
+    }
+
     #[must_use]
     pub fn no_withdraws_configured() -> Self {
         Self(BuildErrorKind::NoWithdrawsConfigured)
@@ -83,6 +96,13 @@ impl BuildError {
             source,
         })
     }
+
+    #[must_use]
+    fn zero_divisor(address: ethers::types::Address) -> Self {
+        Self(BuildErrorKind::ZeroDivisor {
+            address,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -104,10 +124,99 @@ enum BuildErrorKind {
     CallBaseChainAssetPrecision {
         source: Box<dyn std::error::Error + Send + Sync + 'static>,
     },
+    #[error("{kind} withdrawals of contract `{address:?}` were configured more than once")]
+    DuplicateContract {
+        address: ethers::types::Address,
+        kind: WithdrawalKind,
+    },
     #[error("ics20 asset must have a channel to be withdrawn via IBC")]
     Ics20AssetWithoutChannel,
     #[error("could not parse ics20 asset channel as channel ID")]
     ParseIcs20AssetSourceChannel { source: ibc_types::IdentifierError },
+    #[error("the asset withdrawal divisor of contract `{address:?}` must not be zero")]
+    ZeroDivisor { address: ethers::types::Address },
+}
+
+/// The kind of withdrawal event emitted by a contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum WithdrawalKind {
+    Ics20,
+    Sequencer,
+}
+
+impl std::fmt::Display for WithdrawalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ics20 => f.write_str("ics20"),
+            Self::Sequencer => f.write_str("sequencer"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum WithdrawalAsset {
+    Ics20(asset::TracePrefixed),
+    Sequencer(asset::Denom),
+}
+
+/// Maps the withdrawal events of one kind emitted by a contract to the asset withdrawn on the
+/// sequencer.
+///
+/// A contract emitting both sequencer and ics20 withdrawal events (like the native
+/// `AstriaWithdrawer`) is configured by one mapping for each kind.
+#[derive(Clone, Debug)]
+pub struct WithdrawalContract {
+    address: ethers::types::Address,
+    asset: WithdrawalAsset,
+    asset_withdrawal_divisor: Option<u128>,
+}
+
+impl WithdrawalContract {
+    /// Withdraws `asset` to sequencer addresses for `SequencerWithdrawal` events of the contract
+    /// at `address`.
+    #[must_use]
+    pub fn sequencer(address: ethers::types::Address, asset: asset::Denom) -> Self {
+        Self {
+            address,
+            asset: WithdrawalAsset::Sequencer(asset),
+            asset_withdrawal_divisor: None,
+        }
+    }
+
+    /// Withdraws `asset` over IBC for `Ics20Withdrawal` events of the contract at `address`.
+    ///
+    /// `asset` must contain the ics20 channel it is withdrawn over.
+    #[must_use]
+    pub fn ics20(address: ethers::types::Address, asset: asset::TracePrefixed) -> Self {
+        Self {
+            address,
+            asset: WithdrawalAsset::Ics20(asset),
+            asset_withdrawal_divisor: None,
+        }
+    }
+
+    /// Sets the divisor applied to the amounts of the contract's withdrawal events.
+    ///
+    /// If not set, the divisor is derived from the contract's `BASE_CHAIN_ASSET_PRECISION`.
+    #[must_use]
+    pub fn asset_withdrawal_divisor(self, asset_withdrawal_divisor: u128) -> Self {
+        Self {
+            asset_withdrawal_divisor: Some(asset_withdrawal_divisor),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn address(&self) -> ethers::types::Address {
+        self.address
+    }
+
+    fn kind(&self) -> WithdrawalKind {
+        match self.asset {
+            WithdrawalAsset::Ics20(_) => WithdrawalKind::Ics20,
+            WithdrawalAsset::Sequencer(_) => WithdrawalKind::Sequencer,
+        }
+    }
 }
 
 pub struct NoProvider;
@@ -120,6 +229,7 @@ pub struct GetWithdrawalActionsBuilder<TProvider = NoProvider> {
     fee_asset: Option<asset::Denom>,
     sequencer_asset_to_withdraw: Option<asset::Denom>,
     ics20_asset_to_withdraw: Option<asset::TracePrefixed>,
+    withdrawal_contracts: Vec<WithdrawalContract>,
 }
 
 impl Default for GetWithdrawalActionsBuilder {
@@ -138,6 +248,7 @@ impl GetWithdrawalActionsBuilder {
             fee_asset: None,
             sequencer_asset_to_withdraw: None,
             ics20_asset_to_withdraw: None,
+            withdrawal_contracts: Vec::new(),
         }
     }
 }
@@ -151,6 +262,7 @@ impl<P> GetWithdrawalActionsBuilder<P> {
             fee_asset,
             sequencer_asset_to_withdraw,
             ics20_asset_to_withdraw,
+            withdrawal_contracts,
             ..
         } = self;
         GetWithdrawalActionsBuilder {
@@ -160,6 +272,7 @@ impl<P> GetWithdrawalActionsBuilder<P> {
             fee_asset,
             sequencer_asset_to_withdraw,
             ics20_asset_to_withdraw,
+            withdrawal_contracts,
         }
     }
 
@@ -218,6 +331,27 @@ impl<P> GetWithdrawalActionsBuilder<P> {
             ..self
         }
     }
+
+    /// Adds a contract whose withdrawal events are converted to sequencer actions.
+    ///
+    /// This is in addition to the contract configured via `contract_address`, and can be called
+    /// repeatedly to watch several contracts, e.g. the native asset's `AstriaWithdrawer` and any
+    /// number of `AstriaBridgeableERC20` tokens.
+    #[must_use]
+    pub fn withdrawal_contract(mut self, withdrawal_contract: WithdrawalContract) -> Self {
+        self.withdrawal_contracts.push(withdrawal_contract);
+        self
+    }
+
+    /// Adds all `withdrawal_contracts`, see [`Self::withdrawal_contract`].
+    #[must_use]
+    pub fn withdrawal_contracts<I>(mut self, withdrawal_contracts: I) -> Self
+    where
+        I: IntoIterator<Item = WithdrawalContract>,
+    {
+        self.withdrawal_contracts.extend(withdrawal_contracts);
+        self
+    }
 }
 
 impl<P> GetWithdrawalActionsBuilder<WithProvider<P>>
@@ -229,15 +363,19 @@ where
     ///
     /// # Errors
     /// Returns an error in one of these cases:
-    /// + `contract_address` is not set
     /// + `bridge_address` is not set
     /// + `fee_asset` is not set
-    /// + neither `source_asset_to_withdraw` nor `ics20_asset_to_withdraw` are set
-    /// + `ics20_asset_to_withdraw` is set, but does not contain a ics20 channel
-    /// + the `BASE_CHAIN_ASSET_PRECISION` call on the provided `contract_address` cannot be
-    ///   executed
-    /// + the base chain asset precision retrieved from the contract at `contract_address` is
-    ///   greater than 18 (this is currently hardcoded in the smart contract).
+    /// + `contract_address` is set, but neither `sequencer_asset_to_withdraw` nor
+    ///   `ics20_asset_to_withdraw` are set
+    /// + `contract_address` is not set, but either `sequencer_asset_to_withdraw` or
+    ///   `ics20_asset_to_withdraw` are set, or no withdrawal contracts were added
+    /// + an ics20 asset to withdraw does not contain a ics20 channel
+    /// + the same kind of withdrawal of a contract is configured more than once
+    /// + a withdrawal contract was configured with an asset withdrawal divisor of zero
+    /// + the `BASE_CHAIN_ASSET_PRECISION` call on a contract without a configured asset withdrawal
+    ///   divisor cannot be executed
+    /// + the base chain asset precision retrieved from a contract is greater than 18 (this is
+    ///   currently hardcoded in the smart contract).
     pub async fn try_build(self) -> Result<GetWithdrawalActions<P>, BuildError> {
         let Self {
             provider: WithProvider(provider),
@@ -246,11 +384,9 @@ where
             fee_asset,
             sequencer_asset_to_withdraw,
             ics20_asset_to_withdraw,
+            withdrawal_contracts,
         } = self;
 
-        let Some(contract_address) = contract_address else {
-            return Err(BuildError::not_set("contract_address"));
-        };
         let Some(bridge_address) = bridge_address else {
             return Err(BuildError::not_set("bridge_address"));
         };
@@ -258,58 +394,145 @@ where
             return Err(BuildError::not_set("fee_asset"));
         };
 
-        if sequencer_asset_to_withdraw.is_none() && ics20_asset_to_withdraw.is_none() {
-            return Err(BuildError::no_withdraws_configured());
-        }
-
-        let mut ics20_source_channel = None;
-        if let Some(ics20_asset_to_withdraw) = &ics20_asset_to_withdraw {
-            ics20_source_channel.replace(
+        let mut all_withdrawal_contracts = Vec::new();
+        if let Some(contract_address) = contract_address {
+            if sequencer_asset_to_withdraw.is_none() && ics20_asset_to_withdraw.is_none() {
+                return Err(BuildError::no_withdraws_configured());
+            }
+            all_withdrawal_contracts.extend(
+                sequencer_asset_to_withdraw
+                    .map(|asset| WithdrawalContract::sequencer(contract_address, asset)),
+            );
+            all_withdrawal_contracts.extend(
                 ics20_asset_to_withdraw
-                    .last_channel()
-                    .ok_or(BuildError::ics20_asset_without_channel())?
-                    .parse()
-                    .map_err(BuildError::parse_ics20_asset_source_channel)?,
+                    .map(|asset| WithdrawalContract::ics20(contract_address, asset)),
             );
-        };
-
-        let contract =
-            i_astria_withdrawer::IAstriaWithdrawer::new(contract_address, provider.clone());
-
-        let base_chain_asset_precision = contract
-            .base_chain_asset_precision()
-            .call()
-            .await
-            .map_err(BuildError::call_base_chain_asset_precision)?;
-
-        let exponent = 18u32
-            .checked_sub(base_chain_asset_precision)
-            .ok_or_else(|| BuildError::bad_divisor(base_chain_asset_precision))?;
+        } else if withdrawal_contracts.is_empty()
+            || sequencer_asset_to_withdraw.is_some()
+            || ics20_asset_to_withdraw.is_some()
+        {
+            return Err(BuildError::not_set("contract_address"));
+        }
+        all_withdrawal_contracts.extend(withdrawal_contracts);
+
+        // contracts watched for both kinds of withdrawals only need to be queried once.
+        let mut divisors = HashMap::new();
+        let mut contracts = HashMap::new();
+        for withdrawal_contract in all_withdrawal_contracts {
+            let kind = withdrawal_contract.kind();
+            let WithdrawalContract {
+                address,
+                asset,
+                asset_withdrawal_divisor,
+            } = withdrawal_contract;
+
+            let asset_withdrawal_divisor = match asset_withdrawal_divisor {
+                Some(0) => return Err(BuildError::zero_divisor(address)),
+                Some(divisor) => divisor,
+                None => match divisors.entry(address) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        *entry.insert(get_asset_withdrawal_divisor(&provider, address).await?)
+                    }
+                },
+            };
+
+            let asset = match asset {
+                WithdrawalAsset::Ics20(denom) => {
+                    let source_channel = denom
+                        .last_channel()
+                        .ok_or(BuildError::ics20_asset_without_channel())?
+                        .parse()
+                        .map_err(BuildError::parse_ics20_asset_source_channel)?;
+                    WatchedAsset::Ics20 {
+                        denom,
+                        source_channel,
+                    }
+                }
+                WithdrawalAsset::Sequencer(denom) => WatchedAsset::Sequencer(denom),
+            };
+
+            let Entry::Vacant(entry) = contracts.entry((address, kind)) else {
+                return Err(BuildError::duplicate_contract(address, kind));
+            };
+            entry.insert(WatchedContract {
+                asset,
+                asset_withdrawal_divisor,
+            });
+        }
 
-        let asset_withdrawal_divisor = 10u128.pow(exponent);
+        let mut contract_addresses: Vec<_> =
+            contracts.keys().map(|(address, _)| *address).collect();
+        contract_addresses.sort_unstable();
+        contract_addresses.dedup();
+        let mut event_signatures = Vec::new();
+        if contracts
+            .keys()
+            .any(|(_, kind)| *kind == WithdrawalKind::Ics20)
+        {
+            event_signatures.push(Ics20WithdrawalFilter::signature());
+        }
+        if contracts
+            .keys()
+            .any(|(_, kind)| *kind == WithdrawalKind::Sequencer)
+        {
+            event_signatures.push(SequencerWithdrawalFilter::signature());
+        }
 
         Ok(GetWithdrawalActions {
             provider,
-            contract_address,
-            asset_withdrawal_divisor,
             bridge_address,
             fee_asset,
-            sequencer_asset_to_withdraw,
-            ics20_asset_to_withdraw,
-            ics20_source_channel,
+            contracts,
+            contract_addresses,
+            event_signatures,
         })
     }
 }
 
-pub struct GetWithdrawalActions<P> {
-    provider: Arc<P>,
+async fn get_asset_withdrawal_divisor<P>(
+    provider: &Arc<P>,
     contract_address: ethers::types::Address,
+) -> Result<u128, BuildError>
+where
+    P: Middleware + 'static,
+    P::Error: std::error::Error + 'static,
+{
+    let contract = i_astria_withdrawer::IAstriaWithdrawer::new(contract_address, provider.clone());
+
+    let base_chain_asset_precision = contract
+        .base_chain_asset_precision()
+        .call()
+        .await
+        .map_err(BuildError::call_base_chain_asset_precision)?;
+
+    let exponent = 18u32
+        .checked_sub(base_chain_asset_precision)
+        .ok_or_else(|| BuildError::bad_divisor(base_chain_asset_precision))?;
+
+    Ok(10u128.pow(exponent))
+}
+
+struct WatchedContract {
+    asset: WatchedAsset,
     asset_withdrawal_divisor: u128,
+}
+
+enum WatchedAsset {
+    Ics20 {
+        denom: asset::TracePrefixed,
+        source_channel: ibc_types::core::channel::ChannelId,
+    },
+    Sequencer(asset::Denom),
+}
+
+pub struct GetWithdrawalActions<P> {
+    provider: Arc<P>,
     bridge_address: Address,
     fee_asset: asset::Denom,
-    sequencer_asset_to_withdraw: Option<asset::Denom>,
-    ics20_asset_to_withdraw: Option<asset::TracePrefixed>,
-    ics20_source_channel: Option<ibc_types::core::channel::ChannelId>,
+    contracts: HashMap<(ethers::types::Address, WithdrawalKind), WatchedContract>,
+    contract_addresses: Vec<ethers::types::Address>,
+    event_signatures: Vec<H256>,
 }
 
 impl<P> GetWithdrawalActions<P>
@@ -317,63 +540,79 @@ where
     P: Middleware,
     P::Error: std::error::Error + 'static,
 {
-    fn configured_for_sequencer_withdrawals(&self) -> bool {
-        self.sequencer_asset_to_withdraw.is_some()
-    }
-
-    fn configured_for_ics20_withdrawals(&self) -> bool {
-        self.ics20_asset_to_withdraw.is_some()
-    }
-
     /// Gets all withdrawal events for `block_hash` and converts them to astria sequencer actions.
     ///
+    /// The events of all watched contracts are fetched in a single `eth_getLogs` call, and the
+    /// actions are returned in the order the events were emitted in the block.
+    ///
+    /// `block_timestamp` is the timestamp of the block in seconds since the unix epoch. ICS20
+    /// withdrawals time out 5 minutes after it, so that the same block always results in the same
+    /// actions.
+    ///
     /// # Errors
     /// Returns an error in one of the following cases:
-    /// + fetching logs for either ics20 or sequencer withdrawal events fails
+    /// + fetching logs for the withdrawal events fails
     /// + converting either event to Sequencer actions fails due to the events being malformed.
     pub async fn get_for_block_hash(
         &self,
         block_hash: H256,
+        block_timestamp: u64,
     ) -> Result<Vec<Result<Action, GetWithdrawalActionsError>>, GetWithdrawalActionsError> {
-        use futures::FutureExt as _;
-        let get_ics20_logs = if self.configured_for_ics20_withdrawals() {
-            get_logs::<Ics20WithdrawalFilter, _>(&self.provider, self.contract_address, block_hash)
-                .boxed()
-        } else {
-            futures::future::ready(Ok(vec![])).boxed()
-        };
-        let get_sequencer_logs = if self.configured_for_sequencer_withdrawals() {
-            get_logs::<SequencerWithdrawalFilter, _>(
-                &self.provider,
-                self.contract_address,
-                block_hash,
-            )
-            .boxed()
-        } else {
-            futures::future::ready(Ok(vec![])).boxed()
-        };
-        let (ics20_logs, sequencer_logs) =
-            futures::future::try_join(get_ics20_logs, get_sequencer_logs)
-                .await
-                .map_err(GetWithdrawalActionsError::get_logs)?;
-
-        // XXX: The calls to `log_to_*_action` rely on only be called if `GetWithdrawalActions`
-        // is configured for either ics20 or sequencer withdrawals (or both). They would panic
-        // otherwise.
-        Ok(ics20_logs
+        let filter = Filter::new()
+            .at_block_hash(block_hash)
+            .address(ValueOrArray::Array(self.contract_addresses.clone()))
+            .topic0(ValueOrArray::Array(
+                self.event_signatures.iter().copied().map(Some).collect(),
+            ));
+        let logs = self
+            .provider
+            .get_logs(&filter)
+            .await
+            .map_err(|err| GetWithdrawalActionsError::get_logs(GetLogsError::new(err)))?;
+
+        Ok(logs
             .into_iter()
-            .map(|log| self.log_to_ics20_withdrawal_action(log))
-            .chain(
-                sequencer_logs
-                    .into_iter()
-                    .map(|log| self.log_to_sequencer_withdrawal_action(log)),
-            )
+            .filter_map(|log| {
+                let kind = match log.topics.first() {
+                    Some(topic) if *topic == Ics20WithdrawalFilter::signature() => {
+                        WithdrawalKind::Ics20
+                    }
+                    Some(topic) if *topic == SequencerWithdrawalFilter::signature() => {
+                        WithdrawalKind::Sequencer
+                    }
+                    _ => return None,
+                };
+                // a contract emitting both kinds of events might only be watched for one of them.
+                let contract = self.contracts.get(&(log.address, kind))?;
+                let action = match &contract.asset {
+                    WatchedAsset::Ics20 {
+                        denom,
+                        source_channel,
+                    } => self.log_to_ics20_withdrawal_action(
+                        log,
+                        denom,
+                        source_channel,
+                        contract.asset_withdrawal_divisor,
+                        block_timestamp,
+                    ),
+                    WatchedAsset::Sequencer(denom) => self.log_to_sequencer_withdrawal_action(
+                        log,
+                        denom,
+                        contract.asset_withdrawal_divisor,
+                    ),
+                };
+                Some(action)
+            })
             .collect())
     }
 
     fn log_to_ics20_withdrawal_action(
         &self,
         log: Log,
+        denom: &asset::TracePrefixed,
+        source_channel: &ibc_types::core::channel::ChannelId,
+        asset_withdrawal_divisor: u128,
+        block_timestamp: u64,
     ) -> Result<Action, GetWithdrawalActionsError> {
         let rollup_block_number = log
             .block_number
@@ -394,16 +633,6 @@ where
         let event = decode_log::<Ics20WithdrawalFilter>(log)
             .map_err(GetWithdrawalActionsError::decode_log)?;
 
-        let (denom, source_channel) = (
-            self.ics20_asset_to_withdraw
-                .clone()
-                .expect("must be set if this method is entered")
-                .into(),
-            self.ics20_source_channel
-                .clone()
-                .expect("must be set if this method is entered"),
-        );
-
         let memo = memo_to_json(&memos::v1alpha1::Ics20WithdrawalFromRollup {
             memo: event.memo.clone(),
             rollup_block_number,
@@ -412,11 +641,11 @@ where
         })
         .map_err(GetWithdrawalActionsError::encode_memo)?;
 
-        let amount = calculate_amount(&event, self.asset_withdrawal_divisor)
+        let amount = calculate_amount(&event, asset_withdrawal_divisor)
             .map_err(GetWithdrawalActionsError::calculate_withdrawal_amount)?;
 
         let action = Ics20Withdrawal {
-            denom,
+            denom: denom.clone().into(),
             destination_chain_address: event.destination_chain_address,
             return_address: self.bridge_address,
             amount,
@@ -425,8 +654,8 @@ where
             // note: this refers to the timeout on the destination chain, which we are unaware of.
             // thus, we set it to the maximum possible value.
             timeout_height: max_timeout_height(),
-            timeout_time: timeout_in_5_min(),
-            source_channel,
+            timeout_time: timeout_in_5_min(block_timestamp),
+            source_channel: source_channel.clone(),
             bridge_address: Some(self.bridge_address),
             // FIXME: this needs a way to determine when to use compat address
             // https://github.com/astriaorg/astria/issues/1424
@@ -438,6 +667,8 @@ where
     fn log_to_sequencer_withdrawal_action(
         &self,
         log: Log,
+        denom: &asset::Denom,
+        asset_withdrawal_divisor: u128,
     ) -> Result<Action, GetWithdrawalActionsError> {
         let rollup_block_number = log
             .block_number
@@ -458,7 +689,7 @@ where
         let event = decode_log::<SequencerWithdrawalFilter>(log)
             .map_err(GetWithdrawalActionsError::decode_log)?;
 
-        let amount = calculate_amount(&event, self.asset_withdrawal_divisor)
+        let amount = calculate_amount(&event, asset_withdrawal_divisor)
             .map_err(GetWithdrawalActionsError::calculate_withdrawal_amount)?;
 
         let to = parse_destination_chain_as_address(&event)
@@ -560,36 +791,19 @@ fn decode_log<T: EthEvent>(log: Log) -> Result<T, DecodeLogError> {
 }
 
 #[derive(Debug, thiserror::Error)]
-#[error("failed getting the eth logs for event `{event_name}`")]
+#[error("failed getting the eth logs of withdrawal events")]
 struct GetLogsError {
-    event_name: Cow<'static, str>,
     // use a trait object instead of the error to not force the middleware
     // type parameter into the error.
     source: Box<dyn std::error::Error + Send + Sync + 'static>,
 }
 
-async fn get_logs<T: EthEvent, M>(
-    provider: &M,
-    contract_address: ethers::types::Address,
-    block_hash: H256,
-) -> Result<Vec<Log>, GetLogsError>
-where
-    M: Middleware,
-    M::Error: std::error::Error + 'static,
-{
-    let event_sig = T::signature();
-    let filter = Filter::new()
-        .at_block_hash(block_hash)
-        .address(contract_address)
-        .topic0(event_sig);
-
-    provider
-        .get_logs(&filter)
-        .await
-        .map_err(|err| GetLogsError {
-            event_name: T::name(),
-            source: err.into(),
-        })
+impl GetLogsError {
+    fn new<T: Into<Box<dyn std::error::Error + Send + Sync + 'static>>>(source: T) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
 }
 
 trait GetAmount {
@@ -663,21 +877,170 @@ fn parse_destination_chain_as_address(
     event.destination_chain_address.parse().map_err(Into::into)
 }
 
-fn timeout_in_5_min() -> u64 {
-    use std::time::Duration;
-    tendermint::Time::now()
-        .checked_add(Duration::from_secs(300))
-        .expect("adding 5 minutes to the current time should never fail")
-        .unix_timestamp_nanos()
-        .try_into()
-        .expect("timestamp must be positive, so this conversion would only fail if negative")
+/// Returns the unix timestamp in nanoseconds 5 minutes after `block_timestamp` (in seconds).
+///
+/// This is derived from the rollup block instead of the local clock so that every withdrawer
+/// deriving actions from the block, e.g. all members of a signing committee, agrees on it.
+fn timeout_in_5_min(block_timestamp: u64) -> u64 {
+    const NANOS_PER_SEC: u64 = 1_000_000_000;
+    block_timestamp
+        .saturating_add(300)
+        .saturating_mul(NANOS_PER_SEC)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::max_timeout_height;
+    use ethers::{
+        abi::Token,
+        providers::{
+            MockProvider,
+            Provider,
+        },
+    };
+
+    use super::*;
+
+    const NATIVE_CONTRACT: ethers::types::Address = ethers::types::H160([1; 20]);
+    const ERC20_CONTRACT: ethers::types::Address = ethers::types::H160([2; 20]);
+
+    fn astria_address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    fn withdrawal_log<T: EthEvent>(
+        contract: ethers::types::Address,
+        log_index: u64,
+        amount: u64,
+        data: &[Token],
+    ) -> Log {
+        Log {
+            address: contract,
+            topics: vec![
+                T::signature(),
+                H256::from(ethers::types::H160([9; 20])),
+                H256::from_low_u64_be(amount),
+            ],
+            data: ethers::abi::encode(data).into(),
+            block_number: Some(1.into()),
+            transaction_hash: Some(H256::zero()),
+            log_index: Some(log_index.into()),
+            ..Log::default()
+        }
+    }
+
+    fn builder(
+        provider: Provider<MockProvider>,
+    ) -> GetWithdrawalActionsBuilder<WithProvider<Provider<MockProvider>>> {
+        GetWithdrawalActionsBuilder::new()
+            .provider(Arc::new(provider))
+            .bridge_address(astria_address(1))
+            .fee_asset("nria".parse().unwrap())
+    }
+
     #[test]
     fn max_timeout_height_does_not_panic() {
         max_timeout_height();
     }
+
+    #[test]
+    fn events_of_all_withdrawal_contracts_are_converted() {
+        let (provider, mock) = Provider::mocked();
+        let fetcher = futures::executor::block_on(
+            builder(provider)
+                .withdrawal_contract(
+                    WithdrawalContract::sequencer(NATIVE_CONTRACT, "nria".parse().unwrap())
+                        .asset_withdrawal_divisor(1),
+                )
+                .withdrawal_contract(
+                    WithdrawalContract::ics20(
+                        ERC20_CONTRACT,
+                        "transfer/channel-0/utia".parse().unwrap(),
+                    )
+                    .asset_withdrawal_divisor(10),
+                )
+                .try_build(),
+        )
+        .unwrap();
+
+        let destination = astria_address(2).to_string();
+        mock.push::<Vec<Log>, _>(vec![
+            withdrawal_log::<SequencerWithdrawalFilter>(
+                NATIVE_CONTRACT,
+                0,
+                100,
+                &[Token::String(destination.clone())],
+            ),
+            withdrawal_log::<Ics20WithdrawalFilter>(
+                ERC20_CONTRACT,
+                1,
+                100,
+                &[
+                    Token::String("celestia1abc".to_string()),
+                    Token::String("memo".to_string()),
+                ],
+            ),
+            // the erc20 contract is not watched for sequencer withdrawals.
+            withdrawal_log::<SequencerWithdrawalFilter>(
+                ERC20_CONTRACT,
+                2,
+                100,
+                &[Token::String(destination)],
+            ),
+        ])
+        .unwrap();
+
+        let actions = futures::executor::block_on(fetcher.get_for_block_hash(H256::zero(), 1_000))
+            .unwrap()
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let [Action::BridgeUnlock(unlock), Action::Ics20Withdrawal(ics20)] = &actions[..] else {
+            panic!("expected a bridge unlock followed by an ics20 withdrawal, got {actions:?}");
+        };
+        assert_eq!(unlock.to, astria_address(2));
+        assert_eq!(unlock.amount, 100);
+        assert_eq!(ics20.amount, 10);
+        assert_eq!(ics20.source_channel.as_str(), "channel-0");
+        assert_eq!(ics20.timeout_time, 1_300_000_000_000);
+        assert_eq!(
+            ics20.denom,
+            "transfer/channel-0/utia".parse::<asset::Denom>().unwrap()
+        );
+    }
+
+    #[test]
+    fn duplicate_withdrawal_contracts_are_rejected() {
+        let (provider, _mock) = Provider::mocked();
+        let contract = WithdrawalContract::sequencer(NATIVE_CONTRACT, "nria".parse().unwrap())
+            .asset_withdrawal_divisor(1);
+        let Err(error) = futures::executor::block_on(
+            builder(provider)
+                .withdrawal_contract(contract.clone())
+                .withdrawal_contract(contract)
+                .try_build(),
+        ) else {
+            panic!("building with duplicate withdrawal contracts should fail");
+        };
+        assert!(matches!(error.0, BuildErrorKind::DuplicateContract { .. }));
+    }
+
+    #[test]
+    fn withdrawal_divisor_must_not_be_zero() {
+        let (provider, _mock) = Provider::mocked();
+        let Err(error) = futures::executor::block_on(
+            builder(provider)
+                .withdrawal_contract(
+                    WithdrawalContract::sequencer(NATIVE_CONTRACT, "nria".parse().unwrap())
+                        .asset_withdrawal_divisor(0),
+                )
+                .try_build(),
+        ) else {
+            panic!("building with a zero asset withdrawal divisor should fail");
+        };
+        assert!(matches!(error.0, BuildErrorKind::ZeroDivisor { .. }));
+    }
 }
@@ -1,8 +1,15 @@
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+};
 
+use astria_eyre::eyre;
 use axum::{
     extract::{
         FromRef,
+        FromRequestParts,
+        Path,
+        Query,
         State,
     },
     response::{
@@ -11,27 +18,60 @@ use axum::{
     },
     routing::{
         get,
+        post,
         IntoMakeService,
     },
     Json,
     Router,
 };
-use http::status::StatusCode;
+use http::{
+    header::AUTHORIZATION,
+    request::Parts,
+    status::StatusCode,
+};
 use hyper::server::conn::AddrIncoming;
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
 use tokio::sync::watch;
-use tracing::instrument;
+use tracing::{
+    error,
+    instrument,
+};
 
-use crate::bridge_withdrawer::StateSnapshot;
+use crate::bridge_withdrawer::{
+    committee::{
+        CommitRequest,
+        Commitment,
+        LocalParticipant,
+        Participant as _,
+        Share,
+        SignRequest,
+    },
+    ledger::Ledger,
+    StateSnapshot,
+    Withdrawal,
+};
 
 pub(crate) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
 
 type WithdrawerState = watch::Receiver<StateSnapshot>;
 
+/// The default number of withdrawals returned by `/withdrawals`.
+const DEFAULT_WITHDRAWALS_LIMIT: u32 = 100;
+/// The max number of withdrawals returned by `/withdrawals`.
+const MAX_WITHDRAWALS_LIMIT: u32 = 1000;
+
 #[derive(Clone)]
 /// `AppState` is used for as an axum extractor in its method handlers.
 struct AppState {
     withdrawer_state: WithdrawerState,
+    ledger: Arc<Ledger>,
 }
 
 impl FromRef<AppState> for WithdrawerState {
@@ -40,13 +80,57 @@ impl FromRef<AppState> for WithdrawerState {
     }
 }
 
-pub(crate) fn start(socket_addr: SocketAddr, withdrawer_state: WithdrawerState) -> ApiServer {
+impl FromRef<AppState> for Arc<Ledger> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.ledger.clone()
+    }
+}
+
+#[derive(Clone)]
+/// `CommitteeState` is used as an axum extractor in the committee API's method handlers.
+struct CommitteeState {
+    participant: Arc<LocalParticipant>,
+    /// The SHA-256 digest of the committee's shared secret.
+    secret_digest: [u8; 32],
+}
+
+impl FromRef<CommitteeState> for Arc<LocalParticipant> {
+    fn from_ref(committee_state: &CommitteeState) -> Self {
+        committee_state.participant.clone()
+    }
+}
+
+pub(crate) fn start(
+    socket_addr: SocketAddr,
+    withdrawer_state: WithdrawerState,
+    ledger: Arc<Ledger>,
+) -> ApiServer {
     let app = Router::new()
         .route("/healthz", get(get_healthz))
         .route("/readyz", get(get_readyz))
         .route("/status", get(get_status))
+        .route("/withdrawals", get(get_withdrawals))
+        .route("/withdrawals/:event_id", get(get_withdrawal))
         .with_state(AppState {
             withdrawer_state,
+            ledger,
+        });
+    axum::Server::bind(&socket_addr).serve(app.into_make_service())
+}
+
+/// Starts the API through which the committee's coordinator runs the signing rounds with this
+/// member. Only requests carrying `secret` as bearer token are served.
+pub(crate) fn start_committee(
+    socket_addr: SocketAddr,
+    participant: Arc<LocalParticipant>,
+    secret: &str,
+) -> ApiServer {
+    let app = Router::new()
+        .route("/committee/commit", post(post_committee_commit))
+        .route("/committee/sign", post(post_committee_sign))
+        .with_state(CommitteeState {
+            participant,
+            secret_digest: Sha256::digest(secret.as_bytes()).into(),
         });
     axum::Server::bind(&socket_addr).serve(app.into_make_service())
 }
@@ -84,6 +168,127 @@ async fn get_status(State(withdrawer_state): State<WithdrawerState>) -> Json<Sta
     Json(withdrawer_state.borrow().clone())
 }
 
+#[derive(Debug, Deserialize)]
+struct WithdrawalsQuery {
+    #[serde(default)]
+    from_rollup_height: u64,
+    limit: Option<u32>,
+}
+
+/// Handler of a call to `/withdrawals`.
+///
+/// Returns the withdrawals recorded in the ledger at or above the `from_rollup_height` query
+/// parameter, ordered by rollup height and limited to `limit` entries.
+#[instrument(skip_all)]
+async fn get_withdrawals(
+    State(ledger): State<Arc<Ledger>>,
+    Query(query): Query<WithdrawalsQuery>,
+) -> Result<Json<Vec<Withdrawal>>, LedgerError> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_WITHDRAWALS_LIMIT)
+        .min(MAX_WITHDRAWALS_LIMIT);
+    ledger
+        .withdrawals(query.from_rollup_height, Some(limit))
+        .await
+        .map(Json)
+        .map_err(LedgerError::Internal)
+}
+
+/// Handler of a call to `/withdrawals/:event_id`.
+#[instrument(skip_all, fields(%event_id))]
+async fn get_withdrawal(
+    State(ledger): State<Arc<Ledger>>,
+    Path(event_id): Path<String>,
+) -> Result<Json<Withdrawal>, LedgerError> {
+    ledger
+        .withdrawal(&event_id)
+        .await
+        .map_err(LedgerError::Internal)?
+        .map(Json)
+        .ok_or(LedgerError::NotFound)
+}
+
+enum LedgerError {
+    NotFound,
+    Internal(eyre::Report),
+}
+
+impl IntoResponse for LedgerError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound => (StatusCode::NOT_FOUND, "withdrawal not found").into_response(),
+            Self::Internal(error) => {
+                error!(%error, "failed to read withdrawals from ledger");
+                (StatusCode::INTERNAL_SERVER_ERROR, "failed to read ledger").into_response()
+            }
+        }
+    }
+}
+
+/// A request carrying the committee's shared secret as bearer token.
+struct Authenticated;
+
+#[axum::async_trait]
+impl FromRequestParts<CommitteeState> for Authenticated {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &CommitteeState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+        // compare digests so that the time taken does not reveal how much of the secret matched
+        if Sha256::digest(token.as_bytes()).as_slice() == state.secret_digest.as_slice() {
+            Ok(Self)
+        } else {
+            Err(StatusCode::UNAUTHORIZED)
+        }
+    }
+}
+
+/// Handler of a call to `/committee/commit` by the committee's coordinator.
+#[instrument(skip_all, fields(rollup_height = request.rollup_height))]
+async fn post_committee_commit(
+    _: Authenticated,
+    State(participant): State<Arc<LocalParticipant>>,
+    Json(request): Json<CommitRequest>,
+) -> Result<Json<Commitment>, CommitteeError> {
+    participant
+        .commit(&request)
+        .await
+        .map(Json)
+        .map_err(CommitteeError)
+}
+
+/// Handler of a call to `/committee/sign` by the committee's coordinator.
+#[instrument(skip_all, fields(rollup_height = request.rollup_height))]
+async fn post_committee_sign(
+    _: Authenticated,
+    State(participant): State<Arc<LocalParticipant>>,
+    Json(request): Json<SignRequest>,
+) -> Result<Json<Share>, CommitteeError> {
+    participant
+        .sign(&request)
+        .await
+        .map(Json)
+        .map_err(CommitteeError)
+}
+
+/// A committee member refusing to take part in signing.
+struct CommitteeError(eyre::Report);
+
+impl IntoResponse for CommitteeError {
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, format!("{:#}", self.0)).into_response()
+    }
+}
+
 enum Healthz {
     Ok,
     Degraded,
@@ -0,0 +1,140 @@
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+};
+
+use astria_core::{
+    crypto::VerificationKey,
+    protocol::transaction::v1alpha1::{
+        SignedTransaction,
+        UnsignedTransaction,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use frost_ed25519::{
+    keys::PublicKeyPackage,
+    SigningPackage,
+};
+use futures::future::{
+    join_all,
+    try_join_all,
+};
+use prost::Message as _;
+use tracing::{
+    instrument,
+    warn,
+};
+
+use super::{
+    group_verification_key,
+    into_signed,
+    CommitRequest,
+    Participant,
+    SignRequest,
+};
+
+/// Collects signature shares from committee members and aggregates them into a transaction
+/// signed by the committee's group key.
+pub(crate) struct Coordinator {
+    participants: Vec<Arc<dyn Participant>>,
+    public_key_package: PublicKeyPackage,
+    verification_key: VerificationKey,
+    min_signers: usize,
+}
+
+impl Coordinator {
+    pub(crate) fn new(
+        participants: Vec<Arc<dyn Participant>>,
+        public_key_package: PublicKeyPackage,
+        min_signers: usize,
+    ) -> eyre::Result<Self> {
+        ensure!(
+            participants.len() >= min_signers,
+            "committee has {} members, but {min_signers} are required to sign",
+            participants.len(),
+        );
+        let verification_key = group_verification_key(&public_key_package)?;
+        Ok(Self {
+            participants,
+            public_key_package,
+            verification_key,
+            min_signers,
+        })
+    }
+
+    /// Signs `transaction`, which was built for the batch at `rollup_height`.
+    ///
+    /// The first `min_signers` members to commit are asked for their signature shares.
+    ///
+    /// # Errors
+    /// - If fewer than `min_signers` members commit, e.g. because they have not yet derived the
+    ///   batch.
+    /// - If any selected member refuses to sign, or the shares cannot be aggregated.
+    #[instrument(skip_all, fields(rollup_height), err)]
+    pub(crate) async fn sign(
+        &self,
+        rollup_height: u64,
+        transaction: UnsignedTransaction,
+    ) -> eyre::Result<SignedTransaction> {
+        let commit_request = CommitRequest {
+            rollup_height,
+            transaction: transaction.to_raw(),
+        };
+        let commitments = join_all(
+            self.participants
+                .iter()
+                .map(|participant| participant.commit(&commit_request)),
+        )
+        .await;
+        let mut signers = Vec::with_capacity(self.min_signers);
+        let mut signing_commitments = BTreeMap::new();
+        let mut commit_error = None;
+        for (participant, commitment) in self.participants.iter().zip(commitments) {
+            if signers.len() == self.min_signers {
+                break;
+            }
+            match commitment {
+                Ok(commitment) => {
+                    signing_commitments.insert(commitment.identifier, commitment.commitments);
+                    signers.push(participant.clone());
+                }
+                Err(error) => {
+                    warn!(%error, "committee member failed to commit to signing");
+                    commit_error.get_or_insert(error);
+                }
+            }
+        }
+        if signers.len() < self.min_signers {
+            let error = commit_error.unwrap_or_else(|| eyre!("no committee member refused"));
+            return Err(error.wrap_err(format!(
+                "only {} committee members committed to signing, but {} are required",
+                signers.len(),
+                self.min_signers,
+            )));
+        }
+
+        let request = SignRequest {
+            rollup_height,
+            signing_package: SigningPackage::new(
+                signing_commitments,
+                &commit_request.transaction.encode_to_vec(),
+            ),
+        };
+        let shares = try_join_all(signers.iter().map(|signer| signer.sign(&request)))
+            .await
+            .wrap_err("committee member failed to sign")?
+            .into_iter()
+            .map(|share| (share.identifier, share.signature_share))
+            .collect();
+        let signature =
+            frost_ed25519::aggregate(&request.signing_package, &shares, &self.public_key_package)
+                .wrap_err("failed to aggregate signature shares")?;
+
+        into_signed(transaction, &signature, &self.verification_key)
+    }
+}
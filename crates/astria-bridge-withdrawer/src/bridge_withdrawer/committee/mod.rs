@@ -0,0 +1,471 @@
+//! Threshold signing of withdrawal transactions by a committee of withdrawer nodes.
+//!
+//! In committee mode no single node holds the key of the bridge withdrawer address. Instead, each
+//! member holds a FROST ed25519 key share and independently derives withdrawal batches from the
+//! rollup. One member acts as the coordinator: it asks the other members to sign the transaction
+//! it built for a batch, aggregates the signature shares into a single ed25519 signature, and
+//! submits the transaction. Members only sign transactions whose actions match the batch they
+//! derived themselves for the same rollup height, and whose chain ID and nonce match the sequencer
+//! chain and the pending nonce of the committee's address. This requires that all members derive
+//! the same actions from a rollup block, which is why ICS20 withdrawals time out relative to the
+//! rollup block timestamp rather than the local clock.
+//!
+//! Members serve the signing rounds on a dedicated committee API, which only accepts requests
+//! carrying the committee's shared secret.
+use std::{
+    fs,
+    path::Path,
+    sync::Arc,
+};
+
+use astria_core::{
+    crypto::VerificationKey,
+    generated::{
+        protocol::transactions::v1alpha1 as raw,
+        sequencerblock::v1alpha1::sequencer_service_client::SequencerServiceClient,
+    },
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::{
+        SignedTransaction,
+        UnsignedTransaction,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use frost_ed25519::{
+    keys::{
+        KeyPackage,
+        PublicKeyPackage,
+    },
+    round1::SigningCommitments,
+    round2::SignatureShare,
+    Identifier,
+    SigningPackage,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tonic::transport::Channel;
+
+pub(crate) use self::{
+    coordinator::Coordinator,
+    participant::{
+        LocalParticipant,
+        Participant,
+        RemoteParticipant,
+        SequencerNonceSource,
+    },
+};
+
+mod coordinator;
+mod participant;
+
+/// Asks a committee member to commit to signing `transaction`, which was built for the batch at
+/// `rollup_height`.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct CommitRequest {
+    pub(crate) rollup_height: u64,
+    pub(crate) transaction: raw::UnsignedTransaction,
+}
+
+/// A member's round one signing commitments.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct Commitment {
+    pub(crate) identifier: Identifier,
+    pub(crate) commitments: SigningCommitments,
+}
+
+/// Asks a committee member to sign the transaction for the batch at `rollup_height`.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct SignRequest {
+    pub(crate) rollup_height: u64,
+    pub(crate) signing_package: SigningPackage,
+}
+
+/// A member's round two signature share.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct Share {
+    pub(crate) identifier: Identifier,
+    pub(crate) signature_share: SignatureShare,
+}
+
+/// This node's membership in the withdrawer committee.
+pub(crate) struct Member {
+    address: Address,
+    participant: Arc<LocalParticipant>,
+    coordinator: Option<Coordinator>,
+}
+
+impl Member {
+    /// Loads this node's key share and the committee's public key package.
+    ///
+    /// Both files must contain the JSON serialization of the respective `frost-ed25519` type. If
+    /// `peer_endpoints` is not empty, this member acts as the coordinator of the committee and
+    /// requests signature shares from the members serving their committee API at the given
+    /// endpoints, authenticating with `secret`.
+    ///
+    /// This member only signs transactions for `chain_id` using the pending nonce of the
+    /// committee's address, as reported by `sequencer_grpc_client`.
+    ///
+    /// # Errors
+    /// - If the files cannot be read or parsed.
+    /// - If the key share does not belong to the committee's group key.
+    /// - If there are fewer members than required to sign.
+    pub(crate) fn load<P: AsRef<Path>>(
+        key_share_path: P,
+        public_key_package_path: P,
+        peer_endpoints: Vec<String>,
+        secret: &str,
+        address_prefix: &str,
+        chain_id: String,
+        sequencer_grpc_client: SequencerServiceClient<Channel>,
+    ) -> eyre::Result<Self> {
+        let key_package: KeyPackage =
+            read_json(key_share_path.as_ref()).wrap_err("failed to read committee key share")?;
+        let public_key_package: PublicKeyPackage = read_json(public_key_package_path.as_ref())
+            .wrap_err("failed to read committee public key package")?;
+        ensure!(
+            key_package.verifying_key() == public_key_package.verifying_key(),
+            "committee key share does not belong to the group key of the public key package"
+        );
+
+        let verification_key = group_verification_key(&public_key_package)?;
+        let address = Address::builder()
+            .array(verification_key.address_bytes())
+            .prefix(address_prefix)
+            .try_build()
+            .wrap_err_with(|| {
+                format!(
+                    "failed constructing valid sequencer address using the provided prefix \
+                     `{address_prefix}`"
+                )
+            })?;
+
+        let min_signers = usize::from(*key_package.min_signers());
+        let nonce_source = Arc::new(SequencerNonceSource::new(sequencer_grpc_client, address));
+        let participant = Arc::new(LocalParticipant::new(key_package, chain_id, nonce_source));
+        let coordinator = if peer_endpoints.is_empty() {
+            None
+        } else {
+            let mut participants = vec![participant.clone() as Arc<dyn Participant>];
+            for endpoint in peer_endpoints {
+                participants.push(Arc::new(
+                    RemoteParticipant::new(&endpoint, secret.to_string()).wrap_err_with(|| {
+                        format!("failed to construct client for committee member `{endpoint}`")
+                    })?,
+                ));
+            }
+            Some(Coordinator::new(
+                participants,
+                public_key_package,
+                min_signers,
+            )?)
+        };
+
+        Ok(Self {
+            address,
+            participant,
+            coordinator,
+        })
+    }
+
+    /// The bridge withdrawer address controlled by the committee's group key.
+    pub(crate) fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub(crate) fn participant(&self) -> Arc<LocalParticipant> {
+        self.participant.clone()
+    }
+
+    /// Returns the coordinator if this member assembles and submits transactions.
+    pub(crate) fn coordinator(&self) -> Option<&Coordinator> {
+        self.coordinator.as_ref()
+    }
+}
+
+/// Converts the committee's FROST group key into the sequencer's ed25519 verification key.
+fn group_verification_key(public_key_package: &PublicKeyPackage) -> eyre::Result<VerificationKey> {
+    let bytes: [u8; 32] = public_key_package
+        .verifying_key()
+        .serialize()
+        .wrap_err("failed to serialize committee group key")?
+        .try_into()
+        .map_err(|_| eyre!("committee group key must be 32 bytes"))?;
+    VerificationKey::try_from(bytes).wrap_err("committee group key is not a valid ed25519 key")
+}
+
+/// Attaches an aggregated committee signature to `transaction`.
+fn into_signed(
+    transaction: UnsignedTransaction,
+    signature: &frost_ed25519::Signature,
+    verification_key: &VerificationKey,
+) -> eyre::Result<SignedTransaction> {
+    let signature = signature
+        .serialize()
+        .wrap_err("failed to serialize aggregated signature")?;
+    SignedTransaction::try_from_raw(raw::SignedTransaction {
+        signature: signature.into(),
+        public_key: verification_key.to_bytes().to_vec().into(),
+        transaction: Some(transaction.into_any()),
+    })
+    .wrap_err("aggregated signature does not verify under the committee group key")
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> eyre::Result<T> {
+    let contents = fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read file at `{}`", path.display()))?;
+    serde_json::from_str(&contents)
+        .wrap_err_with(|| format!("failed to parse file at `{}` as JSON", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::execution::v1alpha2::{
+            RollupBlockWithdrawals,
+            RollupWithdrawal,
+        },
+        primitive::v1::asset::Denom,
+        protocol::transaction::v1alpha1::{
+            action::TransferAction,
+            Action,
+            TransactionParams,
+        },
+    };
+    use frost_ed25519::keys::IdentifierList;
+
+    use super::{
+        participant::NonceSource,
+        *,
+    };
+    use crate::bridge_withdrawer::grpc::Converter;
+
+    const CHAIN_ID: &str = "test-sequencer";
+
+    /// A nonce source reporting a fixed pending nonce.
+    struct FixedNonce(u32);
+
+    #[async_trait::async_trait]
+    impl NonceSource for FixedNonce {
+        async fn pending_nonce(&self) -> eyre::Result<u32> {
+            Ok(self.0)
+        }
+    }
+
+    fn committee(
+        max_signers: u16,
+        min_signers: u16,
+    ) -> (Vec<Arc<LocalParticipant>>, PublicKeyPackage) {
+        let (shares, public_key_package) = frost_ed25519::keys::generate_with_dealer(
+            max_signers,
+            min_signers,
+            IdentifierList::Default,
+            rand::thread_rng(),
+        )
+        .unwrap();
+        let participants = shares
+            .into_values()
+            .map(|share| {
+                Arc::new(LocalParticipant::new(
+                    KeyPackage::try_from(share).unwrap(),
+                    CHAIN_ID.to_string(),
+                    Arc::new(FixedNonce(0)),
+                ))
+            })
+            .collect();
+        (participants, public_key_package)
+    }
+
+    fn coordinator(
+        participants: &[Arc<LocalParticipant>],
+        public_key_package: PublicKeyPackage,
+        min_signers: usize,
+    ) -> Coordinator {
+        let participants = participants
+            .iter()
+            .map(|participant| participant.clone() as Arc<dyn Participant>)
+            .collect();
+        Coordinator::new(participants, public_key_package, min_signers).unwrap()
+    }
+
+    fn batch(amount: u128) -> Vec<Action> {
+        let denom: Denom = "nria".parse().unwrap();
+        vec![Action::Transfer(TransferAction {
+            to: Address::builder()
+                .array([1; 20])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+            amount,
+            asset: denom.clone(),
+            fee_asset: denom,
+        })]
+    }
+
+    fn transaction_with_params(
+        actions: Vec<Action>,
+        nonce: u32,
+        chain_id: &str,
+    ) -> UnsignedTransaction {
+        UnsignedTransaction {
+            actions,
+            params: TransactionParams::builder()
+                .nonce(nonce)
+                .chain_id(chain_id)
+                .build(),
+        }
+    }
+
+    fn transaction(actions: Vec<Action>) -> UnsignedTransaction {
+        transaction_with_params(actions, 0, CHAIN_ID)
+    }
+
+    #[tokio::test]
+    async fn aggregated_signature_verifies_under_group_key() {
+        let (participants, public_key_package) = committee(3, 2);
+        let group_key = group_verification_key(&public_key_package).unwrap();
+        for participant in &participants {
+            participant.record_batch(1, &batch(100));
+        }
+        let coordinator = coordinator(&participants, public_key_package, 2);
+
+        let signed = coordinator.sign(1, transaction(batch(100))).await.unwrap();
+        assert_eq!(signed.verification_key(), &group_key);
+        assert_eq!(signed.address_bytes(), group_key.address_bytes());
+    }
+
+    #[tokio::test]
+    async fn members_sign_ics20_withdrawals_they_derived_independently() {
+        let (participants, public_key_package) = committee(3, 2);
+        let block = RollupBlockWithdrawals {
+            rollup_height: 1,
+            withdrawals: vec![RollupWithdrawal {
+                rollup_withdrawal_event_id: "event".to_string(),
+                amount: Some(100u128.into()),
+                destination_chain_address: "celestia1abc".to_string(),
+                ics20: true,
+                rollup_return_address: "rollup-sender".to_string(),
+                memo: String::new(),
+            }],
+            timestamp: Some(pbjson_types::Timestamp {
+                seconds: 1_000,
+                nanos: 0,
+            }),
+        };
+        let derive_batch = || {
+            Converter::new(
+                "transfer/channel-0/utia".parse().unwrap(),
+                Address::builder()
+                    .array([2; 20])
+                    .prefix("astria")
+                    .try_build()
+                    .unwrap(),
+                "nria".parse().unwrap(),
+            )
+            .unwrap()
+            .to_batch(block.clone())
+            .actions
+        };
+        // every member derives the batch from the rollup block on its own
+        for participant in &participants {
+            participant.record_batch(1, &derive_batch());
+        }
+        let actions = derive_batch();
+        assert!(
+            matches!(actions[..], [Action::Ics20Withdrawal(_)]),
+            "{actions:?}"
+        );
+        let coordinator = coordinator(&participants, public_key_package, 2);
+
+        coordinator.sign(1, transaction(actions)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn members_refuse_to_sign_actions_they_did_not_derive() {
+        let (participants, public_key_package) = committee(3, 2);
+        for participant in &participants {
+            participant.record_batch(1, &batch(100));
+        }
+        let coordinator = coordinator(&participants, public_key_package, 2);
+
+        let error = coordinator
+            .sign(1, transaction(batch(1_000_000)))
+            .await
+            .unwrap_err();
+        assert!(
+            format!("{error:#}").contains("do not match the batch"),
+            "{error:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn members_without_the_batch_do_not_commit() {
+        let (participants, public_key_package) = committee(3, 2);
+        participants[0].record_batch(1, &batch(100));
+        let coordinator = coordinator(&participants, public_key_package.clone(), 2);
+        coordinator
+            .sign(1, transaction(batch(100)))
+            .await
+            .unwrap_err();
+
+        // the signature can be produced once a second member derived the batch
+        participants[2].record_batch(1, &batch(100));
+        coordinator.sign(1, transaction(batch(100))).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn members_refuse_to_sign_for_another_chain_or_nonce() {
+        let (participants, public_key_package) = committee(3, 2);
+        for participant in &participants {
+            participant.record_batch(1, &batch(100));
+        }
+        let coordinator = coordinator(&participants, public_key_package, 2);
+
+        let error = coordinator
+            .sign(1, transaction_with_params(batch(100), 0, "other-chain"))
+            .await
+            .unwrap_err();
+        assert!(
+            format!("{error:#}").contains("the committee signs for chain"),
+            "{error:#}"
+        );
+
+        let error = coordinator
+            .sign(1, transaction_with_params(batch(100), 1, CHAIN_ID))
+            .await
+            .unwrap_err();
+        assert!(
+            format!("{error:#}").contains("pending nonce of the committee address"),
+            "{error:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn members_sign_a_single_transaction_per_rollup_height() {
+        let (participants, public_key_package) = committee(2, 2);
+        for participant in &participants {
+            participant.record_batch(2, &batch(100));
+        }
+        let coordinator = coordinator(&participants, public_key_package, 2);
+        coordinator.sign(2, transaction(batch(100))).await.unwrap();
+
+        // signing the same transaction again is fine, a different one is not
+        coordinator.sign(2, transaction(batch(100))).await.unwrap();
+        let mut other = transaction(batch(100));
+        other.actions.extend(batch(1));
+        for participant in &participants {
+            participant.record_batch(2, &other.actions);
+        }
+        let error = coordinator.sign(2, other).await.unwrap_err();
+        assert!(
+            format!("{error:#}").contains("a different transaction was already signed"),
+            "{error:#}"
+        );
+    }
+}
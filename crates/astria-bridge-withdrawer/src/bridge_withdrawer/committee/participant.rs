@@ -0,0 +1,334 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    generated::{
+        protocol::transactions::v1alpha1 as raw,
+        sequencerblock::v1alpha1::{
+            sequencer_service_client::SequencerServiceClient,
+            GetPendingNonceRequest,
+        },
+    },
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::Action,
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    OptionExt as _,
+    WrapErr as _,
+};
+use frost_ed25519::{
+    keys::KeyPackage,
+    round1::{
+        self,
+        SigningNonces,
+    },
+    round2,
+};
+use prost::Message as _;
+use tonic::transport::Channel;
+use tracing::instrument;
+
+use super::{
+    CommitRequest,
+    Commitment,
+    Share,
+    SignRequest,
+};
+
+/// The max number of derived batches a member keeps around waiting to be signed.
+const MAX_PENDING_BATCHES: usize = 1024;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A committee member taking part in signing a withdrawal transaction.
+#[async_trait::async_trait]
+pub(crate) trait Participant: Send + Sync {
+    /// Runs round one of FROST signing for the transaction in `request`.
+    async fn commit(&self, request: &CommitRequest) -> eyre::Result<Commitment>;
+
+    /// Runs round two of FROST signing, returning the member's signature share.
+    async fn sign(&self, request: &SignRequest) -> eyre::Result<Share>;
+}
+
+/// Looks up the nonce that the committee's next transaction must use.
+#[async_trait::async_trait]
+pub(crate) trait NonceSource: Send + Sync {
+    async fn pending_nonce(&self) -> eyre::Result<u32>;
+}
+
+/// Reads the pending nonce of the committee's address from the sequencer.
+pub(crate) struct SequencerNonceSource {
+    client: SequencerServiceClient<Channel>,
+    address: Address,
+}
+
+impl SequencerNonceSource {
+    pub(crate) fn new(client: SequencerServiceClient<Channel>, address: Address) -> Self {
+        Self {
+            client,
+            address,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl NonceSource for SequencerNonceSource {
+    #[instrument(skip_all, fields(address = %self.address), err)]
+    async fn pending_nonce(&self) -> eyre::Result<u32> {
+        self.client
+            .clone()
+            .get_pending_nonce(GetPendingNonceRequest {
+                address: Some(self.address.into_raw()),
+            })
+            .await
+            .map(|rsp| rsp.into_inner().inner)
+            .wrap_err("failed to get pending nonce of the committee address from the sequencer")
+    }
+}
+
+/// A round one signing session for a transaction.
+struct Session {
+    message: Vec<u8>,
+    nonces: SigningNonces,
+}
+
+/// The committee member running in this process.
+///
+/// It only commits to signing transactions for the sequencer chain it is configured with, using
+/// the pending nonce of the committee's address and containing exactly the actions of a batch it
+/// has recorded itself for the same rollup height. Once it signed a transaction for a rollup
+/// height, it refuses to sign a different one for the same height.
+pub(crate) struct LocalParticipant {
+    key_package: KeyPackage,
+    chain_id: String,
+    nonce_source: Arc<dyn NonceSource>,
+    batches: Mutex<BTreeMap<u64, Vec<raw::Action>>>,
+    sessions: Mutex<HashMap<u64, Session>>,
+    /// The messages this member signed, keyed by rollup height.
+    signed: Mutex<BTreeMap<u64, Vec<u8>>>,
+}
+
+impl LocalParticipant {
+    pub(crate) fn new(
+        key_package: KeyPackage,
+        chain_id: String,
+        nonce_source: Arc<dyn NonceSource>,
+    ) -> Self {
+        Self {
+            key_package,
+            chain_id,
+            nonce_source,
+            batches: Mutex::new(BTreeMap::new()),
+            sessions: Mutex::new(HashMap::new()),
+            signed: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Records the actions this member derived from the rollup block at `rollup_height`.
+    pub(crate) fn record_batch(&self, rollup_height: u64, actions: &[Action]) {
+        let mut batches = self
+            .batches
+            .lock()
+            .expect("batches lock must not be poisoned");
+        batches.insert(rollup_height, actions.iter().map(Action::to_raw).collect());
+        while batches.len() > MAX_PENDING_BATCHES {
+            batches.pop_first();
+        }
+    }
+
+    /// Checks that `transaction` may be signed for the batch at `rollup_height`, returning the
+    /// message to sign.
+    async fn check_transaction(
+        &self,
+        rollup_height: u64,
+        transaction: &raw::UnsignedTransaction,
+    ) -> eyre::Result<Vec<u8>> {
+        let params = transaction
+            .params
+            .as_ref()
+            .ok_or_eyre("transaction has no params")?;
+        ensure!(
+            params.chain_id == self.chain_id,
+            "transaction is for chain `{}`, but the committee signs for chain `{}`",
+            params.chain_id,
+            self.chain_id,
+        );
+        {
+            let batches = self
+                .batches
+                .lock()
+                .expect("batches lock must not be poisoned");
+            let expected = batches
+                .get(&rollup_height)
+                .ok_or_eyre("no batch derived for the rollup height")?;
+            ensure!(
+                &transaction.actions == expected,
+                "transaction actions do not match the batch derived for the rollup height"
+            );
+        }
+        let message = transaction.encode_to_vec();
+        if let Some(signed) = self
+            .signed
+            .lock()
+            .expect("signed lock must not be poisoned")
+            .get(&rollup_height)
+        {
+            ensure!(
+                *signed == message,
+                "a different transaction was already signed for the rollup height"
+            );
+        }
+        let pending_nonce = self.nonce_source.pending_nonce().await?;
+        ensure!(
+            params.nonce == pending_nonce,
+            "transaction has nonce `{}`, but the pending nonce of the committee address is `{}`",
+            params.nonce,
+            pending_nonce,
+        );
+        Ok(message)
+    }
+
+    async fn commit_checked(&self, request: &CommitRequest) -> eyre::Result<Commitment> {
+        let message = self
+            .check_transaction(request.rollup_height, &request.transaction)
+            .await?;
+        let (nonces, commitments) =
+            round1::commit(self.key_package.signing_share(), &mut rand::thread_rng());
+        // a previous signing session for the same height is abandoned; its nonces must never be
+        // used again.
+        self.sessions
+            .lock()
+            .expect("sessions lock must not be poisoned")
+            .insert(
+                request.rollup_height,
+                Session {
+                    message,
+                    nonces,
+                },
+            );
+        Ok(Commitment {
+            identifier: *self.key_package.identifier(),
+            commitments,
+        })
+    }
+
+    fn sign_sync(&self, request: &SignRequest) -> eyre::Result<Share> {
+        let SignRequest {
+            rollup_height,
+            signing_package,
+        } = request;
+        let Session {
+            message,
+            nonces,
+        } = self
+            .sessions
+            .lock()
+            .expect("sessions lock must not be poisoned")
+            .remove(rollup_height)
+            .ok_or_eyre("no commitment was made for the rollup height")?;
+        ensure!(
+            signing_package.message() == &message,
+            "message to sign is not the transaction that was committed to"
+        );
+
+        let signature_share = round2::sign(signing_package, &nonces, &self.key_package)
+            .wrap_err("failed to create signature share")?;
+        self.batches
+            .lock()
+            .expect("batches lock must not be poisoned")
+            .retain(|height, _| height >= rollup_height);
+        let mut signed = self
+            .signed
+            .lock()
+            .expect("signed lock must not be poisoned");
+        signed.insert(*rollup_height, message);
+        while signed.len() > MAX_PENDING_BATCHES {
+            signed.pop_first();
+        }
+        Ok(Share {
+            identifier: *self.key_package.identifier(),
+            signature_share,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Participant for LocalParticipant {
+    async fn commit(&self, request: &CommitRequest) -> eyre::Result<Commitment> {
+        self.commit_checked(request).await
+    }
+
+    async fn sign(&self, request: &SignRequest) -> eyre::Result<Share> {
+        self.sign_sync(request)
+    }
+}
+
+/// A committee member reached over its committee API server.
+pub(crate) struct RemoteParticipant {
+    client: reqwest::Client,
+    commit_url: String,
+    sign_url: String,
+    secret: String,
+}
+
+impl RemoteParticipant {
+    /// Creates a client for the committee API at `endpoint`, authenticating with `secret`.
+    pub(crate) fn new(endpoint: &str, secret: String) -> eyre::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .wrap_err("failed to build http client")?;
+        let endpoint = endpoint.trim_end_matches('/');
+        Ok(Self {
+            client,
+            commit_url: format!("{endpoint}/committee/commit"),
+            sign_url: format!("{endpoint}/committee/sign"),
+            secret,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Participant for RemoteParticipant {
+    #[instrument(skip_all, fields(url = %self.commit_url), err)]
+    async fn commit(&self, request: &CommitRequest) -> eyre::Result<Commitment> {
+        self.client
+            .post(&self.commit_url)
+            .bearer_auth(&self.secret)
+            .json(request)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .wrap_err("commit request failed")?
+            .json()
+            .await
+            .wrap_err("failed to decode commitment")
+    }
+
+    #[instrument(skip_all, fields(url = %self.sign_url), err)]
+    async fn sign(&self, request: &SignRequest) -> eyre::Result<Share> {
+        self.client
+            .post(&self.sign_url)
+            .bearer_auth(&self.secret)
+            .json(request)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .wrap_err("sign request failed")?
+            .json()
+            .await
+            .wrap_err("failed to decode signature share")
+    }
+}
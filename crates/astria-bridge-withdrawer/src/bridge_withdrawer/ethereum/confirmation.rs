@@ -0,0 +1,229 @@
+//! Tracking of rollup blocks that are not yet confirmed.
+//!
+//! Withdrawal events must only be forwarded to the sequencer once their rollup block can no
+//! longer be reorged out, because the funds released on the sequencer cannot be taken back.
+//! [`PendingBlocks`] buffers the blocks observed since the last forwarded block by height,
+//! detects changes of the canonical chain by comparing parent hashes, and discards buffered blocks
+//! that were replaced by a reorg before their events were forwarded.
+use std::collections::BTreeMap;
+
+use astria_eyre::eyre::{
+    self,
+    OptionExt as _,
+};
+use ethers::types::{
+    Block,
+    H256,
+};
+
+/// When a rollup block is considered confirmed and its withdrawal events are forwarded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Confirmation {
+    /// A block is confirmed once this many blocks were built on top of it. A depth of 0 forwards
+    /// every block as soon as it is seen.
+    Depth(u64),
+    /// A block is confirmed once it is at or below the rollup's finalized block.
+    Finalized,
+}
+
+/// The height, hashes and timestamp of a rollup block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct BlockInfo {
+    pub(crate) height: u64,
+    pub(crate) hash: H256,
+    pub(crate) parent_hash: H256,
+    /// The block timestamp in seconds since the unix epoch.
+    pub(crate) timestamp: u64,
+}
+
+impl TryFrom<&Block<H256>> for BlockInfo {
+    type Error = eyre::Report;
+
+    fn try_from(block: &Block<H256>) -> eyre::Result<Self> {
+        Ok(Self {
+            height: block
+                .number
+                .ok_or_eyre("block did not contain a rollup height")?
+                .as_u64(),
+            hash: block.hash.ok_or_eyre("block did not contain a hash")?,
+            parent_hash: block.parent_hash,
+            timestamp: u64::try_from(block.timestamp)
+                .ok()
+                .ok_or_eyre("block timestamp does not fit into u64")?,
+        })
+    }
+}
+
+/// How a block relates to the blocks known to [`PendingBlocks`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Link {
+    /// The block is already known.
+    Known,
+    /// The block's parent is known, so the block can be inserted.
+    Connected,
+    /// The block's parent is not known and has to be fetched first.
+    Disconnected,
+}
+
+/// The rollup blocks observed after the last block whose events were forwarded (the tip).
+///
+/// The buffered blocks always form a chain descending from the tip.
+#[derive(Debug)]
+pub(crate) struct PendingBlocks {
+    tip_height: u64,
+    tip_hash: H256,
+    blocks: BTreeMap<u64, BlockInfo>,
+}
+
+impl PendingBlocks {
+    /// Creates an empty buffer on top of the last forwarded block.
+    pub(crate) fn new(tip_height: u64, tip_hash: H256) -> Self {
+        Self {
+            tip_height,
+            tip_hash,
+            blocks: BTreeMap::new(),
+        }
+    }
+
+    /// Returns how `block` links to the tip or the buffered blocks.
+    ///
+    /// # Errors
+    /// Returns an error if `block` conflicts with the tip, i.e. if the chain was reorged below the
+    /// last forwarded block.
+    pub(crate) fn link(&self, block: &BlockInfo) -> eyre::Result<Link> {
+        if block.height <= self.tip_height {
+            if block.height == self.tip_height && block.hash == self.tip_hash {
+                return Ok(Link::Known);
+            }
+            return Err(self.reorg_below_tip(block));
+        }
+        if self
+            .blocks
+            .get(&block.height)
+            .is_some_and(|buffered| buffered.hash == block.hash)
+        {
+            return Ok(Link::Known);
+        }
+        // the genesis block has no parent to link to
+        let Some(parent_height) = block.height.checked_sub(1) else {
+            return Ok(Link::Disconnected);
+        };
+        if parent_height == self.tip_height {
+            if block.parent_hash == self.tip_hash {
+                return Ok(Link::Connected);
+            }
+            return Err(self.reorg_below_tip(block));
+        }
+        match self.blocks.get(&parent_height) {
+            Some(parent) if parent.hash == block.parent_hash => Ok(Link::Connected),
+            _ => Ok(Link::Disconnected),
+        }
+    }
+
+    fn reorg_below_tip(&self, block: &BlockInfo) -> eyre::Report {
+        eyre::eyre!(
+            "block `{}` at rollup height `{}` does not descend from the last forwarded block `{}` \
+             at rollup height `{}`; withdrawal events of a reorged block may already have been \
+             forwarded",
+            block.hash,
+            block.height,
+            self.tip_hash,
+            self.tip_height,
+        )
+    }
+
+    /// Inserts `block`, which must be [`Link::Connected`], and returns the buffered blocks at the
+    /// same or greater heights that are no longer canonical.
+    pub(crate) fn insert(&mut self, block: BlockInfo) -> Vec<BlockInfo> {
+        let reorged = self.blocks.split_off(&block.height).into_values().collect();
+        self.blocks.insert(block.height, block);
+        reorged
+    }
+
+    /// Removes and returns the buffered blocks at or below `confirmed_height` in ascending order,
+    /// making the last of them the new tip.
+    pub(crate) fn pop_confirmed(&mut self, confirmed_height: u64) -> Vec<BlockInfo> {
+        let unconfirmed = self.blocks.split_off(&confirmed_height.saturating_add(1));
+        let confirmed: Vec<_> = std::mem::replace(&mut self.blocks, unconfirmed)
+            .into_values()
+            .collect();
+        if let Some(last) = confirmed.last() {
+            self.tip_height = last.height;
+            self.tip_hash = last.hash;
+        }
+        confirmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(height: u64, fork: u8) -> H256 {
+        let mut bytes = [fork; 32];
+        bytes[..8].copy_from_slice(&height.to_be_bytes());
+        H256(bytes)
+    }
+
+    fn block(height: u64, fork: u8, parent_fork: u8) -> BlockInfo {
+        BlockInfo {
+            height,
+            hash: hash(height, fork),
+            parent_hash: hash(height - 1, parent_fork),
+            timestamp: height,
+        }
+    }
+
+    #[test]
+    fn blocks_are_confirmed_in_order() {
+        let mut pending = PendingBlocks::new(10, hash(10, 0));
+        for height in 11..=13 {
+            let next = block(height, 0, 0);
+            assert_eq!(pending.link(&next).unwrap(), Link::Connected);
+            assert!(pending.insert(next).is_empty());
+        }
+
+        assert!(pending.pop_confirmed(10).is_empty());
+        let confirmed = pending.pop_confirmed(12);
+        assert_eq!(
+            confirmed.iter().map(|b| b.height).collect::<Vec<_>>(),
+            vec![11, 12]
+        );
+        assert_eq!(pending.tip_height, 12);
+        assert_eq!(pending.link(&block(12, 0, 0)).unwrap(), Link::Known);
+        assert_eq!(pending.link(&block(13, 0, 0)).unwrap(), Link::Known);
+    }
+
+    #[test]
+    fn reorged_blocks_are_discarded() {
+        let mut pending = PendingBlocks::new(10, hash(10, 0));
+        for height in 11..=13 {
+            pending.insert(block(height, 0, 0));
+        }
+
+        // a block on a fork off of height 11 cannot be linked without its parent
+        assert_eq!(pending.link(&block(13, 1, 1)).unwrap(), Link::Disconnected);
+        let fork_parent = block(12, 1, 0);
+        assert_eq!(pending.link(&fork_parent).unwrap(), Link::Connected);
+
+        let reorged = pending.insert(fork_parent);
+        assert_eq!(reorged, vec![block(12, 0, 0), block(13, 0, 0)]);
+        pending.insert(block(13, 1, 1));
+
+        let confirmed = pending.pop_confirmed(13);
+        assert_eq!(
+            confirmed,
+            vec![block(11, 0, 0), block(12, 1, 0), block(13, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn reorg_below_tip_is_rejected() {
+        let mut pending = PendingBlocks::new(10, hash(10, 0));
+        pending.insert(block(11, 0, 0));
+
+        assert!(pending.link(&block(10, 1, 1)).is_err());
+        assert!(pending.link(&block(11, 1, 1)).is_err());
+        assert!(pending.link(&block(9, 0, 0)).is_err());
+    }
+}
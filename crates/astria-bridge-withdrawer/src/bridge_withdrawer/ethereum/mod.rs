@@ -1 +1,2 @@
-pub(crate) mod watcher;
+pub(crate) mod confirmation;
+pub(crate) mod source;
@@ -0,0 +1,548 @@
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_bridge_contracts::{
+    GetWithdrawalActions,
+    GetWithdrawalActionsBuilder,
+    WithdrawalContract,
+};
+use astria_core::{
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    protocol::transaction::v1alpha1::Action,
+};
+use astria_eyre::{
+    eyre::{
+        self,
+        bail,
+        ensure,
+        eyre,
+        OptionExt as _,
+        WrapErr as _,
+    },
+    Result,
+};
+use ethers::{
+    providers::{
+        Middleware,
+        Provider,
+        ProviderError,
+        StreamExt as _,
+        Ws,
+    },
+    types::{
+        BlockId,
+        BlockNumber,
+    },
+    utils::hex,
+};
+use tracing::{
+    info,
+    info_span,
+    instrument,
+    warn,
+};
+
+use super::confirmation::{
+    BlockInfo,
+    Confirmation,
+    Link,
+    PendingBlocks,
+};
+use crate::bridge_withdrawer::{
+    batch::Batch,
+    source::{
+        batches_from_task,
+        Batches,
+        BatchesTx,
+        WithdrawalSource,
+    },
+    startup,
+};
+
+pub(crate) struct Builder {
+    pub(crate) ethereum_contract_address: String,
+    pub(crate) ethereum_withdrawal_contracts: String,
+    pub(crate) ethereum_rpc_endpoint: String,
+    pub(crate) confirmation: Confirmation,
+    pub(crate) rollup_asset_denom: asset::TracePrefixed,
+    pub(crate) bridge_address: Address,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> Result<Source> {
+        let Builder {
+            ethereum_contract_address,
+            ethereum_withdrawal_contracts,
+            ethereum_rpc_endpoint,
+            confirmation,
+            rollup_asset_denom,
+            bridge_address,
+        } = self;
+
+        let contract_address = address_from_string(&ethereum_contract_address)
+            .wrap_err("failed to parse ethereum contract address")?;
+        let withdrawal_contracts = parse_withdrawal_contracts(&ethereum_withdrawal_contracts)
+            .wrap_err("failed to parse ethereum withdrawal contracts")?;
+
+        Ok(Source {
+            contract_address,
+            withdrawal_contracts,
+            ethereum_rpc_endpoint: ethereum_rpc_endpoint.to_string(),
+            confirmation,
+            rollup_asset_denom,
+            bridge_address,
+        })
+    }
+}
+
+/// Derives withdrawals from the events emitted by the `AstriaWithdrawer` contract and any
+/// additionally configured withdrawal contracts.
+pub(crate) struct Source {
+    contract_address: ethers::types::Address,
+    withdrawal_contracts: Vec<WithdrawalContract>,
+    ethereum_rpc_endpoint: String,
+    confirmation: Confirmation,
+    rollup_asset_denom: asset::TracePrefixed,
+    bridge_address: Address,
+}
+
+#[async_trait::async_trait]
+impl WithdrawalSource for Source {
+    /// Connects to the Ethereum node and starts watching for withdrawal events from the
+    /// starting rollup height.
+    ///
+    /// # Errors
+    /// - If the Ethereum node cannot be connected to after several retries.
+    /// - If the asset withdrawal decimals cannot be fetched.
+    #[instrument(skip_all, err)]
+    async fn connect(self: Box<Self>, info: startup::Info) -> eyre::Result<Batches> {
+        let Self {
+            contract_address,
+            withdrawal_contracts,
+            ethereum_rpc_endpoint,
+            confirmation,
+            rollup_asset_denom,
+            bridge_address,
+        } = *self;
+        let startup::Info {
+            fee_asset,
+            starting_rollup_height,
+            ..
+        } = info;
+
+        // connect to eth node
+        let retry_config = tryhard::RetryFutureConfig::new(1024)
+            .exponential_backoff(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(60))
+            .on_retry(
+                |attempt, next_delay: Option<Duration>, error: &ProviderError| {
+                    let wait_duration = next_delay
+                        .map(humantime::format_duration)
+                        .map(tracing::field::display);
+                    warn!(
+                        attempt,
+                        wait_duration,
+                        error = error as &dyn std::error::Error,
+                        "attempt to connect to rollup node failed; retrying after backoff",
+                    );
+                    futures::future::ready(())
+                },
+            );
+
+        let provider = tryhard::retry_fn(|| {
+            let url = ethereum_rpc_endpoint.clone();
+            async move {
+                let websocket_client = Ws::connect_with_reconnects(url, 0).await?;
+                Ok(Provider::new(websocket_client))
+            }
+        })
+        .with_config(retry_config)
+        .await
+        .wrap_err("failed connecting to rollup after several retries; giving up")?;
+
+        let provider = Arc::new(provider);
+        let ics20_asset_to_withdraw = if rollup_asset_denom.last_channel().is_some() {
+            info!(
+                %rollup_asset_denom,
+                "configured rollup asset contains an ics20 channel; ics20 withdrawals will be emitted"
+            );
+            Some(rollup_asset_denom.clone())
+        } else {
+            info!(
+                %rollup_asset_denom,
+                "configured rollup asset does not contain an ics20 channel; ics20 withdrawals will not be emitted"
+            );
+            None
+        };
+        let action_fetcher = GetWithdrawalActionsBuilder::new()
+            .provider(provider.clone())
+            .fee_asset(fee_asset)
+            .contract_address(contract_address)
+            .bridge_address(bridge_address)
+            .sequencer_asset_to_withdraw(rollup_asset_denom.clone().into())
+            .set_ics20_asset_to_withdraw(ics20_asset_to_withdraw)
+            .withdrawal_contracts(withdrawal_contracts)
+            .try_build()
+            .await
+            .wrap_err("failed to construct contract event to sequencer action fetcher")?;
+
+        Ok(batches_from_task(move |batches_tx| async move {
+            watch_for_blocks(
+                provider,
+                action_fetcher,
+                starting_rollup_height,
+                confirmation,
+                batches_tx,
+            )
+            .await
+            .wrap_err("block handler exited")
+        }))
+    }
+}
+
+#[instrument(skip_all, fields(from_rollup_height, to_rollup_height), err)]
+async fn sync_unprocessed_rollup_heights(
+    provider: &Provider<Ws>,
+    action_fetcher: &GetWithdrawalActions<Provider<Ws>>,
+    batches_tx: &BatchesTx,
+    from_rollup_height: u64,
+    to_rollup_height: u64,
+) -> Result<()> {
+    for i in from_rollup_height..=to_rollup_height {
+        let block = get_block_info(provider, i)
+            .await
+            .wrap_err_with(|| format!("failed to get block at rollup height `{i}`"))?;
+        get_and_forward_block_events(action_fetcher, &block, batches_tx)
+            .await
+            .wrap_err("failed to get and send events at block")?;
+    }
+    Ok(())
+}
+
+async fn watch_for_blocks(
+    provider: Arc<Provider<Ws>>,
+    action_fetcher: GetWithdrawalActions<Provider<Ws>>,
+    next_rollup_block_height: u64,
+    confirmation: Confirmation,
+    batches_tx: BatchesTx,
+) -> Result<()> {
+    let mut block_rx = provider
+        .subscribe_blocks()
+        .await
+        .wrap_err("failed to subscribe to blocks")?;
+
+    // read latest block height from subscription;
+    // use this value for syncing from the next height to submit to the confirmed height.
+    let Some(current_rollup_block) = block_rx.next().await else {
+        bail!("failed to get current rollup block from subscription")
+    };
+    let current_rollup_block = BlockInfo::try_from(&current_rollup_block)
+        .wrap_err("failed to read current rollup block")?;
+
+    let confirmed_rollup_block_height =
+        confirmed_height(&provider, confirmation, current_rollup_block.height)
+            .await
+            .wrap_err("failed to determine confirmed rollup height")?;
+
+    info_span!("watch_for_blocks").in_scope(|| {
+        info!(
+            block.height = current_rollup_block.height,
+            block.hash = %current_rollup_block.hash,
+            confirmed_height = confirmed_rollup_block_height,
+            "got current block"
+        );
+    });
+
+    // sync any confirmed blocks missing between `next_rollup_block_height` and the confirmed
+    // height (inclusive).
+    sync_unprocessed_rollup_heights(
+        &provider,
+        &action_fetcher,
+        &batches_tx,
+        next_rollup_block_height,
+        confirmed_rollup_block_height,
+    )
+    .await
+    .wrap_err("failed to sync from next rollup block height")?;
+
+    // all blocks above the last forwarded block are buffered until they are confirmed.
+    let tip = get_block_info(
+        &provider,
+        confirmed_rollup_block_height.max(next_rollup_block_height.saturating_sub(1)),
+    )
+    .await
+    .wrap_err("failed to get last forwarded rollup block")?;
+    let mut pending_blocks = PendingBlocks::new(tip.height, tip.hash);
+    handle_new_block(
+        &provider,
+        &action_fetcher,
+        confirmation,
+        &mut pending_blocks,
+        current_rollup_block,
+        &batches_tx,
+    )
+    .await
+    .wrap_err("failed to handle current rollup block")?;
+
+    loop {
+        let Some(block) = block_rx.next().await else {
+            bail!("block subscription ended")
+        };
+        let block = BlockInfo::try_from(&block)
+            .wrap_err("failed to read rollup block from subscription")?;
+        handle_new_block(
+            &provider,
+            &action_fetcher,
+            confirmation,
+            &mut pending_blocks,
+            block,
+            &batches_tx,
+        )
+        .await
+        .wrap_err("failed to handle new rollup block")?;
+    }
+}
+
+/// Buffers a new rollup block, together with any of its ancestors not yet seen, and forwards the
+/// withdrawal events of all blocks that became confirmed.
+///
+/// Buffered blocks replaced by the new block's chain are discarded without forwarding their
+/// events.
+///
+/// # Errors
+/// Returns an error if the chain was reorged below the last forwarded block, or if the events of
+/// a confirmed block could not be forwarded.
+#[instrument(skip_all, fields(
+    block.hash = %block.hash,
+    block.number = block.height,
+), err)]
+async fn handle_new_block(
+    provider: &Provider<Ws>,
+    action_fetcher: &GetWithdrawalActions<Provider<Ws>>,
+    confirmation: Confirmation,
+    pending_blocks: &mut PendingBlocks,
+    block: BlockInfo,
+    batches_tx: &BatchesTx,
+) -> Result<()> {
+    // walk back from the new block until it links up with the known chain.
+    let mut new_blocks = vec![block];
+    while let Some(oldest) = new_blocks.last() {
+        match pending_blocks
+            .link(oldest)
+            .wrap_err("detected a reorg of already forwarded rollup blocks")?
+        {
+            Link::Known => {
+                new_blocks.pop();
+                break;
+            }
+            Link::Connected => break,
+            Link::Disconnected => {
+                let parent = get_block_info(provider, oldest.parent_hash)
+                    .await
+                    .wrap_err_with(|| {
+                        format!("failed to get parent block `{}`", oldest.parent_hash)
+                    })?;
+                new_blocks.push(parent);
+            }
+        }
+    }
+    for new_block in new_blocks.into_iter().rev() {
+        for reorged in pending_blocks.insert(new_block) {
+            warn!(
+                reorged.height,
+                reorged.hash = %reorged.hash,
+                "rollup block was reorged out before being confirmed; discarding it"
+            );
+        }
+    }
+
+    let confirmed_rollup_height = confirmed_height(provider, confirmation, block.height)
+        .await
+        .wrap_err("failed to determine confirmed rollup height")?;
+    for confirmed in pending_blocks.pop_confirmed(confirmed_rollup_height) {
+        get_and_forward_block_events(action_fetcher, &confirmed, batches_tx)
+            .await
+            .wrap_err("failed to get and send events at block")?;
+    }
+    Ok(())
+}
+
+/// Returns the height up to which rollup blocks are confirmed, given the height of the latest
+/// rollup block.
+async fn confirmed_height(
+    provider: &Provider<Ws>,
+    confirmation: Confirmation,
+    latest_height: u64,
+) -> Result<u64> {
+    match confirmation {
+        Confirmation::Depth(depth) => Ok(latest_height.saturating_sub(depth)),
+        Confirmation::Finalized => get_block_info(provider, BlockNumber::Finalized)
+            .await
+            .map(|block| block.height)
+            .wrap_err("failed to get finalized rollup block"),
+    }
+}
+
+async fn get_block_info<T: Into<BlockId> + Send + Sync>(
+    provider: &Provider<Ws>,
+    id: T,
+) -> Result<BlockInfo> {
+    provider
+        .get_block(id)
+        .await
+        .map_err(eyre::Report::new)
+        .and_then(|block| block.ok_or_eyre("block is missing"))
+        .and_then(|block| BlockInfo::try_from(&block))
+}
+
+#[instrument(skip_all, fields(
+    block.hash = %block.hash,
+    block.number = block.height,
+), err)]
+async fn get_and_forward_block_events(
+    actions_fetcher: &GetWithdrawalActions<Provider<Ws>>,
+    block: &BlockInfo,
+    batches_tx: &BatchesTx,
+) -> Result<()> {
+    let BlockInfo {
+        hash: block_hash,
+        height: rollup_height,
+        timestamp,
+        ..
+    } = *block;
+    let actions: Vec<Action> = actions_fetcher
+        .get_for_block_hash(block_hash, timestamp)
+        .await
+        .wrap_err("failed getting actions for block")?
+        .into_iter()
+        .filter_map(|r| {
+            r.map_err(|e| {
+                warn!(
+                    error = %eyre::Report::new(e),
+                    "failed to convert rollup withdrawal event to sequencer action; dropping"
+                );
+            })
+            .ok()
+        })
+        .collect();
+
+    if actions.is_empty() {
+        info!(
+            "no withdrawal actions found for block `{block_hash}` at rollup height \
+             `{rollup_height}; skipping"
+        );
+    } else {
+        batches_tx
+            .send(Ok(Batch {
+                actions,
+                rollup_height,
+            }))
+            .await
+            .map_err(|_| eyre!("failed to send batched events; receiver dropped?"))?;
+    }
+
+    Ok(())
+}
+
+// converts an ethereum address string to an `ethers::types::Address`.
+// the input string may be prefixed with "0x" or not.
+fn address_from_string(s: &str) -> Result<ethers::types::Address> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let bytes = hex::decode(s).wrap_err("failed to parse ethereum address as hex")?;
+    let address: [u8; 20] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+        eyre!(
+            "invalid length for {} ethereum address, must be 20 bytes",
+            bytes.len()
+        )
+    })?;
+    Ok(address.into())
+}
+
+/// Parses a comma separated list of withdrawal contracts of the form
+/// `<contract address>:<sequencer|ics20>:<asset>[:<divisor>]`.
+fn parse_withdrawal_contracts(s: &str) -> Result<Vec<WithdrawalContract>> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            parse_withdrawal_contract(s)
+                .wrap_err_with(|| format!("failed to parse withdrawal contract `{s}`"))
+        })
+        .collect()
+}
+
+fn parse_withdrawal_contract(s: &str) -> Result<WithdrawalContract> {
+    let mut parts = s.split(':');
+    let (Some(address), Some(kind), Some(asset)) = (parts.next(), parts.next(), parts.next())
+    else {
+        bail!("expected `<contract address>:<sequencer|ics20>:<asset>[:<divisor>]`");
+    };
+    let divisor = parts.next();
+    ensure!(
+        parts.next().is_none(),
+        "expected `<contract address>:<sequencer|ics20>:<asset>[:<divisor>]`"
+    );
+
+    let address = address_from_string(address).wrap_err("failed to parse contract address")?;
+    let contract = match kind {
+        "sequencer" => WithdrawalContract::sequencer(
+            address,
+            asset.parse().wrap_err("failed to parse sequencer asset")?,
+        ),
+        "ics20" => WithdrawalContract::ics20(
+            address,
+            asset.parse().wrap_err("failed to parse ics20 asset")?,
+        ),
+        other => bail!("unknown withdrawal kind `{other}`; expected `sequencer` or `ics20`"),
+    };
+    let Some(divisor) = divisor else {
+        return Ok(contract);
+    };
+    let divisor = divisor
+        .parse()
+        .wrap_err("failed to parse asset withdrawal divisor")?;
+    Ok(contract.asset_withdrawal_divisor(divisor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn withdrawal_contracts_are_parsed() {
+        let contracts = parse_withdrawal_contracts(
+            "0x0101010101010101010101010101010101010101:sequencer:nria, \
+             0202020202020202020202020202020202020202:ics20:transfer/channel-0/utia:1000",
+        )
+        .unwrap();
+        assert_eq!(contracts.len(), 2);
+        assert_eq!(contracts[0].address(), [1; 20].into());
+        assert_eq!(contracts[1].address(), [2; 20].into());
+    }
+
+    #[test]
+    fn empty_withdrawal_contracts_are_parsed() {
+        assert!(parse_withdrawal_contracts("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn malformed_withdrawal_contracts_are_rejected() {
+        for malformed in [
+            "0x0101010101010101010101010101010101010101:sequencer",
+            "0x0101010101010101010101010101010101010101:erc20:nria",
+            "0x0101010101010101010101010101010101010101:sequencer:nria:ten",
+            "0x0101010101010101010101010101010101010101:sequencer:nria:10:extra",
+            "0x01:sequencer:nria",
+        ] {
+            assert!(
+                parse_withdrawal_contracts(malformed).is_err(),
+                "`{malformed}` should not parse"
+            );
+        }
+    }
+}
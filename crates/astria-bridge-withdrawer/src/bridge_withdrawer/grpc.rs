@@ -0,0 +1,375 @@
+//! Derives withdrawals from rollups serving the `astria.execution.v1alpha2.WithdrawalService`.
+//!
+//! This allows rollups that are not EVM based, and hence cannot emit the events of the
+//! `AstriaWithdrawer` contract, to withdraw funds from their bridge account.
+use std::time::Duration;
+
+use astria_core::{
+    generated::execution::v1alpha2::{
+        withdrawal_service_client::WithdrawalServiceClient,
+        RollupBlockWithdrawals,
+        RollupWithdrawal,
+        StreamWithdrawalsRequest,
+    },
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    protocol::{
+        memos,
+        transaction::v1alpha1::{
+            action::{
+                BridgeUnlockAction,
+                Ics20Withdrawal,
+            },
+            Action,
+        },
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    OptionExt as _,
+    WrapErr as _,
+};
+use futures::StreamExt as _;
+use http::Uri;
+use tonic::transport::Channel;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use super::{
+    batch::Batch,
+    source::{
+        Batches,
+        WithdrawalSource,
+    },
+    startup,
+};
+
+pub(crate) struct Source {
+    endpoint: Uri,
+    rollup_asset_denom: asset::TracePrefixed,
+    bridge_address: Address,
+}
+
+impl Source {
+    pub(crate) fn new(
+        endpoint: &str,
+        rollup_asset_denom: asset::TracePrefixed,
+        bridge_address: Address,
+    ) -> eyre::Result<Self> {
+        let endpoint = endpoint
+            .parse()
+            .wrap_err("failed to parse rollup grpc endpoint as URI")?;
+        Ok(Self {
+            endpoint,
+            rollup_asset_denom,
+            bridge_address,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl WithdrawalSource for Source {
+    /// Subscribes to the rollup's withdrawals from the starting rollup height.
+    ///
+    /// # Errors
+    /// - If the rollup's ics20 channel cannot be parsed.
+    /// - If the rollup cannot be subscribed to after several retries.
+    #[instrument(skip_all, fields(endpoint = %self.endpoint), err)]
+    async fn connect(self: Box<Self>, info: startup::Info) -> eyre::Result<Batches> {
+        let Self {
+            endpoint,
+            rollup_asset_denom,
+            bridge_address,
+        } = *self;
+        let converter = Converter::new(rollup_asset_denom, bridge_address, info.fee_asset)?;
+
+        let client = WithdrawalServiceClient::new(Channel::builder(endpoint).connect_lazy());
+        let request = StreamWithdrawalsRequest {
+            start_height: info.starting_rollup_height,
+        };
+        let retry_config = tryhard::RetryFutureConfig::new(1024)
+            .exponential_backoff(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(60))
+            .on_retry(
+                |attempt, next_delay: Option<Duration>, status: &tonic::Status| {
+                    let wait_duration = next_delay
+                        .map(humantime::format_duration)
+                        .map(tracing::field::display);
+                    warn!(
+                        attempt,
+                        wait_duration,
+                        error = status as &dyn std::error::Error,
+                        "attempt to subscribe to rollup withdrawals failed; retrying after backoff",
+                    );
+                    futures::future::ready(())
+                },
+            );
+        let withdrawals = tryhard::retry_fn(|| {
+            let mut client = client.clone();
+            let request = request.clone();
+            async move { client.stream_withdrawals(request).await }
+        })
+        .with_config(retry_config)
+        .await
+        .wrap_err("failed subscribing to rollup withdrawals after several retries; giving up")?
+        .into_inner();
+
+        Ok(withdrawals
+            .map(move |block| {
+                block
+                    .wrap_err("failed receiving withdrawals from rollup")
+                    .map(|block| converter.to_batch(block))
+            })
+            .boxed())
+    }
+}
+
+/// Converts the withdrawals streamed by a rollup to sequencer actions.
+pub(super) struct Converter {
+    bridge_address: Address,
+    fee_asset: asset::Denom,
+    ics20_asset_to_withdraw: Option<(asset::TracePrefixed, ibc_types::core::channel::ChannelId)>,
+}
+
+impl Converter {
+    pub(super) fn new(
+        rollup_asset_denom: asset::TracePrefixed,
+        bridge_address: Address,
+        fee_asset: asset::Denom,
+    ) -> eyre::Result<Self> {
+        let ics20_asset_to_withdraw = if let Some(channel) = rollup_asset_denom.last_channel() {
+            info!(
+                %rollup_asset_denom,
+                "configured rollup asset contains an ics20 channel; ics20 withdrawals will be emitted"
+            );
+            let channel = channel
+                .parse()
+                .wrap_err("failed to parse ics20 channel of rollup asset")?;
+            Some((rollup_asset_denom, channel))
+        } else {
+            info!(
+                %rollup_asset_denom,
+                "configured rollup asset does not contain an ics20 channel; ics20 withdrawals will not be emitted"
+            );
+            None
+        };
+        Ok(Self {
+            bridge_address,
+            fee_asset,
+            ics20_asset_to_withdraw,
+        })
+    }
+
+    /// Converts the withdrawals of a rollup block, dropping any that are malformed.
+    pub(super) fn to_batch(&self, block: RollupBlockWithdrawals) -> Batch {
+        let RollupBlockWithdrawals {
+            rollup_height,
+            withdrawals,
+            timestamp,
+        } = block;
+        let timeout_time = timestamp.as_ref().map(timeout_in_5_min);
+        let actions = withdrawals
+            .into_iter()
+            .filter_map(|withdrawal| {
+                let event_id = withdrawal.rollup_withdrawal_event_id.clone();
+                self.to_action(withdrawal, rollup_height, timeout_time)
+                    .map_err(|error| {
+                        warn!(
+                            rollup_height,
+                            rollup_withdrawal_event_id = event_id,
+                            %error,
+                            "failed to convert rollup withdrawal to sequencer action; dropping"
+                        );
+                    })
+                    .ok()
+            })
+            .collect();
+        Batch {
+            actions,
+            rollup_height,
+        }
+    }
+
+    fn to_action(
+        &self,
+        withdrawal: RollupWithdrawal,
+        rollup_block_number: u64,
+        timeout_time: Option<u64>,
+    ) -> eyre::Result<Action> {
+        let RollupWithdrawal {
+            rollup_withdrawal_event_id,
+            amount,
+            destination_chain_address,
+            ics20,
+            rollup_return_address,
+            memo,
+        } = withdrawal;
+        let amount = amount
+            .ok_or_eyre("withdrawal did not contain an amount")?
+            .into();
+
+        if !ics20 {
+            let to = destination_chain_address
+                .parse()
+                .wrap_err("failed to parse destination chain address as sequencer address")?;
+            return Ok(Action::BridgeUnlock(BridgeUnlockAction {
+                to,
+                amount,
+                rollup_block_number,
+                rollup_withdrawal_event_id,
+                memo: String::new(),
+                fee_asset: self.fee_asset.clone(),
+                bridge_address: self.bridge_address,
+            }));
+        }
+
+        let (denom, source_channel) = self
+            .ics20_asset_to_withdraw
+            .clone()
+            .ok_or_eyre("ics20 withdrawal requested, but the rollup asset has no ics20 channel")?;
+        let timeout_time = timeout_time.ok_or_eyre(
+            "ics20 withdrawal requested, but the rollup block did not contain a timestamp",
+        )?;
+        let memo = serde_json::to_string(&memos::v1alpha1::Ics20WithdrawalFromRollup {
+            memo,
+            rollup_block_number,
+            rollup_return_address,
+            rollup_withdrawal_event_id,
+        })
+        .wrap_err("failed encoding ics20 withdrawal memo as JSON")?;
+        Ok(Action::Ics20Withdrawal(Ics20Withdrawal {
+            denom: denom.into(),
+            destination_chain_address,
+            return_address: self.bridge_address,
+            amount,
+            memo,
+            fee_asset: self.fee_asset.clone(),
+            // note: this refers to the timeout on the destination chain, which we are unaware of.
+            // thus, we set it to the maximum possible value.
+            timeout_height: ibc_types::core::client::Height::new(u64::MAX, u64::MAX)
+                .expect("non-zero arguments should never fail"),
+            timeout_time,
+            source_channel,
+            bridge_address: Some(self.bridge_address),
+            use_compat_address: false,
+        }))
+    }
+}
+
+/// Returns the unix timestamp in nanoseconds 5 minutes after the rollup block `timestamp`.
+///
+/// This is derived from the rollup block instead of the local clock so that all members of a
+/// signing committee derive the same actions from the block.
+fn timeout_in_5_min(timestamp: &pbjson_types::Timestamp) -> u64 {
+    const NANOS_PER_SEC: u64 = 1_000_000_000;
+    let seconds = u64::try_from(timestamp.seconds).unwrap_or(0);
+    let nanos = u64::try_from(timestamp.nanos).unwrap_or(0);
+    seconds
+        .saturating_add(300)
+        .saturating_mul(NANOS_PER_SEC)
+        .saturating_add(nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    fn converter(rollup_asset_denom: &str) -> Converter {
+        Converter::new(
+            rollup_asset_denom.parse().unwrap(),
+            address(1),
+            "nria".parse().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn withdrawal(destination_chain_address: String, ics20: bool) -> RollupWithdrawal {
+        RollupWithdrawal {
+            rollup_withdrawal_event_id: "event".to_string(),
+            amount: Some(100u128.into()),
+            destination_chain_address,
+            ics20,
+            rollup_return_address: "rollup-sender".to_string(),
+            memo: "hello".to_string(),
+        }
+    }
+
+    #[test]
+    fn sequencer_withdrawals_are_converted_to_bridge_unlocks() {
+        let batch = converter("nria").to_batch(RollupBlockWithdrawals {
+            rollup_height: 5,
+            withdrawals: vec![withdrawal(address(2).to_string(), false)],
+            timestamp: None,
+        });
+        assert_eq!(batch.rollup_height, 5);
+        let [Action::BridgeUnlock(unlock)] = &batch.actions[..] else {
+            panic!("expected a single bridge unlock, got {:?}", batch.actions);
+        };
+        assert_eq!(unlock.to, address(2));
+        assert_eq!(unlock.amount, 100);
+        assert_eq!(unlock.rollup_block_number, 5);
+        assert_eq!(unlock.rollup_withdrawal_event_id, "event");
+        assert_eq!(unlock.bridge_address, address(1));
+    }
+
+    #[test]
+    fn ics20_withdrawals_carry_the_rollup_memo() {
+        let batch = converter("transfer/channel-0/utia").to_batch(RollupBlockWithdrawals {
+            rollup_height: 5,
+            withdrawals: vec![withdrawal("celestia1abc".to_string(), true)],
+            timestamp: Some(pbjson_types::Timestamp {
+                seconds: 1_000,
+                nanos: 5,
+            }),
+        });
+        let [Action::Ics20Withdrawal(ics20)] = &batch.actions[..] else {
+            panic!(
+                "expected a single ics20 withdrawal, got {:?}",
+                batch.actions
+            );
+        };
+        assert_eq!(ics20.source_channel.as_str(), "channel-0");
+        assert_eq!(ics20.timeout_time, 1_300_000_000_005);
+        let memo: memos::v1alpha1::Ics20WithdrawalFromRollup =
+            serde_json::from_str(&ics20.memo).unwrap();
+        assert_eq!(memo.rollup_block_number, 5);
+        assert_eq!(memo.rollup_withdrawal_event_id, "event");
+        assert_eq!(memo.rollup_return_address, "rollup-sender");
+        assert_eq!(memo.memo, "hello");
+    }
+
+    #[test]
+    fn malformed_withdrawals_are_dropped() {
+        let batch = converter("nria").to_batch(RollupBlockWithdrawals {
+            rollup_height: 5,
+            withdrawals: vec![
+                withdrawal("not-an-address".to_string(), false),
+                // ics20 withdrawals require the rollup asset to have an ics20 channel
+                withdrawal("celestia1abc".to_string(), true),
+            ],
+            timestamp: None,
+        });
+        assert!(batch.actions.is_empty());
+
+        // ics20 withdrawals require the rollup block to have a timestamp
+        let batch = converter("transfer/channel-0/utia").to_batch(RollupBlockWithdrawals {
+            rollup_height: 5,
+            withdrawals: vec![withdrawal("celestia1abc".to_string(), true)],
+            timestamp: None,
+        });
+        assert!(batch.actions.is_empty());
+    }
+}
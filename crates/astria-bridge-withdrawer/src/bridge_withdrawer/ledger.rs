@@ -0,0 +1,572 @@
+//! A durable record of the withdrawals observed by the bridge withdrawer.
+//!
+//! Every withdrawal event derived from the rollup is written to an embedded sqlite database
+//! together with the rollup height of its batch. Once the batch was executed on the sequencer, the
+//! hash and inclusion height of its transaction are recorded as well. The ledger is served by the
+//! `/withdrawals` endpoints of the API server and can be reconciled against the withdrawal events
+//! recorded by the sequencer.
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use astria_core::{
+    primitive::v1::Address,
+    protocol::{
+        memos,
+        transaction::v1alpha1::Action,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    bail,
+    WrapErr as _,
+};
+use rusqlite::{
+    params,
+    Connection,
+    OptionalExtension as _,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tracing::{
+    instrument,
+    warn,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS batches (
+    rollup_height INTEGER PRIMARY KEY,
+    status TEXT NOT NULL,
+    sequencer_tx_hash TEXT,
+    sequencer_height INTEGER,
+    error TEXT
+);
+CREATE TABLE IF NOT EXISTS withdrawals (
+    event_id TEXT PRIMARY KEY,
+    rollup_height INTEGER NOT NULL REFERENCES batches (rollup_height),
+    action TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS withdrawals_by_rollup_height ON withdrawals (rollup_height);
+";
+
+const SELECT_WITHDRAWAL: &str = "SELECT w.event_id, w.rollup_height, w.action, b.status, \
+                                 b.sequencer_tx_hash, b.sequencer_height, b.error FROM \
+                                 withdrawals w JOIN batches b ON w.rollup_height = b.rollup_height";
+
+/// The state of the batch a withdrawal belongs to.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    /// The batch was derived from the rollup but not yet executed on the sequencer.
+    Pending,
+    /// The batch was executed on the sequencer.
+    Submitted,
+    /// Submitting the batch failed.
+    Failed,
+}
+
+impl BatchStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Submitted => "submitted",
+            Self::Failed => "failed",
+        }
+    }
+
+    fn parse(status: &str) -> eyre::Result<Self> {
+        match status {
+            "pending" => Ok(Self::Pending),
+            "submitted" => Ok(Self::Submitted),
+            "failed" => Ok(Self::Failed),
+            other => bail!("unknown batch status `{other}` in ledger"),
+        }
+    }
+}
+
+/// A withdrawal as recorded in the ledger.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Withdrawal {
+    /// The `rollup_withdrawal_event_id` of the withdrawal.
+    pub event_id: String,
+    /// The rollup height of the block emitting the withdrawal event.
+    pub rollup_height: u64,
+    /// The JSON encoding of the sequencer action executing the withdrawal.
+    pub action: serde_json::Value,
+    pub status: BatchStatus,
+    /// The hash of the sequencer transaction executing the withdrawal's batch.
+    pub sequencer_tx_hash: Option<String>,
+    /// The sequencer height at which the withdrawal's batch was executed.
+    pub sequencer_height: Option<u64>,
+    /// The reason submitting the withdrawal's batch failed.
+    pub error: Option<String>,
+}
+
+pub(crate) struct Ledger {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl Ledger {
+    /// Opens the ledger at `path`, creating it if it does not exist.
+    ///
+    /// If `path` is empty, the ledger is only kept in memory and lost on restart.
+    ///
+    /// # Errors
+    /// Returns an error if the database cannot be opened or its schema cannot be created.
+    pub(crate) fn open(path: &str) -> eyre::Result<Self> {
+        let connection = if path.is_empty() {
+            warn!("no ledger path configured; observed withdrawals are only recorded in memory");
+            Connection::open_in_memory().wrap_err("failed to open in-memory ledger")?
+        } else {
+            Connection::open(Path::new(path))
+                .wrap_err_with(|| format!("failed to open ledger at `{path}`"))?
+        };
+        connection
+            .execute_batch(SCHEMA)
+            .wrap_err("failed to create ledger schema")?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Runs `f` against the ledger on the blocking thread pool.
+    async fn run<T, F>(&self, f: F) -> eyre::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> eyre::Result<T> + Send + 'static,
+    {
+        let connection = self.connection.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection.lock().expect("ledger lock must not be poisoned");
+            f(&mut connection)
+        })
+        .await
+        .wrap_err("ledger task panicked")?
+    }
+
+    /// Records the withdrawals of the batch derived from the rollup block at `rollup_height`.
+    ///
+    /// A batch that was already recorded as submitted keeps its status.
+    #[instrument(skip_all, fields(rollup_height), err)]
+    pub(crate) async fn record_batch(
+        &self,
+        rollup_height: u64,
+        actions: &[Action],
+    ) -> eyre::Result<()> {
+        let mut withdrawals = Vec::new();
+        for action in actions {
+            let Some(event_id) = withdrawal_event_id(action)? else {
+                continue;
+            };
+            let action = serde_json::to_string(&action.to_raw())
+                .wrap_err("failed to encode withdrawal action as JSON")?;
+            withdrawals.push((event_id, action));
+        }
+        self.run(move |connection| {
+            let transaction = connection
+                .transaction()
+                .wrap_err("failed to start ledger transaction")?;
+            transaction
+                .execute(
+                    "INSERT INTO batches (rollup_height, status) VALUES (?1, ?2) ON CONFLICT \
+                     (rollup_height) DO UPDATE SET status = excluded.status, error = NULL WHERE \
+                     status != ?3",
+                    params![
+                        rollup_height,
+                        BatchStatus::Pending.as_str(),
+                        BatchStatus::Submitted.as_str()
+                    ],
+                )
+                .wrap_err("failed to record batch")?;
+            for (event_id, action) in withdrawals {
+                transaction
+                    .execute(
+                        "INSERT OR REPLACE INTO withdrawals (event_id, rollup_height, action) \
+                         VALUES (?1, ?2, ?3)",
+                        params![event_id, rollup_height, action],
+                    )
+                    .wrap_err_with(|| format!("failed to record withdrawal `{event_id}`"))?;
+            }
+            transaction
+                .commit()
+                .wrap_err("failed to commit ledger transaction")
+        })
+        .await
+    }
+
+    /// Records that the batch at `rollup_height` was executed on the sequencer.
+    #[instrument(skip_all, fields(rollup_height), err)]
+    pub(crate) async fn record_submitted(
+        &self,
+        rollup_height: u64,
+        sequencer_tx_hash: &str,
+        sequencer_height: u64,
+    ) -> eyre::Result<()> {
+        let sequencer_tx_hash = sequencer_tx_hash.to_string();
+        self.run(move |connection| {
+            connection
+                .execute(
+                    "UPDATE batches SET status = ?2, sequencer_tx_hash = ?3, sequencer_height = \
+                     ?4, error = NULL WHERE rollup_height = ?1",
+                    params![
+                        rollup_height,
+                        BatchStatus::Submitted.as_str(),
+                        sequencer_tx_hash,
+                        sequencer_height
+                    ],
+                )
+                .wrap_err("failed to record submitted batch")?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Records that submitting the batch at `rollup_height` failed.
+    #[instrument(skip_all, fields(rollup_height), err)]
+    pub(crate) async fn record_failed(&self, rollup_height: u64, error: &str) -> eyre::Result<()> {
+        let error = error.to_string();
+        self.run(move |connection| {
+            connection
+                .execute(
+                    "UPDATE batches SET status = ?2, error = ?3 WHERE rollup_height = ?1 AND \
+                     status != ?4",
+                    params![
+                        rollup_height,
+                        BatchStatus::Failed.as_str(),
+                        error,
+                        BatchStatus::Submitted.as_str()
+                    ],
+                )
+                .wrap_err("failed to record failed batch")?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Returns the withdrawal with the given `rollup_withdrawal_event_id`, if recorded.
+    pub(crate) async fn withdrawal(&self, event_id: &str) -> eyre::Result<Option<Withdrawal>> {
+        let event_id = event_id.to_string();
+        self.run(move |connection| {
+            connection
+                .query_row(
+                    &format!("{SELECT_WITHDRAWAL} WHERE w.event_id = ?1"),
+                    params![event_id],
+                    RawWithdrawal::from_row,
+                )
+                .optional()
+                .wrap_err("failed to query withdrawal")?
+                .map(RawWithdrawal::try_into_withdrawal)
+                .transpose()
+        })
+        .await
+    }
+
+    /// Returns the withdrawals emitted at or above `from_rollup_height`, ordered by rollup height.
+    ///
+    /// All matching withdrawals are returned if `limit` is `None`.
+    pub(crate) async fn withdrawals(
+        &self,
+        from_rollup_height: u64,
+        limit: Option<u32>,
+    ) -> eyre::Result<Vec<Withdrawal>> {
+        self.run(move |connection| {
+            let mut statement = connection
+                .prepare(&format!(
+                    "{SELECT_WITHDRAWAL} WHERE w.rollup_height >= ?1 ORDER BY w.rollup_height, \
+                     w.event_id LIMIT ?2"
+                ))
+                .wrap_err("failed to prepare withdrawals query")?;
+            // a negative limit means no limit in sqlite
+            let limit = limit.map_or(-1, i64::from);
+            let rows = statement
+                .query_map(params![from_rollup_height, limit], RawWithdrawal::from_row)
+                .wrap_err("failed to query withdrawals")?;
+            rows.map(|row| {
+                row.wrap_err("failed to read withdrawal")
+                    .and_then(RawWithdrawal::try_into_withdrawal)
+            })
+            .collect()
+        })
+        .await
+    }
+}
+
+struct RawWithdrawal {
+    event_id: String,
+    rollup_height: u64,
+    action: String,
+    status: String,
+    sequencer_tx_hash: Option<String>,
+    sequencer_height: Option<u64>,
+    error: Option<String>,
+}
+
+impl RawWithdrawal {
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            event_id: row.get(0)?,
+            rollup_height: row.get(1)?,
+            action: row.get(2)?,
+            status: row.get(3)?,
+            sequencer_tx_hash: row.get(4)?,
+            sequencer_height: row.get(5)?,
+            error: row.get(6)?,
+        })
+    }
+
+    fn try_into_withdrawal(self) -> eyre::Result<Withdrawal> {
+        Ok(Withdrawal {
+            action: serde_json::from_str(&self.action)
+                .wrap_err("failed to decode withdrawal action recorded in ledger")?,
+            status: BatchStatus::parse(&self.status)?,
+            event_id: self.event_id,
+            rollup_height: self.rollup_height,
+            sequencer_tx_hash: self.sequencer_tx_hash,
+            sequencer_height: self.sequencer_height,
+            error: self.error,
+        })
+    }
+}
+
+/// Returns the `rollup_withdrawal_event_id` of a withdrawal action.
+pub(crate) fn withdrawal_event_id(action: &Action) -> eyre::Result<Option<String>> {
+    match action {
+        Action::BridgeUnlock(action) => Ok(Some(action.rollup_withdrawal_event_id.clone())),
+        Action::Ics20Withdrawal(action) => {
+            let memo: memos::v1alpha1::Ics20WithdrawalFromRollup =
+                serde_json::from_str(&action.memo)
+                    .wrap_err("failed to parse memo of ics20 withdrawal")?;
+            Ok(Some(memo.rollup_withdrawal_event_id))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The outcome of comparing the ledger against the sequencer.
+#[derive(Debug, Default, Serialize)]
+pub struct Reconciliation {
+    /// The number of withdrawals in the ledger that were checked.
+    pub checked: usize,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+/// A withdrawal whose ledger entry disagrees with the sequencer.
+#[derive(Debug, Serialize)]
+pub struct Discrepancy {
+    pub kind: DiscrepancyKind,
+    pub withdrawal: Withdrawal,
+    /// The rollup block number the sequencer recorded for the withdrawal event, if any.
+    pub sequencer_rollup_block_number: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscrepancyKind {
+    /// The ledger records the withdrawal as submitted, but the sequencer does not know it.
+    MissingOnSequencer,
+    /// The sequencer executed the withdrawal, but the ledger does not record it as submitted.
+    NotRecordedAsSubmitted,
+    /// The sequencer executed the withdrawal for a different rollup block than recorded.
+    RollupHeightMismatch,
+}
+
+/// Compares a ledger entry with the rollup block number the sequencer recorded for it.
+fn compare(
+    withdrawal: &Withdrawal,
+    sequencer_rollup_block_number: Option<u64>,
+) -> Option<DiscrepancyKind> {
+    match (withdrawal.status, sequencer_rollup_block_number) {
+        (BatchStatus::Submitted, None) => Some(DiscrepancyKind::MissingOnSequencer),
+        (_, None) => None,
+        (_, Some(number)) if number != withdrawal.rollup_height => {
+            Some(DiscrepancyKind::RollupHeightMismatch)
+        }
+        (BatchStatus::Submitted, Some(_)) => None,
+        (BatchStatus::Pending | BatchStatus::Failed, Some(_)) => {
+            Some(DiscrepancyKind::NotRecordedAsSubmitted)
+        }
+    }
+}
+
+/// Compares every withdrawal in the ledger against the withdrawal events the sequencer recorded
+/// for `bridge_address`.
+///
+/// # Errors
+/// Returns an error if the ledger or the sequencer cannot be queried.
+pub(crate) async fn reconcile(
+    ledger: &Ledger,
+    client: &sequencer_client::HttpClient,
+    bridge_address: Address,
+) -> eyre::Result<Reconciliation> {
+    use sequencer_client::SequencerClientExt as _;
+
+    let withdrawals = ledger.withdrawals(0, None).await?;
+    let mut reconciliation = Reconciliation {
+        checked: withdrawals.len(),
+        ..Reconciliation::default()
+    };
+    for withdrawal in withdrawals {
+        let sequencer_rollup_block_number = client
+            .get_bridge_account_withdrawal_event(bridge_address, &withdrawal.event_id)
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed to query sequencer for withdrawal event `{}`",
+                    withdrawal.event_id
+                )
+            })?
+            .rollup_block_number;
+        if let Some(kind) = compare(&withdrawal, sequencer_rollup_block_number) {
+            reconciliation.discrepancies.push(Discrepancy {
+                kind,
+                withdrawal,
+                sequencer_rollup_block_number,
+            });
+        }
+    }
+    Ok(reconciliation)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset::Denom,
+        protocol::transaction::v1alpha1::action::BridgeUnlockAction,
+    };
+
+    use super::*;
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    fn unlock(event_id: &str, rollup_height: u64) -> Action {
+        let denom: Denom = "nria".parse().unwrap();
+        Action::BridgeUnlock(BridgeUnlockAction {
+            to: address(1),
+            amount: 100,
+            fee_asset: denom,
+            bridge_address: address(2),
+            memo: String::new(),
+            rollup_block_number: rollup_height,
+            rollup_withdrawal_event_id: event_id.to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn batch_lifecycle_is_recorded() {
+        let ledger = Ledger::open("").unwrap();
+        ledger
+            .record_batch(5, &[unlock("a", 5), unlock("b", 5)])
+            .await
+            .unwrap();
+        ledger.record_batch(7, &[unlock("c", 7)]).await.unwrap();
+
+        let pending = ledger.withdrawal("a").await.unwrap().unwrap();
+        assert_eq!(pending.status, BatchStatus::Pending);
+        assert_eq!(pending.rollup_height, 5);
+        assert!(pending.sequencer_tx_hash.is_none());
+
+        ledger.record_submitted(5, "deadbeef", 42).await.unwrap();
+        let submitted = ledger.withdrawal("b").await.unwrap().unwrap();
+        assert_eq!(submitted.status, BatchStatus::Submitted);
+        assert_eq!(submitted.sequencer_tx_hash.as_deref(), Some("deadbeef"));
+        assert_eq!(submitted.sequencer_height, Some(42));
+
+        // observing a submitted batch again, e.g. after a restart, does not reset it
+        ledger.record_batch(5, &[unlock("a", 5)]).await.unwrap();
+        ledger.record_failed(5, "boom").await.unwrap();
+        assert_eq!(
+            ledger.withdrawal("a").await.unwrap().unwrap().status,
+            BatchStatus::Submitted
+        );
+
+        ledger.record_failed(7, "boom").await.unwrap();
+        let failed = ledger.withdrawal("c").await.unwrap().unwrap();
+        assert_eq!(failed.status, BatchStatus::Failed);
+        assert_eq!(failed.error.as_deref(), Some("boom"));
+
+        assert!(ledger.withdrawal("d").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn withdrawals_are_listed_by_rollup_height() {
+        let ledger = Ledger::open("").unwrap();
+        ledger.record_batch(7, &[unlock("c", 7)]).await.unwrap();
+        ledger
+            .record_batch(5, &[unlock("b", 5), unlock("a", 5)])
+            .await
+            .unwrap();
+
+        let event_ids = |withdrawals: Vec<Withdrawal>| {
+            withdrawals
+                .into_iter()
+                .map(|w| w.event_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            event_ids(ledger.withdrawals(0, None).await.unwrap()),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            event_ids(ledger.withdrawals(6, None).await.unwrap()),
+            vec!["c"]
+        );
+        assert_eq!(
+            event_ids(ledger.withdrawals(0, Some(2)).await.unwrap()),
+            vec!["a", "b"]
+        );
+    }
+
+    #[tokio::test]
+    async fn ledger_survives_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ledger.sqlite");
+        let path = path.to_str().unwrap();
+        {
+            let ledger = Ledger::open(path).unwrap();
+            ledger.record_batch(5, &[unlock("a", 5)]).await.unwrap();
+            ledger.record_submitted(5, "deadbeef", 42).await.unwrap();
+        }
+        let ledger = Ledger::open(path).unwrap();
+        assert_eq!(
+            ledger.withdrawal("a").await.unwrap().unwrap().status,
+            BatchStatus::Submitted
+        );
+    }
+
+    #[tokio::test]
+    async fn ledger_entries_are_compared_against_sequencer() {
+        let ledger = Ledger::open("").unwrap();
+        ledger.record_batch(5, &[unlock("a", 5)]).await.unwrap();
+        let pending = ledger.withdrawal("a").await.unwrap().unwrap();
+        ledger.record_submitted(5, "deadbeef", 42).await.unwrap();
+        let submitted = ledger.withdrawal("a").await.unwrap().unwrap();
+
+        assert_eq!(compare(&pending, None), None);
+        assert_eq!(compare(&submitted, Some(5)), None);
+        assert_eq!(
+            compare(&submitted, None),
+            Some(DiscrepancyKind::MissingOnSequencer)
+        );
+        assert_eq!(
+            compare(&pending, Some(5)),
+            Some(DiscrepancyKind::NotRecordedAsSubmitted)
+        );
+        assert_eq!(
+            compare(&submitted, Some(6)),
+            Some(DiscrepancyKind::RollupHeightMismatch)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    fs,
     net::SocketAddr,
     sync::Arc,
     time::Duration,
@@ -7,6 +8,7 @@ use std::{
 use astria_core::generated::sequencerblock::v1alpha1::sequencer_service_client::SequencerServiceClient;
 use astria_eyre::eyre::{
     self,
+    ensure,
     WrapErr as _,
 };
 use axum::{
@@ -14,7 +16,10 @@ use axum::{
     Router,
     Server,
 };
-use ethereum::watcher::Watcher;
+use futures::{
+    future,
+    FutureExt as _,
+};
 use http::Uri;
 use hyper::server::conn::AddrIncoming;
 use startup::Startup;
@@ -37,20 +42,39 @@ use tracing::{
     instrument,
 };
 
+pub use self::ledger::{
+    BatchStatus,
+    Discrepancy,
+    DiscrepancyKind,
+    Reconciliation,
+    Withdrawal,
+};
 pub(crate) use self::state::StateSnapshot;
 use self::{
-    ethereum::watcher,
+    ethereum::confirmation::Confirmation,
+    ledger::Ledger,
+    source::{
+        Watcher,
+        WithdrawalSource,
+    },
     state::State,
     submitter::Submitter,
 };
 use crate::{
     api,
-    config::Config,
+    config::{
+        Config,
+        RollupSource,
+    },
     metrics::Metrics,
 };
 
 mod batch;
+pub(crate) mod committee;
 mod ethereum;
+mod grpc;
+mod ledger;
+mod source;
 mod startup;
 mod state;
 mod submitter;
@@ -59,8 +83,9 @@ pub struct BridgeWithdrawer {
     // Token to signal all subtasks to shut down gracefully.
     shutdown_token: CancellationToken,
     api_server: api::ApiServer,
+    committee_api_server: Option<api::ApiServer>,
     submitter: Submitter,
-    ethereum_watcher: watcher::Watcher,
+    watcher: Watcher,
     startup: startup::Startup,
     state: Arc<State>,
 }
@@ -78,17 +103,31 @@ impl BridgeWithdrawer {
             sequencer_cometbft_endpoint,
             sequencer_chain_id,
             sequencer_key_path,
+            committee_key_share_path,
+            committee_public_key_package_path,
+            committee_peer_endpoints,
+            committee_api_addr,
+            committee_secret_path,
             sequencer_address_prefix,
             fee_asset_denomination,
             ethereum_contract_address,
+            ethereum_withdrawal_contracts,
             ethereum_rpc_endpoint,
+            ethereum_confirmation_depth,
+            ethereum_wait_for_finalized,
+            rollup_source,
+            rollup_grpc_endpoint,
             rollup_asset_denomination,
             sequencer_bridge_address,
             sequencer_grpc_endpoint,
+            ledger_path,
+            max_actions_per_transaction,
+            fee_top_up_alert_runway_blocks,
             ..
         } = cfg;
 
         let state = Arc::new(State::new());
+        let ledger = Arc::new(Ledger::open(&ledger_path).wrap_err("failed to open ledger")?);
 
         let sequencer_bridge_address = sequencer_bridge_address
             .parse()
@@ -106,17 +145,67 @@ impl BridgeWithdrawer {
         let startup = startup::Builder {
             shutdown_token: shutdown_handle.token(),
             state: state.clone(),
-            sequencer_chain_id,
+            sequencer_chain_id: sequencer_chain_id.clone(),
             sequencer_cometbft_client: sequencer_cometbft_client.clone(),
             sequencer_bridge_address,
             sequencer_grpc_client: sequencer_grpc_client.clone(),
             expected_fee_asset: fee_asset_denomination,
+            resume_last_batch: max_actions_per_transaction > 0,
             metrics,
         }
         .build();
 
         let startup_handle = startup::InfoHandle::new(state.subscribe());
 
+        let committee_secret = if committee_key_share_path.is_empty() {
+            None
+        } else {
+            let secret = fs::read_to_string(&committee_secret_path).wrap_err_with(|| {
+                format!("failed to read committee secret from path `{committee_secret_path}`")
+            })?;
+            let secret = secret.trim().to_string();
+            ensure!(!secret.is_empty(), "committee secret must not be empty");
+            Some(secret)
+        };
+        let committee = if let Some(secret) = &committee_secret {
+            let peer_endpoints = committee_peer_endpoints
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(ToString::to_string)
+                .collect();
+            Some(
+                committee::Member::load(
+                    &committee_key_share_path,
+                    &committee_public_key_package_path,
+                    peer_endpoints,
+                    secret,
+                    &sequencer_address_prefix,
+                    sequencer_chain_id,
+                    sequencer_grpc_client.clone(),
+                )
+                .wrap_err("failed to load withdrawer committee membership")?,
+            )
+        } else {
+            None
+        };
+        let committee_api_server = match (&committee, &committee_secret) {
+            (Some(member), Some(secret)) => {
+                let socket_addr = committee_api_addr.parse::<SocketAddr>().wrap_err_with(|| {
+                    format!(
+                        "failed to parse provided `committee_api_addr` string as socket address: \
+                         `{committee_api_addr}`"
+                    )
+                })?;
+                Some(api::start_committee(
+                    socket_addr,
+                    member.participant(),
+                    secret,
+                ))
+            }
+            _ => None,
+        };
+
         // make submitter object
         let (submitter, submitter_handle) = submitter::Builder {
             shutdown_token: shutdown_handle.token(),
@@ -124,38 +213,69 @@ impl BridgeWithdrawer {
             sequencer_cometbft_client,
             sequencer_grpc_client,
             sequencer_key_path,
+            committee,
             sequencer_address_prefix: sequencer_address_prefix.clone(),
+            ledger: ledger.clone(),
+            sequencer_bridge_address,
+            max_actions_per_transaction,
+            fee_top_up_alert_runway_blocks,
             state: state.clone(),
             metrics,
         }
         .build()
         .wrap_err("failed to initialize submitter")?;
 
-        let ethereum_watcher = watcher::Builder {
-            ethereum_contract_address,
-            ethereum_rpc_endpoint,
-            startup_handle,
+        let source: Box<dyn WithdrawalSource> = match rollup_source {
+            RollupSource::Ethereum => {
+                let confirmation = if ethereum_wait_for_finalized {
+                    Confirmation::Finalized
+                } else {
+                    Confirmation::Depth(ethereum_confirmation_depth)
+                };
+                Box::new(
+                    ethereum::source::Builder {
+                        ethereum_contract_address,
+                        ethereum_withdrawal_contracts,
+                        ethereum_rpc_endpoint,
+                        confirmation,
+                        rollup_asset_denom: rollup_asset_denomination,
+                        bridge_address: sequencer_bridge_address,
+                    }
+                    .build()
+                    .wrap_err("failed to build ethereum withdrawal source")?,
+                )
+            }
+            RollupSource::Grpc => Box::new(
+                grpc::Source::new(
+                    &rollup_grpc_endpoint,
+                    rollup_asset_denomination,
+                    sequencer_bridge_address,
+                )
+                .wrap_err("failed to build grpc withdrawal source")?,
+            ),
+        };
+        let watcher = source::Builder {
             shutdown_token: shutdown_handle.token(),
+            startup_handle,
             state: state.clone(),
-            rollup_asset_denom: rollup_asset_denomination,
-            bridge_address: sequencer_bridge_address,
+            source,
             submitter_handle,
         }
-        .build()
-        .wrap_err("failed to build ethereum watcher")?;
+        .build();
 
         // make api server
         let state_rx = state.subscribe();
         let api_socket_addr = api_addr.parse::<SocketAddr>().wrap_err_with(|| {
             format!("failed to parse provided `api_addr` string as socket address: `{api_addr}`",)
         })?;
-        let api_server = api::start(api_socket_addr, state_rx);
+        let api_server = api::start(api_socket_addr, state_rx, ledger);
 
         let service = Self {
             shutdown_token: shutdown_handle.token(),
             api_server,
+            committee_api_server,
             submitter,
-            ethereum_watcher,
+            watcher,
             startup,
             state,
         };
@@ -167,14 +287,23 @@ impl BridgeWithdrawer {
         self.api_server.local_addr()
     }
 
+    /// Returns the address of the committee API if this node is a member of a withdrawer
+    /// committee.
+    pub fn committee_local_addr(&self) -> Option<SocketAddr> {
+        self.committee_api_server
+            .as_ref()
+            .map(api::ApiServer::local_addr)
+    }
+
     // Panic won't happen because `startup_task` is unwraped lazily after checking if it's `Some`.
     #[allow(clippy::missing_panics_doc)]
     pub async fn run(self) {
         let Self {
             shutdown_token,
             api_server,
+            committee_api_server,
             submitter,
-            ethereum_watcher,
+            watcher,
             startup,
             state: _state,
         } = self;
@@ -186,13 +315,14 @@ impl BridgeWithdrawer {
             mut api_task,
             mut startup_task,
             mut submitter_task,
-            mut ethereum_watcher_task,
+            mut watcher_task,
         } = spawn_tasks(
             api_server,
+            committee_api_server,
             api_shutdown_signal_rx,
             startup,
             submitter,
-            ethereum_watcher,
+            watcher,
         );
 
         let shutdown = loop {
@@ -208,7 +338,7 @@ impl BridgeWithdrawer {
                             break Shutdown {
                                 api_task: Some(api_task),
                                 submitter_task: Some(submitter_task),
-                                ethereum_watcher_task: Some(ethereum_watcher_task),
+                                watcher_task: Some(watcher_task),
                                 startup_task: None,
                                 api_shutdown_signal,
                                 token: shutdown_token,
@@ -221,7 +351,7 @@ impl BridgeWithdrawer {
                     break Shutdown {
                         api_task: None,
                         submitter_task: Some(submitter_task),
-                        ethereum_watcher_task: Some(ethereum_watcher_task),
+                        watcher_task: Some(watcher_task),
                         startup_task,
                         api_shutdown_signal,
                        token: shutdown_token
@@ -232,18 +362,18 @@ impl BridgeWithdrawer {
                     break Shutdown {
                         api_task: Some(api_task),
                         submitter_task: None,
-                        ethereum_watcher_task:Some(ethereum_watcher_task),
+                        watcher_task:Some(watcher_task),
                         startup_task,
                         api_shutdown_signal,
                         token: shutdown_token
                     }
                 }
-                o = &mut ethereum_watcher_task => {
-                    report_exit("ethereum watcher", o);
+                o = &mut watcher_task => {
+                    report_exit("watcher", o);
                     break Shutdown {
                         api_task: Some(api_task),
                         submitter_task: Some(submitter_task),
-                        ethereum_watcher_task: None,
+                        watcher_task: None,
                         startup_task,
                         api_shutdown_signal,
                         token: shutdown_token
@@ -255,29 +385,57 @@ impl BridgeWithdrawer {
     }
 }
 
+/// Compares the withdrawals recorded in the ledger at `cfg.ledger_path` against the withdrawal
+/// events recorded by the sequencer for `cfg.sequencer_bridge_address`.
+///
+/// # Errors
+///
+/// - If the ledger cannot be opened or read.
+/// - If the sequencer cannot be queried.
+pub async fn reconcile(cfg: &Config) -> eyre::Result<Reconciliation> {
+    eyre::ensure!(
+        !cfg.ledger_path.is_empty(),
+        "a ledger path must be configured to reconcile the ledger"
+    );
+    let ledger = Ledger::open(&cfg.ledger_path).wrap_err("failed to open ledger")?;
+    let bridge_address = cfg
+        .sequencer_bridge_address
+        .parse()
+        .wrap_err("failed to parse sequencer bridge address")?;
+    let client = sequencer_client::HttpClient::new(&*cfg.sequencer_cometbft_endpoint)
+        .wrap_err("failed constructing cometbft http client")?;
+    ledger::reconcile(&ledger, &client, bridge_address).await
+}
+
 #[allow(clippy::struct_field_names)] // allow: for parity with the `Shutdown` struct.
 struct TaskHandles {
     api_task: JoinHandle<eyre::Result<()>>,
     startup_task: Option<JoinHandle<eyre::Result<()>>>,
     submitter_task: JoinHandle<eyre::Result<()>>,
-    ethereum_watcher_task: JoinHandle<eyre::Result<()>>,
+    watcher_task: JoinHandle<eyre::Result<()>>,
 }
 
 #[instrument(skip_all)]
 fn spawn_tasks(
     api_server: Server<AddrIncoming, IntoMakeService<Router>>,
+    committee_api_server: Option<Server<AddrIncoming, IntoMakeService<Router>>>,
     api_shutdown_signal_rx: Receiver<()>,
     startup: Startup,
     submitter: Submitter,
-    ethereum_watcher: Watcher,
+    watcher: Watcher,
 ) -> TaskHandles {
+    let api_shutdown_signal = api_shutdown_signal_rx.map(|_| ()).shared();
     let api_task = tokio::spawn(async move {
-        api_server
-            .with_graceful_shutdown(async move {
-                let _ = api_shutdown_signal_rx.await;
-            })
-            .await
-            .wrap_err("api server ended unexpectedly")
+        let api = api_server
+            .with_graceful_shutdown(api_shutdown_signal.clone())
+            .map(|res| res.wrap_err("api server ended unexpectedly"));
+        let Some(committee_api_server) = committee_api_server else {
+            return api.await;
+        };
+        let committee_api = committee_api_server
+            .with_graceful_shutdown(api_shutdown_signal)
+            .map(|res| res.wrap_err("committee api server ended unexpectedly"));
+        future::try_join(api, committee_api).await.map(|_| ())
     });
     info!("spawned API server");
 
@@ -286,14 +444,14 @@ fn spawn_tasks(
 
     let submitter_task = tokio::spawn(submitter.run());
     info!("spawned submitter task");
-    let ethereum_watcher_task = tokio::spawn(ethereum_watcher.run());
-    info!("spawned ethereum watcher task");
+    let watcher_task = tokio::spawn(watcher.run());
+    info!("spawned watcher task");
 
     TaskHandles {
         api_task,
         startup_task,
         submitter_task,
-        ethereum_watcher_task,
+        watcher_task,
     }
 }
 
@@ -356,7 +514,7 @@ fn report_exit(task_name: &str, outcome: Result<eyre::Result<()>, JoinError>) {
 struct Shutdown {
     api_task: Option<JoinHandle<eyre::Result<()>>>,
     submitter_task: Option<JoinHandle<eyre::Result<()>>>,
-    ethereum_watcher_task: Option<JoinHandle<eyre::Result<()>>>,
+    watcher_task: Option<JoinHandle<eyre::Result<()>>>,
     startup_task: Option<JoinHandle<eyre::Result<()>>>,
     api_shutdown_signal: oneshot::Sender<()>,
     token: CancellationToken,
@@ -373,7 +531,7 @@ impl Shutdown {
         let Self {
             api_task,
             submitter_task,
-            ethereum_watcher_task,
+            watcher_task,
             startup_task,
             api_shutdown_signal,
             token,
@@ -418,15 +576,12 @@ impl Shutdown {
             }
         }
 
-        // Giving ethereum watcher 5 seconds to shutdown because Kubernetes issues a SIGKILL after
+        // Giving watcher 5 seconds to shutdown because Kubernetes issues a SIGKILL after
         // 30.
-        if let Some(mut ethereum_watcher_task) = ethereum_watcher_task {
+        if let Some(mut watcher_task) = watcher_task {
             info!("waiting for watcher task to shut down");
             let limit = Duration::from_secs(Self::ETHEREUM_WATCHER_SHUTDOWN_TIMEOUT_SECONDS);
-            match timeout(limit, &mut ethereum_watcher_task)
-                .await
-                .map(flatten_result)
-            {
+            match timeout(limit, &mut watcher_task).await.map(flatten_result) {
                 Ok(Ok(())) => info!("watcher exited gracefully"),
                 Ok(Err(error)) => error!(%error, "watcher exited with an error"),
                 Err(_) => {
@@ -434,7 +589,7 @@ impl Shutdown {
                         timeout_secs = limit.as_secs(),
                         "watcher did not shut down within timeout; killing it"
                     );
-                    ethereum_watcher_task.abort();
+                    watcher_task.abort();
                 }
             }
         }
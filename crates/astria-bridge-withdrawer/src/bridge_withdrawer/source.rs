@@ -0,0 +1,233 @@
+//! Sources of withdrawals derived from a rollup.
+//!
+//! A [`WithdrawalSource`] connects to a rollup and yields a [`Batch`] of sequencer actions per
+//! rollup block containing withdrawals. The [`Watcher`] drives the configured source and forwards
+//! its batches to the submitter, independent of how the rollup exposes its withdrawals.
+use std::{
+    future::Future,
+    sync::Arc,
+};
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    OptionExt as _,
+    WrapErr as _,
+};
+use futures::{
+    stream::{
+        self,
+        BoxStream,
+        PollNext,
+    },
+    StreamExt as _,
+};
+use tokio::{
+    select,
+    sync::mpsc,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    debug,
+    info,
+    instrument,
+};
+
+use super::{
+    batch::Batch,
+    startup,
+    state::State,
+    submitter,
+};
+
+/// The withdrawal batches yielded by a [`WithdrawalSource`].
+pub(crate) type Batches = BoxStream<'static, eyre::Result<Batch>>;
+
+/// The channel a task passed to [`batches_from_task`] sends its batches to.
+pub(crate) type BatchesTx = mpsc::Sender<eyre::Result<Batch>>;
+
+/// A rollup from which withdrawals are derived.
+#[async_trait::async_trait]
+pub(crate) trait WithdrawalSource: Send + 'static {
+    /// Connects to the rollup and returns the batches of withdrawals of all rollup blocks at or
+    /// above `info.starting_rollup_height` in ascending order of rollup height.
+    ///
+    /// A batch must only be yielded once its rollup block can no longer be reorged out. Batches
+    /// without actions are skipped.
+    async fn connect(self: Box<Self>, info: startup::Info) -> eyre::Result<Batches>;
+}
+
+/// Adapts `task`, which pushes batches into the provided channel, to a stream of these batches.
+///
+/// The task is only driven while the stream is polled. If the task fails, its error is yielded
+/// after all batches it sent.
+pub(crate) fn batches_from_task<F, Fut>(task: F) -> Batches
+where
+    F: FnOnce(BatchesTx) -> Fut,
+    Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+{
+    let (batches_tx, batches_rx) = mpsc::channel(1);
+    let batches = stream::unfold(batches_rx, |mut batches_rx| async move {
+        let batch = batches_rx.recv().await?;
+        Some((batch, batches_rx))
+    });
+    let task = task(batches_tx.clone());
+    let driver = stream::once(async move {
+        if let Err(error) = task.await {
+            // the receiver is only gone if the stream was dropped.
+            let _ = batches_tx.send(Err(error)).await;
+        }
+    })
+    .filter_map(|()| futures::future::ready(None::<eyre::Result<Batch>>));
+    stream::select_with_strategy(batches, driver, |(): &mut ()| PollNext::Left).boxed()
+}
+
+pub(crate) struct Builder {
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) startup_handle: startup::InfoHandle,
+    pub(crate) state: Arc<State>,
+    pub(crate) source: Box<dyn WithdrawalSource>,
+    pub(crate) submitter_handle: submitter::Handle,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> Watcher {
+        let Self {
+            shutdown_token,
+            startup_handle,
+            state,
+            source,
+            submitter_handle,
+        } = self;
+        Watcher {
+            shutdown_token,
+            startup_handle,
+            state,
+            source,
+            submitter_handle,
+        }
+    }
+}
+
+/// Forwards the withdrawal batches of a [`WithdrawalSource`] to the submitter.
+pub(crate) struct Watcher {
+    shutdown_token: CancellationToken,
+    startup_handle: startup::InfoHandle,
+    state: Arc<State>,
+    source: Box<dyn WithdrawalSource>,
+    submitter_handle: submitter::Handle,
+}
+
+impl Watcher {
+    pub(crate) async fn run(self) -> eyre::Result<()> {
+        let Self {
+            shutdown_token,
+            mut startup_handle,
+            state,
+            source,
+            submitter_handle,
+        } = self;
+
+        let info = select! {
+            () = shutdown_token.cancelled() => {
+                return Err(eyre!("watcher received shutdown signal while waiting for startup"));
+            }
+
+            startup_info = startup_handle.get_info() => {
+                startup_info.wrap_err("failed to receive startup info")?
+            }
+        };
+        debug!(
+            fee_asset = %info.fee_asset,
+            starting_rollup_height = info.starting_rollup_height,
+            "received startup info"
+        );
+
+        let mut next_rollup_height = info.starting_rollup_height;
+        let mut batches = select! {
+            () = shutdown_token.cancelled() => {
+                info!("watcher received shutdown signal while connecting to the rollup");
+                return Ok(());
+            }
+
+            batches = source.connect(info) => {
+                batches.wrap_err("failed to connect to withdrawal source")?
+            }
+        };
+        state.set_watcher_ready();
+
+        loop {
+            select! {
+                biased;
+
+                () = shutdown_token.cancelled() => {
+                    info!("watcher shutting down");
+                    return Ok(());
+                }
+
+                batch = batches.next() => {
+                    let batch = batch
+                        .ok_or_eyre("withdrawal source ended unexpectedly")?
+                        .wrap_err("withdrawal source failed")?;
+                    ensure!(
+                        batch.rollup_height >= next_rollup_height,
+                        "withdrawal source yielded a batch for rollup height `{}`, but batches \
+                         are expected at or above rollup height `{next_rollup_height}`",
+                        batch.rollup_height,
+                    );
+                    next_rollup_height = batch.rollup_height.saturating_add(1);
+                    forward_batch(&submitter_handle, batch).await?;
+                }
+            }
+        }
+    }
+}
+
+#[instrument(skip_all, fields(rollup_height = batch.rollup_height), err)]
+async fn forward_batch(submitter_handle: &submitter::Handle, batch: Batch) -> eyre::Result<()> {
+    if batch.actions.is_empty() {
+        info!("no withdrawal actions found for rollup block; skipping");
+        return Ok(());
+    }
+    submitter_handle
+        .send_batch(batch)
+        .await
+        .wrap_err("failed to send batched events; receiver dropped?")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(rollup_height: u64) -> Batch {
+        Batch {
+            actions: vec![],
+            rollup_height,
+        }
+    }
+
+    #[tokio::test]
+    async fn batches_from_task_yields_sent_batches_before_error() {
+        let batches = batches_from_task(|batches_tx| async move {
+            batches_tx.send(Ok(batch(1))).await.unwrap();
+            batches_tx.send(Ok(batch(2))).await.unwrap();
+            Err(eyre!("task failed"))
+        });
+        let outcome: Vec<_> = batches.collect().await;
+        assert_eq!(outcome.len(), 3);
+        assert_eq!(outcome[0].as_ref().unwrap().rollup_height, 1);
+        assert_eq!(outcome[1].as_ref().unwrap().rollup_height, 2);
+        assert!(outcome[2].is_err());
+    }
+
+    #[tokio::test]
+    async fn batches_from_task_ends_when_task_succeeds() {
+        let batches = batches_from_task(|batches_tx| async move {
+            batches_tx.send(Ok(batch(1))).await.unwrap();
+            Ok(())
+        });
+        let outcome: Vec<_> = batches.collect().await;
+        assert_eq!(outcome.len(), 1);
+    }
+}
@@ -69,6 +69,7 @@ pub(super) struct Builder {
     pub(super) sequencer_grpc_client: SequencerServiceClient<Channel>,
     pub(super) sequencer_bridge_address: Address,
     pub(super) expected_fee_asset: asset::Denom,
+    pub(super) resume_last_batch: bool,
     pub(super) metrics: &'static Metrics,
 }
 
@@ -82,6 +83,7 @@ impl Builder {
             sequencer_bridge_address,
             sequencer_grpc_client,
             expected_fee_asset,
+            resume_last_batch,
             metrics,
         } = self;
 
@@ -93,13 +95,14 @@ impl Builder {
             sequencer_grpc_client,
             sequencer_bridge_address,
             expected_fee_asset,
+            resume_last_batch,
             metrics,
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
-pub(super) struct Info {
+pub(crate) struct Info {
     pub(super) starting_rollup_height: u64,
     pub(super) fee_asset: asset::Denom,
     pub(super) chain_id: String,
@@ -140,6 +143,9 @@ pub(super) struct Startup {
     sequencer_grpc_client: SequencerServiceClient<Channel>,
     sequencer_bridge_address: Address,
     expected_fee_asset: asset::Denom,
+    /// Resume at the rollup height of the last batch instead of the one after it, because the
+    /// last batch might have been split across several transactions of which not all executed.
+    resume_last_batch: bool,
     metrics: &'static Metrics,
 }
 
@@ -330,12 +336,22 @@ impl Startup {
             .await
             .wrap_err("failed to get the bridge account's last sequencer transaction")?;
         let starting_rollup_height = if let Some(signed_transaction) = signed_transaction {
-            rollup_height_from_signed_transaction(&signed_transaction)
-                .wrap_err(
+            let last_batch_rollup_height =
+                rollup_height_from_signed_transaction(&signed_transaction).wrap_err(
                     "failed to extract rollup height from last transaction by the bridge account",
-                )?
-                .checked_add(1)
-                .ok_or_eyre("failed to increment rollup height by 1")?
+                )?;
+            if self.resume_last_batch {
+                info!(
+                    last_batch.rollup_height = last_batch_rollup_height,
+                    "batches may be split across several transactions; resuming at the last \
+                     batch's rollup height to submit its remaining withdrawals"
+                );
+                last_batch_rollup_height
+            } else {
+                last_batch_rollup_height
+                    .checked_add(1)
+                    .ok_or_eyre("failed to increment rollup height by 1")?
+            }
         } else {
             info!(
                 bridge_account_address = %self.sequencer_bridge_address,
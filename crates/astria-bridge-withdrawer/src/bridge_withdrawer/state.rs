@@ -46,6 +46,9 @@ forward_setter!(
     [set_last_rollup_height_submitted <- u64],
     [set_last_sequencer_height <- u64],
     [set_last_sequencer_tx_hash <- tendermint::Hash],
+    [set_fee_asset_balance <- u128],
+    [set_fee_runway_blocks <- u64],
+    [set_fee_top_up_required <- bool],
 );
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
@@ -60,6 +63,14 @@ pub(crate) struct StateSnapshot {
     last_rollup_height_submitted: Option<u64>,
     last_sequencer_block: Option<u64>,
     last_sequencer_tx_hash: Option<tendermint::Hash>,
+
+    /// The balance of the fee asset available to pay for withdrawal transactions.
+    fee_asset_balance: Option<u128>,
+    /// The number of further rollup blocks the fee asset balance can pay withdrawal fees for,
+    /// based on the fees recently spent per rollup block.
+    fee_runway_blocks: Option<u64>,
+    /// Whether the fee asset balance is insufficient, or its runway below the alert threshold.
+    fee_top_up_required: bool,
 }
 
 impl StateSnapshot {
@@ -118,4 +129,22 @@ impl StateSnapshot {
         self.last_sequencer_tx_hash = Some(hash);
         changed
     }
+
+    fn set_fee_asset_balance(&mut self, balance: u128) -> bool {
+        let changed = self.fee_asset_balance.map_or(true, |b| b != balance);
+        self.fee_asset_balance = Some(balance);
+        changed
+    }
+
+    fn set_fee_runway_blocks(&mut self, runway: u64) -> bool {
+        let changed = self.fee_runway_blocks.map_or(true, |r| r != runway);
+        self.fee_runway_blocks = Some(runway);
+        changed
+    }
+
+    fn set_fee_top_up_required(&mut self, required: bool) -> bool {
+        let changed = self.fee_top_up_required ^ required;
+        self.fee_top_up_required = required;
+        changed
+    }
 }
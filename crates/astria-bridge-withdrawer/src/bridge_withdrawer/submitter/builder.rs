@@ -1,10 +1,18 @@
-use std::sync::Arc;
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
 
 use astria_core::generated::sequencerblock::v1alpha1::sequencer_service_client::SequencerServiceClient;
 use astria_eyre::eyre::{
     self,
+    ensure,
     Context as _,
 };
+use sequencer_client::Address;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{
@@ -12,9 +20,15 @@ use tracing::{
     instrument,
 };
 
-use super::state::State;
+use super::{
+    fees::FeeHistory,
+    signer::Signer,
+    state::State,
+};
 use crate::{
     bridge_withdrawer::{
+        committee,
+        ledger::Ledger,
         startup,
         submitter::Batch,
     },
@@ -47,7 +61,12 @@ pub(crate) struct Builder {
     pub(crate) shutdown_token: CancellationToken,
     pub(crate) startup_handle: startup::InfoHandle,
     pub(crate) sequencer_key_path: String,
+    pub(crate) committee: Option<committee::Member>,
     pub(crate) sequencer_address_prefix: String,
+    pub(crate) ledger: Arc<Ledger>,
+    pub(crate) sequencer_bridge_address: Address,
+    pub(crate) max_actions_per_transaction: u32,
+    pub(crate) fee_top_up_alert_runway_blocks: u64,
     pub(crate) sequencer_cometbft_client: sequencer_client::HttpClient,
     pub(crate) sequencer_grpc_client: SequencerServiceClient<tonic::transport::Channel>,
     pub(crate) state: Arc<State>,
@@ -61,19 +80,45 @@ impl Builder {
             shutdown_token,
             startup_handle,
             sequencer_key_path,
+            committee,
             sequencer_address_prefix,
+            ledger,
+            sequencer_bridge_address,
+            max_actions_per_transaction,
+            fee_top_up_alert_runway_blocks,
             sequencer_cometbft_client,
             sequencer_grpc_client,
             state,
             metrics,
         } = self;
 
-        let signer = super::signer::SequencerKey::builder()
-            .path(sequencer_key_path)
-            .prefix(sequencer_address_prefix)
-            .try_build()
-            .wrap_err("failed to load sequencer private key")?;
-        info!(address = %signer.address(), "loaded sequencer signer");
+        let max_actions_per_transaction = NonZeroUsize::new(
+            max_actions_per_transaction
+                .try_into()
+                .wrap_err("max actions per transaction does not fit into usize")?,
+        );
+        ensure!(
+            committee.is_none() || max_actions_per_transaction.is_none(),
+            "splitting batches across several transactions is not supported when signing as a \
+             member of a withdrawer committee"
+        );
+
+        let signer = if let Some(member) = committee {
+            info!(
+                address = %member.address(),
+                is_coordinator = member.coordinator().is_some(),
+                "signing as member of withdrawer committee"
+            );
+            Signer::Committee(member)
+        } else {
+            let key = super::signer::SequencerKey::builder()
+                .path(sequencer_key_path)
+                .prefix(sequencer_address_prefix)
+                .try_build()
+                .wrap_err("failed to load sequencer private key")?;
+            info!(address = %key.address(), "loaded sequencer signer");
+            Signer::Key(key)
+        };
 
         let (batches_tx, batches_rx) = tokio::sync::mpsc::channel(BATCH_QUEUE_SIZE);
         let handle = Handle::new(batches_tx);
@@ -87,6 +132,11 @@ impl Builder {
                 sequencer_cometbft_client,
                 sequencer_grpc_client,
                 signer,
+                ledger,
+                sequencer_bridge_address,
+                max_actions_per_transaction,
+                fee_top_up_alert_runway_blocks,
+                fee_history: Mutex::new(FeeHistory::default()),
                 metrics,
             },
             handle,
@@ -0,0 +1,251 @@
+//! Fee estimation and fee balance tracking for the transactions submitted by the withdrawer.
+use std::{
+    collections::VecDeque,
+    num::NonZeroUsize,
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_core::{
+    primitive::v1::asset,
+    protocol::transaction::v1alpha1::{
+        Action,
+        UnsignedTransaction,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use sequencer_client::{
+    Address,
+    SequencerClientExt as _,
+};
+use tracing::{
+    instrument,
+    warn,
+};
+
+use crate::bridge_withdrawer::state::State;
+
+/// Estimates the fee in `fee_asset` the sequencer charges for executing `tx`.
+#[instrument(skip_all, err)]
+pub(super) async fn estimate_fee(
+    client: sequencer_client::HttpClient,
+    tx: UnsignedTransaction,
+    fee_asset: &asset::Denom,
+    state: Arc<State>,
+) -> eyre::Result<u128> {
+    let fee_asset = fee_asset.to_ibc_prefixed();
+    let response = retry_query("estimating transaction fee", state, || {
+        let client = client.clone();
+        let tx = tx.clone();
+        async move { client.get_transaction_fee(tx).await }
+    })
+    .await?;
+    Ok(response
+        .fees
+        .iter()
+        .filter(|(asset, _)| asset.to_ibc_prefixed() == fee_asset)
+        .fold(0u128, |total, (_, fee)| total.saturating_add(*fee)))
+}
+
+/// Returns the balance of `fee_asset` held by `address`.
+#[instrument(skip_all, err)]
+pub(super) async fn get_fee_asset_balance(
+    client: sequencer_client::HttpClient,
+    address: Address,
+    fee_asset: &asset::Denom,
+    state: Arc<State>,
+) -> eyre::Result<u128> {
+    let fee_asset = fee_asset.to_ibc_prefixed();
+    let response = retry_query("getting fee asset balance", state, || {
+        let client = client.clone();
+        async move { client.get_latest_balance(address).await }
+    })
+    .await?;
+    Ok(response
+        .balances
+        .iter()
+        .find(|balance| balance.denom.to_ibc_prefixed() == fee_asset)
+        .map_or(0, |balance| balance.balance))
+}
+
+/// The number of most recent transaction fees used to estimate the fee runway.
+const FEE_HISTORY_LEN: usize = 100;
+
+/// The fees of the most recent transactions, together with the rollup heights of the batches they
+/// were submitted for.
+#[derive(Debug, Default)]
+pub(super) struct FeeHistory {
+    fees: VecDeque<(u64, u128)>,
+}
+
+impl FeeHistory {
+    /// Records the `fee` of a transaction for the batch at `rollup_height`.
+    pub(super) fn record(&mut self, rollup_height: u64, fee: u128) {
+        self.fees.push_back((rollup_height, fee));
+        if self.fees.len() > FEE_HISTORY_LEN {
+            self.fees.pop_front();
+        }
+    }
+
+    /// Returns the number of rollup blocks that `balance` can pay withdrawal fees for.
+    ///
+    /// The fees spent per rollup block are estimated as the sum of the recorded fees over the
+    /// number of rollup blocks they span, which includes the blocks without withdrawals.
+    pub(super) fn runway_blocks(&self, balance: u128) -> u64 {
+        let (Some((first_height, _)), Some((last_height, _))) =
+            (self.fees.front(), self.fees.back())
+        else {
+            return u64::MAX;
+        };
+        let blocks = last_height.saturating_sub(*first_height).saturating_add(1);
+        let total_fees = self
+            .fees
+            .iter()
+            .fold(0u128, |total, (_, fee)| total.saturating_add(*fee));
+        balance
+            .saturating_mul(u128::from(blocks))
+            .checked_div(total_fees)
+            .map_or(u64::MAX, |runway| u64::try_from(runway).unwrap_or(u64::MAX))
+    }
+}
+
+/// Splits `actions` into chunks of at most `max_actions_per_transaction` actions, preserving
+/// their order. All actions are kept in a single chunk if no maximum is set.
+pub(super) fn split_actions(
+    mut actions: Vec<Action>,
+    max_actions_per_transaction: Option<NonZeroUsize>,
+) -> Vec<Vec<Action>> {
+    let Some(max) = max_actions_per_transaction else {
+        return vec![actions];
+    };
+    let mut chunks = Vec::with_capacity(actions.len().div_ceil(max.get()));
+    while actions.len() > max.get() {
+        let rest = actions.split_off(max.get());
+        chunks.push(std::mem::replace(&mut actions, rest));
+    }
+    chunks.push(actions);
+    chunks
+}
+
+async fn retry_query<F, Fut, T>(what: &'static str, state: Arc<State>, f: F) -> eyre::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, sequencer_client::extension_trait::Error>>,
+{
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(200))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt,
+             next_delay: Option<Duration>,
+             err: &sequencer_client::extension_trait::Error| {
+                state.set_sequencer_connected(false);
+
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    error = err as &dyn std::error::Error,
+                    "failed {what}; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+    let res = tryhard::retry_fn(f)
+        .with_config(retry_config)
+        .await
+        .wrap_err_with(|| format!("failed {what} after 1024 attempts"));
+    state.set_sequencer_connected(res.is_ok());
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::Address,
+        protocol::transaction::v1alpha1::action::BridgeUnlockAction,
+    };
+
+    use super::*;
+
+    fn unlocks(n: usize) -> Vec<Action> {
+        let address = Address::builder()
+            .array([1; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap();
+        (0..n)
+            .map(|i| {
+                Action::BridgeUnlock(BridgeUnlockAction {
+                    to: address,
+                    amount: 100,
+                    fee_asset: "nria".parse().unwrap(),
+                    bridge_address: address,
+                    memo: String::new(),
+                    rollup_block_number: 1,
+                    rollup_withdrawal_event_id: i.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn event_ids(actions: &[Action]) -> Vec<String> {
+        actions
+            .iter()
+            .map(|action| {
+                let Action::BridgeUnlock(unlock) = action else {
+                    panic!("expected a bridge unlock, got {action:?}");
+                };
+                unlock.rollup_withdrawal_event_id.clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn actions_are_not_split_without_maximum() {
+        let chunks = split_actions(unlocks(5), None);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 5);
+    }
+
+    #[test]
+    fn actions_are_split_into_ordered_chunks() {
+        let chunks = split_actions(unlocks(5), NonZeroUsize::new(2));
+        let chunks: Vec<_> = chunks.iter().map(Vec::as_slice).map(event_ids).collect();
+        assert_eq!(chunks, vec![vec!["0", "1"], vec!["2", "3"], vec!["4"]]);
+    }
+
+    #[test]
+    fn runway_is_balance_over_fees_per_rollup_block() {
+        let mut history = FeeHistory::default();
+        assert_eq!(history.runway_blocks(100), u64::MAX);
+
+        history.record(10, 30);
+        assert_eq!(history.runway_blocks(100), 3);
+        assert_eq!(history.runway_blocks(10), 0);
+
+        // 60 spent over the 10 rollup blocks from height 10 to 19
+        history.record(19, 30);
+        assert_eq!(history.runway_blocks(100), 16);
+
+        let mut free = FeeHistory::default();
+        free.record(10, 0);
+        assert_eq!(free.runway_blocks(10), u64::MAX);
+    }
+
+    #[test]
+    fn fee_history_only_keeps_the_most_recent_fees() {
+        let mut history = FeeHistory::default();
+        history.record(0, 1_000);
+        for height in 1..=u64::try_from(FEE_HISTORY_LEN).unwrap() {
+            history.record(height, 1);
+        }
+        // the expensive first fee was evicted
+        assert_eq!(history.runway_blocks(100), 100);
+    }
+}
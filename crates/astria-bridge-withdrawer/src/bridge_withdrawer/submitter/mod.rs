@@ -1,5 +1,9 @@
 use std::{
-    sync::Arc,
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        Mutex,
+    },
     time::Duration,
 };
 
@@ -11,6 +15,7 @@ use astria_core::{
         },
         GetPendingNonceRequest,
     },
+    primitive::v1::asset,
     protocol::transaction::v1alpha1::{
         Action,
         TransactionParams,
@@ -25,6 +30,7 @@ use astria_eyre::eyre::{
 };
 pub(crate) use builder::Builder;
 pub(super) use builder::Handle;
+use fees::FeeHistory;
 use sequencer_client::{
     tendermint_rpc::endpoint::{
         broadcast::tx_sync,
@@ -34,7 +40,7 @@ use sequencer_client::{
     SequencerClientExt,
     SignedTransaction,
 };
-use signer::SequencerKey;
+use signer::Signer;
 use state::State;
 use tokio::{
     select,
@@ -55,14 +61,22 @@ use tracing::{
 
 use super::{
     batch::Batch,
+    ledger::{
+        withdrawal_event_id,
+        Ledger,
+    },
     startup,
     state,
 };
 use crate::metrics::Metrics;
 
 mod builder;
+mod fees;
 pub(crate) mod signer;
 
+/// How long a batch is held before checking again whether the fee asset balance can pay for it.
+const FEE_BALANCE_RECHECK_INTERVAL: Duration = Duration::from_secs(10);
+
 pub(super) struct Submitter {
     shutdown_token: CancellationToken,
     startup_handle: startup::InfoHandle,
@@ -70,23 +84,28 @@ pub(super) struct Submitter {
     batches_rx: mpsc::Receiver<Batch>,
     sequencer_cometbft_client: sequencer_client::HttpClient,
     sequencer_grpc_client: SequencerServiceClient<Channel>,
-    signer: SequencerKey,
+    signer: Signer,
+    ledger: Arc<Ledger>,
+    sequencer_bridge_address: Address,
+    max_actions_per_transaction: Option<NonZeroUsize>,
+    fee_top_up_alert_runway_blocks: u64,
+    fee_history: Mutex<FeeHistory>,
     metrics: &'static Metrics,
 }
 
 impl Submitter {
     pub(super) async fn run(mut self) -> eyre::Result<()> {
-        let sequencer_chain_id = select! {
+        let startup_info = select! {
             () = self.shutdown_token.cancelled() => {
                 report_exit(Ok("submitter received shutdown signal while waiting for startup"));
                 return Ok(());
             }
 
             startup_info = self.startup_handle.get_info() => {
-                let startup::Info { chain_id, .. } = startup_info.wrap_err("submitter failed to get startup info")?;
+                let startup_info = startup_info.wrap_err("submitter failed to get startup info")?;
 
                 self.state.set_submitter_ready();
-                chain_id
+                startup_info
             }
         };
         self.state.set_submitter_ready();
@@ -107,10 +126,18 @@ impl Submitter {
                     // if batch submission fails, halt the submitter
                     if let Err(e) = self.process_batch(
                         self.sequencer_grpc_client.clone(),
-                        &sequencer_chain_id,
+                        &startup_info,
                         actions,
                         rollup_height,
                     ).await {
+                        let reason = format!("{e:#}");
+                        if let Err(error) = self
+                            .ledger
+                            .record_failed(rollup_height, &reason)
+                            .await
+                        {
+                            warn!(%error, "failed to record failed batch in ledger");
+                        }
                         break Err(e);
                     }
                 }
@@ -132,10 +159,122 @@ impl Submitter {
     async fn process_batch(
         &self,
         sequencer_grpc_client: SequencerServiceClient<Channel>,
-        sequencer_chain_id: &String,
+        startup_info: &startup::Info,
         actions: Vec<Action>,
         rollup_height: u64,
     ) -> eyre::Result<()> {
+        let Self {
+            sequencer_cometbft_client,
+            signer,
+            ledger,
+            state,
+            ..
+        } = self;
+
+        ledger
+            .record_batch(rollup_height, &actions)
+            .await
+            .wrap_err("failed to record batch in ledger")?;
+
+        if let Signer::Committee(member) = signer {
+            member.participant().record_batch(rollup_height, &actions);
+            if member.coordinator().is_none() {
+                debug!(
+                    rollup.height = rollup_height,
+                    "recorded batch for signing by the withdrawer committee; leaving submission \
+                     to the coordinator"
+                );
+                return Ok(());
+            }
+        }
+
+        // a batch split across several transactions might have been partially executed before the
+        // withdrawer restarted, in which case startup resumes at its rollup height.
+        let actions = if self.max_actions_per_transaction.is_some()
+            && rollup_height == startup_info.starting_rollup_height
+        {
+            drop_executed_withdrawals(
+                sequencer_cometbft_client.clone(),
+                self.sequencer_bridge_address,
+                actions,
+            )
+            .await
+            .wrap_err("failed to drop withdrawals already executed by the sequencer")?
+        } else {
+            actions
+        };
+        if actions.is_empty() {
+            info!(
+                rollup.height = rollup_height,
+                "all withdrawals of the batch were already executed by the sequencer; skipping"
+            );
+            return Ok(());
+        }
+
+        let chunks = fees::split_actions(actions, self.max_actions_per_transaction);
+        let num_chunks = chunks.len();
+        let mut last_tx_response = None;
+        for (index, actions) in chunks.into_iter().enumerate() {
+            let Some(tx_response) = self
+                .submit_actions(
+                    sequencer_grpc_client.clone(),
+                    startup_info,
+                    actions,
+                    rollup_height,
+                )
+                .await?
+            else {
+                info!(
+                    rollup.height = rollup_height,
+                    "shutdown requested while the batch was held for a fee top-up; leaving it \
+                     pending"
+                );
+                return Ok(());
+            };
+            if num_chunks > 1 {
+                debug!(
+                    rollup.height = rollup_height,
+                    part = index.saturating_add(1),
+                    num_parts = num_chunks,
+                    "submitted part of split batch"
+                );
+            }
+            last_tx_response = Some(tx_response);
+        }
+        let tx_response =
+            last_tx_response.expect("splitting the batch must have returned at least one chunk");
+
+        // update state after successful submission
+        info!(
+            sequencer.block = tx_response.height.value(),
+            sequencer.tx_hash = %tx_response.hash,
+            rollup.height = rollup_height,
+            "withdraw batch successfully executed."
+        );
+        state.set_last_rollup_height_submitted(rollup_height);
+        state.set_last_sequencer_height(tx_response.height.value());
+        state.set_last_sequencer_tx_hash(tx_response.hash);
+        ledger
+            .record_submitted(
+                rollup_height,
+                &tx_response.hash.to_string(),
+                tx_response.height.value(),
+            )
+            .await
+            .wrap_err("failed to record submitted batch in ledger")
+    }
+
+    /// Signs and submits `actions` in a single transaction once the fee asset balance can pay for
+    /// it.
+    ///
+    /// Returns `None` if shutdown was requested while waiting for the fee asset balance.
+    async fn submit_actions(
+        &self,
+        sequencer_grpc_client: SequencerServiceClient<Channel>,
+        startup_info: &startup::Info,
+        actions: Vec<Action>,
+        rollup_height: u64,
+    ) -> eyre::Result<Option<tx::Response>> {
         let Self {
             sequencer_cometbft_client,
             signer,
@@ -143,9 +282,10 @@ impl Submitter {
             metrics,
             ..
         } = self;
+
         // get nonce and make unsigned transaction
         let nonce = get_pending_nonce(
-            sequencer_grpc_client.clone(),
+            sequencer_grpc_client,
             *signer.address(),
             state.clone(),
             metrics,
@@ -158,12 +298,36 @@ impl Submitter {
             actions,
             params: TransactionParams::builder()
                 .nonce(nonce)
-                .chain_id(sequencer_chain_id)
+                .chain_id(&startup_info.chain_id)
                 .build(),
         };
 
+        let fee = fees::estimate_fee(
+            sequencer_cometbft_client.clone(),
+            unsigned.clone(),
+            &startup_info.fee_asset,
+            state.clone(),
+        )
+        .await
+        .wrap_err("failed to estimate transaction fee")?;
+        debug!(fee, "estimated transaction fee");
+        self.fee_history
+            .lock()
+            .expect("fee history lock must not be poisoned")
+            .record(rollup_height, fee);
+        if !self
+            .wait_for_fee_balance(&startup_info.fee_asset, fee)
+            .await
+            .wrap_err("failed to wait for a sufficient fee asset balance")?
+        {
+            return Ok(None);
+        }
+
         // sign transaction
-        let signed = unsigned.into_signed(signer.signing_key());
+        let signed = signer
+            .sign(rollup_height, unsigned)
+            .await
+            .wrap_err("failed to sign transaction")?;
         debug!(transaction_id = %&signed.id(), "signed transaction");
 
         // submit transaction and handle response
@@ -190,19 +354,96 @@ impl Submitter {
                 tx_response.tx_result.log,
             ))
         } else {
-            // update state after successful submission
-            info!(
-                sequencer.block = tx_response.height.value(),
-                sequencer.tx_hash = %tx_response.hash,
-                rollup.height = rollup_height,
-                "withdraw batch successfully executed."
+            Ok(Some(tx_response))
+        }
+    }
+
+    /// Waits until the fee asset balance of the signer can pay `fee`, updating the reported
+    /// balance and the number of further rollup blocks it can pay withdrawal fees for.
+    ///
+    /// Returns `false` if shutdown was requested while waiting.
+    #[instrument(skip_all, fields(fee), err)]
+    async fn wait_for_fee_balance(
+        &self,
+        fee_asset: &asset::Denom,
+        fee: u128,
+    ) -> eyre::Result<bool> {
+        loop {
+            let balance = fees::get_fee_asset_balance(
+                self.sequencer_cometbft_client.clone(),
+                *self.signer.address(),
+                fee_asset,
+                self.state.clone(),
+            )
+            .await
+            .wrap_err("failed to get fee asset balance")?;
+            let runway_blocks = self
+                .fee_history
+                .lock()
+                .expect("fee history lock must not be poisoned")
+                .runway_blocks(balance);
+            self.state.set_fee_asset_balance(balance);
+            self.state.set_fee_runway_blocks(runway_blocks);
+            self.metrics.set_fee_asset_balance(balance);
+            self.metrics.set_fee_runway_blocks(runway_blocks);
+
+            if balance >= fee {
+                let top_up_required = runway_blocks < self.fee_top_up_alert_runway_blocks;
+                if top_up_required {
+                    warn!(
+                        balance,
+                        runway_blocks,
+                        address = %self.signer.address(),
+                        "fee asset balance is running low; top up the withdrawer account"
+                    );
+                }
+                self.state.set_fee_top_up_required(top_up_required);
+                return Ok(true);
+            }
+
+            self.state.set_fee_top_up_required(true);
+            self.metrics.increment_insufficient_fee_balance_count();
+            warn!(
+                balance,
+                address = %self.signer.address(),
+                "fee asset balance cannot pay for the batch; holding it until the withdrawer \
+                 account is topped up"
             );
-            state.set_last_rollup_height_submitted(rollup_height);
-            state.set_last_sequencer_height(tx_response.height.value());
-            state.set_last_sequencer_tx_hash(tx_response.hash);
-            Ok(())
+            select! {
+                () = self.shutdown_token.cancelled() => return Ok(false),
+                () = tokio::time::sleep(FEE_BALANCE_RECHECK_INTERVAL) => {}
+            }
+        }
+    }
+}
+
+/// Drops the withdrawals in `actions` that the sequencer already executed for the bridge account.
+#[instrument(skip_all, err)]
+async fn drop_executed_withdrawals(
+    client: sequencer_client::HttpClient,
+    bridge_address: Address,
+    actions: Vec<Action>,
+) -> eyre::Result<Vec<Action>> {
+    let mut remaining = Vec::with_capacity(actions.len());
+    for action in actions {
+        if let Some(event_id) = withdrawal_event_id(&action)? {
+            let executed = client
+                .get_bridge_account_withdrawal_event(bridge_address, &event_id)
+                .await
+                .wrap_err_with(|| format!("failed to get withdrawal event `{event_id}`"))?
+                .rollup_block_number
+                .is_some();
+            if executed {
+                debug!(
+                    event_id,
+                    "withdrawal was already executed by the sequencer; dropping it"
+                );
+                continue;
+            }
         }
+        remaining.push(action);
     }
+    Ok(remaining)
 }
 
 #[instrument(skip_all)]
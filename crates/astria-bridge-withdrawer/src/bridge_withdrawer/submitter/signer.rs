@@ -4,19 +4,101 @@ use std::{
         Path,
         PathBuf,
     },
+    time::Duration,
 };
 
 use astria_core::{
     crypto::SigningKey,
     primitive::v1::Address,
+    protocol::transaction::v1alpha1::{
+        SignedTransaction,
+        UnsignedTransaction,
+    },
 };
 use astria_eyre::eyre::{
     self,
     bail,
     eyre,
     Context,
+    OptionExt as _,
+};
+use tracing::{
+    instrument,
+    warn,
 };
 
+use crate::bridge_withdrawer::committee;
+
+/// Signs transactions on behalf of the bridge withdrawer address.
+pub(crate) enum Signer {
+    /// The key is held by this node.
+    Key(SequencerKey),
+    /// The key is shared among a committee of withdrawer nodes.
+    Committee(committee::Member),
+}
+
+impl Signer {
+    pub(crate) fn address(&self) -> &Address {
+        match self {
+            Self::Key(key) => key.address(),
+            Self::Committee(member) => member.address(),
+        }
+    }
+
+    /// Signs `transaction`, which contains the batch derived from the rollup block at
+    /// `rollup_height`.
+    ///
+    /// # Errors
+    /// - If this node is a committee member but not the committee's coordinator.
+    /// - If the committee fails to sign.
+    pub(crate) async fn sign(
+        &self,
+        rollup_height: u64,
+        transaction: UnsignedTransaction,
+    ) -> eyre::Result<SignedTransaction> {
+        match self {
+            Self::Key(key) => Ok(transaction.into_signed(key.signing_key())),
+            Self::Committee(member) => {
+                let coordinator = member
+                    .coordinator()
+                    .ok_or_eyre("only the coordinator of the withdrawer committee signs")?;
+                sign_with_committee(coordinator, rollup_height, transaction).await
+            }
+        }
+    }
+}
+
+/// Has the withdrawer committee sign `transaction`, retrying with an exponential backoff while
+/// too few members are able to sign, e.g. because they are still deriving the batch.
+#[instrument(skip_all, err)]
+async fn sign_with_committee(
+    coordinator: &committee::Coordinator,
+    rollup_height: u64,
+    transaction: UnsignedTransaction,
+) -> eyre::Result<SignedTransaction> {
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(200))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt, next_delay: Option<Duration>, error: &eyre::Report| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    %error,
+                    "failed to sign transaction with withdrawer committee; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+    tryhard::retry_fn(|| coordinator.sign(rollup_height, transaction.clone()))
+        .with_config(retry_config)
+        .await
+        .wrap_err("failed to sign transaction with withdrawer committee after 1024 attempts")
+}
+
 pub(crate) struct SequencerKey {
     address: Address,
     signing_key: SigningKey,
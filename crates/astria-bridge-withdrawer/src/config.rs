@@ -4,6 +4,15 @@ use serde::{
     Serialize,
 };
 
+/// How the rollup exposes its withdrawals.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RollupSource {
+    /// Withdrawal events emitted by the `AstriaWithdrawer` contract on an EVM rollup.
+    Ethereum,
+    /// Withdrawals streamed by the rollup's `astria.execution.v1alpha2.WithdrawalService`.
+    Grpc,
+}
+
 // Allowed `struct_excessive_bools` because this is used as a container
 // for deserialization. Making this a builder-pattern is not actionable.
 #[allow(clippy::struct_excessive_bools)]
@@ -18,16 +27,52 @@ pub struct Config {
     pub sequencer_chain_id: String,
     // The path to the private key used to sign transactions submitted to the sequencer.
     pub sequencer_key_path: String,
+    // The path to this node's FROST key share if the node is a member of a withdrawer committee.
+    // If set, transactions are signed by the committee instead of with `sequencer_key_path`.
+    pub committee_key_share_path: String,
+    // The path to the public key package of the withdrawer committee.
+    pub committee_public_key_package_path: String,
+    // The comma separated API endpoints of the other committee members. If set, this node is the
+    // committee's coordinator and submits the transactions signed by the committee.
+    pub committee_peer_endpoints: String,
+    // The socket address at which committee members serve the signing rounds to the coordinator.
+    pub committee_api_addr: String,
+    // The path to the file storing the secret shared by all committee members, which the
+    // coordinator uses to authenticate against the committee API of the other members.
+    pub committee_secret_path: String,
     // The fee asset denomination to use for the bridge account's transactions.
     pub fee_asset_denomination: asset::Denom,
     // The asset denomination being withdrawn from the rollup.
     pub rollup_asset_denomination: asset::denom::TracePrefixed,
     // The bridge address corresponding to the bridged rollup asset on the sequencer.
     pub sequencer_bridge_address: String,
+    // How the rollup exposes its withdrawals. The `ethereum_*` settings only apply to the
+    // `Ethereum` source, `rollup_grpc_endpoint` only to the `Grpc` source.
+    pub rollup_source: RollupSource,
+    // The endpoint of the rollup's withdrawal gRPC service.
+    pub rollup_grpc_endpoint: String,
     // The address of the AstriaWithdrawer contract on the evm rollup.
     pub ethereum_contract_address: String,
+    // Additional contracts on the evm rollup whose withdrawal events are watched, as a comma
+    // separated list of `<contract address>:<sequencer|ics20>:<asset>[:<divisor>]`.
+    pub ethereum_withdrawal_contracts: String,
     // The rpc endpoint of the evm rollup.
     pub ethereum_rpc_endpoint: String,
+    // The number of blocks built on top of a rollup block before its withdrawal events are
+    // forwarded to the sequencer. Events are forwarded as soon as the block is seen if 0.
+    pub ethereum_confirmation_depth: u64,
+    // Forward withdrawal events only once their rollup block is finalized (i.e. firmly committed
+    // for astria rollups). Takes precedence over `ethereum_confirmation_depth` if set.
+    pub ethereum_wait_for_finalized: bool,
+    // The path to the sqlite database recording the observed withdrawals and their submission
+    // status. Withdrawals are only recorded in memory if empty.
+    pub ledger_path: String,
+    // The maximum number of withdrawals submitted in a single sequencer transaction. Larger
+    // batches are split across several transactions. Batches are never split if 0.
+    pub max_actions_per_transaction: u32,
+    // A warning is logged and a fee top-up reported as required on `/status` once the fee asset
+    // balance can pay for fewer than this many further rollup blocks. No alert is raised if 0.
+    pub fee_top_up_alert_runway_blocks: u64,
     // The address prefix to use when constructing sequencer addresses using the signing key.
     pub sequencer_address_prefix: String,
     // The socket address at which the bridge service will server healthz, readyz, and status
@@ -4,7 +4,13 @@ mod build_info;
 pub(crate) mod config;
 pub(crate) mod metrics;
 
-pub use bridge_withdrawer::BridgeWithdrawer;
+pub use bridge_withdrawer::{
+    reconcile,
+    BridgeWithdrawer,
+};
 pub use build_info::BUILD_INFO;
-pub use config::Config;
+pub use config::{
+    Config,
+    RollupSource,
+};
 pub use metrics::Metrics;
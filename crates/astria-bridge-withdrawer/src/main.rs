@@ -1,6 +1,7 @@
 use std::process::ExitCode;
 
 use astria_bridge_withdrawer::{
+    reconcile,
     BridgeWithdrawer,
     Config,
     BUILD_INFO,
@@ -16,15 +17,29 @@ use tracing::{
     warn,
 };
 
+/// Compares the withdrawal ledger against the sequencer instead of running the withdrawer.
+const RECONCILE_SUBCOMMAND: &str = "reconcile";
+
 #[tokio::main]
 async fn main() -> ExitCode {
     astria_eyre::install().expect("astria eyre hook must be the first hook installed");
 
     eprintln!("{}", telemetry::display::json(&BUILD_INFO));
 
-    let cfg: Config = config::get().expect("failed to read configuration");
+    let (subcommand, cfg): (_, Config) =
+        match config::get_from_args_with_subcommand(&[RECONCILE_SUBCOMMAND]) {
+            Ok(subcommand_and_cfg) => subcommand_and_cfg,
+            Err(e) => {
+                eprintln!("failed to read configuration:\n{e:?}");
+                return ExitCode::FAILURE;
+            }
+        };
     eprintln!("{}", telemetry::display::json(&cfg),);
 
+    if subcommand == Some(RECONCILE_SUBCOMMAND) {
+        return reconcile_ledger(&cfg).await;
+    }
+
     let mut telemetry_conf = telemetry::configure()
         .set_no_otel(cfg.no_otel)
         .set_force_stdout(cfg.force_stdout)
@@ -83,3 +98,27 @@ async fn main() -> ExitCode {
     info!("withdrawer stopped");
     ExitCode::SUCCESS
 }
+
+/// Compares the withdrawal ledger against the sequencer, printing the result as JSON to stdout.
+///
+/// Fails if the ledger and the sequencer disagree on any withdrawal.
+async fn reconcile_ledger(cfg: &Config) -> ExitCode {
+    match reconcile(cfg).await {
+        Err(error) => {
+            eprintln!("reconciling the withdrawal ledger failed:\n{error:?}");
+            ExitCode::FAILURE
+        }
+        Ok(reconciliation) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&reconciliation)
+                    .expect("serializing to a string cannot fail")
+            );
+            if reconciliation.discrepancies.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
@@ -13,6 +13,9 @@ use telemetry::{
 
 pub struct Metrics {
     current_nonce: Gauge,
+    fee_asset_balance: Gauge,
+    fee_runway_blocks: Gauge,
+    insufficient_fee_balance_count: Counter,
     nonce_fetch_count: Counter,
     nonce_fetch_failure_count: Counter,
     nonce_fetch_latency: Histogram,
@@ -25,6 +28,22 @@ impl Metrics {
         self.current_nonce.set(nonce);
     }
 
+    // allow: the gauge only needs to reflect the order of magnitude of the balance.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn set_fee_asset_balance(&self, balance: u128) {
+        self.fee_asset_balance.set(balance as f64);
+    }
+
+    // allow: the gauge only needs to reflect the order of magnitude of the runway.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn set_fee_runway_blocks(&self, runway: u64) {
+        self.fee_runway_blocks.set(runway as f64);
+    }
+
+    pub(crate) fn increment_insufficient_fee_balance_count(&self) {
+        self.insufficient_fee_balance_count.increment(1);
+    }
+
     pub(crate) fn increment_nonce_fetch_count(&self) {
         self.nonce_fetch_count.increment(1);
     }
@@ -57,6 +76,28 @@ impl metrics::Metrics for Metrics {
             .new_gauge_factory(CURRENT_NONCE, "The current nonce")?
             .register()?;
 
+        let fee_asset_balance = builder
+            .new_gauge_factory(
+                FEE_ASSET_BALANCE,
+                "The balance of the fee asset available to pay for withdrawal transactions",
+            )?
+            .register()?;
+
+        let fee_runway_blocks = builder
+            .new_gauge_factory(
+                FEE_RUNWAY_BLOCKS,
+                "The number of further rollup blocks the fee asset balance can pay fees for",
+            )?
+            .register()?;
+
+        let insufficient_fee_balance_count = builder
+            .new_counter_factory(
+                INSUFFICIENT_FEE_BALANCE_COUNT,
+                "The number of times a batch was held because the fee asset balance could not pay \
+                 for it",
+            )?
+            .register()?;
+
         let nonce_fetch_count = builder
             .new_counter_factory(
                 NONCE_FETCH_COUNT,
@@ -94,6 +135,9 @@ impl metrics::Metrics for Metrics {
 
         Ok(Self {
             current_nonce,
+            fee_asset_balance,
+            fee_runway_blocks,
+            insufficient_fee_balance_count,
             nonce_fetch_count,
             nonce_fetch_failure_count,
             nonce_fetch_latency,
@@ -108,6 +152,9 @@ metric_names!(const METRICS_NAMES:
     NONCE_FETCH_FAILURE_COUNT,
     NONCE_FETCH_LATENCY,
     CURRENT_NONCE,
+    FEE_ASSET_BALANCE,
+    FEE_RUNWAY_BLOCKS,
+    INSUFFICIENT_FEE_BALANCE_COUNT,
     SEQUENCER_SUBMISSION_FAILURE_COUNT,
     SEQUENCER_SUBMISSION_LATENCY
 );
@@ -116,6 +163,9 @@ metric_names!(const METRICS_NAMES:
 mod tests {
     use super::{
         CURRENT_NONCE,
+        FEE_ASSET_BALANCE,
+        FEE_RUNWAY_BLOCKS,
+        INSUFFICIENT_FEE_BALANCE_COUNT,
         NONCE_FETCH_COUNT,
         NONCE_FETCH_FAILURE_COUNT,
         NONCE_FETCH_LATENCY,
@@ -134,6 +184,12 @@ mod tests {
     #[test]
     fn metrics_are_as_expected() {
         assert_const(CURRENT_NONCE, "current_nonce");
+        assert_const(FEE_ASSET_BALANCE, "fee_asset_balance");
+        assert_const(FEE_RUNWAY_BLOCKS, "fee_runway_blocks");
+        assert_const(
+            INSUFFICIENT_FEE_BALANCE_COUNT,
+            "insufficient_fee_balance_count",
+        );
         assert_const(NONCE_FETCH_COUNT, "nonce_fetch_count");
         assert_const(NONCE_FETCH_FAILURE_COUNT, "nonce_fetch_failure_count");
         assert_const(NONCE_FETCH_LATENCY, "nonce_fetch_latency");
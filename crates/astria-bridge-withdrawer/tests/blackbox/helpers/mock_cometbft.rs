@@ -2,7 +2,14 @@ use std::time::Duration;
 
 use astria_core::{
     primitive::v1::asset,
-    protocol::bridge::v1alpha1::BridgeAccountLastTxHashResponse,
+    protocol::{
+        account::v1alpha1::{
+            AssetBalance,
+            BalanceResponse,
+        },
+        bridge::v1alpha1::BridgeAccountLastTxHashResponse,
+        transaction::v1alpha1::TransactionFeeResponse,
+    },
 };
 use prost::Message as _;
 use sequencer_client::{
@@ -282,6 +289,67 @@ fn prepare_get_nonce_response(response: NonceResponse) -> Mock {
     .expect(1)
 }
 
+/// Mounts a response to every transaction fee estimate, charging `fee` of `fee_asset`.
+pub async fn mount_transaction_fee_response(
+    server: &MockServer,
+    fee_asset: asset::Denom,
+    fee: u128,
+) {
+    let response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: TransactionFeeResponse {
+                height: 1,
+                fees: vec![(fee_asset, fee)],
+            }
+            .into_raw()
+            .encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let wrapper = response::Wrapper::new_with_id(tendermint_rpc::Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(
+        serde_json::json!({"method": "abci_query"}),
+    ))
+    .and(body_string_contains("transaction/fee"))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(wrapper)
+            .append_header("Content-Type", "application/json"),
+    )
+    .mount(server)
+    .await;
+}
+
+/// Mounts a response to every balance query, holding `balance` of `asset`.
+pub async fn mount_balance_response(server: &MockServer, asset: asset::Denom, balance: u128) {
+    let response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: BalanceResponse {
+                height: 1,
+                balances: vec![AssetBalance {
+                    denom: asset,
+                    balance,
+                }],
+            }
+            .into_raw()
+            .encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let wrapper = response::Wrapper::new_with_id(tendermint_rpc::Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(
+        serde_json::json!({"method": "abci_query"}),
+    ))
+    .and(body_string_contains("accounts/balance"))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(wrapper)
+            .append_header("Content-Type", "application/json"),
+    )
+    .mount(server)
+    .await;
+}
+
 pub async fn mount_tx_response(server: &MockServer, response: tx::Response) {
     prepare_tx_response(response).mount(server).await;
 }
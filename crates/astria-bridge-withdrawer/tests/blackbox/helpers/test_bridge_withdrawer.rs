@@ -11,6 +11,7 @@ use astria_bridge_withdrawer::{
     BridgeWithdrawer,
     Config,
     Metrics,
+    RollupSource,
 };
 use astria_core::{
     primitive::v1::asset::{
@@ -55,9 +56,11 @@ use super::{
     ethereum::AstriaBridgeableERC20DeployerConfig,
     make_tx_sync_success_response,
     mock_cometbft::{
+        mount_balance_response,
         mount_default_chain_id,
         mount_get_nonce_response,
         mount_native_fee_asset,
+        mount_transaction_fee_response,
     },
     mount_broadcast_tx_sync_response_as_scoped,
     mount_ibc_fee_asset,
@@ -153,6 +156,7 @@ impl TestBridgeWithdrawer {
         self.mount_sequencer_config_responses().await;
         self.mount_wait_for_mempool_response().await;
         self.mount_last_bridge_tx_responses().await;
+        self.mount_fee_responses().await;
     }
 
     async fn mount_sequencer_config_responses(&mut self) {
@@ -164,6 +168,12 @@ impl TestBridgeWithdrawer {
         }
     }
 
+    async fn mount_fee_responses(&mut self) {
+        let fee_asset = self.config.fee_asset_denomination.clone();
+        mount_transaction_fee_response(&self.cometbft_mock, fee_asset.clone(), 12).await;
+        mount_balance_response(&self.cometbft_mock, fee_asset, 1_000_000_000).await;
+    }
+
     async fn mount_wait_for_mempool_response(&mut self) {
         // TODO: add config to allow testing for non-empty mempool
         let empty_mempool_response = NonceResponse {
@@ -272,11 +282,24 @@ impl TestBridgeWithdrawerConfig {
             sequencer_grpc_endpoint: format!("http://{}", sequencer_mock.local_addr),
             sequencer_chain_id: SEQUENCER_CHAIN_ID.into(),
             sequencer_key_path,
+            committee_key_share_path: String::new(),
+            committee_public_key_package_path: String::new(),
+            committee_peer_endpoints: String::new(),
+            committee_api_addr: String::new(),
+            committee_secret_path: String::new(),
             fee_asset_denomination: asset_denom.clone(),
             rollup_asset_denomination: asset_denom.as_trace_prefixed().unwrap().clone(),
             sequencer_bridge_address: default_bridge_address().to_string(),
+            rollup_source: RollupSource::Ethereum,
+            rollup_grpc_endpoint: String::new(),
             ethereum_contract_address: ethereum.contract_address(),
+            ethereum_withdrawal_contracts: String::new(),
             ethereum_rpc_endpoint: ethereum.ws_endpoint(),
+            ethereum_confirmation_depth: 0,
+            ethereum_wait_for_finalized: false,
+            ledger_path: String::new(),
+            max_actions_per_transaction: 0,
+            fee_top_up_alert_runway_blocks: 0,
             sequencer_address_prefix: ASTRIA_ADDRESS_PREFIX.into(),
             api_addr: "0.0.0.0:0".into(),
             log: String::new(),
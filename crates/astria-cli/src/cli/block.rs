@@ -0,0 +1,25 @@
+use clap::{
+    Args,
+    Subcommand,
+};
+
+use crate::cli::OutputFormat;
+
+/// Inspect Sequencer blocks
+#[derive(Debug, Subcommand)]
+pub(crate) enum Command {
+    /// Get and decode the Sequencer block at a height, including its rollup data and proofs
+    Get(GetArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct GetArgs {
+    /// The height of the block
+    pub(crate) height: u64,
+    /// The url of the Sequencer node's gRPC server
+    #[arg(long, env = "SEQUENCER_GRPC_URL")]
+    pub(crate) sequencer_grpc_url: String,
+    /// The format to print the block in
+    #[arg(long, value_enum, default_value_t)]
+    pub(crate) format: OutputFormat,
+}
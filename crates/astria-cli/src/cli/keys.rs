@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use clap::{
+    Args,
+    Subcommand,
+};
+
+/// Manage the signing keys in the encrypted keystore
+#[derive(Debug, Subcommand)]
+pub(crate) enum Command {
+    /// Generate a new signing key and add it to the keystore
+    Create(CreateArgs),
+    /// Add an existing signing key to the keystore
+    Import(ImportArgs),
+    /// List the keys in the keystore and their addresses
+    List(ListArgs),
+    /// Decrypt a key from the keystore and print it in plain text
+    Export(ExportArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct KeystoreArgs {
+    /// The directory of the keystore [default: ~/.astria/keystore]
+    #[arg(long, env = "ASTRIA_KEYSTORE")]
+    pub(crate) keystore: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct CreateArgs {
+    /// The name to store the new key under
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The bech32m prefix used to display the address of the new key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ImportArgs {
+    /// The name to store the imported key under
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// A file containing the hex-encoded private key to import. The key is prompted for if not
+    /// provided
+    #[arg(long)]
+    pub(crate) private_key_file: Option<PathBuf>,
+    /// The bech32m prefix used to display the address of the imported key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ListArgs {
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The bech32m prefix used to display the addresses of the keys
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ExportArgs {
+    /// The name of the key to export
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// Write the hex-encoded private key to this file instead of printing it. The file must not
+    /// exist yet
+    #[arg(long)]
+    pub(crate) output: Option<PathBuf>,
+}
+
+/// Selects the key used to sign a transaction.
+///
+/// Keys are read from the keystore by default. Raw private keys end up in shell histories and
+/// logs, so they are only accepted if explicitly allowed with `--allow-raw-private-key`.
+#[derive(Args, Debug)]
+pub(crate) struct SignerArgs {
+    /// The name of the keystore key to sign with. Takes precedence over a raw private key
+    #[arg(long, env = "SEQUENCER_KEY_NAME")]
+    pub(crate) key_name: Option<String>,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The hex-encoded private key to sign with. Requires `--allow-raw-private-key`
+    #[arg(long, env = "SEQUENCER_PRIVATE_KEY", hide_env_values = true)]
+    // TODO: https://github.com/astriaorg/astria/issues/594
+    // Don't use a plain text private, prefer wrapper like from
+    // the secrecy crate with specialized `Debug` and `Drop` implementations
+    // that overwrite the key on drop and don't reveal it when printing.
+    pub(crate) private_key: Option<String>,
+    /// A file containing the hex-encoded private key to sign with. Requires
+    /// `--allow-raw-private-key`. Takes precedence over `--private-key`
+    #[arg(long, alias = "signing-key")]
+    pub(crate) private_key_file: Option<PathBuf>,
+    /// Allow signing with an unencrypted private key passed by `--private-key` or
+    /// `--private-key-file`
+    #[arg(long, env = "SEQUENCER_ALLOW_RAW_PRIVATE_KEY")]
+    pub(crate) allow_raw_private_key: bool,
+}
@@ -1,9 +1,13 @@
+pub(crate) mod block;
 pub(crate) mod bridge;
+pub(crate) mod keys;
 pub(crate) mod sequencer;
+pub(crate) mod tx;
 
 use clap::{
     Parser,
     Subcommand,
+    ValueEnum,
 };
 use color_eyre::eyre;
 
@@ -39,8 +43,22 @@ pub(crate) enum Command {
         #[command(subcommand)]
         command: bridge::Command,
     },
+    Keys {
+        #[command(subcommand)]
+        command: keys::Command,
+    },
     Sequencer {
         #[command(subcommand)]
         command: SequencerCommand,
     },
 }
+
+/// The format in which query results are printed
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub(crate) enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Human,
+    /// JSON, for processing by other tools
+    Json,
+}
@@ -3,8 +3,11 @@ use astria_sequencer_client::Address;
 use clap::{
     Args,
     Subcommand,
+    ValueEnum,
 };
 
+use crate::cli::keys::SignerArgs;
+
 /// Interact with a Sequencer node
 #[derive(Debug, Subcommand)]
 pub(crate) enum Command {
@@ -23,6 +26,11 @@ pub(crate) enum Command {
         #[command(subcommand)]
         command: BalanceCommand,
     },
+    /// Commands for inspecting Sequencer blocks
+    Block {
+        #[command(subcommand)]
+        command: crate::cli::block::Command,
+    },
     /// Commands for interacting with Sequencer block heights
     #[command(name = "blockheight")]
     BlockHeight {
@@ -40,11 +48,25 @@ pub(crate) enum Command {
     InitBridgeAccount(InitBridgeAccountArgs),
     /// Command for transferring to a bridge account
     BridgeLock(BridgeLockArgs),
+    /// Command for withdrawing from a bridge account
+    BridgeUnlock(BridgeUnlockArgs),
+    /// Command for changing the sudo or withdrawer address of a bridge account
+    BridgeSudoChange(BridgeSudoChangeArgs),
+    /// Command for withdrawing funds to another chain via ICS20
+    Ics20Withdrawal(Ics20WithdrawalArgs),
+    /// Command for submitting rollup data
+    Sequence(SequenceArgs),
+    /// Commands for building, signing and broadcasting transactions in separate steps
+    Tx {
+        #[command(subcommand)]
+        command: crate::cli::tx::Command,
+    },
 }
 
 #[derive(Debug, Subcommand)]
 pub(crate) enum AccountCommand {
-    /// Create a new Sequencer account
+    /// Create a new Sequencer account and print its private key. Prefer `keys create`, which
+    /// stores the key in the encrypted keystore
     Create,
     Balance(BasicAccountArgs),
     Nonce(BasicAccountArgs),
@@ -74,6 +96,14 @@ pub(crate) enum SudoCommand {
     },
     SudoAddressChange(SudoAddressChangeArgs),
     ValidatorUpdate(ValidatorUpdateArgs),
+    /// Change the address authorized to change the IBC relayer set
+    IbcSudoAddressChange(IbcSudoAddressChangeArgs),
+    /// Change a transaction fee
+    FeeChange(FeeChangeArgs),
+    /// Change how block fees are distributed
+    FeeDistributionChange(FeeDistributionChangeArgs),
+    /// Schedule a chain upgrade at a future height
+    UpgradeSchedule(UpgradeScheduleArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -122,16 +152,11 @@ pub(crate) struct TransferArgs {
     // The amount being sent
     #[arg(long)]
     pub(crate) amount: u128,
-    /// The bech32m prefix that will be used for constructing addresses using the private key
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    /// The private key of account being sent from
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -156,15 +181,11 @@ pub(crate) struct TransferArgs {
 
 #[derive(Args, Debug)]
 pub(crate) struct FeeAssetChangeArgs {
-    /// The bech32m prefix that will be used for constructing addresses using the private key
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -186,15 +207,11 @@ pub(crate) struct FeeAssetChangeArgs {
 
 #[derive(Args, Debug)]
 pub(crate) struct IbcRelayerChangeArgs {
-    /// The prefix to construct a bech32m address given the private key.
+    /// The prefix to construct a bech32m address given the signing key.
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -216,15 +233,11 @@ pub(crate) struct IbcRelayerChangeArgs {
 
 #[derive(Args, Debug)]
 pub(crate) struct InitBridgeAccountArgs {
-    /// The bech32m prefix that will be used for constructing addresses using the private key
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -260,15 +273,11 @@ pub(crate) struct BridgeLockArgs {
     pub(crate) amount: u128,
     #[arg(long)]
     pub(crate) destination_chain_address: String,
-    /// The prefix to construct a bech32m address given the private key.
+    /// The prefix to construct a bech32m address given the signing key.
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -317,15 +326,11 @@ pub(crate) struct BlockHeightGetArgs {
 
 #[derive(Args, Debug)]
 pub(crate) struct SudoAddressChangeArgs {
-    /// The bech32m prefix that will be used for constructing addresses using the private key
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -361,16 +366,11 @@ pub(crate) struct ValidatorUpdateArgs {
         default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
     )]
     pub(crate) sequencer_chain_id: String,
-    /// The bech32m prefix that will be used for constructing addresses using the private key
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    /// The private key of the sudo account authorizing change
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
     /// The address of the Validator being updated
     #[arg(long)]
     pub(crate) validator_public_key: String,
@@ -378,3 +378,305 @@ pub(crate) struct ValidatorUpdateArgs {
     #[arg(long)]
     pub(crate) power: u32,
 }
+
+#[derive(Args, Debug)]
+pub(crate) struct BridgeUnlockArgs {
+    /// The address of the Sequencer account to send the unlocked amount to
+    pub(crate) to_address: Address,
+    /// The amount being unlocked
+    #[arg(long)]
+    pub(crate) amount: u128,
+    /// The bridge account to unlock from. The signer must be its withdrawer
+    #[arg(long)]
+    pub(crate) bridge_address: Address,
+    /// A memo to include with the unlock
+    #[arg(long, default_value = "")]
+    pub(crate) memo: String,
+    /// The number of the rollup block containing the withdrawal event
+    #[arg(long)]
+    pub(crate) rollup_block_number: u64,
+    /// The identifier of the withdrawal event in the rollup block
+    #[arg(long)]
+    pub(crate) rollup_withdrawal_event_id: String,
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The asset to pay the transaction fees with.
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: asset::Denom,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct BridgeSudoChangeArgs {
+    /// The bridge account to change. The signer must be its sudo address
+    #[arg(long)]
+    pub(crate) bridge_address: Address,
+    /// The new sudo address of the bridge account
+    #[arg(long)]
+    pub(crate) new_sudo_address: Option<Address>,
+    /// The new withdrawer address of the bridge account
+    #[arg(long)]
+    pub(crate) new_withdrawer_address: Option<Address>,
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The asset to pay the transaction fees with.
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: asset::Denom,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct Ics20WithdrawalArgs {
+    /// The amount being withdrawn
+    #[arg(long)]
+    pub(crate) amount: u128,
+    /// The asset to withdraw
+    #[arg(long)]
+    pub(crate) denom: asset::Denom,
+    /// The address on the destination chain to send the funds to
+    #[arg(long)]
+    pub(crate) destination_chain_address: String,
+    /// The Sequencer address funds are returned to if the withdrawal fails [default: the
+    /// address of the signer]
+    #[arg(long)]
+    pub(crate) return_address: Option<Address>,
+    /// The channel on the Sequencer the withdrawal is sent through
+    #[arg(long)]
+    pub(crate) source_channel: String,
+    /// The height on the destination chain at which the withdrawal times out, given as
+    /// `<revision number>-<revision height>` [default: no timeout height]
+    #[arg(long)]
+    pub(crate) timeout_height: Option<String>,
+    /// The time after which the withdrawal times out
+    #[arg(long, default_value = "5m")]
+    pub(crate) timeout: humantime::Duration,
+    /// A memo to include with the withdrawal
+    #[arg(long, default_value = "")]
+    pub(crate) memo: String,
+    /// The bridge account to withdraw from, if the signer is its withdrawer
+    #[arg(long)]
+    pub(crate) bridge_address: Option<Address>,
+    /// Use a bech32-compatible return address for chains that require it, like noble
+    #[arg(long)]
+    pub(crate) use_compat_address: bool,
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The asset to pay the transaction fees with.
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: asset::Denom,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct SequenceArgs {
+    /// Plaintext rollup name (to be hashed into a rollup ID) to submit the data to
+    #[arg(long)]
+    pub(crate) rollup_name: String,
+    /// The hex-encoded data to submit
+    #[arg(long)]
+    pub(crate) data: String,
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The asset to pay the transaction fees with.
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: asset::Denom,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct IbcSudoAddressChangeArgs {
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The new address to take over IBC sudo privileges
+    #[arg(long)]
+    pub(crate) address: Address,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct FeeChangeArgs {
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The fee to change
+    #[arg(long, value_enum)]
+    pub(crate) fee: FeeKind,
+    /// The new value of the fee
+    #[arg(long)]
+    pub(crate) value: u128,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct FeeDistributionChangeArgs {
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The percentage of block fees paid to the block proposer
+    #[arg(long)]
+    pub(crate) proposer_percent: u32,
+    /// The percentage of block fees paid to the validators that signed the last commit
+    #[arg(long)]
+    pub(crate) validators_percent: u32,
+    /// The percentage of block fees paid to the treasury
+    #[arg(long)]
+    pub(crate) treasury_percent: u32,
+    /// The percentage of native asset block fees that is burned
+    #[arg(long)]
+    pub(crate) burn_percent: u32,
+    /// The address of the treasury
+    #[arg(long)]
+    pub(crate) treasury_address: Address,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct UpgradeScheduleArgs {
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The name of the upgrade
+    #[arg(long)]
+    pub(crate) name: String,
+    /// The height at which the chain halts unless nodes run a binary supporting the upgrade
+    #[arg(long)]
+    pub(crate) height: u64,
+}
+
+/// The fees that can be changed by a `FeeChange` action
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum FeeKind {
+    TransferBaseFee,
+    SequenceBaseFee,
+    SequenceByteCostMultiplier,
+    InitBridgeAccountBaseFee,
+    BridgeLockByteCostMultiplier,
+    BridgeSudoChangeBaseFee,
+    Ics20WithdrawalBaseFee,
+}
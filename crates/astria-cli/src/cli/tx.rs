@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use astria_sequencer_client::Address;
+use clap::{
+    Args,
+    Subcommand,
+};
+
+use crate::cli::{
+    keys::SignerArgs,
+    OutputFormat,
+};
+
+/// Build, sign, inspect and broadcast transactions in separate steps
+///
+/// This allows signing transactions on a machine without network access.
+#[derive(Debug, Subcommand)]
+pub(crate) enum Command {
+    /// Build an unsigned transaction from a file of actions
+    Build(BuildArgs),
+    /// Sign an unsigned transaction. Does not require network access
+    Sign(SignArgs),
+    /// Decode and print an unsigned or signed transaction
+    Inspect(InspectArgs),
+    /// Submit a signed transaction to the Sequencer and wait for its inclusion
+    Broadcast(BroadcastArgs),
+    /// Build, sign and submit a transaction from a file of actions in one step
+    FromJson(FromJsonArgs),
+    /// Get and decode an executed transaction and its events
+    Get(QueryArgs),
+    /// Wait for a transaction to be executed, then decode it and its events
+    Wait(QueryArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct BuildArgs {
+    /// A JSON file containing the list of actions of the transaction, in the same format as the
+    /// per rollup height actions written by `bridge collect-withdrawals`
+    #[arg(long)]
+    pub(crate) actions: PathBuf,
+    /// The nonce of the transaction. Fetched from the Sequencer for `--signer-address` if not
+    /// provided
+    #[arg(long)]
+    pub(crate) nonce: Option<u32>,
+    /// The address of the account that will sign the transaction. Used to fetch its nonce
+    #[arg(long, required_unless_present = "nonce")]
+    pub(crate) signer_address: Option<Address>,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// Write the unsigned transaction to this file instead of printing it
+    #[arg(long)]
+    pub(crate) output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct SignArgs {
+    /// A JSON file containing the unsigned transaction, as written by `tx build`
+    pub(crate) input: PathBuf,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The bech32m prefix used to display the address of the signer
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    /// Write the signed transaction to this file instead of printing it
+    #[arg(long)]
+    pub(crate) output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct InspectArgs {
+    /// A JSON file containing an unsigned or signed transaction
+    pub(crate) input: PathBuf,
+    /// The bech32m prefix used to display the address of the signer
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct BroadcastArgs {
+    /// A JSON file containing the signed transaction, as written by `tx sign`
+    pub(crate) input: PathBuf,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct FromJsonArgs {
+    /// A JSON file containing the list of actions of the transaction, in the same format as
+    /// taken by `tx build`
+    pub(crate) actions: PathBuf,
+    /// The bech32m prefix that will be used for constructing addresses using the signing key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct QueryArgs {
+    /// The hex-encoded hash of the transaction
+    pub(crate) hash: String,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The bech32m prefix used to display the address of the signer
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    /// The format to print the transaction in
+    #[arg(long, value_enum, default_value_t)]
+    pub(crate) format: OutputFormat,
+}
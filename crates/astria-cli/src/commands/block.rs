@@ -0,0 +1,270 @@
+use astria_core::{
+    generated::sequencerblock::v1alpha1::{
+        self as raw,
+        sequencer_service_client::SequencerServiceClient,
+        GetSequencerBlockRequest,
+    },
+    sequencerblock::v1alpha1::{
+        block::{
+            Deposit,
+            RollupData,
+            SequencerBlock,
+            SequencerBlockParts,
+        },
+        RollupTransactions,
+    },
+};
+use color_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use prost::Message as _;
+use serde::Serialize;
+use tonic::transport::Endpoint;
+
+use crate::cli::{
+    block::{
+        Command,
+        GetArgs,
+    },
+    OutputFormat,
+};
+
+impl Command {
+    pub(crate) async fn run(self) -> eyre::Result<()> {
+        match self {
+            Command::Get(args) => get(args).await,
+        }
+    }
+}
+
+/// Gets the Sequencer block at a height and prints it
+///
+/// # Errors
+///
+/// * If the gRPC endpoint is malformed or cannot be reached
+/// * If the block cannot be fetched or fails verification
+async fn get(args: GetArgs) -> eyre::Result<()> {
+    let endpoint: Endpoint = args
+        .sequencer_grpc_url
+        .parse()
+        .wrap_err("failed parsing provided sequencer grpc url")?;
+    let mut client = SequencerServiceClient::new(
+        endpoint
+            .connect()
+            .await
+            .wrap_err("failed connecting to sequencer grpc server")?,
+    );
+    let raw = client
+        .get_sequencer_block(GetSequencerBlockRequest {
+            height: args.height,
+        })
+        .await
+        .wrap_err("failed to get sequencer block")?
+        .into_inner();
+    let block = SequencerBlock::try_from_raw(raw)
+        .wrap_err("failed to decode and verify sequencer block")?;
+
+    let view = BlockView::from(block);
+    match args.format {
+        OutputFormat::Human => view.print(),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&view).wrap_err("failed encoding block as JSON")?
+        ),
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockView {
+    block_hash: String,
+    chain_id: String,
+    height: u64,
+    time: String,
+    proposer_address: String,
+    rollup_transactions_root: String,
+    data_hash: String,
+    rollup_transactions_proof: ProofView,
+    rollup_ids_proof: ProofView,
+    rollup_transactions: Vec<RollupTransactionsView>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProofView {
+    audit_path: String,
+    leaf_index: usize,
+    tree_size: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RollupTransactionsView {
+    rollup_id: String,
+    proof: ProofView,
+    items: Vec<RollupItemView>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum RollupItemView {
+    /// Hex-encoded data sequenced by a `SequenceAction`
+    SequencedData(String),
+    Deposit(Box<Deposit>),
+    /// Hex-encoded bytes that could not be decoded as rollup data
+    Undecodable(String),
+}
+
+impl From<SequencerBlock> for BlockView {
+    fn from(block: SequencerBlock) -> Self {
+        let SequencerBlockParts {
+            block_hash,
+            header,
+            rollup_transactions,
+            rollup_transactions_proof,
+            rollup_ids_proof,
+        } = block.into_parts();
+        Self {
+            block_hash: hex::encode(block_hash),
+            chain_id: header.chain_id().to_string(),
+            height: header.height().value(),
+            time: header.time().to_string(),
+            proposer_address: header.proposer_address().to_string(),
+            rollup_transactions_root: hex::encode(header.rollup_transactions_root()),
+            data_hash: hex::encode(header.data_hash()),
+            rollup_transactions_proof: ProofView::from(&rollup_transactions_proof),
+            rollup_ids_proof: ProofView::from(&rollup_ids_proof),
+            rollup_transactions: rollup_transactions
+                .into_values()
+                .map(RollupTransactionsView::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&astria_merkle::Proof> for ProofView {
+    fn from(proof: &astria_merkle::Proof) -> Self {
+        Self {
+            audit_path: hex::encode(proof.audit_path()),
+            leaf_index: proof.leaf_index(),
+            tree_size: proof.tree_size().get(),
+        }
+    }
+}
+
+impl From<RollupTransactions> for RollupTransactionsView {
+    fn from(rollup_transactions: RollupTransactions) -> Self {
+        Self {
+            rollup_id: rollup_transactions.rollup_id().to_string(),
+            proof: ProofView::from(rollup_transactions.proof()),
+            items: rollup_transactions
+                .transactions()
+                .iter()
+                .map(|bytes| RollupItemView::decode(bytes))
+                .collect(),
+        }
+    }
+}
+
+impl RollupItemView {
+    fn decode(bytes: &[u8]) -> Self {
+        match raw::RollupData::decode(bytes)
+            .ok()
+            .and_then(|raw| RollupData::try_from_raw(raw).ok())
+        {
+            Some(RollupData::SequencedData(data)) => Self::SequencedData(hex::encode(data)),
+            Some(RollupData::Deposit(deposit)) => Self::Deposit(deposit),
+            None => Self::Undecodable(hex::encode(bytes)),
+        }
+    }
+}
+
+impl BlockView {
+    fn print(&self) {
+        println!("Sequencer block {} (proofs verified)", self.height);
+        println!("    Block hash:               {}", self.block_hash);
+        println!("    Chain ID:                 {}", self.chain_id);
+        println!("    Time:                     {}", self.time);
+        println!("    Proposer:                 {}", self.proposer_address);
+        println!("    Data hash:                {}", self.data_hash);
+        println!(
+            "    Rollup transactions root: {}",
+            self.rollup_transactions_root
+        );
+        println!(
+            "    Rollup transactions proof: {}",
+            self.rollup_transactions_proof
+        );
+        println!("    Rollup IDs proof:         {}", self.rollup_ids_proof);
+        println!(
+            "    Rollups:                  {}",
+            self.rollup_transactions.len()
+        );
+        for rollup in &self.rollup_transactions {
+            println!();
+            println!("    Rollup {}", rollup.rollup_id);
+            println!("        Proof: {}", rollup.proof);
+            for (index, item) in rollup.items.iter().enumerate() {
+                match item {
+                    RollupItemView::SequencedData(data) => {
+                        println!(
+                            "        {index}: sequenced data ({} bytes): {data}",
+                            data.len() / 2
+                        );
+                    }
+                    RollupItemView::Deposit(deposit) => {
+                        println!(
+                            "        {index}: deposit of {} {} from bridge {} to {} (source tx \
+                             {}, action {})",
+                            deposit.amount,
+                            deposit.asset,
+                            deposit.bridge_address,
+                            deposit.destination_chain_address,
+                            deposit.source_transaction_id,
+                            deposit.source_action_index,
+                        );
+                    }
+                    RollupItemView::Undecodable(bytes) => {
+                        println!("        {index}: undecodable rollup data: {bytes}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ProofView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "leaf {} of {}, audit path {}",
+            self.leaf_index, self.tree_size, self.audit_path
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequenced_data_is_decoded() {
+        let bytes = RollupData::SequencedData(vec![1, 2, 3].into())
+            .into_raw()
+            .encode_to_vec();
+        let RollupItemView::SequencedData(data) = RollupItemView::decode(&bytes) else {
+            panic!("expected sequenced data");
+        };
+        assert_eq!(data, "010203");
+    }
+
+    #[test]
+    fn garbage_is_undecodable() {
+        let RollupItemView::Undecodable(bytes) = RollupItemView::decode(&[0xff, 0xff]) else {
+            panic!("expected undecodable data");
+        };
+        assert_eq!(bytes, "ffff");
+    }
+}
@@ -194,8 +194,11 @@ async fn block_to_actions(
         .number
         .ok_or_eyre("block did not contain a rollup height; skipping")?
         .as_u64();
+    let block_timestamp = u64::try_from(block.timestamp)
+        .ok()
+        .ok_or_eyre("block timestamp does not fit into u64; skipping")?;
     let actions = actions_fetcher
-        .get_for_block_hash(block_hash)
+        .get_for_block_hash(block_hash, block_timestamp)
         .await
         .wrap_err_with(|| {
             format!(
@@ -30,12 +30,14 @@ use tracing::{
     warn,
 };
 
+use crate::cli::keys::SignerArgs;
+
 #[derive(Args, Debug)]
 pub(crate) struct WithdrawalEvents {
     #[arg(long, short)]
     input: PathBuf,
-    #[arg(long)]
-    signing_key: PathBuf,
+    #[command(flatten)]
+    signer: SignerArgs,
     #[arg(long, default_value = "astria")]
     sequencer_address_prefix: String,
     #[arg(long)]
@@ -46,12 +48,10 @@ pub(crate) struct WithdrawalEvents {
 
 impl WithdrawalEvents {
     pub(crate) async fn run(self) -> eyre::Result<()> {
-        let signing_key = read_signing_key(&self.signing_key).wrap_err_with(|| {
-            format!(
-                "failed reading signing key from file: {}",
-                self.signing_key.display()
-            )
-        })?;
+        let signing_key = self
+            .signer
+            .signing_key()
+            .wrap_err("failed to get signing key")?;
 
         let actions_by_rollup_number = read_actions(&self.input).wrap_err_with(|| {
             format!("failed reading actions from file: {}", self.input.display())
@@ -103,13 +103,6 @@ fn read_actions<P: AsRef<Path>>(path: P) -> eyre::Result<super::collect::Actions
         .wrap_err("failed deserializing file contents height-to-sequencer-actions serde object")
 }
 
-fn read_signing_key<P: AsRef<Path>>(path: P) -> eyre::Result<SigningKey> {
-    let hex =
-        std::fs::read_to_string(&path).wrap_err("failed to read file contents into buffer")?;
-    let bytes = hex::decode(hex.trim()).wrap_err("failed to decode file contents as hex")?;
-    SigningKey::try_from(&*bytes).wrap_err("failed to construct signing key hex-decoded bytes")
-}
-
 #[instrument(skip_all, fields(actions = actions.len()), err)]
 async fn submit_transaction(
     client: HttpClient,
@@ -0,0 +1,255 @@
+use std::{
+    io::Write as _,
+    path::Path,
+};
+
+use astria_core::{
+    crypto::SigningKey,
+    primitive::v1::{
+        Address,
+        ADDRESS_LEN,
+    },
+};
+use color_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use rand::rngs::OsRng;
+use zeroize::Zeroizing;
+
+use crate::{
+    cli::keys::{
+        Command,
+        CreateArgs,
+        ExportArgs,
+        ImportArgs,
+        ListArgs,
+        SignerArgs,
+    },
+    keystore::{
+        self,
+        Keystore,
+    },
+};
+
+impl Command {
+    pub(crate) fn run(self) -> eyre::Result<()> {
+        match self {
+            Command::Create(args) => create(args),
+            Command::Import(args) => import(args),
+            Command::List(args) => list(args),
+            Command::Export(args) => export(args),
+        }
+    }
+}
+
+impl SignerArgs {
+    /// Returns the signing key selected by the arguments.
+    ///
+    /// # Errors
+    ///
+    /// * If a key name is given and the key cannot be decrypted from the keystore
+    /// * If a raw private key is given without `--allow-raw-private-key`
+    /// * If the raw private key is malformed
+    /// * If no key is given
+    pub(crate) fn signing_key(&self) -> eyre::Result<SigningKey> {
+        if let Some(name) = &self.key_name {
+            let keystore = Keystore::open(self.keystore.keystore.clone())?;
+            let password = keystore::read_password(&format!("Password for key `{name}`: "))?;
+            return keystore.load(name, &password);
+        }
+
+        let private_key = match (&self.private_key_file, &self.private_key) {
+            (Some(path), _) => read_private_key_file(path)?,
+            (None, Some(private_key)) => Zeroizing::new(private_key.clone()),
+            (None, None) => bail!(
+                "no signing key provided; select a keystore key with `--key-name` or create one \
+                 with `astria-cli keys create`"
+            ),
+        };
+        ensure!(
+            self.allow_raw_private_key,
+            "signing with a raw private key is disabled; use `--key-name` to sign with a key from \
+             the keystore, or pass `--allow-raw-private-key` to opt in"
+        );
+        signing_key_from_hex(&private_key)
+    }
+}
+
+/// Generates a new signing key and adds it to the keystore
+///
+/// # Errors
+///
+/// * If the password cannot be read
+/// * If the key cannot be added to the keystore
+fn create(args: CreateArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore)?;
+    let signing_key = SigningKey::new(OsRng);
+    let password = keystore::read_new_password()?;
+    add_to_keystore(&keystore, &args.name, &signing_key, &password, &args.prefix)
+}
+
+/// Adds an existing signing key to the keystore
+///
+/// # Errors
+///
+/// * If the private key or password cannot be read
+/// * If the private key is malformed
+/// * If the key cannot be added to the keystore
+fn import(args: ImportArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore)?;
+    let private_key = match &args.private_key_file {
+        Some(path) => read_private_key_file(path)?,
+        None => rpassword::prompt_password("Enter hex-encoded private key: ")
+            .map(Zeroizing::new)
+            .wrap_err("failed to read private key")?,
+    };
+    let signing_key = signing_key_from_hex(&private_key)?;
+    let password = keystore::read_new_password()?;
+    add_to_keystore(&keystore, &args.name, &signing_key, &password, &args.prefix)
+}
+
+/// Lists the keys in the keystore
+///
+/// # Errors
+///
+/// * If the keystore cannot be read
+fn list(args: ListArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore)?;
+    let entries = keystore.list()?;
+    if entries.is_empty() {
+        println!("No keys in keystore {}", keystore.dir().display());
+        return Ok(());
+    }
+    println!("Keys in keystore {}", keystore.dir().display());
+    for entry in entries {
+        let address = make_address(entry.address_bytes, &args.prefix)?;
+        println!("    {}: {address}", entry.name);
+    }
+    Ok(())
+}
+
+/// Decrypts a key from the keystore and prints it, or writes it to a file
+///
+/// # Errors
+///
+/// * If the password cannot be read
+/// * If the key cannot be decrypted
+/// * If the output file cannot be written
+fn export(args: ExportArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore)?;
+    let password = keystore::read_password(&format!("Password for key `{}`: ", args.name))?;
+    let signing_key = keystore.load(&args.name, &password)?;
+    let private_key = Zeroizing::new(hex::encode(signing_key.to_bytes()));
+
+    if let Some(output) = &args.output {
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt as _;
+            options.mode(0o600);
+        }
+        options
+            .open(output)
+            .and_then(|mut file| file.write_all(private_key.as_bytes()))
+            .wrap_err_with(|| format!("failed to write private key to `{}`", output.display()))?;
+        println!(
+            "Private key of `{}` written to {}",
+            args.name,
+            output.display()
+        );
+    } else {
+        eprintln!("WARNING: the private key is printed in plain text; keep it secret");
+        println!("{}", &*private_key);
+    }
+    Ok(())
+}
+
+fn add_to_keystore(
+    keystore: &Keystore,
+    name: &str,
+    signing_key: &SigningKey,
+    password: &str,
+    prefix: &str,
+) -> eyre::Result<()> {
+    let entry = keystore
+        .add(name, signing_key, password)
+        .wrap_err("failed to add key to keystore")?;
+    let address = make_address(entry.address_bytes, prefix)?;
+    println!(
+        "Added key `{}` to keystore {}",
+        entry.name,
+        keystore.dir().display()
+    );
+    println!(
+        "Public Key:  {}",
+        hex::encode(signing_key.verification_key().to_bytes())
+    );
+    println!("Address:     {address}");
+    Ok(())
+}
+
+pub(super) fn make_address(bytes: [u8; ADDRESS_LEN], prefix: &str) -> eyre::Result<Address> {
+    Address::builder()
+        .array(bytes)
+        .prefix(prefix)
+        .try_build()
+        .wrap_err("failed constructing a valid address from the provided prefix")
+}
+
+fn read_private_key_file(path: &Path) -> eyre::Result<Zeroizing<String>> {
+    std::fs::read_to_string(path)
+        .map(Zeroizing::new)
+        .wrap_err_with(|| format!("failed reading private key from file `{}`", path.display()))
+}
+
+fn signing_key_from_hex(private_key: &str) -> eyre::Result<SigningKey> {
+    let bytes: [u8; 32] = hex::decode(private_key.trim())
+        .map(Zeroizing::new)
+        .wrap_err("failed to decode private key bytes from hex string")?
+        .as_slice()
+        .try_into()
+        .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
+    Ok(SigningKey::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::keys::KeystoreArgs;
+
+    fn raw_signer(private_key: &str, allow_raw_private_key: bool) -> SignerArgs {
+        SignerArgs {
+            key_name: None,
+            keystore: KeystoreArgs {
+                keystore: None,
+            },
+            private_key: Some(private_key.to_string()),
+            private_key_file: None,
+            allow_raw_private_key,
+        }
+    }
+
+    #[test]
+    fn raw_private_key_requires_opt_in() {
+        let private_key = hex::encode([42u8; 32]);
+        assert!(raw_signer(&private_key, false).signing_key().is_err());
+        assert_eq!(
+            raw_signer(&private_key, true)
+                .signing_key()
+                .unwrap()
+                .to_bytes(),
+            [42u8; 32],
+        );
+    }
+
+    #[test]
+    fn malformed_raw_private_key_is_rejected() {
+        assert!(raw_signer("abcd", true).signing_key().is_err());
+        assert!(raw_signer("not hex", true).signing_key().is_err());
+    }
+}
@@ -1,5 +1,8 @@
+mod block;
 pub(crate) mod bridge;
+mod keys;
 mod sequencer;
+mod tx;
 
 use color_eyre::{
     eyre,
@@ -40,6 +43,9 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
             Command::Bridge {
                 command,
             } => command.run().await?,
+            Command::Keys {
+                command,
+            } => command.run()?,
             Command::Sequencer {
                 command,
             } => match command {
@@ -87,8 +93,21 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                     SudoCommand::SudoAddressChange(args) => {
                         sequencer::sudo_address_change(&args).await?;
                     }
+                    SudoCommand::IbcSudoAddressChange(args) => {
+                        sequencer::ibc_sudo_address_change(&args).await?;
+                    }
+                    SudoCommand::FeeChange(args) => sequencer::fee_change(&args).await?,
+                    SudoCommand::FeeDistributionChange(args) => {
+                        sequencer::fee_distribution_change(&args).await?;
+                    }
+                    SudoCommand::UpgradeSchedule(args) => {
+                        sequencer::upgrade_schedule(&args).await?;
+                    }
                 },
                 SequencerCommand::Transfer(args) => sequencer::send_transfer(&args).await?,
+                SequencerCommand::Block {
+                    command,
+                } => command.run().await?,
                 SequencerCommand::BlockHeight {
                     command,
                 } => match command {
@@ -98,6 +117,17 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                     sequencer::init_bridge_account(&args).await?;
                 }
                 SequencerCommand::BridgeLock(args) => sequencer::bridge_lock(&args).await?,
+                SequencerCommand::BridgeUnlock(args) => sequencer::bridge_unlock(&args).await?,
+                SequencerCommand::BridgeSudoChange(args) => {
+                    sequencer::bridge_sudo_change(&args).await?;
+                }
+                SequencerCommand::Ics20Withdrawal(args) => {
+                    sequencer::ics20_withdrawal(&args).await?;
+                }
+                SequencerCommand::Sequence(args) => sequencer::sequence(&args).await?,
+                SequencerCommand::Tx {
+                    command,
+                } => command.run().await?,
             },
         }
     } else {
@@ -9,11 +9,21 @@ use astria_core::{
         action::{
             Action,
             BridgeLockAction,
+            BridgeSudoChangeAction,
+            BridgeUnlockAction,
             FeeAssetChangeAction,
+            FeeChange,
+            FeeChangeAction,
+            FeeDistribution,
+            FeeDistributionChangeAction,
             IbcRelayerChangeAction,
+            IbcSudoChangeAction,
+            Ics20Withdrawal,
             InitBridgeAccountAction,
+            SequenceAction,
             SudoAddressChangeAction,
             TransferAction,
+            UpgradeScheduleAction,
             ValidatorUpdate,
         },
         TransactionParams,
@@ -30,23 +40,36 @@ use color_eyre::{
     eyre,
     eyre::{
         ensure,
-        eyre,
         Context,
+        OptionExt as _,
     },
 };
+use ibc_types::core::client::Height as IbcHeight;
 use rand::rngs::OsRng;
 
-use crate::cli::sequencer::{
-    BasicAccountArgs,
-    Bech32mAddressArgs,
-    BlockHeightGetArgs,
-    BridgeLockArgs,
-    FeeAssetChangeArgs,
-    IbcRelayerChangeArgs,
-    InitBridgeAccountArgs,
-    SudoAddressChangeArgs,
-    TransferArgs,
-    ValidatorUpdateArgs,
+use crate::cli::{
+    keys::SignerArgs,
+    sequencer::{
+        BasicAccountArgs,
+        Bech32mAddressArgs,
+        BlockHeightGetArgs,
+        BridgeLockArgs,
+        BridgeSudoChangeArgs,
+        BridgeUnlockArgs,
+        FeeAssetChangeArgs,
+        FeeChangeArgs,
+        FeeDistributionChangeArgs,
+        FeeKind,
+        IbcRelayerChangeArgs,
+        IbcSudoAddressChangeArgs,
+        Ics20WithdrawalArgs,
+        InitBridgeAccountArgs,
+        SequenceArgs,
+        SudoAddressChangeArgs,
+        TransferArgs,
+        UpgradeScheduleArgs,
+        ValidatorUpdateArgs,
+    },
 };
 
 /// Generate a new signing key (this is also called a secret key by other implementations)
@@ -194,13 +217,13 @@ pub(crate) async fn send_transfer(args: &TransferArgs) -> eyre::Result<()> {
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::Transfer(TransferAction {
+        &args.signer,
+        vec![Action::Transfer(TransferAction {
             to: args.to_address,
             amount: args.amount,
             asset: args.asset.clone(),
             fee_asset: args.fee_asset.clone(),
-        }),
+        })],
     )
     .await
     .wrap_err("failed to submit transfer transaction")?;
@@ -225,8 +248,10 @@ pub(crate) async fn ibc_relayer_add(args: &IbcRelayerChangeArgs) -> eyre::Result
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::IbcRelayerChange(IbcRelayerChangeAction::Addition(args.address)),
+        &args.signer,
+        vec![Action::IbcRelayerChange(IbcRelayerChangeAction::Addition(
+            args.address,
+        ))],
     )
     .await
     .wrap_err("failed to submit IbcRelayerChangeAction::Addition transaction")?;
@@ -251,8 +276,10 @@ pub(crate) async fn ibc_relayer_remove(args: &IbcRelayerChangeArgs) -> eyre::Res
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::IbcRelayerChange(IbcRelayerChangeAction::Removal(args.address)),
+        &args.signer,
+        vec![Action::IbcRelayerChange(IbcRelayerChangeAction::Removal(
+            args.address,
+        ))],
     )
     .await
     .wrap_err("failed to submit IbcRelayerChangeAction::Removal transaction")?;
@@ -280,14 +307,14 @@ pub(crate) async fn init_bridge_account(args: &InitBridgeAccountArgs) -> eyre::R
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::InitBridgeAccount(InitBridgeAccountAction {
+        &args.signer,
+        vec![Action::InitBridgeAccount(InitBridgeAccountAction {
             rollup_id,
             asset: args.asset.clone(),
             fee_asset: args.fee_asset.clone(),
             sudo_address: None,
             withdrawer_address: None,
-        }),
+        })],
     )
     .await
     .wrap_err("failed to submit InitBridgeAccount transaction")?;
@@ -314,14 +341,14 @@ pub(crate) async fn bridge_lock(args: &BridgeLockArgs) -> eyre::Result<()> {
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::BridgeLock(BridgeLockAction {
+        &args.signer,
+        vec![Action::BridgeLock(BridgeLockAction {
             to: args.to_address,
             asset: args.asset.clone(),
             amount: args.amount,
             fee_asset: args.fee_asset.clone(),
             destination_chain_address: args.destination_chain_address.clone(),
-        }),
+        })],
     )
     .await
     .wrap_err("failed to submit BridgeLock transaction")?;
@@ -346,8 +373,10 @@ pub(crate) async fn fee_asset_add(args: &FeeAssetChangeArgs) -> eyre::Result<()>
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::FeeAssetChange(FeeAssetChangeAction::Addition(args.asset.clone())),
+        &args.signer,
+        vec![Action::FeeAssetChange(FeeAssetChangeAction::Addition(
+            args.asset.clone(),
+        ))],
     )
     .await
     .wrap_err("failed to submit FeeAssetChangeAction::Addition transaction")?;
@@ -372,8 +401,10 @@ pub(crate) async fn fee_asset_remove(args: &FeeAssetChangeArgs) -> eyre::Result<
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::FeeAssetChange(FeeAssetChangeAction::Removal(args.asset.clone())),
+        &args.signer,
+        vec![Action::FeeAssetChange(FeeAssetChangeAction::Removal(
+            args.asset.clone(),
+        ))],
     )
     .await
     .wrap_err("failed to submit FeeAssetChangeAction::Removal transaction")?;
@@ -398,10 +429,10 @@ pub(crate) async fn sudo_address_change(args: &SudoAddressChangeArgs) -> eyre::R
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::SudoAddressChange(SudoAddressChangeAction {
+        &args.signer,
+        vec![Action::SudoAddressChange(SudoAddressChangeAction {
             new_address: args.address,
-        }),
+        })],
     )
     .await
     .wrap_err("failed to submit SudoAddressChange transaction")?;
@@ -436,8 +467,8 @@ pub(crate) async fn validator_update(args: &ValidatorUpdateArgs) -> eyre::Result
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::ValidatorUpdate(validator_update),
+        &args.signer,
+        vec![Action::ValidatorUpdate(validator_update)],
     )
     .await
     .wrap_err("failed to submit ValidatorUpdate transaction")?;
@@ -447,22 +478,348 @@ pub(crate) async fn validator_update(args: &ValidatorUpdateArgs) -> eyre::Result
     Ok(())
 }
 
-async fn submit_transaction(
+/// Unlocks funds from a bridge account
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn bridge_unlock(args: &BridgeUnlockArgs) -> eyre::Result<()> {
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::BridgeUnlock(BridgeUnlockAction {
+            to: args.to_address,
+            amount: args.amount,
+            fee_asset: args.fee_asset.clone(),
+            bridge_address: args.bridge_address,
+            memo: args.memo.clone(),
+            rollup_block_number: args.rollup_block_number,
+            rollup_withdrawal_event_id: args.rollup_withdrawal_event_id.clone(),
+        })],
+    )
+    .await
+    .wrap_err("failed to submit BridgeUnlock transaction")?;
+
+    println!("BridgeUnlock completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Changes the sudo or withdrawer address of a bridge account
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If neither a new sudo nor a new withdrawer address is given
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn bridge_sudo_change(args: &BridgeSudoChangeArgs) -> eyre::Result<()> {
+    ensure!(
+        args.new_sudo_address.is_some() || args.new_withdrawer_address.is_some(),
+        "at least one of `--new-sudo-address` and `--new-withdrawer-address` must be set"
+    );
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::BridgeSudoChange(BridgeSudoChangeAction {
+            bridge_address: args.bridge_address,
+            new_sudo_address: args.new_sudo_address,
+            new_withdrawer_address: args.new_withdrawer_address,
+            fee_asset: args.fee_asset.clone(),
+        })],
+    )
+    .await
+    .wrap_err("failed to submit BridgeSudoChange transaction")?;
+
+    println!("BridgeSudoChange completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Withdraws funds to another chain via ICS20
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the source channel or timeout height are malformed
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn ics20_withdrawal(args: &Ics20WithdrawalArgs) -> eyre::Result<()> {
+    let source_channel = args
+        .source_channel
+        .parse()
+        .wrap_err("failed to parse source channel")?;
+    let timeout_height = match &args.timeout_height {
+        Some(timeout_height) => parse_ibc_height(timeout_height)?,
+        // the timeout height is on the destination chain, which we are unaware of. thus, it is
+        // set to the maximum possible value, leaving the timeout to `timeout_time`.
+        None => IbcHeight::new(u64::MAX, u64::MAX).expect("non-zero arguments should never fail"),
+    };
+    let timeout_time = tendermint::Time::now()
+        .checked_add(*args.timeout)
+        .ok_or_eyre("timeout is too far in the future")?
+        .unix_timestamp_nanos()
+        .try_into()
+        .wrap_err("timeout time must be after the unix epoch")?;
+
+    let signer = args
+        .signer
+        .signing_key()
+        .wrap_err("failed to get signing key")?;
+    let return_address = match args.return_address {
+        Some(return_address) => return_address,
+        None => Address::builder()
+            .array(signer.verification_key().address_bytes())
+            .prefix(&args.prefix)
+            .try_build()
+            .wrap_err("failed constructing a valid return address from the provided prefix")?,
+    };
+
+    let res = submit_transaction_with_key(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &signer,
+        vec![Action::Ics20Withdrawal(Ics20Withdrawal {
+            amount: args.amount,
+            denom: args.denom.clone(),
+            destination_chain_address: args.destination_chain_address.clone(),
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel,
+            fee_asset: args.fee_asset.clone(),
+            memo: args.memo.clone(),
+            bridge_address: args.bridge_address,
+            use_compat_address: args.use_compat_address,
+        })],
+    )
+    .await
+    .wrap_err("failed to submit Ics20Withdrawal transaction")?;
+
+    println!("Ics20Withdrawal completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Submits data to a rollup
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the data is not hex-encoded
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn sequence(args: &SequenceArgs) -> eyre::Result<()> {
+    use astria_core::primitive::v1::RollupId;
+
+    let rollup_id = RollupId::from_unhashed_bytes(args.rollup_name.as_bytes());
+    let data = hex::decode(&args.data).wrap_err("failed to decode data from hex string")?;
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::Sequence(SequenceAction {
+            rollup_id,
+            data: data.into(),
+            fee_asset: args.fee_asset.clone(),
+        })],
+    )
+    .await
+    .wrap_err("failed to submit Sequence transaction")?;
+
+    println!("Sequence completed!");
+    println!("Included in block: {}", res.height);
+    println!("Rollup ID: {rollup_id}");
+    Ok(())
+}
+
+/// Changes the address authorized to change the IBC relayer set
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn ibc_sudo_address_change(args: &IbcSudoAddressChangeArgs) -> eyre::Result<()> {
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::IbcSudoChange(IbcSudoChangeAction {
+            new_address: args.address,
+        })],
+    )
+    .await
+    .wrap_err("failed to submit IbcSudoChange transaction")?;
+
+    println!("IbcSudoChange completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Changes a transaction fee
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn fee_change(args: &FeeChangeArgs) -> eyre::Result<()> {
+    let fee_change = match args.fee {
+        FeeKind::TransferBaseFee => FeeChange::TransferBaseFee,
+        FeeKind::SequenceBaseFee => FeeChange::SequenceBaseFee,
+        FeeKind::SequenceByteCostMultiplier => FeeChange::SequenceByteCostMultiplier,
+        FeeKind::InitBridgeAccountBaseFee => FeeChange::InitBridgeAccountBaseFee,
+        FeeKind::BridgeLockByteCostMultiplier => FeeChange::BridgeLockByteCostMultiplier,
+        FeeKind::BridgeSudoChangeBaseFee => FeeChange::BridgeSudoChangeBaseFee,
+        FeeKind::Ics20WithdrawalBaseFee => FeeChange::Ics20WithdrawalBaseFee,
+    };
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::FeeChange(FeeChangeAction {
+            fee_change,
+            new_value: args.value,
+        })],
+    )
+    .await
+    .wrap_err("failed to submit FeeChange transaction")?;
+
+    println!("FeeChange completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Changes how block fees are distributed
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the percentages do not add up to 100
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn fee_distribution_change(args: &FeeDistributionChangeArgs) -> eyre::Result<()> {
+    let new_distribution = FeeDistribution::try_new(
+        args.proposer_percent,
+        args.validators_percent,
+        args.treasury_percent,
+        args.burn_percent,
+        args.treasury_address,
+    )
+    .wrap_err("invalid fee distribution")?;
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::FeeDistributionChange(FeeDistributionChangeAction {
+            new_distribution,
+        })],
+    )
+    .await
+    .wrap_err("failed to submit FeeDistributionChange transaction")?;
+
+    println!("FeeDistributionChange completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Schedules a chain upgrade at a future height
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn upgrade_schedule(args: &UpgradeScheduleArgs) -> eyre::Result<()> {
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        vec![Action::UpgradeSchedule(UpgradeScheduleAction {
+            name: args.name.clone(),
+            height: args.height,
+        })],
+    )
+    .await
+    .wrap_err("failed to submit UpgradeSchedule transaction")?;
+
+    println!("UpgradeSchedule completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Parses an IBC height given as `<revision number>-<revision height>`
+fn parse_ibc_height(height: &str) -> eyre::Result<IbcHeight> {
+    let (revision_number, revision_height) = height
+        .split_once('-')
+        .ok_or_eyre("height must be given as `<revision number>-<revision height>`")?;
+    let revision_number = revision_number
+        .parse()
+        .wrap_err("failed to parse revision number")?;
+    let revision_height = revision_height
+        .parse()
+        .wrap_err("failed to parse revision height")?;
+    IbcHeight::new(revision_number, revision_height).wrap_err("invalid height")
+}
+
+pub(super) async fn submit_transaction(
     sequencer_url: &str,
     chain_id: String,
     prefix: &str,
-    private_key: &str,
-    action: Action,
+    signer: &SignerArgs,
+    actions: Vec<Action>,
+) -> eyre::Result<Response> {
+    let sequencer_key = signer.signing_key().wrap_err("failed to get signing key")?;
+    submit_transaction_with_key(sequencer_url, chain_id, prefix, &sequencer_key, actions).await
+}
+
+async fn submit_transaction_with_key(
+    sequencer_url: &str,
+    chain_id: String,
+    prefix: &str,
+    sequencer_key: &SigningKey,
+    actions: Vec<Action>,
 ) -> eyre::Result<Response> {
     let sequencer_client =
         HttpClient::new(sequencer_url).wrap_err("failed constructing http sequencer client")?;
 
-    let private_key_bytes: [u8; 32] = hex::decode(private_key)
-        .wrap_err("failed to decode private key bytes from hex string")?
-        .try_into()
-        .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
-    let sequencer_key = SigningKey::from(private_key_bytes);
-
     let from_address = Address::builder()
         .array(sequencer_key.verification_key().address_bytes())
         .prefix(prefix)
@@ -480,24 +837,10 @@ async fn submit_transaction(
             .nonce(nonce_res.nonce)
             .chain_id(chain_id)
             .build(),
-        actions: vec![action],
+        actions,
     }
-    .into_signed(&sequencer_key);
-    let res = sequencer_client
-        .submit_transaction_sync(tx)
-        .await
-        .wrap_err("failed to submit transaction")?;
-
-    let tx_response = sequencer_client.wait_for_tx_inclusion(res.hash).await;
-
-    ensure!(res.code.is_ok(), "failed to check tx: {}", res.log);
-
-    ensure!(
-        tx_response.tx_result.code.is_ok(),
-        "failed to execute tx: {}",
-        tx_response.tx_result.log
-    );
-    Ok(tx_response)
+    .into_signed(sequencer_key);
+    super::tx::submit_and_wait(&sequencer_client, tx).await
 }
 
 #[cfg(test)]
@@ -552,4 +895,14 @@ mod tests {
         let address_pretty = get_address_pretty(&signing_key);
         assert_eq!(address_pretty.len(), 40);
     }
+
+    #[test]
+    fn ibc_height_is_parsed_from_revision_number_and_height() {
+        assert_eq!(
+            parse_ibc_height("1-100").unwrap(),
+            IbcHeight::new(1, 100).unwrap()
+        );
+        assert!(parse_ibc_height("100").is_err());
+        assert!(parse_ibc_height("a-100").is_err());
+    }
 }
@@ -0,0 +1,490 @@
+use std::path::Path;
+
+use astria_core::{
+    generated::protocol::transactions::v1alpha1 as raw,
+    protocol::transaction::v1alpha1::{
+        Action,
+        SignedTransaction,
+        TransactionParams,
+        UnsignedTransaction,
+    },
+};
+use astria_sequencer_client::{
+    tendermint_rpc::endpoint::tx::Response,
+    Client as _,
+    HttpClient,
+    SequencerClientExt as _,
+};
+use color_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use prost::Message as _;
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+use super::keys::make_address;
+use crate::cli::{
+    tx::{
+        BroadcastArgs,
+        BuildArgs,
+        Command,
+        FromJsonArgs,
+        InspectArgs,
+        QueryArgs,
+        SignArgs,
+    },
+    OutputFormat,
+};
+
+impl Command {
+    pub(crate) async fn run(self) -> eyre::Result<()> {
+        match self {
+            Command::Build(args) => build(args).await,
+            Command::Sign(args) => sign(&args),
+            Command::Inspect(args) => inspect(&args),
+            Command::Broadcast(args) => broadcast(args).await,
+            Command::FromJson(args) => from_json(args).await,
+            Command::Get(args) => get(args).await,
+            Command::Wait(args) => wait(args).await,
+        }
+    }
+}
+
+/// Builds an unsigned transaction from a file of actions
+///
+/// # Errors
+///
+/// * If the actions cannot be read
+/// * If no nonce is given and it cannot be fetched from the Sequencer
+/// * If the transaction cannot be written
+async fn build(args: BuildArgs) -> eyre::Result<()> {
+    let actions = read_actions(&args.actions)?;
+
+    let nonce = if let Some(nonce) = args.nonce {
+        nonce
+    } else {
+        let address = args
+            .signer_address
+            .expect("clap requires the signer address if no nonce is given");
+        let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+            .wrap_err("failed constructing http sequencer client")?;
+        sequencer_client
+            .get_latest_nonce(address)
+            .await
+            .wrap_err("failed to get nonce")?
+            .nonce
+    };
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(nonce)
+            .chain_id(args.sequencer_chain_id)
+            .build(),
+        actions,
+    };
+    write_json(args.output.as_deref(), &tx.into_raw())
+}
+
+/// Signs an unsigned transaction
+///
+/// # Errors
+///
+/// * If the transaction cannot be read or is invalid
+/// * If the signing key cannot be obtained
+/// * If the signed transaction cannot be written
+fn sign(args: &SignArgs) -> eyre::Result<()> {
+    let tx = read_unsigned_transaction(&args.input)?;
+    let signing_key = args
+        .signer
+        .signing_key()
+        .wrap_err("failed to get signing key")?;
+    let signed = tx.into_signed(&signing_key);
+
+    let signer = make_address(signed.address_bytes(), &args.prefix)?;
+    eprintln!("Signed by:        {signer}");
+    eprintln!("Transaction hash: {}", signed.id());
+    write_json(args.output.as_deref(), &signed.into_raw())
+}
+
+/// Decodes and prints an unsigned or signed transaction
+///
+/// # Errors
+///
+/// * If the file contains neither an unsigned nor a signed transaction
+fn inspect(args: &InspectArgs) -> eyre::Result<()> {
+    let contents = std::fs::read_to_string(&args.input)
+        .wrap_err_with(|| format!("failed reading transaction from `{}`", args.input.display()))?;
+    let tx = if let Ok(raw) = serde_json::from_str::<raw::SignedTransaction>(&contents) {
+        let signed = SignedTransaction::try_from_raw(raw)
+            .wrap_err("failed to validate signed transaction")?;
+        let signer = make_address(signed.address_bytes(), &args.prefix)?;
+        println!("Signed transaction");
+        println!("    Signer:           {signer}");
+        println!("    Transaction hash: {}", signed.id());
+        signed.into_unsigned()
+    } else {
+        let raw: raw::UnsignedTransaction = serde_json::from_str(&contents)
+            .wrap_err("file contains neither a signed nor an unsigned transaction")?;
+        println!("Unsigned transaction");
+        UnsignedTransaction::try_from_raw(raw)
+            .wrap_err("failed to validate unsigned transaction")?
+    };
+
+    println!("    Chain ID:         {}", tx.chain_id());
+    println!("    Nonce:            {}", tx.nonce());
+    print_actions(&tx.actions);
+    Ok(())
+}
+
+/// Gets an executed transaction and prints it with its events
+///
+/// # Errors
+///
+/// * If the hash is malformed
+/// * If the transaction cannot be fetched or decoded
+async fn get(args: QueryArgs) -> eyre::Result<()> {
+    let hash = parse_transaction_hash(&args.hash)?;
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+    let res = sequencer_client
+        .tx(hash, false)
+        .await
+        .wrap_err("failed to get transaction")?;
+    print_executed(res, &args)
+}
+
+/// Waits for a transaction to be executed and prints it with its events
+///
+/// # Errors
+///
+/// * If the hash is malformed
+/// * If the transaction cannot be decoded
+async fn wait(args: QueryArgs) -> eyre::Result<()> {
+    let hash = parse_transaction_hash(&args.hash)?;
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+    let res = sequencer_client.wait_for_tx_inclusion(hash).await;
+    print_executed(res, &args)
+}
+
+fn print_executed(res: Response, args: &QueryArgs) -> eyre::Result<()> {
+    let view = ExecutedTransactionView::try_from_response(res, &args.prefix)?;
+    match args.format {
+        OutputFormat::Human => view.print(),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&view).wrap_err("failed encoding transaction as JSON")?
+        ),
+    }
+    Ok(())
+}
+
+fn print_actions(actions: &[Action]) {
+    println!("    Actions:");
+    for (index, action) in actions.iter().enumerate() {
+        let action =
+            serde_json::to_string_pretty(&action).unwrap_or_else(|_| format!("{action:?}"));
+        println!("    {index}: {}", action.replace('\n', "\n       "));
+    }
+}
+
+fn parse_transaction_hash(hash: &str) -> eyre::Result<tendermint::Hash> {
+    let bytes: [u8; 32] = hex::decode(hash.trim_start_matches("0x"))
+        .wrap_err("failed to decode transaction hash from hex string")?
+        .try_into()
+        .map_err(|_| eyre!("invalid transaction hash length; must be 32 bytes"))?;
+    Ok(tendermint::Hash::Sha256(bytes))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecutedTransactionView {
+    hash: String,
+    height: u64,
+    index: u32,
+    code: u32,
+    log: String,
+    gas_wanted: i64,
+    gas_used: i64,
+    signer: String,
+    chain_id: String,
+    nonce: u32,
+    actions: Vec<Action>,
+    events: Vec<EventView>,
+}
+
+#[derive(Serialize)]
+struct EventView {
+    kind: String,
+    attributes: Vec<AttributeView>,
+}
+
+#[derive(Serialize)]
+struct AttributeView {
+    key: String,
+    value: String,
+}
+
+impl ExecutedTransactionView {
+    fn try_from_response(res: Response, prefix: &str) -> eyre::Result<Self> {
+        let raw = raw::SignedTransaction::decode(&*res.tx)
+            .wrap_err("failed to decode transaction bytes as protobuf signed transaction")?;
+        let tx = SignedTransaction::try_from_raw(raw)
+            .wrap_err("failed to validate signed transaction")?;
+        let signer = make_address(tx.address_bytes(), prefix)?;
+        let events = res
+            .tx_result
+            .events
+            .into_iter()
+            .map(|event| EventView {
+                kind: event.kind,
+                attributes: event
+                    .attributes
+                    .into_iter()
+                    .map(|attribute| AttributeView {
+                        key: attribute.key,
+                        value: attribute.value,
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(Self {
+            hash: res.hash.to_string(),
+            height: res.height.value(),
+            index: res.index,
+            code: res.tx_result.code.value(),
+            log: res.tx_result.log,
+            gas_wanted: res.tx_result.gas_wanted,
+            gas_used: res.tx_result.gas_used,
+            signer: signer.to_string(),
+            chain_id: tx.chain_id().to_string(),
+            nonce: tx.nonce(),
+            actions: tx.actions().to_vec(),
+            events,
+        })
+    }
+
+    fn print(&self) {
+        let status = if self.code == 0 {
+            "succeeded".to_string()
+        } else {
+            format!("failed with code {}: {}", self.code, self.log)
+        };
+        println!("Transaction {}", self.hash);
+        println!("    Status:           {status}");
+        println!("    Height:           {}", self.height);
+        println!("    Index in block:   {}", self.index);
+        println!(
+            "    Gas used:         {} of {}",
+            self.gas_used, self.gas_wanted
+        );
+        println!("    Signer:           {}", self.signer);
+        println!("    Chain ID:         {}", self.chain_id);
+        println!("    Nonce:            {}", self.nonce);
+        print_actions(&self.actions);
+        println!("    Events:");
+        for event in &self.events {
+            println!("        {}", event.kind);
+            for attribute in &event.attributes {
+                println!("            {}: {}", attribute.key, attribute.value);
+            }
+        }
+    }
+}
+
+/// Submits a signed transaction and waits for its inclusion
+///
+/// # Errors
+///
+/// * If the transaction cannot be read or its signature is invalid
+/// * If the transaction is rejected or fails to execute
+async fn broadcast(args: BroadcastArgs) -> eyre::Result<()> {
+    let raw: raw::SignedTransaction = read_json(&args.input).wrap_err_with(|| {
+        format!(
+            "failed reading signed transaction from `{}`",
+            args.input.display()
+        )
+    })?;
+    let tx =
+        SignedTransaction::try_from_raw(raw).wrap_err("failed to validate signed transaction")?;
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+
+    let res = submit_and_wait(&sequencer_client, tx).await?;
+    println!("Transaction completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Builds, signs and submits a transaction from a file of actions
+///
+/// # Errors
+///
+/// * If the actions cannot be read
+/// * If the signing key cannot be obtained
+/// * If the transaction failed to be included
+async fn from_json(args: FromJsonArgs) -> eyre::Result<()> {
+    let actions = read_actions(&args.actions)?;
+    let res = super::sequencer::submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id,
+        &args.prefix,
+        &args.signer,
+        actions,
+    )
+    .await
+    .wrap_err("failed to submit transaction")?;
+
+    println!("Transaction completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
+/// Submits `tx` to the Sequencer and waits for its inclusion in a block
+///
+/// # Errors
+///
+/// * If the transaction cannot be submitted
+/// * If the transaction fails `CheckTx` or its execution
+pub(super) async fn submit_and_wait(
+    sequencer_client: &HttpClient,
+    tx: SignedTransaction,
+) -> eyre::Result<Response> {
+    let res = sequencer_client
+        .submit_transaction_sync(tx)
+        .await
+        .wrap_err("failed to submit transaction")?;
+    ensure!(res.code.is_ok(), "failed to check tx: {}", res.log);
+
+    let tx_response = sequencer_client.wait_for_tx_inclusion(res.hash).await;
+    ensure!(
+        tx_response.tx_result.code.is_ok(),
+        "failed to execute tx: {}",
+        tx_response.tx_result.log
+    );
+    Ok(tx_response)
+}
+
+fn read_actions(path: &Path) -> eyre::Result<Vec<Action>> {
+    let actions: Vec<Action> = read_json(path)
+        .wrap_err_with(|| format!("failed reading actions from `{}`", path.display()))?;
+    ensure!(
+        !actions.is_empty(),
+        "a transaction must contain at least one action"
+    );
+    Ok(actions)
+}
+
+fn read_unsigned_transaction(path: &Path) -> eyre::Result<UnsignedTransaction> {
+    let raw: raw::UnsignedTransaction = read_json(path).wrap_err_with(|| {
+        format!(
+            "failed reading unsigned transaction from `{}`",
+            path.display()
+        )
+    })?;
+    UnsignedTransaction::try_from_raw(raw).wrap_err("failed to validate unsigned transaction")
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> eyre::Result<T> {
+    let s = std::fs::read_to_string(path).wrap_err("failed buffering file contents as string")?;
+    serde_json::from_str(&s).wrap_err("failed deserializing file contents as JSON")
+}
+
+fn write_json<T: Serialize>(output: Option<&Path>, value: &T) -> eyre::Result<()> {
+    let json = serde_json::to_string_pretty(value).wrap_err("failed encoding as JSON")?;
+    if let Some(output) = output {
+        std::fs::write(output, json)
+            .wrap_err_with(|| format!("failed writing to `{}`", output.display()))?;
+    } else {
+        println!("{json}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::Address,
+        protocol::transaction::v1alpha1::action::TransferAction,
+    };
+
+    use super::*;
+
+    fn unsigned_transaction() -> UnsignedTransaction {
+        let to = Address::builder()
+            .array([1; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap();
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(3)
+                .chain_id("test-chain")
+                .build(),
+            actions: vec![Action::Transfer(TransferAction {
+                to,
+                amount: 100,
+                asset: "nria".parse().unwrap(),
+                fee_asset: "nria".parse().unwrap(),
+            })],
+        }
+    }
+
+    #[test]
+    fn unsigned_transaction_survives_json_roundtrip() {
+        let tx = unsigned_transaction();
+        let json = serde_json::to_string(&tx.to_raw()).unwrap();
+        let raw: raw::UnsignedTransaction = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            UnsignedTransaction::try_from_raw(raw).unwrap().to_raw(),
+            tx.to_raw()
+        );
+    }
+
+    #[test]
+    fn signed_transaction_survives_json_roundtrip() {
+        let signed = unsigned_transaction().into_signed(&SigningKey::from([42; 32]));
+        let json = serde_json::to_string(&signed.to_raw()).unwrap();
+        let raw: raw::SignedTransaction = serde_json::from_str(&json).unwrap();
+        let roundtripped = SignedTransaction::try_from_raw(raw).unwrap();
+        assert_eq!(roundtripped.id(), signed.id());
+    }
+
+    #[test]
+    fn unsigned_transaction_is_not_mistaken_for_signed() {
+        let json = serde_json::to_string(&unsigned_transaction().to_raw()).unwrap();
+        assert!(serde_json::from_str::<raw::SignedTransaction>(&json).is_err());
+    }
+
+    #[test]
+    fn multiple_actions_are_read_in_order() {
+        let tx = unsigned_transaction();
+        let actions = vec![tx.actions[0].clone(), tx.actions[0].clone()];
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), serde_json::to_string(&actions).unwrap()).unwrap();
+        assert_eq!(read_actions(file.path()).unwrap().len(), 2);
+
+        std::fs::write(file.path(), "[]").unwrap();
+        assert!(read_actions(file.path()).is_err());
+    }
+
+    #[test]
+    fn transaction_hash_is_parsed_from_hex() {
+        let hash = hex::encode([7u8; 32]);
+        assert_eq!(
+            parse_transaction_hash(&hash).unwrap(),
+            tendermint::Hash::Sha256([7; 32])
+        );
+        assert_eq!(
+            parse_transaction_hash(&format!("0x{}", hash.to_uppercase())).unwrap(),
+            tendermint::Hash::Sha256([7; 32])
+        );
+        assert!(parse_transaction_hash("abcd").is_err());
+    }
+}
@@ -0,0 +1,541 @@
+//! A password-encrypted store of sequencer signing keys.
+//!
+//! Each key is kept in its own `<name>.json` file in the keystore directory. The file format
+//! follows the Ethereum v3 keystore: the signing key is encrypted with a key derived from the
+//! password using scrypt, while the address of the key is stored in plain text so that keys can
+//! be listed without a password. Instead of AES-128-CTR and a separate MAC the key is sealed
+//! with ChaCha20-Poly1305, with the address as associated data.
+use std::{
+    fs,
+    io::Write as _,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::{
+    crypto::SigningKey,
+    primitive::v1::ADDRESS_LEN,
+};
+use chacha20poly1305::{
+    aead::{
+        Aead as _,
+        NewAead as _,
+        Payload,
+    },
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use color_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use rand::{
+    rngs::OsRng,
+    RngCore as _,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use zeroize::Zeroizing;
+
+/// The environment variable from which the keystore password is read instead of prompting for it.
+const PASSWORD_ENV: &str = "ASTRIA_KEYSTORE_PASSWORD";
+
+const KEY_FILE_VERSION: u32 = 1;
+const CIPHER: &str = "chacha20-poly1305";
+const KDF: &str = "scrypt";
+const DERIVED_KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+// The scrypt parameters used by the Ethereum v3 keystore.
+const SCRYPT_LOG_N: u8 = 18;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Returns the default keystore directory, `~/.astria/keystore`.
+pub(crate) fn default_dir() -> eyre::Result<PathBuf> {
+    let home = dirs::home_dir().ok_or_else(|| {
+        eyre!("failed to determine the home directory; provide the keystore directory explicitly")
+    })?;
+    Ok(home.join(".astria").join("keystore"))
+}
+
+/// Reads the password of the keystore from [`PASSWORD_ENV`], or prompts for it.
+pub(crate) fn read_password(prompt: &str) -> eyre::Result<Zeroizing<String>> {
+    if let Ok(password) = std::env::var(PASSWORD_ENV) {
+        return Ok(Zeroizing::new(password));
+    }
+    rpassword::prompt_password(prompt)
+        .map(Zeroizing::new)
+        .wrap_err("failed to read password")
+}
+
+/// Reads a new password for the keystore from [`PASSWORD_ENV`], or prompts for it twice.
+pub(crate) fn read_new_password() -> eyre::Result<Zeroizing<String>> {
+    if let Ok(password) = std::env::var(PASSWORD_ENV) {
+        return Ok(Zeroizing::new(password));
+    }
+    let password = read_password("Enter password to encrypt the key: ")?;
+    let repeated = read_password("Repeat password: ")?;
+    ensure!(password == repeated, "passwords do not match");
+    Ok(password)
+}
+
+/// A key stored in the keystore.
+#[derive(Debug)]
+pub(crate) struct Entry {
+    pub(crate) name: String,
+    pub(crate) address_bytes: [u8; ADDRESS_LEN],
+}
+
+pub(crate) struct Keystore {
+    dir: PathBuf,
+    scrypt_log_n: u8,
+}
+
+impl Keystore {
+    /// Opens the keystore at `dir`, or at [`default_dir`] if not provided.
+    ///
+    /// The directory is only created once a key is added.
+    pub(crate) fn open(dir: Option<PathBuf>) -> eyre::Result<Self> {
+        let dir = match dir {
+            Some(dir) => dir,
+            None => default_dir()?,
+        };
+        Ok(Self {
+            dir,
+            scrypt_log_n: SCRYPT_LOG_N,
+        })
+    }
+
+    pub(crate) fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Encrypts `signing_key` with `password` and stores it under `name`.
+    ///
+    /// # Errors
+    ///
+    /// * If `name` is not a valid key name
+    /// * If a key with the same name already exists
+    /// * If the key file cannot be written
+    pub(crate) fn add(
+        &self,
+        name: &str,
+        signing_key: &SigningKey,
+        password: &str,
+    ) -> eyre::Result<Entry> {
+        validate_name(name)?;
+        let path = self.path(name);
+        ensure!(
+            !path.exists(),
+            "a key named `{name}` already exists at `{}`",
+            path.display()
+        );
+
+        let key_file = KeyFile::encrypt(name, signing_key, password, self.scrypt_log_n)?;
+        let contents =
+            serde_json::to_vec_pretty(&key_file).wrap_err("failed to serialize key file")?;
+
+        create_private_dir(&self.dir).wrap_err_with(|| {
+            format!(
+                "failed to create keystore directory `{}`",
+                self.dir.display()
+            )
+        })?;
+        let mut file = create_private_file(&path)
+            .wrap_err_with(|| format!("failed to create key file `{}`", path.display()))?;
+        file.write_all(&contents)
+            .wrap_err_with(|| format!("failed to write key file `{}`", path.display()))?;
+
+        Ok(Entry {
+            name: name.to_string(),
+            address_bytes: signing_key.verification_key().address_bytes(),
+        })
+    }
+
+    /// Decrypts the key stored under `name` with `password`.
+    ///
+    /// # Errors
+    ///
+    /// * If no key with the name exists
+    /// * If the key file is malformed
+    /// * If the password is wrong
+    pub(crate) fn load(&self, name: &str, password: &str) -> eyre::Result<SigningKey> {
+        validate_name(name)?;
+        let key_file = self.read_key_file(name)?;
+        key_file
+            .decrypt(password)
+            .wrap_err_with(|| format!("failed to decrypt key `{name}`"))
+    }
+
+    /// Lists the keys in the keystore, ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// * If the keystore directory cannot be read
+    /// * If a key file in the keystore is malformed
+    pub(crate) fn list(&self) -> eyre::Result<Vec<Entry>> {
+        if !self.dir.exists() {
+            return Ok(vec![]);
+        }
+        let dir = fs::read_dir(&self.dir).wrap_err_with(|| {
+            format!("failed to read keystore directory `{}`", self.dir.display())
+        })?;
+        let mut entries = vec![];
+        for dir_entry in dir {
+            let path = dir_entry
+                .wrap_err("failed to read keystore directory entry")?
+                .path();
+            let Some(name) = key_name_of(&path) else {
+                continue;
+            };
+            let key_file = self.read_key_file(name)?;
+            entries.push(Entry {
+                name: name.to_string(),
+                address_bytes: key_file.address_bytes()?,
+            });
+        }
+        entries.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        Ok(entries)
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.json"))
+    }
+
+    fn read_key_file(&self, name: &str) -> eyre::Result<KeyFile> {
+        let path = self.path(name);
+        ensure!(
+            path.exists(),
+            "no key named `{name}` in keystore `{}`",
+            self.dir.display()
+        );
+        let contents = fs::read(&path)
+            .wrap_err_with(|| format!("failed to read key file `{}`", path.display()))?;
+        serde_json::from_slice(&contents)
+            .wrap_err_with(|| format!("failed to parse key file `{}`", path.display()))
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct KeyFile {
+    version: u32,
+    name: String,
+    address: String,
+    crypto: Crypto,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CipherParams {
+    nonce: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct KdfParams {
+    dklen: usize,
+    n: u64,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+impl KeyFile {
+    fn encrypt(
+        name: &str,
+        signing_key: &SigningKey,
+        password: &str,
+        scrypt_log_n: u8,
+    ) -> eyre::Result<Self> {
+        let address_bytes = signing_key.verification_key().address_bytes();
+
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let derived_key = derive_key(password, &salt, scrypt_log_n, SCRYPT_R, SCRYPT_P)?;
+        let secret = Zeroizing::new(signing_key.to_bytes());
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&*derived_key))
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &*secret,
+                    aad: &address_bytes,
+                },
+            )
+            .map_err(|_| eyre!("failed to encrypt signing key"))?;
+
+        Ok(Self {
+            version: KEY_FILE_VERSION,
+            name: name.to_string(),
+            address: hex::encode(address_bytes),
+            crypto: Crypto {
+                cipher: CIPHER.to_string(),
+                cipherparams: CipherParams {
+                    nonce: hex::encode(nonce),
+                },
+                ciphertext: hex::encode(ciphertext),
+                kdf: KDF.to_string(),
+                kdfparams: KdfParams {
+                    dklen: DERIVED_KEY_LEN,
+                    n: 1 << scrypt_log_n,
+                    r: SCRYPT_R,
+                    p: SCRYPT_P,
+                    salt: hex::encode(salt),
+                },
+            },
+        })
+    }
+
+    fn decrypt(&self, password: &str) -> eyre::Result<SigningKey> {
+        ensure!(
+            self.version == KEY_FILE_VERSION,
+            "unsupported key file version `{}`",
+            self.version
+        );
+        let Crypto {
+            cipher,
+            cipherparams,
+            ciphertext,
+            kdf,
+            kdfparams,
+        } = &self.crypto;
+        ensure!(cipher == CIPHER, "unsupported cipher `{cipher}`");
+        ensure!(kdf == KDF, "unsupported key derivation function `{kdf}`");
+        ensure!(
+            kdfparams.dklen == DERIVED_KEY_LEN,
+            "unsupported derived key length `{}`",
+            kdfparams.dklen
+        );
+        ensure!(
+            kdfparams.n.is_power_of_two() && kdfparams.n > 1,
+            "scrypt parameter `n` must be a power of two greater than 1, but is `{}`",
+            kdfparams.n
+        );
+        // `n` is a power of two, so its trailing zeros are its base 2 logarithm.
+        let log_n = u8::try_from(kdfparams.n.trailing_zeros())
+            .expect("the base 2 logarithm of a u64 is less than 64");
+
+        let address_bytes = self.address_bytes()?;
+        let salt = hex::decode(&kdfparams.salt).wrap_err("failed to decode salt as hex")?;
+        let nonce: [u8; NONCE_LEN] = hex::decode(&cipherparams.nonce)
+            .wrap_err("failed to decode nonce as hex")?
+            .try_into()
+            .map_err(|_| eyre!("invalid nonce length; must be {NONCE_LEN} bytes"))?;
+        let ciphertext = hex::decode(ciphertext).wrap_err("failed to decode ciphertext as hex")?;
+
+        let derived_key = derive_key(password, &salt, log_n, kdfparams.r, kdfparams.p)?;
+        let secret = ChaCha20Poly1305::new(Key::from_slice(&*derived_key))
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: &address_bytes,
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| eyre!("wrong password or corrupted key file"))?;
+        let signing_key = SigningKey::try_from(&**secret)
+            .wrap_err("decrypted bytes are not a valid signing key")?;
+        ensure!(
+            signing_key.verification_key().address_bytes() == address_bytes,
+            "decrypted signing key does not match the address stored in the key file"
+        );
+        Ok(signing_key)
+    }
+
+    fn address_bytes(&self) -> eyre::Result<[u8; ADDRESS_LEN]> {
+        hex::decode(&self.address)
+            .wrap_err("failed to decode address as hex")?
+            .try_into()
+            .map_err(|_| eyre!("invalid address length; must be {ADDRESS_LEN} bytes"))
+    }
+}
+
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> eyre::Result<Zeroizing<[u8; DERIVED_KEY_LEN]>> {
+    let params = scrypt::Params::new(log_n, r, p)
+        .map_err(|_| eyre!("invalid scrypt parameters: log_n={log_n}, r={r}, p={p}"))?;
+    let mut derived_key = Zeroizing::new([0u8; DERIVED_KEY_LEN]);
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut *derived_key)
+        .map_err(|_| eyre!("failed to derive key from password"))?;
+    Ok(derived_key)
+}
+
+/// Key names are used as file names, so they are restricted to ASCII alphanumerics, `-` and `_`.
+fn validate_name(name: &str) -> eyre::Result<()> {
+    if name.is_empty() {
+        bail!("key name must not be empty");
+    }
+    ensure!(
+        name.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "invalid key name `{name}`; only ASCII alphanumerics, `-` and `_` are allowed"
+    );
+    Ok(())
+}
+
+fn key_name_of(path: &Path) -> Option<&str> {
+    if path.extension()? != "json" {
+        return None;
+    }
+    let name = path.file_stem()?.to_str()?;
+    validate_name(name).ok().map(|()| name)
+}
+
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt as _;
+        builder.mode(0o700);
+    }
+    builder.create(dir)
+}
+
+fn create_private_file(path: &Path) -> std::io::Result<fs::File> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt as _;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Cheap scrypt parameters to keep the tests fast.
+    const TEST_SCRYPT_LOG_N: u8 = 4;
+
+    fn keystore(dir: &Path) -> Keystore {
+        Keystore {
+            dir: dir.join("keystore"),
+            scrypt_log_n: TEST_SCRYPT_LOG_N,
+        }
+    }
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from([42; 32])
+    }
+
+    #[test]
+    fn added_key_can_be_loaded_with_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        keystore.add("alice", &signing_key(), "hunter2").unwrap();
+
+        let loaded = keystore.load("alice", "hunter2").unwrap();
+        assert_eq!(loaded.to_bytes(), signing_key().to_bytes());
+    }
+
+    #[test]
+    fn key_cannot_be_loaded_with_wrong_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        keystore.add("alice", &signing_key(), "hunter2").unwrap();
+
+        let error = keystore.load("alice", "hunter3").unwrap_err();
+        assert!(
+            format!("{error:?}").contains("wrong password"),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[test]
+    fn key_file_does_not_contain_plaintext_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        keystore.add("alice", &signing_key(), "hunter2").unwrap();
+
+        let contents = fs::read_to_string(keystore.path("alice")).unwrap();
+        assert!(!contents.contains(&hex::encode(signing_key().to_bytes())));
+        assert!(contents.contains(&hex::encode(
+            signing_key().verification_key().address_bytes()
+        )));
+    }
+
+    #[test]
+    fn tampered_address_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        keystore.add("alice", &signing_key(), "hunter2").unwrap();
+
+        let path = keystore.path("alice");
+        let mut key_file: KeyFile = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        key_file.address = hex::encode([1u8; ADDRESS_LEN]);
+        fs::write(&path, serde_json::to_vec(&key_file).unwrap()).unwrap();
+
+        assert!(keystore.load("alice", "hunter2").is_err());
+    }
+
+    #[test]
+    fn duplicate_names_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        keystore.add("alice", &signing_key(), "hunter2").unwrap();
+        assert!(keystore
+            .add("alice", &SigningKey::from([1; 32]), "hunter2")
+            .is_err());
+    }
+
+    #[test]
+    fn invalid_names_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        for name in ["", "../alice", "alice.json", "al ice"] {
+            assert!(
+                keystore.add(name, &signing_key(), "hunter2").is_err(),
+                "name `{name}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn keys_are_listed_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        assert!(keystore.list().unwrap().is_empty());
+
+        keystore
+            .add("bob", &SigningKey::from([1; 32]), "hunter2")
+            .unwrap();
+        keystore.add("alice", &signing_key(), "hunter2").unwrap();
+        fs::write(keystore.dir().join("notes.txt"), "not a key").unwrap();
+
+        let entries = keystore.list().unwrap();
+        let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(
+            entries[0].address_bytes,
+            signing_key().verification_key().address_bytes()
+        );
+    }
+}
@@ -11,8 +11,10 @@ use axum::{
     },
     routing::{
         get,
+        post,
         IntoMakeService,
     },
+    Json,
     Router,
 };
 use hyper::server::conn::AddrIncoming;
@@ -23,7 +25,13 @@ use tracing::{
     instrument,
 };
 
-use crate::composer;
+use crate::{
+    composer,
+    reload::{
+        ReloadHandle,
+        ReloadableConfig,
+    },
+};
 
 pub(super) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
 
@@ -33,6 +41,7 @@ type ComposerStatus = watch::Receiver<composer::Status>;
 #[derive(Clone)]
 struct AppState {
     composer_status: ComposerStatus,
+    reload_handle: ReloadHandle,
 }
 
 impl FromRef<AppState> for ComposerStatus {
@@ -41,11 +50,24 @@ impl FromRef<AppState> for ComposerStatus {
     }
 }
 
-pub(super) fn start(listen_addr: SocketAddr, composer_status: ComposerStatus) -> ApiServer {
+impl FromRef<AppState> for ReloadHandle {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.reload_handle.clone()
+    }
+}
+
+pub(super) fn start(
+    listen_addr: SocketAddr,
+    composer_status: ComposerStatus,
+    reload_handle: ReloadHandle,
+) -> ApiServer {
     let app = Router::new()
         .route("/readyz", get(readyz))
+        .route("/config", get(get_config))
+        .route("/reload", post(post_reload))
         .with_state(AppState {
             composer_status,
+            reload_handle,
         });
     axum::Server::bind(&listen_addr).serve(app.into_make_service())
 }
@@ -86,3 +108,19 @@ async fn readyz(State(composer_status): State<ComposerStatus>) -> Readyz {
         Readyz::NotReady
     }
 }
+
+/// Returns the active config of the fields that can be changed while composer is running.
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn get_config(State(reload_handle): State<ReloadHandle>) -> Json<ReloadableConfig> {
+    Json(reload_handle.subscribe().borrow().clone())
+}
+
+/// Triggers composer to re-read its config, same as sending it `SIGHUP`.
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn post_reload(State(reload_handle): State<ReloadHandle>) -> axum::http::StatusCode {
+    debug!("received reload request");
+    reload_handle.trigger().trigger();
+    axum::http::StatusCode::ACCEPTED
+}
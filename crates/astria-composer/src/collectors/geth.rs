@@ -37,13 +37,9 @@ use ethers::{
     },
     types::Transaction,
 };
-use telemetry::metrics::Counter;
 use tokio::{
     select,
-    sync::{
-        mpsc::error::SendTimeoutError,
-        watch,
-    },
+    sync::watch,
 };
 use tokio_util::sync::CancellationToken;
 use tracing::{
@@ -54,11 +50,13 @@ use tracing::{
 };
 
 use crate::{
-    collectors::EXECUTOR_SEND_TIMEOUT,
-    executor::{
-        self,
-        Handle,
+    collectors::{
+        forward_tx,
+        txs_dropped_counter,
+        txs_received_counter,
+        Status,
     },
+    executor,
     metrics::Metrics,
     utils::report_exit_reason,
 };
@@ -91,23 +89,6 @@ pub(crate) struct Geth {
     fee_asset: asset::Denom,
 }
 
-#[derive(Debug)]
-pub(crate) struct Status {
-    pub(crate) is_connected: bool,
-}
-
-impl Status {
-    fn new() -> Self {
-        Self {
-            is_connected: false,
-        }
-    }
-
-    pub(crate) fn is_connected(&self) -> bool {
-        self.is_connected
-    }
-}
-
 pub(crate) struct Builder {
     pub(crate) chain_name: String,
     pub(crate) url: String,
@@ -202,7 +183,7 @@ impl Geth {
 
                         txs_received_counter.increment(1);
 
-                        if let Err(err) = forward_geth_tx(
+                        if let Err(err) = forward_tx(
                             &executor_handle,
                             seq_action,
                             tx_hash,
@@ -231,39 +212,6 @@ impl Geth {
     }
 }
 
-#[instrument(skip_all)]
-async fn forward_geth_tx(
-    executor_handle: &Handle,
-    seq_action: SequenceAction,
-    tx_hash: ethers::types::H256,
-    txs_dropped_counter: &Counter,
-) -> eyre::Result<()> {
-    match executor_handle
-        .send_timeout(seq_action, EXECUTOR_SEND_TIMEOUT)
-        .await
-    {
-        Ok(()) => Ok(()),
-        Err(SendTimeoutError::Timeout(_seq_action)) => {
-            warn!(
-                transaction.hash = %tx_hash,
-                timeout_ms = EXECUTOR_SEND_TIMEOUT.as_millis(),
-                "timed out sending new transaction to executor; dropping tx",
-            );
-            txs_dropped_counter.increment(1);
-            Ok(())
-        }
-        Err(SendTimeoutError::Closed(_seq_action)) => {
-            warn!(
-                transaction.hash = %tx_hash,
-                "executor channel closed while sending transaction; dropping transaction \
-                    and exiting event loop"
-            );
-            txs_dropped_counter.increment(1);
-            Err(eyre!("executor channel closed while sending transaction"))
-        }
-    }
-}
-
 #[instrument(skip_all)]
 async fn unsubscribe_from_rollup(tx_stream: &SubscriptionStream<'_, Ws, Transaction>) {
     // give 2s for the websocket connection to be unsubscribed as we want to avoid having
@@ -280,34 +228,6 @@ async fn unsubscribe_from_rollup(tx_stream: &SubscriptionStream<'_, Ws, Transact
     }
 }
 
-#[instrument(skip_all)]
-fn txs_received_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
-    metrics
-        .geth_txs_received(chain_name)
-        .cloned()
-        .unwrap_or_else(|| {
-            error!(
-                rollup_chain_name = %chain_name,
-                "failed to get geth transactions_received counter"
-            );
-            Counter::noop()
-        })
-}
-
-#[instrument(skip_all)]
-fn txs_dropped_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
-    metrics
-        .geth_txs_dropped(chain_name)
-        .cloned()
-        .unwrap_or_else(|| {
-            error!(
-                rollup_chain_name = %chain_name,
-                "failed to get geth transactions_dropped counter"
-            );
-            Counter::noop()
-        })
-}
-
 #[instrument(skip_all, err)]
 async fn connect_to_geth_node(url: String) -> eyre::Result<Provider<Ws>> {
     let retry_config = tryhard::RetryFutureConfig::new(1024)
@@ -14,7 +14,6 @@ use astria_core::{
     },
     protocol::transaction::v1alpha1::action::SequenceAction,
 };
-use tokio::sync::mpsc::error::SendTimeoutError;
 use tonic::{
     Request,
     Response,
@@ -23,7 +22,10 @@ use tonic::{
 
 use crate::{
     collectors::EXECUTOR_SEND_TIMEOUT,
-    executor,
+    executor::{
+        self,
+        SendError,
+    },
     metrics::Metrics,
 };
 
@@ -75,11 +77,19 @@ impl GrpcCollectorService for Grpc {
             .await
         {
             Ok(()) => {}
-            Err(SendTimeoutError::Timeout(_seq_action)) => {
+            Err(SendError::Timeout) => {
                 self.metrics.increment_grpc_txs_dropped(&rollup_id);
                 return Err(Status::unavailable("timeout while sending txs to composer"));
             }
-            Err(SendTimeoutError::Closed(_seq_action)) => {
+            Err(
+                err @ SendError::RollupQuotaFull {
+                    ..
+                },
+            ) => {
+                self.metrics.increment_grpc_txs_dropped(&rollup_id);
+                return Err(Status::resource_exhausted(err.to_string()));
+            }
+            Err(SendError::Closed) => {
                 self.metrics.increment_grpc_txs_dropped(&rollup_id);
                 return Err(Status::failed_precondition("composer is not available"));
             }
@@ -0,0 +1,218 @@
+//! `Http` accepts raw rollup transactions sent to composer over HTTP.
+//!
+//! [`Http`] binds an HTTP server to the address configured for its rollup. Every `POST /`
+//! request's body is treated as a single raw rollup transaction that is forwarded to the
+//! executor as-is.
+//!
+//! ## Note
+//! Like the [`Websocket`](super::Websocket) collector, this collector makes no assumptions
+//! about the rollup's execution environment. Unlike the gRPC collector, clients do not need to
+//! know the rollup ID: it is derived from the rollup name the endpoint is configured for.
+//! ```sh
+//! curl -X POST --data-binary @tx.bin http://127.0.0.1:9100/
+//! ```
+
+use std::net::SocketAddr;
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        RollupId,
+    },
+    protocol::transaction::v1alpha1::action::SequenceAction,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::StatusCode,
+    routing::{
+        post,
+        IntoMakeService,
+    },
+    Router,
+};
+use hyper::server::conn::AddrIncoming;
+use telemetry::metrics::Counter;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use crate::{
+    collectors::{
+        txs_dropped_counter,
+        txs_received_counter,
+        Status,
+        EXECUTOR_SEND_TIMEOUT,
+    },
+    executor::{
+        self,
+        SendError,
+    },
+    metrics::Metrics,
+    utils::report_exit_reason,
+};
+
+type HttpServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
+
+/// `Http` listens for raw rollup transactions submitted as HTTP POST request bodies and passes
+/// them downstream for further processing.
+pub(crate) struct Http {
+    // Name of the chain the transactions are submitted for.
+    chain_name: String,
+    // The server accepting the rollup's transactions.
+    server: HttpServer,
+    // The status of this collector instance.
+    status: watch::Sender<Status>,
+    // Token to signal the http collector to stop upon shutdown.
+    shutdown_token: CancellationToken,
+}
+
+pub(crate) struct Builder {
+    pub(crate) chain_name: String,
+    pub(crate) listen_addr: String,
+    pub(crate) executor_handle: executor::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) metrics: &'static Metrics,
+    pub(crate) fee_asset: asset::Denom,
+}
+
+impl Builder {
+    /// Binds the collector's HTTP server to the configured listen address.
+    ///
+    /// # Errors
+    /// Returns an error if the listen address is not a socket address or if it cannot be bound.
+    pub(crate) fn build(self) -> eyre::Result<Http> {
+        let Self {
+            chain_name,
+            listen_addr,
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+        let listen_addr: SocketAddr = listen_addr.parse().wrap_err_with(|| {
+            format!("failed parsing `{listen_addr}` as listen address of http collector")
+        })?;
+        let (status, _) = watch::channel(Status::new());
+        let rollup_id = RollupId::from_unhashed_bytes(&chain_name);
+        let app = Router::new()
+            .route("/", post(submit_rollup_transaction))
+            .with_state(AppState {
+                rollup_id,
+                executor_handle,
+                fee_asset,
+                txs_received_counter: txs_received_counter(metrics, &chain_name),
+                txs_dropped_counter: txs_dropped_counter(metrics, &chain_name),
+            });
+        let server = axum::Server::try_bind(&listen_addr)
+            .wrap_err_with(|| format!("failed binding http collector to `{listen_addr}`"))?
+            .serve(app.into_make_service());
+        info!(
+            rollup_name = %chain_name,
+            rollup_id = %rollup_id,
+            listen_addr = %server.local_addr(),
+            "created new http collector for rollup",
+        );
+        Ok(Http {
+            chain_name,
+            server,
+            status,
+            shutdown_token,
+        })
+    }
+}
+
+impl Http {
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        self.status.subscribe()
+    }
+
+    /// Runs the collector's HTTP server until failure or until explicitly closed
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        let Self {
+            chain_name,
+            server,
+            status,
+            shutdown_token,
+        } = self;
+
+        status.send_modify(|status| status.is_connected = true);
+
+        let reason = server
+            .with_graceful_shutdown(shutdown_token.cancelled())
+            .await
+            .map(|()| "shutdown signal received")
+            .wrap_err_with(|| format!("http collector server for `{chain_name}` failed"));
+
+        report_exit_reason(reason.as_deref());
+
+        status.send_modify(|status| status.is_connected = false);
+
+        reason.map(|_| ())
+    }
+}
+
+#[derive(Clone)]
+struct AppState {
+    rollup_id: RollupId,
+    executor_handle: executor::Handle,
+    fee_asset: asset::Denom,
+    txs_received_counter: Counter,
+    txs_dropped_counter: Counter,
+}
+
+#[instrument(skip_all)]
+async fn submit_rollup_transaction(State(state): State<AppState>, body: Bytes) -> StatusCode {
+    if body.is_empty() {
+        return StatusCode::BAD_REQUEST;
+    }
+    let seq_action = SequenceAction {
+        rollup_id: state.rollup_id,
+        data: body,
+        fee_asset: state.fee_asset.clone(),
+    };
+
+    state.txs_received_counter.increment(1);
+
+    match state
+        .executor_handle
+        .send_timeout(seq_action, EXECUTOR_SEND_TIMEOUT)
+        .await
+    {
+        Ok(()) => StatusCode::OK,
+        Err(SendError::Timeout) => {
+            warn!(
+                timeout_ms = EXECUTOR_SEND_TIMEOUT.as_millis(),
+                "timed out sending new transaction to executor; dropping tx",
+            );
+            state.txs_dropped_counter.increment(1);
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+        Err(
+            err @ SendError::RollupQuotaFull {
+                ..
+            },
+        ) => {
+            warn!(
+                error = &err as &dyn std::error::Error,
+                "executor rejected new transaction; dropping tx",
+            );
+            state.txs_dropped_counter.increment(1);
+            StatusCode::TOO_MANY_REQUESTS
+        }
+        Err(SendError::Closed) => {
+            warn!("executor channel closed while sending transaction; dropping transaction");
+            state.txs_dropped_counter.increment(1);
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+    }
+}
@@ -1,9 +1,255 @@
 pub(crate) mod geth;
 pub(crate) mod grpc;
+pub(crate) mod http;
+pub(crate) mod txpool;
+pub(crate) mod websocket;
 
-use std::time::Duration;
+use std::{
+    fmt::Display,
+    time::Duration,
+};
+
+use astria_core::{
+    primitive::v1::asset,
+    protocol::transaction::v1alpha1::action::SequenceAction,
+};
+use astria_eyre::eyre::{
+    self,
+    eyre,
+};
+use telemetry::metrics::Counter;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    error,
+    instrument,
+    warn,
+};
+
+use crate::{
+    executor::{
+        self,
+        SendError,
+    },
+    metrics::Metrics,
+    rollup::{
+        CollectorEndpoint,
+        CollectorKind,
+    },
+};
 
 const EXECUTOR_SEND_TIMEOUT: Duration = Duration::from_millis(500);
 
 pub(crate) use geth::Geth;
 pub(crate) use grpc::Grpc;
+pub(crate) use http::Http;
+pub(crate) use txpool::Txpool;
+pub(crate) use websocket::Websocket;
+
+/// The status of a collector feeding transactions of a single rollup to the executor.
+#[derive(Debug)]
+pub(crate) struct Status {
+    pub(crate) is_connected: bool,
+}
+
+impl Status {
+    fn new() -> Self {
+        Self {
+            is_connected: false,
+        }
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+}
+
+/// A collector of one of the kinds that can be configured per rollup.
+pub(crate) enum Collector {
+    Geth(Geth),
+    Txpool(Txpool),
+    Websocket(Websocket),
+    Http(Http),
+}
+
+impl Collector {
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        match self {
+            Self::Geth(collector) => collector.subscribe(),
+            Self::Txpool(collector) => collector.subscribe(),
+            Self::Websocket(collector) => collector.subscribe(),
+            Self::Http(collector) => collector.subscribe(),
+        }
+    }
+
+    /// Starts the collector instance and runs until failure or until
+    /// explicitly closed
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        match self {
+            Self::Geth(collector) => collector.run_until_stopped().await,
+            Self::Txpool(collector) => collector.run_until_stopped().await,
+            Self::Websocket(collector) => collector.run_until_stopped().await,
+            Self::Http(collector) => collector.run_until_stopped().await,
+        }
+    }
+}
+
+pub(crate) struct Builder {
+    pub(crate) chain_name: String,
+    pub(crate) endpoint: CollectorEndpoint,
+    pub(crate) txpool_poll_interval: Duration,
+    pub(crate) executor_handle: executor::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) metrics: &'static Metrics,
+    pub(crate) fee_asset: asset::Denom,
+}
+
+impl Builder {
+    /// Builds the collector of the kind configured for the rollup.
+    ///
+    /// # Errors
+    /// Returns an error if the collector's endpoint is not valid for its kind.
+    pub(crate) fn build(self) -> eyre::Result<Collector> {
+        let Self {
+            chain_name,
+            endpoint,
+            txpool_poll_interval,
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+        let CollectorEndpoint {
+            kind,
+            url,
+        } = endpoint;
+        let collector = match kind {
+            CollectorKind::Geth => Collector::Geth(
+                geth::Builder {
+                    chain_name,
+                    url,
+                    executor_handle,
+                    shutdown_token,
+                    metrics,
+                    fee_asset,
+                }
+                .build(),
+            ),
+            CollectorKind::Txpool => Collector::Txpool(
+                txpool::Builder {
+                    chain_name,
+                    url,
+                    poll_interval: txpool_poll_interval,
+                    executor_handle,
+                    shutdown_token,
+                    metrics,
+                    fee_asset,
+                }
+                .build(),
+            ),
+            CollectorKind::Websocket => Collector::Websocket(
+                websocket::Builder {
+                    chain_name,
+                    url,
+                    executor_handle,
+                    shutdown_token,
+                    metrics,
+                    fee_asset,
+                }
+                .build(),
+            ),
+            CollectorKind::Http => Collector::Http(
+                http::Builder {
+                    chain_name,
+                    listen_addr: url,
+                    executor_handle,
+                    shutdown_token,
+                    metrics,
+                    fee_asset,
+                }
+                .build()?,
+            ),
+        };
+        Ok(collector)
+    }
+}
+
+/// Forwards `seq_action` to the executor, dropping it if the executor does not accept it within
+/// [`EXECUTOR_SEND_TIMEOUT`] or if its rollup has used up its quota.
+///
+/// # Errors
+/// Returns an error if the executor channel is closed.
+#[instrument(skip_all)]
+async fn forward_tx(
+    executor_handle: &executor::Handle,
+    seq_action: SequenceAction,
+    tx_id: impl Display,
+    txs_dropped_counter: &Counter,
+) -> eyre::Result<()> {
+    match executor_handle
+        .send_timeout(seq_action, EXECUTOR_SEND_TIMEOUT)
+        .await
+    {
+        Ok(()) => Ok(()),
+        Err(SendError::Timeout) => {
+            warn!(
+                transaction.id = %tx_id,
+                timeout_ms = EXECUTOR_SEND_TIMEOUT.as_millis(),
+                "timed out sending new transaction to executor; dropping tx",
+            );
+            txs_dropped_counter.increment(1);
+            Ok(())
+        }
+        Err(
+            err @ SendError::RollupQuotaFull {
+                ..
+            },
+        ) => {
+            warn!(
+                transaction.id = %tx_id,
+                error = &err as &dyn std::error::Error,
+                "executor rejected new transaction; dropping tx",
+            );
+            txs_dropped_counter.increment(1);
+            Ok(())
+        }
+        Err(SendError::Closed) => {
+            warn!(
+                transaction.id = %tx_id,
+                "executor channel closed while sending transaction; dropping transaction \
+                    and exiting event loop"
+            );
+            txs_dropped_counter.increment(1);
+            Err(eyre!("executor channel closed while sending transaction"))
+        }
+    }
+}
+
+#[instrument(skip_all)]
+fn txs_received_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
+    metrics
+        .collector_txs_received(chain_name)
+        .cloned()
+        .unwrap_or_else(|| {
+            error!(
+                rollup_chain_name = %chain_name,
+                "failed to get collector transactions_received counter"
+            );
+            Counter::noop()
+        })
+}
+
+#[instrument(skip_all)]
+fn txs_dropped_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
+    metrics
+        .collector_txs_dropped(chain_name)
+        .cloned()
+        .unwrap_or_else(|| {
+            error!(
+                rollup_chain_name = %chain_name,
+                "failed to get collector transactions_dropped counter"
+            );
+            Counter::noop()
+        })
+}
@@ -0,0 +1,328 @@
+//! `Txpool` polls the mempool of an EVM rollup node for pending transactions.
+//!
+//! [`Txpool`] periodically calls the [`txpool_content`](https://geth.ethereum.org/docs/interacting-with-geth/rpc/ns-txpool#txpool-content)
+//! JSON-RPC method of a rollup node over HTTP and forwards every pending transaction that it
+//! has not seen in a previous poll to the executor.
+//!
+//! ## Note
+//! This collector is meant for EVM nodes that do not offer the full transaction
+//! `newPendingTransactions` subscription used by the [`Geth`](super::Geth) collector, but do
+//! implement the `txpool` namespace (e.g. reth and most go-ethereum forks).
+//! ``` json
+//! { "id": 1, "jsonrpc": "2.0", "method": "txpool_content", "params": [] }
+//! ```
+
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        RollupId,
+    },
+    protocol::transaction::v1alpha1::action::SequenceAction,
+};
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use ethers::{
+    providers::{
+        Http,
+        Middleware as _,
+        Provider,
+        ProviderError,
+    },
+    types::{
+        Transaction,
+        TxpoolContent,
+        H256,
+    },
+};
+use tokio::{
+    select,
+    sync::watch,
+    time::MissedTickBehavior,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use crate::{
+    collectors::{
+        forward_tx,
+        txs_dropped_counter,
+        txs_received_counter,
+        Status,
+    },
+    executor,
+    metrics::Metrics,
+    utils::report_exit_reason,
+};
+
+type StdError = dyn std::error::Error;
+
+/// The number of polls that may fail in a row before the collector gives up and exits.
+const MAX_CONSECUTIVE_POLL_FAILURES: u32 = 10;
+
+/// `Txpool` polls the `txpool_content` of a rollup node and passes newly seen pending
+/// transactions downstream for further processing.
+pub(crate) struct Txpool {
+    // Chain ID to identify in the astria sequencer block which rollup a serialized sequencer
+    // action belongs to. Created from `chain_name`.
+    rollup_id: RollupId,
+    // Name of the chain the transactions are read from.
+    chain_name: String,
+    // The channel on which the collector sends new txs to the executor.
+    executor_handle: executor::Handle,
+    // The status of this collector instance.
+    status: watch::Sender<Status>,
+    // Rollup URL
+    url: String,
+    // The interval at which the rollup's txpool is polled.
+    poll_interval: Duration,
+    // Token to signal the txpool collector to stop upon shutdown.
+    shutdown_token: CancellationToken,
+    metrics: &'static Metrics,
+    fee_asset: asset::Denom,
+}
+
+pub(crate) struct Builder {
+    pub(crate) chain_name: String,
+    pub(crate) url: String,
+    pub(crate) poll_interval: Duration,
+    pub(crate) executor_handle: executor::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) metrics: &'static Metrics,
+    pub(crate) fee_asset: asset::Denom,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> Txpool {
+        let Self {
+            chain_name,
+            url,
+            poll_interval,
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+        let (status, _) = watch::channel(Status::new());
+        let rollup_id = RollupId::from_unhashed_bytes(&chain_name);
+        info!(
+            rollup_name = %chain_name,
+            rollup_id = %rollup_id,
+            "created new txpool collector for rollup",
+        );
+        Txpool {
+            rollup_id,
+            chain_name,
+            executor_handle,
+            status,
+            url,
+            poll_interval,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        }
+    }
+}
+
+impl Txpool {
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        self.status.subscribe()
+    }
+
+    /// Starts the collector instance and runs until failure or until
+    /// explicitly closed
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        let Self {
+            rollup_id,
+            chain_name,
+            executor_handle,
+            status,
+            url,
+            poll_interval,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+
+        let txs_received_counter = txs_received_counter(metrics, &chain_name);
+        let txs_dropped_counter = txs_dropped_counter(metrics, &chain_name);
+
+        let client = Provider::<Http>::try_from(url.as_str())
+            .wrap_err("failed to construct http client for rollup node")?;
+
+        let mut content = fetch_initial_txpool_content(&client)
+            .await
+            .wrap_err("failed fetching the initial txpool content from the rollup node")?;
+
+        status.send_modify(|status| status.is_connected = true);
+
+        let mut seen_txs = HashSet::new();
+        let mut consecutive_failures: u32 = 0;
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // the first tick completes immediately, but the initial content was just fetched
+        interval.tick().await;
+
+        let reason = 'poll: loop {
+            let pending = pending_txs(content);
+            let mut still_pending = HashSet::with_capacity(pending.len());
+            for tx in pending {
+                still_pending.insert(tx.hash);
+                if seen_txs.contains(&tx.hash) {
+                    continue;
+                }
+                let tx_hash = tx.hash;
+                let seq_action = SequenceAction {
+                    rollup_id,
+                    data: tx.rlp().to_vec().into(),
+                    fee_asset: fee_asset.clone(),
+                };
+
+                txs_received_counter.increment(1);
+
+                if let Err(err) =
+                    forward_tx(&executor_handle, seq_action, tx_hash, &txs_dropped_counter).await
+                {
+                    break 'poll Err(err);
+                }
+            }
+            // only remember transactions that are still pending so that the set of seen
+            // transactions does not grow without bound.
+            seen_txs = still_pending;
+
+            content = loop {
+                select! {
+                    biased;
+                    () = shutdown_token.cancelled() => {
+                        break 'poll Ok("shutdown signal received");
+                    },
+                    _ = interval.tick() => {
+                        match client.txpool_content().await {
+                            Ok(content) => {
+                                consecutive_failures = 0;
+                                break content;
+                            }
+                            Err(error) => {
+                                consecutive_failures = consecutive_failures.saturating_add(1);
+                                warn!(
+                                    consecutive_failures,
+                                    error = &error as &StdError,
+                                    "failed polling txpool content of rollup node",
+                                );
+                                if consecutive_failures >= MAX_CONSECUTIVE_POLL_FAILURES {
+                                    break 'poll Err(eyre!(
+                                        "polling the txpool content of the rollup node failed \
+                                         {consecutive_failures} times in a row"
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            };
+        };
+
+        report_exit_reason(reason.as_deref());
+
+        status.send_modify(|status| status.is_connected = false);
+
+        reason.map(|_| ())
+    }
+}
+
+/// Returns the pending transactions of `content` ordered by sender and nonce.
+fn pending_txs(content: TxpoolContent) -> Vec<Transaction> {
+    let mut txs: Vec<_> = content
+        .pending
+        .into_values()
+        .flat_map(|txs_by_nonce| txs_by_nonce.into_values())
+        .collect();
+    txs.sort_unstable_by_key(|tx| (tx.from, tx.nonce));
+    txs
+}
+
+#[instrument(skip_all, err)]
+async fn fetch_initial_txpool_content(client: &Provider<Http>) -> eyre::Result<TxpoolContent> {
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(500))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt, next_delay: Option<Duration>, error: &ProviderError| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    error = error as &StdError,
+                    "attempt to fetch txpool content from rollup node failed; retrying after \
+                     backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    tryhard::retry_fn(|| client.txpool_content())
+        .with_config(retry_config)
+        .await
+        .wrap_err("failed fetching txpool content after several retries; giving up")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ethers::types::{
+        Address,
+        U256,
+    };
+
+    use super::*;
+
+    fn tx(from: u8, nonce: u64) -> Transaction {
+        Transaction {
+            hash: H256::from_low_u64_be(u64::from(from) << 32 | nonce),
+            from: Address::repeat_byte(from),
+            nonce: U256::from(nonce),
+            ..Transaction::default()
+        }
+    }
+
+    #[test]
+    fn pending_txs_are_ordered_by_sender_and_numeric_nonce() {
+        let mut pending = BTreeMap::new();
+        pending.insert(
+            Address::repeat_byte(2),
+            BTreeMap::from([("10".to_string(), tx(2, 10)), ("9".to_string(), tx(2, 9))]),
+        );
+        pending.insert(
+            Address::repeat_byte(1),
+            BTreeMap::from([("0".to_string(), tx(1, 0))]),
+        );
+        let content = TxpoolContent {
+            pending,
+            queued: BTreeMap::from([(
+                Address::repeat_byte(3),
+                BTreeMap::from([("5".to_string(), tx(3, 5))]),
+            )]),
+        };
+        let hashes: Vec<_> = pending_txs(content).into_iter().map(|tx| tx.hash).collect();
+        assert_eq!(
+            vec![tx(1, 0).hash, tx(2, 9).hash, tx(2, 10).hash],
+            hashes,
+            "queued transactions must be excluded and pending ones ordered by sender and nonce"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! `Websocket` receives raw rollup transactions from a generic websocket endpoint.
+//!
+//! [`Websocket`] connects to a websocket endpoint and treats every message it receives as a
+//! single raw rollup transaction, which is forwarded to the executor as-is. Binary messages are
+//! used verbatim, while text messages are expected to contain the hex encoding of the
+//! transaction bytes, optionally prefixed with `0x`.
+//!
+//! ## Note
+//! This collector makes no assumptions about the rollup's execution environment and can be
+//! used to feed any rollup's transactions to composer without implementing the gRPC collector
+//! service.
+
+use std::time::Duration;
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        RollupId,
+    },
+    protocol::transaction::v1alpha1::action::SequenceAction,
+};
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use futures::StreamExt as _;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::{
+    net::TcpStream,
+    select,
+    sync::watch,
+};
+use tokio_tungstenite::{
+    tungstenite::{
+        self,
+        Message,
+    },
+    MaybeTlsStream,
+    WebSocketStream,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use crate::{
+    collectors::{
+        forward_tx,
+        txs_dropped_counter,
+        txs_received_counter,
+        Status,
+    },
+    executor,
+    metrics::Metrics,
+    utils::report_exit_reason,
+};
+
+type StdError = dyn std::error::Error;
+
+/// `Websocket` reads raw transactions from a websocket endpoint and passes them downstream for
+/// further processing.
+pub(crate) struct Websocket {
+    // Chain ID to identify in the astria sequencer block which rollup a serialized sequencer
+    // action belongs to. Created from `chain_name`.
+    rollup_id: RollupId,
+    // Name of the chain the transactions are read from.
+    chain_name: String,
+    // The channel on which the collector sends new txs to the executor.
+    executor_handle: executor::Handle,
+    // The status of this collector instance.
+    status: watch::Sender<Status>,
+    // The URL of the websocket endpoint emitting rollup transactions.
+    url: String,
+    // Token to signal the websocket collector to stop upon shutdown.
+    shutdown_token: CancellationToken,
+    metrics: &'static Metrics,
+    fee_asset: asset::Denom,
+}
+
+pub(crate) struct Builder {
+    pub(crate) chain_name: String,
+    pub(crate) url: String,
+    pub(crate) executor_handle: executor::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) metrics: &'static Metrics,
+    pub(crate) fee_asset: asset::Denom,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> Websocket {
+        let Self {
+            chain_name,
+            url,
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+        let (status, _) = watch::channel(Status::new());
+        let rollup_id = RollupId::from_unhashed_bytes(&chain_name);
+        info!(
+            rollup_name = %chain_name,
+            rollup_id = %rollup_id,
+            "created new websocket collector for rollup",
+        );
+        Websocket {
+            rollup_id,
+            chain_name,
+            executor_handle,
+            status,
+            url,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        }
+    }
+}
+
+impl Websocket {
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        self.status.subscribe()
+    }
+
+    /// Starts the collector instance and runs until failure or until
+    /// explicitly closed
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        let Self {
+            rollup_id,
+            chain_name,
+            executor_handle,
+            status,
+            url,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+
+        let txs_received_counter = txs_received_counter(metrics, &chain_name);
+        let txs_dropped_counter = txs_dropped_counter(metrics, &chain_name);
+
+        let mut stream = connect_to_websocket(url)
+            .await
+            .wrap_err("failed to connect to websocket endpoint")?;
+
+        status.send_modify(|status| status.is_connected = true);
+
+        let reason = loop {
+            select! {
+                biased;
+                () = shutdown_token.cancelled() => {
+                    break Ok("shutdown signal received");
+                },
+                msg = stream.next() => {
+                    let data = match msg {
+                        Some(Ok(Message::Binary(data))) => data,
+                        Some(Ok(Message::Text(text))) => match decode_hex_tx(&text) {
+                            Ok(data) => data,
+                            Err(error) => {
+                                warn!(
+                                    error = &error as &StdError,
+                                    "received text message that is not a hex encoded \
+                                     transaction; dropping it",
+                                );
+                                txs_dropped_counter.increment(1);
+                                continue;
+                            }
+                        },
+                        Some(Ok(Message::Close(frame))) => {
+                            break Err(eyre!("websocket endpoint closed the connection: {frame:?}"));
+                        }
+                        // pings are answered by tungstenite while reading the stream
+                        Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => {
+                            continue;
+                        }
+                        Some(Err(error)) => {
+                            break Err(error).wrap_err("failed reading from websocket stream");
+                        }
+                        None => break Err(eyre!("websocket stream ended")),
+                    };
+
+                    let tx_id = hex::encode(Sha256::digest(&data));
+                    let seq_action = SequenceAction {
+                        rollup_id,
+                        data: data.into(),
+                        fee_asset: fee_asset.clone(),
+                    };
+
+                    txs_received_counter.increment(1);
+
+                    if let Err(err) = forward_tx(
+                        &executor_handle,
+                        seq_action,
+                        tx_id,
+                        &txs_dropped_counter,
+                    ).await {
+                        break Err(err);
+                    }
+                }
+            }
+        };
+
+        report_exit_reason(reason.as_deref());
+
+        status.send_modify(|status| status.is_connected = false);
+
+        if let Err(error) = stream.close(None).await {
+            warn!(
+                error = &error as &StdError,
+                "failed closing the websocket connection"
+            );
+        }
+
+        reason.map(|_| ())
+    }
+}
+
+fn decode_hex_tx(text: &str) -> Result<Vec<u8>, hex::FromHexError> {
+    let text = text.trim();
+    hex::decode(text.strip_prefix("0x").unwrap_or(text))
+}
+
+#[instrument(skip_all, err)]
+async fn connect_to_websocket(
+    url: String,
+) -> eyre::Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(500))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt, next_delay: Option<Duration>, error: &tungstenite::Error| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    error = error as &StdError,
+                    "attempt to connect to websocket endpoint failed; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let (stream, _response) = tryhard::retry_fn(|| tokio_tungstenite::connect_async(url.as_str()))
+        .with_config(retry_config)
+        .await
+        .wrap_err("failed connecting to websocket endpoint after several retries; giving up")?;
+
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_hex_tx;
+
+    #[test]
+    fn hex_tx_with_and_without_prefix_is_decoded() {
+        assert_eq!(vec![0xde, 0xad], decode_hex_tx("0xdead").unwrap());
+        assert_eq!(vec![0xbe, 0xef], decode_hex_tx("beef\n").unwrap());
+        decode_hex_tx("0xnothex").unwrap_err();
+    }
+}
@@ -1,12 +1,17 @@
 use std::{
     collections::HashMap,
     net::SocketAddr,
+    sync::Arc,
     time::Duration,
 };
 
-use astria_core::primitive::v1::asset;
+use astria_core::primitive::v1::{
+    asset,
+    RollupId,
+};
 use astria_eyre::eyre::{
     self,
+    eyre,
     WrapErr as _,
 };
 use itertools::Itertools as _;
@@ -16,7 +21,10 @@ use tokio::{
         signal,
         SignalKind,
     },
-    sync::watch,
+    sync::{
+        mpsc,
+        watch,
+    },
     task::{
         JoinError,
         JoinHandle,
@@ -40,23 +48,38 @@ use crate::{
         ApiServer,
     },
     collectors,
-    collectors::geth,
     composer,
     executor,
-    executor::Executor,
+    executor::{
+        filter::{
+            EvmSenderFilter,
+            MaxTxSize,
+            PreFilter,
+            SenderLists,
+        },
+        Executor,
+        TxFilter,
+    },
     grpc,
     grpc::GrpcServer,
     metrics::Metrics,
+    reload::{
+        ReloadHandle,
+        RollupsReload,
+    },
+    rollup::CollectorEndpoint,
     Config,
 };
 
 const API_SERVER_SHUTDOWN_DURATION: Duration = Duration::from_secs(2);
 const GRPC_SERVER_SHUTDOWN_DURATION: Duration = Duration::from_secs(5);
 const EXECUTOR_SHUTDOWN_DURATION: Duration = Duration::from_secs(17);
-const GETH_COLLECTOR_SHUTDOWN_DURATION: Duration = Duration::from_secs(5);
+const COLLECTOR_SHUTDOWN_DURATION: Duration = Duration::from_secs(5);
+const COLLECTOR_REBUILD_MIN_DELAY: Duration = Duration::from_secs(1);
+const COLLECTOR_REBUILD_MAX_DELAY: Duration = Duration::from_secs(60);
 
-/// `Composer` is a service responsible for spinning up `GethCollectors` which are responsible
-/// for fetching pending transactions submitted to the rollup Geth nodes and then passing them
+/// `Composer` is a service responsible for spinning up collectors which are responsible
+/// for fetching pending transactions submitted to the rollup nodes and then passing them
 /// downstream for the executor to process. Thus, a composer can have multiple collectors running
 /// at the same time funneling data from multiple rollup nodes.
 pub struct Composer {
@@ -71,15 +94,17 @@ pub struct Composer {
     /// responsible for signing and submitting sequencer transactions.
     /// The sequencer transactions are received from various collectors.
     executor: Executor,
-    /// The collection of geth collectors and their rollup names.
-    geth_collectors: HashMap<String, collectors::Geth>,
-    /// The collection of the status of each geth collector.
-    geth_collector_statuses: HashMap<String, watch::Receiver<collectors::geth::Status>>,
-    /// The set of tasks tracking if the geth collectors are still running and to receive
-    /// the final result of each geth collector.
-    geth_collector_tasks: JoinMap<String, eyre::Result<()>>,
-    /// The map of chain ID to the URLs to which geth collectors should connect.
-    rollups: HashMap<String, String>,
+    /// The collection of rollup collectors and their rollup names.
+    collectors: HashMap<String, collectors::Collector>,
+    /// The collection of the status of each rollup collector.
+    collector_statuses: HashMap<String, watch::Receiver<collectors::Status>>,
+    /// The set of tasks tracking if the rollup collectors are still running and to receive
+    /// the final result of each rollup collector.
+    collector_tasks: JoinMap<String, eyre::Result<()>>,
+    /// The map of chain ID to the collectors and endpoints used for each rollup.
+    rollups: HashMap<String, CollectorEndpoint>,
+    /// The interval at which `txpool` collectors poll their rollup node.
+    txpool_poll_interval: Duration,
     /// The gRPC server that listens for incoming requests from the collectors via the
     /// `GrpcCollector` service. It also exposes a health service.
     grpc_server: GrpcServer,
@@ -88,6 +113,10 @@ pub struct Composer {
     metrics: &'static Metrics,
     /// The asset set in config to pay for transactions and sequence actions.
     fee_asset: asset::Denom,
+    /// Used to change the log level and the rollups while composer is running.
+    reload_handle: ReloadHandle,
+    /// The rollups to run collectors for, as reloaded through `reload_handle`.
+    rollups_reloads: mpsc::Receiver<RollupsReload>,
 }
 
 /// Announces the current status of the Composer for other modules in the crate to use
@@ -123,14 +152,31 @@ impl Composer {
         let (composer_status_sender, _) = watch::channel(Status::default());
         let shutdown_token = CancellationToken::new();
 
+        let rollup_weights = cfg
+            .parse_rollup_weights()
+            .wrap_err("failed parsing rollup weights")?
+            .into_iter()
+            .map(|(name, weight)| (RollupId::from_unhashed_bytes(name.as_bytes()), weight))
+            .collect();
+        let rollup_bundle_quotas = cfg
+            .parse_rollup_bundle_quotas()
+            .wrap_err("failed parsing rollup bundle quotas")?
+            .into_iter()
+            .map(|(name, quota)| (RollupId::from_unhashed_bytes(name.as_bytes()), quota))
+            .collect();
+
+        let tx_filter = tx_filter_from_config(cfg).wrap_err("failed constructing tx filter")?;
+
         let (executor, executor_handle) = executor::Builder {
             sequencer_url: cfg.sequencer_url.clone(),
             sequencer_chain_id: cfg.sequencer_chain_id.clone(),
-            private_key_file: cfg.private_key_file.clone(),
+            private_key_files: cfg.private_key_files(),
             sequencer_address_prefix: cfg.sequencer_address_prefix.clone(),
             block_time_ms: cfg.block_time_ms,
             max_bytes_per_bundle: cfg.max_bytes_per_bundle,
             bundle_queue_capacity: cfg.bundle_queue_capacity,
+            fairness: executor::Fairness::new(rollup_weights, rollup_bundle_quotas),
+            tx_filter,
             shutdown_token: shutdown_token.clone(),
             metrics,
         }
@@ -153,34 +199,43 @@ impl Composer {
             "gRPC server listening"
         );
 
-        let api_server = api::start(cfg.api_listen_addr, composer_status_sender.subscribe());
+        let (reload_handle, rollups_reloads) =
+            ReloadHandle::new(cfg).wrap_err("failed parsing reloadable config")?;
+
+        let api_server = api::start(
+            cfg.api_listen_addr,
+            composer_status_sender.subscribe(),
+            reload_handle.clone(),
+        );
 
         info!(
             listen_addr = %api_server.local_addr(),
             "API server listening"
         );
 
-        let rollups = cfg.parse_rollups()?;
-        let geth_collectors = rollups
+        let rollups = reload_handle.subscribe().borrow().rollups.clone();
+        let txpool_poll_interval = Duration::from_millis(cfg.txpool_poll_interval_ms);
+        let collectors = rollups
             .iter()
-            .map(|(rollup_name, url)| {
-                let collector = geth::Builder {
+            .map(|(rollup_name, endpoint)| {
+                let collector = collectors::Builder {
                     chain_name: rollup_name.clone(),
-                    url: url.clone(),
+                    endpoint: endpoint.clone(),
+                    txpool_poll_interval,
                     executor_handle: executor_handle.clone(),
                     shutdown_token: shutdown_token.clone(),
                     metrics,
                     fee_asset: cfg.fee_asset.clone(),
                 }
-                .build();
-                (rollup_name.clone(), collector)
+                .build()
+                .wrap_err_with(|| format!("failed constructing collector for `{rollup_name}`"))?;
+                Ok((rollup_name.clone(), collector))
             })
-            .collect::<HashMap<_, _>>();
-        let geth_collector_statuses: HashMap<String, watch::Receiver<geth::Status>> =
-            geth_collectors
-                .iter()
-                .map(|(rollup_name, collector)| (rollup_name.clone(), collector.subscribe()))
-                .collect();
+            .collect::<eyre::Result<HashMap<_, _>>>()?;
+        let collector_statuses: HashMap<String, watch::Receiver<collectors::Status>> = collectors
+            .iter()
+            .map(|(rollup_name, collector)| (rollup_name.clone(), collector.subscribe()))
+            .collect();
 
         Ok(Self {
             api_server,
@@ -188,16 +243,25 @@ impl Composer {
             executor_handle,
             executor,
             rollups,
-            geth_collectors,
-            geth_collector_statuses,
-            geth_collector_tasks: JoinMap::new(),
+            txpool_poll_interval,
+            collectors,
+            collector_statuses,
+            collector_tasks: JoinMap::new(),
             grpc_server,
             shutdown_token,
             metrics,
             fee_asset: cfg.fee_asset.clone(),
+            reload_handle,
+            rollups_reloads,
         })
     }
 
+    /// Returns a handle to change the log level and the rollups of the running composer.
+    #[must_use]
+    pub fn reload_handle(&self) -> ReloadHandle {
+        self.reload_handle.clone()
+    }
+
     /// Returns the socket address the api server is served over
     pub fn local_addr(&self) -> SocketAddr {
         self.api_server.local_addr()
@@ -213,7 +277,7 @@ impl Composer {
     /// Runs the composer.
     ///
     /// # Errors
-    /// It errors out if the API Server, Executor or any of the Geth Collectors fail to start.
+    /// It errors out if the API Server, Executor or any of the rollup collectors fail to start.
     ///
     /// # Panics
     /// It panics if the Composer cannot set the SIGTERM listener.
@@ -226,16 +290,34 @@ impl Composer {
             mut composer_status_sender,
             executor,
             executor_handle,
-            mut geth_collector_tasks,
-            mut geth_collectors,
-            rollups,
-            mut geth_collector_statuses,
+            mut collector_tasks,
+            mut collectors,
+            mut rollups,
+            txpool_poll_interval,
+            mut collector_statuses,
             grpc_server,
             shutdown_token,
             metrics,
             fee_asset,
+            reload_handle,
+            mut rollups_reloads,
         } = self;
 
+        let mut collector_rebuild_attempts: HashMap<String, u32> = HashMap::new();
+        let collector_shutdown_token = shutdown_token.clone();
+        let build_collector = move |rollup: &String, endpoint: &CollectorEndpoint| {
+            collectors::Builder {
+                chain_name: rollup.clone(),
+                endpoint: endpoint.clone(),
+                txpool_poll_interval,
+                executor_handle: executor_handle.clone(),
+                shutdown_token: collector_shutdown_token.clone(),
+                metrics,
+                fee_asset: fee_asset.clone(),
+            }
+            .build()
+        };
+
         // we need the API server to shutdown at the end, since it is used by k8s
         // to report the liveness of the service
         let api_server_shutdown_token = CancellationToken::new();
@@ -250,15 +332,15 @@ impl Composer {
         });
 
         // run the collectors and executor
-        spawn_geth_collectors(&mut geth_collectors, &mut geth_collector_tasks);
+        spawn_collectors(&mut collectors, &mut collector_tasks);
 
         let executor_status = executor.subscribe().clone();
         let mut executor_task = tokio::spawn(executor.run_until_stopped());
 
         // wait for collectors and executor to come online
-        wait_for_collectors(&geth_collector_statuses, &mut composer_status_sender)
+        wait_for_collectors(&collector_statuses, &mut composer_status_sender)
             .await
-            .wrap_err("geth collectors failed to become ready")?;
+            .wrap_err("rollup collectors failed to become ready")?;
         wait_for_executor(executor_status, &mut composer_status_sender)
             .await
             .wrap_err("executor failed to become ready")?;
@@ -286,7 +368,7 @@ impl Composer {
                         api_server_task_handle: Some(api_task),
                         executor_task_handle: Some(executor_task),
                         grpc_server_task_handle: Some(grpc_server_handle),
-                        geth_collector_tasks,
+                        collector_tasks,
                     };
             },
             o = &mut api_task => {
@@ -297,7 +379,7 @@ impl Composer {
                         api_server_task_handle: None,
                         executor_task_handle: Some(executor_task),
                         grpc_server_task_handle: Some(grpc_server_handle),
-                        geth_collector_tasks,
+                        collector_tasks,
                     };
             },
             o = &mut executor_task => {
@@ -308,7 +390,7 @@ impl Composer {
                         api_server_task_handle: Some(api_task),
                         executor_task_handle: None,
                         grpc_server_task_handle: Some(grpc_server_handle),
-                        geth_collector_tasks,
+                        collector_tasks,
                     };
             },
             o = &mut grpc_server_handle => {
@@ -319,28 +401,89 @@ impl Composer {
                         api_server_task_handle: Some(api_task),
                         executor_task_handle: Some(executor_task),
                         grpc_server_task_handle: None,
-                        geth_collector_tasks,
+                        collector_tasks,
                     };
             },
-            Some((rollup, collector_exit)) = geth_collector_tasks.join_next() => {
-                report_exit("collector", collector_exit);
-                if let Some(url) = rollups.get(&rollup) {
-                    let collector = geth::Builder {
-                        chain_name: rollup.clone(),
-                        url: url.clone(),
-                        executor_handle: executor_handle.clone(),
-                        shutdown_token: shutdown_token.clone(),
-                        metrics,
-                        fee_asset: fee_asset.clone(),
+            Some(RollupsReload { rollups: reloaded, result }) = rollups_reloads.recv() => {
+                rollups.retain(|rollup, _| {
+                    let keep = reloaded.contains_key(rollup);
+                    if !keep {
+                        info!(%rollup, "rollup removed from config; stopping its collector");
+                        collector_tasks.abort(rollup);
+                        collector_statuses.remove(rollup);
+                    }
+                    keep
+                });
+                let mut failed = Vec::new();
+                for (rollup, endpoint) in reloaded {
+                    if rollups.get(&rollup) == Some(&endpoint) {
+                        continue;
                     }
-                    .build();
-                    geth_collector_statuses.insert(rollup.clone(), collector.subscribe());
-                    geth_collector_tasks.spawn(rollup, collector.run_until_stopped());
+                    match build_collector(&rollup, &endpoint) {
+                        Ok(collector) => {
+                            info!(
+                                %rollup,
+                                collector = %endpoint.kind,
+                                "starting collector of reloaded rollup",
+                            );
+                            collector_statuses.insert(rollup.clone(), collector.subscribe());
+                            // replaces and cancels the collector of the rollup, if any
+                            collector_tasks.spawn(rollup.clone(), collector.run_until_stopped());
+                            rollups.insert(rollup, endpoint);
+                        }
+                        Err(error) => {
+                            // keep the previous collector of the rollup, if any
+                            error!(
+                                %rollup,
+                                %error,
+                                "failed building collector of reloaded rollup",
+                            );
+                            failed.push(rollup);
+                        }
+                    }
+                }
+                reload_handle.set_active_rollups(rollups.clone());
+                let _ = result.send(if failed.is_empty() {
+                    Ok(())
                 } else {
-                    error!(
-                        "rollup should have had an entry in the rollup->url map but doesn't; not reconnecting \
-                         it"
-                    );
+                    Err(eyre!(
+                        "failed building collectors of reloaded rollups `{}`",
+                        failed.iter().join("`, `")
+                    ))
+                });
+            },
+            Some((rollup, collector_exit)) = collector_tasks.join_next() => {
+                let Some(endpoint) = rollups.get(&rollup) else {
+                    info!(%rollup, "collector of rollup removed from config stopped");
+                    collector_rebuild_attempts.remove(&rollup);
+                    continue;
+                };
+                report_exit("collector", collector_exit);
+                match build_collector(&rollup, endpoint) {
+                    Ok(collector) => {
+                        collector_rebuild_attempts.remove(&rollup);
+                        collector_statuses.insert(rollup.clone(), collector.subscribe());
+                        collector_tasks.spawn(rollup, collector.run_until_stopped());
+                    }
+                    Err(error) => {
+                        let attempts =
+                            collector_rebuild_attempts.entry(rollup.clone()).or_default();
+                        *attempts = attempts.saturating_add(1);
+                        let delay = collector_rebuild_delay(*attempts);
+                        error!(
+                            %rollup,
+                            %error,
+                            attempts = *attempts,
+                            delay = %humantime::format_duration(delay),
+                            "failed rebuilding collector; retrying after delay",
+                        );
+                        // the delayed task takes the place of the collector so that rebuilding it
+                        // is retried once the task exits; reloading the rollup replaces it.
+                        collector_tasks.spawn(rollup, async move {
+                            tokio::time::sleep(delay).await;
+                            Err(error.wrap_err("collector was not rebuilt"))
+                        });
+                    }
                 }
             });
         };
@@ -355,7 +498,7 @@ struct ShutdownInfo {
     api_server_task_handle: Option<JoinHandle<eyre::Result<()>>>,
     executor_task_handle: Option<JoinHandle<eyre::Result<()>>>,
     grpc_server_task_handle: Option<JoinHandle<eyre::Result<()>>>,
-    geth_collector_tasks: JoinMap<String, eyre::Result<()>>,
+    collector_tasks: JoinMap<String, eyre::Result<()>>,
 }
 
 impl ShutdownInfo {
@@ -366,7 +509,7 @@ impl ShutdownInfo {
             api_server_task_handle,
             executor_task_handle,
             grpc_server_task_handle,
-            mut geth_collector_tasks,
+            mut collector_tasks,
         } = self;
 
         // if the composer is shutting down because of an unexpected shutdown from any one of the
@@ -405,7 +548,7 @@ impl ShutdownInfo {
         };
 
         let shutdown_loop = async {
-            while let Some((name, res)) = geth_collector_tasks.join_next().await {
+            while let Some((name, res)) = collector_tasks.join_next().await {
                 let message = "task shut down";
                 match flatten_result(res) {
                     Ok(()) => info!(name, message),
@@ -414,21 +557,21 @@ impl ShutdownInfo {
             }
         };
 
-        // we give 5s to shut down all the other geth collectors. geth collectors shouldn't take
-        // too long to shutdown since they just need to unsubscribe to their WSS
-        // streams.
-        if timeout(GETH_COLLECTOR_SHUTDOWN_DURATION, shutdown_loop)
+        // we give 5s to shut down all the other collectors. collectors shouldn't take
+        // too long to shutdown since they just need to unsubscribe from their streams or stop
+        // their servers.
+        if timeout(COLLECTOR_SHUTDOWN_DURATION, shutdown_loop)
             .await
             .is_err()
         {
-            let tasks = geth_collector_tasks.keys().join(", ");
+            let tasks = collector_tasks.keys().join(", ");
             warn!(
                 tasks = format_args!("[{tasks}]"),
-                "aborting all geth collector tasks that have not yet shut down",
+                "aborting all collector tasks that have not yet shut down",
             );
-            geth_collector_tasks.abort_all();
+            collector_tasks.abort_all();
         } else {
-            info!("all geth collector tasks shut down regularly");
+            info!("all collector tasks shut down regularly");
         }
 
         // cancel the api server at the end
@@ -452,12 +595,48 @@ impl ShutdownInfo {
     }
 }
 
-fn spawn_geth_collectors(
-    geth_collectors: &mut HashMap<String, collectors::Geth>,
-    geth_collector_tasks: &mut JoinMap<String, eyre::Result<()>>,
+/// Constructs the transaction filter of the executor from the deduplication and pre-filter
+/// settings of `cfg`.
+fn tx_filter_from_config(cfg: &Config) -> eyre::Result<TxFilter> {
+    let mut pre_filters: Vec<Arc<dyn PreFilter>> = vec![];
+    if cfg.max_tx_bytes > 0 {
+        pre_filters.push(Arc::new(MaxTxSize::new(cfg.max_tx_bytes)));
+    }
+
+    let mut sender_lists: HashMap<RollupId, SenderLists> = HashMap::new();
+    for (rollup_name, address) in cfg
+        .parse_sender_allow_list()
+        .wrap_err("failed parsing sender allow list")?
+    {
+        sender_lists
+            .entry(RollupId::from_unhashed_bytes(rollup_name.as_bytes()))
+            .or_default()
+            .allow
+            .insert(address);
+    }
+    for (rollup_name, address) in cfg
+        .parse_sender_deny_list()
+        .wrap_err("failed parsing sender deny list")?
+    {
+        sender_lists
+            .entry(RollupId::from_unhashed_bytes(rollup_name.as_bytes()))
+            .or_default()
+            .deny
+            .insert(address);
+    }
+    if !sender_lists.is_empty() {
+        pre_filters.push(Arc::new(EvmSenderFilter::new(sender_lists)));
+    }
+
+    Ok(TxFilter::new(cfg.dedup_cache_size, pre_filters))
+}
+
+fn spawn_collectors(
+    collectors: &mut HashMap<String, collectors::Collector>,
+    collector_tasks: &mut JoinMap<String, eyre::Result<()>>,
 ) {
-    for (chain_id, collector) in geth_collectors.drain() {
-        geth_collector_tasks.spawn(chain_id, collector.run_until_stopped());
+    for (chain_id, collector) in collectors.drain() {
+        collector_tasks.spawn(chain_id, collector.run_until_stopped());
     }
 }
 
@@ -481,7 +660,7 @@ async fn wait_for_executor(
 /// Waits for all collectors to come online.
 #[instrument(skip_all, err)]
 async fn wait_for_collectors(
-    collector_statuses: &HashMap<String, watch::Receiver<collectors::geth::Status>>,
+    collector_statuses: &HashMap<String, watch::Receiver<collectors::Status>>,
     composer_status_sender: &mut watch::Sender<composer::Status>,
 ) -> eyre::Result<()> {
     use futures::{
@@ -496,10 +675,7 @@ async fn wait_for_collectors(
         .map(|(chain_id, status)| {
             let mut status = status.clone();
             async move {
-                match status
-                    .wait_for(collectors::geth::Status::is_connected)
-                    .await
-                {
+                match status.wait_for(collectors::Status::is_connected).await {
                     // `wait_for` returns a reference to status; throw it
                     // away because this future cannot return a reference to
                     // a stack local object.
@@ -529,6 +705,15 @@ async fn wait_for_collectors(
     Ok(())
 }
 
+/// Returns the delay before rebuilding a collector is retried, doubling with every failed
+/// attempt up to [`COLLECTOR_REBUILD_MAX_DELAY`].
+fn collector_rebuild_delay(attempts: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+    COLLECTOR_REBUILD_MIN_DELAY
+        .saturating_mul(factor)
+        .min(COLLECTOR_REBUILD_MAX_DELAY)
+}
+
 fn report_exit(task_name: &str, outcome: Result<eyre::Result<()>, JoinError>) {
     match outcome {
         Ok(Ok(())) => info!(task = task_name, "task exited successfully"),
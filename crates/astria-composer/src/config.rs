@@ -1,15 +1,25 @@
 use std::{
-    collections::HashMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
     net::SocketAddr,
 };
 
+use config::MaybeEncoded;
+use ethers::types::Address as EvmAddress;
 use serde::{
     Deserialize,
     Serialize,
 };
 
 use crate::rollup::{
+    normalize_rollup_values,
+    parse_rollup_pairs,
+    parse_rollup_values,
+    CollectorEndpoint,
     ParseError,
+    ParseValueError,
     Rollup,
 };
 
@@ -30,12 +40,20 @@ pub struct Config {
     /// The chain ID of the sequencer chain
     pub sequencer_chain_id: String,
 
-    /// A list of `<rollup_name>::<url>` pairs
-    pub rollups: String,
+    /// A list of `<rollup_name>::[<collector>::]<url>` definitions, or a table of rollup names
+    /// to their `collector` and `url`
+    pub rollups: MaybeEncoded<BTreeMap<String, CollectorEndpoint>>,
+
+    /// The interval in milliseconds at which `txpool` collectors poll their rollup node
+    pub txpool_poll_interval_ms: u64,
 
     /// Path to private key for the sequencer account used for signing transactions
     pub private_key_file: String,
 
+    /// A comma separated list, or a list, of paths to further private keys. Every key, including
+    /// the one at `private_key_file`, signs over its own submission lane with its own nonce.
+    pub additional_private_key_files: MaybeEncoded<Vec<String>>,
+
     /// The address prefix to use when constructing sequencer addresses using the signing key.
     pub sequencer_address_prefix: String,
 
@@ -50,6 +68,36 @@ pub struct Config {
     /// Max amount of `SizedBundle`s to allow to accrue in the `BundleFactory`'s finished queue.
     pub bundle_queue_capacity: usize,
 
+    /// A list of `<rollup_name>::<weight>` pairs setting the relative share of bundle space of
+    /// each rollup, or a table of rollup names to weights. Rollups without an entry have a weight
+    /// of 1.
+    ///
+    /// A rollup may have up to `weight * max_bytes_per_bundle * (bundle_queue_capacity + 1)`
+    /// bytes of transactions waiting to be bundled. Transactions beyond that are rejected.
+    pub rollup_weights: MaybeEncoded<BTreeMap<String, u32>>,
+
+    /// A list of `<rollup_name>::<max_bytes>` pairs limiting the bytes of sequence actions each
+    /// rollup may take up in a single bundle, or a table of rollup names to max bytes. Rollups
+    /// without an entry are not limited.
+    pub rollup_bundle_quotas: MaybeEncoded<BTreeMap<String, usize>>,
+
+    /// The number of recently seen transaction hashes remembered per rollup to drop duplicate
+    /// transactions. Deduplication is disabled if 0.
+    pub dedup_cache_size: usize,
+
+    /// The max size in bytes of a single rollup transaction. Larger transactions are dropped
+    /// before bundling. Disabled if 0.
+    pub max_tx_bytes: usize,
+
+    /// A list of `<rollup_name>::<address>` pairs, or a table of rollup names to lists of
+    /// addresses. If a rollup has any entries, only EVM transactions sent from one of its listed
+    /// addresses are bundled.
+    pub sender_allow_list: MaybeEncoded<BTreeMap<String, Vec<EvmAddress>>>,
+
+    /// A list of `<rollup_name>::<address>` pairs, or a table of rollup names to lists of
+    /// addresses. EVM transactions sent from a listed address are dropped for the rollup.
+    pub sender_deny_list: MaybeEncoded<BTreeMap<String, Vec<EvmAddress>>>,
+
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
     pub force_stdout: bool,
 
@@ -73,17 +121,101 @@ pub struct Config {
 }
 
 impl Config {
-    /// Returns a map of rollup names to rollup URLs.
+    /// Returns the paths of all private key files, starting with `private_key_file`.
+    #[must_use]
+    pub fn private_key_files(&self) -> Vec<String> {
+        let additional: Vec<String> = match &self.additional_private_key_files {
+            MaybeEncoded::Encoded(files) => files
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+            MaybeEncoded::Structured(files) => files.clone(),
+        };
+        std::iter::once(self.private_key_file.clone())
+            .chain(additional)
+            .collect()
+    }
+
+    /// Returns a map of rollup names to the collectors feeding their transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_rollups(&self) -> Result<HashMap<String, CollectorEndpoint>, ParseError> {
+        match &self.rollups {
+            MaybeEncoded::Encoded(rollups) => rollups
+                .split(',')
+                .filter(|s| !s.is_empty())
+                .map(|s| Rollup::parse(s).map(Rollup::into_parts))
+                .collect::<Result<HashMap<_, _>, _>>(),
+            MaybeEncoded::Structured(rollups) => normalize_rollup_values(rollups)
+                .collect::<Result<HashMap<_, _>, _>>()
+                .map_err(|_| ParseError::new()),
+        }
+    }
+
+    /// Returns a map of rollup names to their weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_rollup_weights(&self) -> Result<HashMap<String, u32>, ParseValueError> {
+        match &self.rollup_weights {
+            MaybeEncoded::Encoded(weights) => parse_rollup_values(weights),
+            MaybeEncoded::Structured(weights) => normalize_rollup_values(weights).collect(),
+        }
+    }
+
+    /// Returns a map of rollup names to the max bytes they may take up in a single bundle.
     ///
     /// # Errors
     ///
     /// Returns an error if parsing fails.
-    pub fn parse_rollups(&self) -> Result<HashMap<String, String>, ParseError> {
-        self.rollups
-            .split(',')
-            .filter(|s| !s.is_empty())
-            .map(|s| Rollup::parse(s).map(Rollup::into_parts))
-            .collect::<Result<HashMap<_, _>, _>>()
+    pub fn parse_rollup_bundle_quotas(&self) -> Result<HashMap<String, usize>, ParseValueError> {
+        match &self.rollup_bundle_quotas {
+            MaybeEncoded::Encoded(quotas) => parse_rollup_values(quotas),
+            MaybeEncoded::Structured(quotas) => normalize_rollup_values(quotas).collect(),
+        }
+    }
+
+    /// Returns pairs of rollup names and the EVM addresses allowed to send their transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_sender_allow_list(&self) -> Result<Vec<(String, EvmAddress)>, ParseValueError> {
+        parse_sender_list(&self.sender_allow_list)
+    }
+
+    /// Returns pairs of rollup names and the EVM addresses whose transactions are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_sender_deny_list(&self) -> Result<Vec<(String, EvmAddress)>, ParseValueError> {
+        parse_sender_list(&self.sender_deny_list)
+    }
+}
+
+fn parse_sender_list(
+    list: &MaybeEncoded<BTreeMap<String, Vec<EvmAddress>>>,
+) -> Result<Vec<(String, EvmAddress)>, ParseValueError> {
+    match list {
+        MaybeEncoded::Encoded(list) => parse_rollup_pairs(list).collect(),
+        MaybeEncoded::Structured(list) => {
+            let mut pairs = Vec::new();
+            for rollup in normalize_rollup_values(list) {
+                let (rollup_name, addresses) = rollup?;
+                pairs.extend(
+                    addresses
+                        .into_iter()
+                        .map(|address| (rollup_name.clone(), address)),
+                );
+            }
+            Ok(pairs)
+        }
     }
 }
 
@@ -93,12 +225,62 @@ impl config::Config for Config {
 
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use std::io::Write as _;
+
+    use super::*;
+    use crate::rollup::CollectorKind;
 
     const EXAMPLE_ENV: &str = include_str!("../local.env.example");
+    const EXAMPLE_FILE: &str = include_str!("../config.example.toml");
 
     #[test]
     fn example_env_config_is_up_to_date() {
         config::tests::example_env_config_is_up_to_date::<Config>(EXAMPLE_ENV);
     }
+
+    #[test]
+    fn example_file_config_is_up_to_date() {
+        config::tests::example_file_config_is_up_to_date::<Config>(
+            "config.example.toml",
+            EXAMPLE_FILE,
+        );
+    }
+
+    #[test]
+    fn structured_values_match_encoded_values() {
+        let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        file.write_all(EXAMPLE_FILE.as_bytes()).unwrap();
+        let structured: Config = config::get_with_file(Some(file.path())).unwrap();
+        let mut encoded: Config = config::get_with_file(Some(file.path())).unwrap();
+        encoded.rollups = MaybeEncoded::Encoded(
+            "AstriaChain::ws://127.0.0.1:8545,otherchain::txpool::http://127.0.0.1:8546"
+                .to_string(),
+        );
+        encoded.rollup_weights = MaybeEncoded::Encoded("astriachain::3".to_string());
+        encoded.rollup_bundle_quotas = MaybeEncoded::Encoded("astriachain::100000".to_string());
+        encoded.sender_deny_list = MaybeEncoded::Encoded(
+            "otherchain::0x0000000000000000000000000000000000000001".to_string(),
+        );
+
+        let rollups = structured.parse_rollups().unwrap();
+        assert_eq!(rollups, encoded.parse_rollups().unwrap());
+        assert_eq!(rollups["otherchain"].kind, CollectorKind::Txpool);
+        assert_eq!(
+            structured.parse_rollup_weights().unwrap(),
+            encoded.parse_rollup_weights().unwrap()
+        );
+        assert_eq!(
+            structured.parse_rollup_bundle_quotas().unwrap(),
+            encoded.parse_rollup_bundle_quotas().unwrap()
+        );
+        assert_eq!(
+            structured.parse_sender_deny_list().unwrap(),
+            encoded.parse_sender_deny_list().unwrap()
+        );
+        assert!(structured.parse_sender_allow_list().unwrap().is_empty());
+        assert_eq!(
+            structured.private_key_files(),
+            vec!["/path/to/priv_sequencer_key.json".to_string()]
+        );
+    }
 }
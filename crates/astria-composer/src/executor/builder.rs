@@ -1,6 +1,7 @@
 use std::{
     fs,
     path::Path,
+    sync::Arc,
     time::Duration,
 };
 
@@ -11,26 +12,37 @@ use astria_core::{
 };
 use astria_eyre::eyre::{
     self,
+    ensure,
     eyre,
     WrapErr as _,
 };
+use itertools::Itertools as _;
 use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 
 use crate::{
     executor,
-    executor::Status,
+    executor::{
+        Fairness,
+        LaneKey,
+        RollupQuotas,
+        Status,
+        TxFilter,
+    },
     metrics::Metrics,
 };
 
 pub(crate) struct Builder {
     pub(crate) sequencer_url: String,
     pub(crate) sequencer_chain_id: String,
-    pub(crate) private_key_file: String,
+    /// The files of the private keys to sign with, one per submission lane.
+    pub(crate) private_key_files: Vec<String>,
     pub(crate) sequencer_address_prefix: String,
     pub(crate) block_time_ms: u64,
     pub(crate) max_bytes_per_bundle: usize,
     pub(crate) bundle_queue_capacity: usize,
+    pub(crate) fairness: Fairness,
+    pub(crate) tx_filter: TxFilter,
     pub(crate) shutdown_token: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -40,11 +52,13 @@ impl Builder {
         let Self {
             sequencer_url,
             sequencer_chain_id,
-            private_key_file,
+            private_key_files,
             sequencer_address_prefix,
             block_time_ms,
             max_bytes_per_bundle,
             bundle_queue_capacity,
+            fairness,
+            tx_filter,
             shutdown_token,
             metrics,
         } = self;
@@ -52,34 +66,58 @@ impl Builder {
             .wrap_err("failed constructing sequencer client")?;
         let (status, _) = watch::channel(Status::new());
 
-        let sequencer_key = read_signing_key_from_file(&private_key_file).wrap_err_with(|| {
-            format!("failed reading signing key from file at path `{private_key_file}`")
-        })?;
-
-        let sequencer_address = Address::builder()
-            .prefix(sequencer_address_prefix)
-            .array(sequencer_key.verification_key().address_bytes())
-            .try_build()
-            .wrap_err("failed constructing a sequencer address from private key")?;
+        ensure!(
+            !private_key_files.is_empty(),
+            "at least one private key file must be provided"
+        );
+        let lanes = private_key_files
+            .iter()
+            .map(|private_key_file| {
+                let signing_key =
+                    read_signing_key_from_file(private_key_file).wrap_err_with(|| {
+                        format!("failed reading signing key from file at path `{private_key_file}`")
+                    })?;
+                let address = Address::builder()
+                    .prefix(&sequencer_address_prefix)
+                    .array(signing_key.verification_key().address_bytes())
+                    .try_build()
+                    .wrap_err("failed constructing a sequencer address from private key")?;
+                Ok(LaneKey {
+                    signing_key,
+                    address,
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        ensure!(
+            lanes.iter().map(|lane| lane.address).unique().count() == lanes.len(),
+            "the private key files must contain distinct keys"
+        );
 
         let (serialized_rollup_transaction_tx, serialized_rollup_transaction_rx) =
             tokio::sync::mpsc::channel::<SequenceAction>(256);
+        let rollup_quotas = Arc::new(RollupQuotas::new(
+            max_bytes_per_bundle,
+            bundle_queue_capacity,
+            fairness.clone(),
+        ));
 
         Ok((
             super::Executor {
                 status,
                 serialized_rollup_transactions: serialized_rollup_transaction_rx,
+                rollup_quotas: rollup_quotas.clone(),
                 sequencer_client,
                 sequencer_chain_id,
-                sequencer_key,
-                address: sequencer_address,
+                lanes,
                 block_time: Duration::from_millis(block_time_ms),
                 max_bytes_per_bundle,
                 bundle_queue_capacity,
+                fairness,
+                tx_filter,
                 shutdown_token,
                 metrics,
             },
-            executor::Handle::new(serialized_rollup_transaction_tx),
+            executor::Handle::new(serialized_rollup_transaction_tx, rollup_quotas),
         ))
     }
 }
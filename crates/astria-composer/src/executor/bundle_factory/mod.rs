@@ -1,7 +1,16 @@
 //! This module is responsible for bundling sequence actions into bundles that can be
 //! submitted to the sequencer.
+//!
+//! Sequence actions are first put into a queue per rollup. The queues are drained into bundles
+//! using self-clocked weighted fair queuing, so that a rollup submitting a lot of data cannot
+//! starve other rollups of bundle space: each queued action is tagged with a virtual finish
+//! time that advances inversely proportional to its rollup's weight, and the action with the
+//! smallest tag is bundled next. Queuing several actions before scheduling them (see
+//! [`BundleFactory::try_queue`]) lets their rollups' weights decide the order in which they are
+//! bundled. Additionally, a rollup can be limited to a quota of bytes per bundle.
 use std::{
     collections::{
+        BTreeMap,
         HashMap,
         VecDeque,
     },
@@ -61,6 +70,9 @@ pub(super) struct SizedBundle {
     max_size: usize,
     /// Mapping of rollup id to the number of sequence actions for that rollup id in the bundle.
     rollup_counts: HashMap<RollupId, usize>,
+    /// Mapping of rollup id to the number of bytes of sequence actions for that rollup id in the
+    /// bundle.
+    rollup_sizes: HashMap<RollupId, usize>,
 }
 
 impl SizedBundle {
@@ -71,9 +83,21 @@ impl SizedBundle {
             curr_size: 0,
             max_size,
             rollup_counts: HashMap::new(),
+            rollup_sizes: HashMap::new(),
         }
     }
 
+    /// Returns if a sequence action of `seq_action_size` bytes fits into the remaining space of
+    /// the bundle.
+    fn has_space_for(&self, seq_action_size: usize) -> bool {
+        self.curr_size.saturating_add(seq_action_size) <= self.max_size
+    }
+
+    /// Returns the number of bytes of sequence actions for `rollup_id` in the bundle.
+    fn rollup_size(&self, rollup_id: &RollupId) -> usize {
+        self.rollup_sizes.get(rollup_id).copied().unwrap_or(0)
+    }
+
     /// Buffer `seq_action` into the bundle.
     /// # Errors
     /// - `seq_action` is beyond the max size allowed for the entire bundle
@@ -95,6 +119,10 @@ impl SizedBundle {
             .entry(seq_action.rollup_id)
             .and_modify(|count| *count = count.saturating_add(1))
             .or_insert(1);
+        self.rollup_sizes
+            .entry(seq_action.rollup_id)
+            .and_modify(|size| *size = size.saturating_add(seq_action_size))
+            .or_insert(seq_action_size);
         self.buffer.push(Action::Sequence(seq_action));
         self.curr_size = new_size;
 
@@ -154,10 +182,86 @@ impl From<FinishedQueueFull> for BundleFactoryError {
     }
 }
 
-/// Manages the bundling of sequence actions into `SizedBundle`s. A `Vec<Action>` is flushed and
-/// added to the `finished` queue when an incoming `SequenceAction` won't fit in the current bundle.
-/// The `finished` queue operates in FIFO order, where `Vec<Action>`s are added to the back and
-/// taken off from the front.
+/// The weight of rollups that were not explicitly given one.
+const DEFAULT_ROLLUP_WEIGHT: u32 = 1;
+
+/// Scales the bytes of a sequence action before dividing them by its rollup's weight so that
+/// virtual finish times of small actions and large weights do not collapse to zero.
+const VIRTUAL_TIME_SCALE: u128 = 1 << 20;
+
+/// Per-rollup settings used to share the space of bundles between rollups.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Fairness {
+    /// The relative share of bundle space each rollup is given if several rollups have pending
+    /// sequence actions. Rollups without an entry have a weight of [`DEFAULT_ROLLUP_WEIGHT`].
+    weights: HashMap<RollupId, u32>,
+    /// The max number of bytes of sequence actions each rollup may take up in a single bundle.
+    /// Rollups without an entry may take up the entire bundle.
+    quotas: HashMap<RollupId, usize>,
+}
+
+impl Fairness {
+    pub(crate) fn new(weights: HashMap<RollupId, u32>, quotas: HashMap<RollupId, usize>) -> Self {
+        Self {
+            weights,
+            quotas,
+        }
+    }
+
+    pub(super) fn weight(&self, rollup_id: &RollupId) -> u32 {
+        self.weights
+            .get(rollup_id)
+            .copied()
+            .unwrap_or(DEFAULT_ROLLUP_WEIGHT)
+            .max(1)
+    }
+
+    /// Returns if a sequence action of `seq_action_size` bytes for `rollup_id` can be put into
+    /// `bundle` without exceeding the rollup's quota.
+    ///
+    /// A rollup's first action in a bundle never exceeds its quota, so that a quota smaller than
+    /// a single action cannot stall the rollup.
+    fn is_within_quota(
+        &self,
+        rollup_id: &RollupId,
+        seq_action_size: usize,
+        bundle: &SizedBundle,
+    ) -> bool {
+        let Some(quota) = self.quotas.get(rollup_id) else {
+            return true;
+        };
+        let used = bundle.rollup_size(rollup_id);
+        used == 0 || used.saturating_add(seq_action_size) <= *quota
+    }
+}
+
+/// A sequence action waiting in its rollup's queue to be put into a bundle.
+struct QueuedAction {
+    seq_action: SequenceAction,
+    size: usize,
+    /// The virtual time at which the action would finish being served under weighted fair
+    /// queuing. Actions are bundled in ascending order of their finish times.
+    finish_time: u128,
+}
+
+/// The sequence actions of a single rollup that have not yet been put into a bundle.
+#[derive(Default)]
+struct RollupQueue {
+    actions: VecDeque<QueuedAction>,
+    /// The sum of the sizes of all queued actions in bytes.
+    size: usize,
+    /// The finish time of the action last pushed to the queue.
+    last_finish_time: u128,
+}
+
+/// Manages the bundling of sequence actions into `SizedBundle`s. Incoming `SequenceAction`s are
+/// put into a queue per rollup, from which they are moved into the current bundle in weighted
+/// fair order. The current bundle is flushed and added to the `finished` queue when the next
+/// action won't fit in it. The `finished` queue operates in FIFO order, where `SizedBundle`s are
+/// added to the back and taken off from the front.
+///
+/// If the `finished` queue is at capacity, queued sequence actions are held back until space
+/// frees up, and new sequence actions are rejected unless they can be bundled right away.
 pub(super) struct BundleFactory {
     /// The current bundle being built.
     curr_bundle: SizedBundle,
@@ -165,71 +269,205 @@ pub(super) struct BundleFactory {
     finished: VecDeque<SizedBundle>,
     /// Max amount of `SizedBundle`s that can be in the `finished` queue.
     finished_queue_capacity: usize,
+    /// The sequence actions of each rollup that have not yet been put into a bundle.
+    queues: BTreeMap<RollupId, RollupQueue>,
+    /// The weights and quotas of each rollup.
+    fairness: Fairness,
+    /// The virtual time of weighted fair queuing, which is the finish time of the action that was
+    /// last put into a bundle.
+    virtual_time: u128,
 }
 
 impl BundleFactory {
     pub(super) fn new(max_bytes_per_bundle: usize, finished_queue_capacity: usize) -> Self {
+        Self::with_fairness(
+            max_bytes_per_bundle,
+            finished_queue_capacity,
+            Fairness::default(),
+        )
+    }
+
+    pub(super) fn with_fairness(
+        max_bytes_per_bundle: usize,
+        finished_queue_capacity: usize,
+        fairness: Fairness,
+    ) -> Self {
         Self {
             curr_bundle: SizedBundle::new(max_bytes_per_bundle),
             finished: VecDeque::new(),
             finished_queue_capacity,
+            queues: BTreeMap::new(),
+            fairness,
+            virtual_time: 0,
         }
     }
 
-    /// Buffer `seq_action` into the current bundle. If the bundle won't fit `seq_action`, flush
-    /// `curr_bundle` into the `finished` queue and start a new bundle, unless the `finished` queue
-    /// is at capacity.
+    /// Queue `seq_action` and move as many queued sequence actions as possible into bundles.
+    ///
+    /// # Errors
+    /// See [`BundleFactory::try_queue`].
     pub(super) fn try_push(
         &mut self,
         seq_action: SequenceAction,
+    ) -> Result<(), BundleFactoryError> {
+        self.try_queue(seq_action)?;
+        self.schedule();
+        Ok(())
+    }
+
+    /// Queue `seq_action` without moving it into a bundle. Call [`BundleFactory::schedule`] once
+    /// all sequence actions received together have been queued.
+    ///
+    /// # Errors
+    /// - `seq_action` is larger than the max bundle size
+    /// - the `finished` queue is at capacity and `seq_action` cannot be bundled right away
+    pub(super) fn try_queue(
+        &mut self,
+        seq_action: SequenceAction,
     ) -> Result<(), BundleFactoryError> {
         let seq_action = with_ibc_prefixed(seq_action);
         let seq_action_size = encoded_len(&seq_action);
 
-        match self.curr_bundle.try_push(seq_action) {
-            Err(SizedBundleError::SequenceActionTooLarge(_seq_action)) => {
-                // reject the sequence action if it is larger than the max bundle size
-                Err(BundleFactoryError::SequenceActionTooLarge {
-                    size: seq_action_size,
-                    max_size: self.curr_bundle.max_size,
-                })
+        if seq_action_size > self.curr_bundle.max_size {
+            // reject the sequence action if it is larger than the max bundle size
+            return Err(BundleFactoryError::SequenceActionTooLarge {
+                size: seq_action_size,
+                max_size: self.curr_bundle.max_size,
+            });
+        }
+
+        if self.is_full()
+            && (self.has_queued_actions() || !self.curr_bundle.has_space_for(seq_action_size))
+        {
+            return Err(FinishedQueueFull {
+                curr_bundle_size: self.curr_bundle.curr_size,
+                finished_queue_capacity: self.finished_queue_capacity,
+                sequence_action_size: seq_action_size,
+                seq_action,
             }
-            Err(SizedBundleError::NotEnoughSpace(seq_action)) => {
-                if self.finished.len() >= self.finished_queue_capacity {
-                    Err(FinishedQueueFull {
-                        curr_bundle_size: self.curr_bundle.curr_size,
-                        finished_queue_capacity: self.finished_queue_capacity,
-                        sequence_action_size: seq_action_size,
-                        seq_action,
-                    }
-                    .into())
-                } else {
-                    // if the bundle is full, flush it and start a new one
-                    self.finished.push_back(self.curr_bundle.flush());
-                    self.curr_bundle.try_push(seq_action).expect(
-                        "seq_action should not be larger than max bundle size, this is a bug",
-                    );
-                    trace!(
-                        new_bundle_size = self.curr_bundle.curr_size,
-                        seq_action_size = seq_action_size,
-                        finished_queue.current_size = self.finished.len(),
-                        finished_queue.capacity = self.finished_queue_capacity,
-                        "created new bundle and bundled new sequence action"
-                    );
-                    Ok(())
+            .into());
+        }
+
+        let rollup_id = seq_action.rollup_id;
+        let weight = self.fairness.weight(&rollup_id);
+        let queue = self.queues.entry(rollup_id).or_default();
+        let weighted_size = (seq_action_size as u128)
+            .saturating_mul(VIRTUAL_TIME_SCALE)
+            .checked_div(u128::from(weight))
+            .expect("rollup weights are at least 1");
+        let finish_time = self
+            .virtual_time
+            .max(queue.last_finish_time)
+            .saturating_add(weighted_size);
+        queue.last_finish_time = finish_time;
+        queue.size = queue.size.saturating_add(seq_action_size);
+        queue.actions.push_back(QueuedAction {
+            seq_action,
+            size: seq_action_size,
+            finish_time,
+        });
+        trace!(
+            %rollup_id,
+            seq_action_size,
+            rollup_queue.size = queue.size,
+            rollup_queue.len = queue.actions.len(),
+            "queued new sequence action"
+        );
+        Ok(())
+    }
+
+    /// Moves queued sequence actions into the current bundle in order of their finish times,
+    /// flushing the current bundle into the `finished` queue whenever the next action does not
+    /// fit, until all queues are empty or the `finished` queue is at capacity.
+    pub(super) fn schedule(&mut self) {
+        loop {
+            let Some(rollup_id) = self.next_eligible_rollup() else {
+                // all rollups with queued actions have exhausted their quota in the current
+                // bundle: finish it so that they can continue in the next one.
+                if self.has_queued_actions() && !self.curr_bundle.is_empty() && !self.is_full() {
+                    self.finish_curr_bundle();
+                    continue;
                 }
+                break;
+            };
+            let queue = self
+                .queues
+                .get_mut(&rollup_id)
+                .expect("the eligible rollup has a queue; this is a bug");
+            let next_size = queue
+                .actions
+                .front()
+                .expect("the queue of an eligible rollup is not empty; this is a bug")
+                .size;
+            if !self.curr_bundle.has_space_for(next_size) {
+                if self.is_full() {
+                    break;
+                }
+                self.finish_curr_bundle();
+                continue;
             }
-            Ok(()) => {
-                trace!(
-                    new_bundle_size = self.curr_bundle.curr_size,
-                    seq_action_size = seq_action_size,
-                    "bundled new sequence action"
-                );
-                Ok(())
-            }
+            let QueuedAction {
+                seq_action,
+                size,
+                finish_time,
+            } = queue
+                .actions
+                .pop_front()
+                .expect("the queue of an eligible rollup is not empty; this is a bug");
+            queue.size = queue.size.saturating_sub(size);
+            self.curr_bundle.try_push(seq_action).expect(
+                "seq_action should fit into the current bundle as checked above, this is a bug",
+            );
+            self.virtual_time = finish_time;
+            trace!(
+                %rollup_id,
+                new_bundle_size = self.curr_bundle.curr_size,
+                seq_action_size = size,
+                "bundled new sequence action"
+            );
         }
     }
 
+    /// Returns the rollup whose next queued action has the smallest finish time, ignoring rollups
+    /// that have exhausted their quota in the current bundle.
+    fn next_eligible_rollup(&self) -> Option<RollupId> {
+        self.queues
+            .iter()
+            .filter_map(|(rollup_id, queue)| {
+                let next = queue.actions.front()?;
+                self.fairness
+                    .is_within_quota(rollup_id, next.size, &self.curr_bundle)
+                    .then_some((next.finish_time, *rollup_id))
+            })
+            .min()
+            .map(|(_, rollup_id)| rollup_id)
+    }
+
+    fn has_queued_actions(&self) -> bool {
+        self.queues.values().any(|queue| !queue.actions.is_empty())
+    }
+
+    fn finish_curr_bundle(&mut self) {
+        self.finished.push_back(self.curr_bundle.flush());
+        trace!(
+            finished_queue.current_size = self.finished.len(),
+            finished_queue.capacity = self.finished_queue_capacity,
+            "finished bundle and started a new one"
+        );
+    }
+
+    /// Returns the number of sequence actions waiting in each rollup's queue.
+    pub(super) fn queue_depths(&self) -> impl Iterator<Item = (&RollupId, usize)> {
+        self.queues
+            .iter()
+            .map(|(rollup_id, queue)| (rollup_id, queue.actions.len()))
+    }
+
+    /// Returns true if the `finished` queue is at capacity.
+    pub(super) fn is_full(&self) -> bool {
+        self.finished.len() >= self.finished_queue_capacity
+    }
+
     /// Returns a handle to the next finished bundle if it exists.
     ///
     /// The bundle is only removed from the factory on calling [`NextFinishedBundle::pop`].
@@ -248,14 +486,12 @@ impl BundleFactory {
     ///
     /// Returns an empty bundle if there are no bundled transactions.
     pub(super) fn pop_now(&mut self) -> SizedBundle {
-        self.finished
+        let bundle = self
+            .finished
             .pop_front()
-            .or_else(|| Some(self.curr_bundle.flush()))
-            .unwrap_or(SizedBundle::new(self.curr_bundle.max_size))
-    }
-
-    pub(super) fn is_full(&self) -> bool {
-        self.finished.len() >= self.finished_queue_capacity
+            .unwrap_or_else(|| self.curr_bundle.flush());
+        self.schedule();
+        bundle
     }
 }
 
@@ -265,10 +501,13 @@ pub(super) struct NextFinishedBundle<'a> {
 
 impl<'a> NextFinishedBundle<'a> {
     pub(super) fn pop(self) -> SizedBundle {
-        self.bundle_factory
+        let bundle = self
+            .bundle_factory
             .finished
             .pop_front()
-            .expect("next bundle exists. this is a bug.")
+            .expect("next bundle exists. this is a bug.");
+        self.bundle_factory.schedule();
+        bundle
     }
 }
 
@@ -279,7 +518,7 @@ fn with_ibc_prefixed(action: SequenceAction) -> SequenceAction {
     }
 }
 
-fn encoded_len(action: &SequenceAction) -> usize {
+pub(super) fn encoded_len(action: &SequenceAction) -> usize {
     use prost::Message as _;
     action.to_raw().encoded_len()
 }
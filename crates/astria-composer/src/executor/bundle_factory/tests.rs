@@ -83,11 +83,15 @@ mod sized_bundle {
 
 #[cfg(test)]
 mod bundle_factory {
+    use std::collections::HashMap;
+
     use super::*;
     use crate::{
         executor::bundle_factory::{
+            encoded_len,
             BundleFactory,
             BundleFactoryError,
+            Fairness,
         },
         test_utils::{
             sequence_action_of_max_size,
@@ -95,6 +99,13 @@ mod bundle_factory {
         },
     };
 
+    fn sequence_action_for_rollup(rollup: u8, n: usize) -> SequenceAction {
+        SequenceAction {
+            rollup_id: RollupId::new([rollup; ROLLUP_ID_LEN]),
+            ..sequence_action_with_n_bytes(n)
+        }
+    }
+
     #[test]
     fn try_push_works_no_flush() {
         // create a bundle factory with max bundle size as 100 bytes
@@ -333,4 +344,90 @@ mod bundle_factory {
         assert_eq!(bundle_factory.finished.len(), 0);
         assert!(!bundle_factory.is_full());
     }
+
+    #[test]
+    fn weights_share_bundle_space() {
+        let action_size = encoded_len(&sequence_action_for_rollup(0, 50));
+        let fairness = Fairness::new(
+            HashMap::from([
+                (RollupId::new([1; ROLLUP_ID_LEN]), 3),
+                (RollupId::new([2; ROLLUP_ID_LEN]), 1),
+            ]),
+            HashMap::new(),
+        );
+        let mut bundle_factory = BundleFactory::with_fairness(4 * action_size, 10, fairness);
+
+        // queue the actions of both rollups before scheduling them, as the executor does with
+        // all actions it receives at once
+        for _ in 0..4 {
+            bundle_factory
+                .try_queue(sequence_action_for_rollup(2, 50))
+                .unwrap();
+            bundle_factory
+                .try_queue(sequence_action_for_rollup(1, 50))
+                .unwrap();
+        }
+        bundle_factory.schedule();
+
+        let bundle = bundle_factory.pop_now();
+        assert_eq!(bundle.actions_count(), 4);
+        assert_eq!(
+            bundle.rollup_counts[&RollupId::new([1; ROLLUP_ID_LEN])],
+            3,
+            "the rollup with weight 3 should get 3 out of 4 slots"
+        );
+        assert_eq!(bundle.rollup_counts[&RollupId::new([2; ROLLUP_ID_LEN])], 1);
+    }
+
+    #[test]
+    fn quota_limits_rollup_bytes_per_bundle() {
+        let action_size = encoded_len(&sequence_action_for_rollup(0, 50));
+        let fairness = Fairness::new(
+            HashMap::new(),
+            HashMap::from([(RollupId::new([0; ROLLUP_ID_LEN]), 2 * action_size)]),
+        );
+        let mut bundle_factory = BundleFactory::with_fairness(4 * action_size, 10, fairness);
+
+        for _ in 0..3 {
+            bundle_factory
+                .try_push(sequence_action_for_rollup(0, 50))
+                .unwrap();
+        }
+
+        // the third action exceeded the rollup's quota, so the current bundle was finished
+        assert_eq!(bundle_factory.finished.len(), 1);
+        assert_eq!(bundle_factory.pop_now().actions_count(), 2);
+        assert_eq!(bundle_factory.pop_now().actions_count(), 1);
+    }
+
+    #[test]
+    fn queued_actions_are_held_back_while_full() {
+        let action_size = encoded_len(&sequence_action_for_rollup(0, 50));
+        let mut bundle_factory = BundleFactory::new(2 * action_size, 1);
+
+        // queue enough actions for three bundles while the factory is not yet full
+        for _ in 0..6 {
+            bundle_factory
+                .try_queue(sequence_action_for_rollup(0, 50))
+                .unwrap();
+        }
+        bundle_factory.schedule();
+        assert!(bundle_factory.is_full());
+        assert_eq!(
+            bundle_factory.queue_depths().collect::<Vec<_>>(),
+            vec![(&RollupId::new([0; ROLLUP_ID_LEN]), 2)],
+        );
+
+        // new actions are rejected while queued actions are held back
+        let err = bundle_factory
+            .try_push(sequence_action_for_rollup(1, 50))
+            .expect_err("the action should be rejected");
+        assert!(matches!(err, BundleFactoryError::FinishedQueueFull(_)));
+
+        // popping a bundle moves the held back actions into bundles
+        let _ = bundle_factory.next_finished().unwrap().pop();
+        assert!(bundle_factory.queue_depths().all(|(_, depth)| depth == 0));
+        assert_eq!(bundle_factory.pop_now().actions_count(), 2);
+        assert_eq!(bundle_factory.pop_now().actions_count(), 2);
+    }
 }
@@ -0,0 +1,386 @@
+//! Filters applied to rollup transactions before they are bundled.
+//!
+//! Collectors forward every transaction they see, including re-broadcasts of transactions that
+//! were already forwarded. To avoid sequencing (and paying sequencer fees for) the same rollup
+//! transaction more than once, the [`TxFilter`] keeps a bounded cache of recently seen
+//! transaction hashes per rollup. Before the deduplication check, transactions are run through
+//! a list of [`PreFilter`]s that can drop unwanted transactions early.
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    fmt,
+    sync::Arc,
+};
+
+use astria_core::{
+    primitive::v1::RollupId,
+    protocol::transaction::v1alpha1::action::SequenceAction,
+};
+use ethers::{
+    types::{
+        Address,
+        Transaction,
+    },
+    utils::{
+        keccak256,
+        rlp::{
+            Decodable as _,
+            Rlp,
+        },
+    },
+};
+
+/// The reason a transaction was dropped by the [`TxFilter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum DropReason {
+    /// The transaction was recently seen for the same rollup.
+    Duplicate,
+    /// The transaction is larger than the configured max size.
+    TooLarge,
+    /// The transaction could not be decoded to determine its sender.
+    Undecodable,
+    /// The sender of the transaction is not on the rollup's allow list.
+    SenderNotAllowed,
+    /// The sender of the transaction is on the rollup's deny list.
+    SenderDenied,
+}
+
+impl DropReason {
+    pub(crate) const ALL: [Self; 5] = [
+        Self::Duplicate,
+        Self::TooLarge,
+        Self::Undecodable,
+        Self::SenderNotAllowed,
+        Self::SenderDenied,
+    ];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Duplicate => "duplicate",
+            Self::TooLarge => "too_large",
+            Self::Undecodable => "undecodable",
+            Self::SenderNotAllowed => "sender_not_allowed",
+            Self::SenderDenied => "sender_denied",
+        }
+    }
+}
+
+impl fmt::Display for DropReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A check run on every transaction before it is deduplicated and bundled.
+pub(crate) trait PreFilter: Send + Sync {
+    /// Returns the reason to drop `seq_action`, or `Ok` if it should be kept.
+    fn check(&self, seq_action: &SequenceAction) -> Result<(), DropReason>;
+}
+
+/// Drops transactions whose data is larger than `max_bytes`.
+pub(crate) struct MaxTxSize {
+    max_bytes: usize,
+}
+
+impl MaxTxSize {
+    pub(crate) fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+        }
+    }
+}
+
+impl PreFilter for MaxTxSize {
+    fn check(&self, seq_action: &SequenceAction) -> Result<(), DropReason> {
+        if seq_action.data.len() > self.max_bytes {
+            return Err(DropReason::TooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// The senders that are allowed or denied for a single EVM rollup.
+#[derive(Debug, Default)]
+pub(crate) struct SenderLists {
+    /// If not empty, only transactions from these senders are kept.
+    pub(crate) allow: HashSet<Address>,
+    /// Transactions from these senders are dropped.
+    pub(crate) deny: HashSet<Address>,
+}
+
+/// Drops transactions of EVM rollups based on their sender, which is recovered by decoding the
+/// RLP encoded transaction.
+///
+/// Only rollups with an entry are filtered; transactions of all other rollups are kept without
+/// being decoded.
+pub(crate) struct EvmSenderFilter {
+    rollups: HashMap<RollupId, SenderLists>,
+}
+
+impl EvmSenderFilter {
+    pub(crate) fn new(rollups: HashMap<RollupId, SenderLists>) -> Self {
+        Self {
+            rollups,
+        }
+    }
+}
+
+impl PreFilter for EvmSenderFilter {
+    fn check(&self, seq_action: &SequenceAction) -> Result<(), DropReason> {
+        let Some(lists) = self.rollups.get(&seq_action.rollup_id) else {
+            return Ok(());
+        };
+        let sender = Transaction::decode(&Rlp::new(&seq_action.data))
+            .ok()
+            .and_then(|tx| tx.recover_from().ok())
+            .ok_or(DropReason::Undecodable)?;
+        if lists.deny.contains(&sender) {
+            return Err(DropReason::SenderDenied);
+        }
+        if !lists.allow.is_empty() && !lists.allow.contains(&sender) {
+            return Err(DropReason::SenderNotAllowed);
+        }
+        Ok(())
+    }
+}
+
+/// A bounded set of the most recently seen transaction hashes of a single rollup. The oldest
+/// hash is evicted once the capacity is reached.
+#[derive(Clone, Default)]
+struct SeenHashes {
+    hashes: HashSet<[u8; 32]>,
+    order: VecDeque<[u8; 32]>,
+}
+
+impl SeenHashes {
+    fn contains(&self, hash: &[u8; 32]) -> bool {
+        self.hashes.contains(hash)
+    }
+
+    /// Inserts `hash`, evicting the oldest hash if `capacity` is exceeded.
+    fn insert(&mut self, hash: [u8; 32], capacity: usize) {
+        if !self.hashes.insert(hash) {
+            return;
+        }
+        self.order.push_back(hash);
+        if self.order.len() > capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.hashes.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// A transaction that passed the [`TxFilter`], but is not yet remembered as seen.
+///
+/// Pass it to [`TxFilter::record`] once the transaction was queued for bundling.
+#[must_use = "the transaction must be recorded to be deduplicated"]
+pub(crate) struct Passed {
+    rollup_id: RollupId,
+    /// The hash of the transaction, or `None` if deduplication is disabled.
+    hash: Option<[u8; 32]>,
+}
+
+/// Runs the configured [`PreFilter`]s on transactions and drops those recently seen for the same
+/// rollup.
+#[derive(Clone, Default)]
+pub(crate) struct TxFilter {
+    /// The number of transaction hashes remembered per rollup. Deduplication is disabled if 0.
+    dedup_cache_size: usize,
+    seen: HashMap<RollupId, SeenHashes>,
+    pre_filters: Vec<Arc<dyn PreFilter>>,
+}
+
+impl TxFilter {
+    pub(crate) fn new(dedup_cache_size: usize, pre_filters: Vec<Arc<dyn PreFilter>>) -> Self {
+        Self {
+            dedup_cache_size,
+            seen: HashMap::new(),
+            pre_filters,
+        }
+    }
+
+    /// Returns the reason to drop `seq_action`, or [`Passed`] if it should be bundled.
+    ///
+    /// The transaction is not remembered until it is passed to [`TxFilter::record`], so that a
+    /// transaction that could not be bundled is not dropped as a duplicate when it is
+    /// re-broadcast.
+    pub(crate) fn check(&self, seq_action: &SequenceAction) -> Result<Passed, DropReason> {
+        for pre_filter in &self.pre_filters {
+            pre_filter.check(seq_action)?;
+        }
+        if self.dedup_cache_size == 0 {
+            return Ok(Passed {
+                rollup_id: seq_action.rollup_id,
+                hash: None,
+            });
+        }
+        let hash = keccak256(&seq_action.data);
+        if self
+            .seen
+            .get(&seq_action.rollup_id)
+            .is_some_and(|seen| seen.contains(&hash))
+        {
+            return Err(DropReason::Duplicate);
+        }
+        Ok(Passed {
+            rollup_id: seq_action.rollup_id,
+            hash: Some(hash),
+        })
+    }
+
+    /// Remembers a transaction that passed [`TxFilter::check`] so that it is dropped as a
+    /// duplicate when seen again.
+    pub(crate) fn record(&mut self, passed: Passed) {
+        let Passed {
+            rollup_id,
+            hash,
+        } = passed;
+        if let Some(hash) = hash {
+            self.seen
+                .entry(rollup_id)
+                .or_default()
+                .insert(hash, self.dedup_cache_size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::ROLLUP_ID_LEN;
+    use ethers::{
+        signers::{
+            LocalWallet,
+            Signer as _,
+        },
+        types::{
+            transaction::eip2718::TypedTransaction,
+            TransactionRequest,
+        },
+    };
+
+    use super::*;
+    use crate::test_utils::sequence_action_with_n_bytes;
+
+    fn seq_action(rollup: u8, data: &[u8]) -> SequenceAction {
+        SequenceAction {
+            rollup_id: RollupId::new([rollup; ROLLUP_ID_LEN]),
+            data: data.to_vec().into(),
+            ..sequence_action_with_n_bytes(0)
+        }
+    }
+
+    fn signed_tx(wallet: &LocalWallet) -> Vec<u8> {
+        let tx: TypedTransaction = TransactionRequest::new()
+            .to(Address::zero())
+            .nonce(0)
+            .gas(21000)
+            .gas_price(1)
+            .chain_id(1)
+            .into();
+        let signature = wallet
+            .clone()
+            .with_chain_id(1u64)
+            .sign_transaction_sync(&tx)
+            .unwrap();
+        tx.rlp_signed(&signature).to_vec()
+    }
+
+    /// Checks `seq_action` and records it if it passed.
+    fn check_and_record(
+        filter: &mut TxFilter,
+        seq_action: &SequenceAction,
+    ) -> Result<(), DropReason> {
+        let passed = filter.check(seq_action)?;
+        filter.record(passed);
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates_are_dropped_per_rollup() {
+        let mut filter = TxFilter::new(10, vec![]);
+        check_and_record(&mut filter, &seq_action(0, b"tx")).unwrap();
+        assert_eq!(
+            check_and_record(&mut filter, &seq_action(0, b"tx")),
+            Err(DropReason::Duplicate)
+        );
+        check_and_record(&mut filter, &seq_action(1, b"tx")).unwrap();
+    }
+
+    #[test]
+    fn unrecorded_txs_are_not_duplicates() {
+        let mut filter = TxFilter::new(10, vec![]);
+        let _ = filter.check(&seq_action(0, b"tx")).unwrap();
+        check_and_record(&mut filter, &seq_action(0, b"tx")).unwrap();
+        assert_eq!(
+            check_and_record(&mut filter, &seq_action(0, b"tx")),
+            Err(DropReason::Duplicate)
+        );
+    }
+
+    #[test]
+    fn oldest_hashes_are_evicted() {
+        let mut filter = TxFilter::new(2, vec![]);
+        check_and_record(&mut filter, &seq_action(0, b"tx-0")).unwrap();
+        check_and_record(&mut filter, &seq_action(0, b"tx-1")).unwrap();
+        check_and_record(&mut filter, &seq_action(0, b"tx-2")).unwrap();
+        check_and_record(&mut filter, &seq_action(0, b"tx-0")).unwrap();
+        assert_eq!(
+            check_and_record(&mut filter, &seq_action(0, b"tx-2")),
+            Err(DropReason::Duplicate)
+        );
+    }
+
+    #[test]
+    fn zero_cache_size_disables_deduplication() {
+        let mut filter = TxFilter::new(0, vec![]);
+        check_and_record(&mut filter, &seq_action(0, b"tx")).unwrap();
+        check_and_record(&mut filter, &seq_action(0, b"tx")).unwrap();
+    }
+
+    #[test]
+    fn too_large_txs_are_dropped_and_not_remembered() {
+        let mut filter = TxFilter::new(10, vec![Arc::new(MaxTxSize::new(2))]);
+        assert_eq!(
+            check_and_record(&mut filter, &seq_action(0, b"tx-0")),
+            Err(DropReason::TooLarge)
+        );
+        assert!(filter.seen.is_empty());
+        check_and_record(&mut filter, &seq_action(0, b"tx")).unwrap();
+    }
+
+    #[test]
+    fn evm_sender_lists_are_applied() {
+        let allowed = LocalWallet::new(&mut rand::thread_rng());
+        let denied = LocalWallet::new(&mut rand::thread_rng());
+        let other = LocalWallet::new(&mut rand::thread_rng());
+        let sender_filter = EvmSenderFilter::new(HashMap::from([(
+            RollupId::new([0; ROLLUP_ID_LEN]),
+            SenderLists {
+                allow: HashSet::from([allowed.address(), denied.address()]),
+                deny: HashSet::from([denied.address()]),
+            },
+        )]));
+
+        sender_filter
+            .check(&seq_action(0, &signed_tx(&allowed)))
+            .unwrap();
+        assert_eq!(
+            sender_filter.check(&seq_action(0, &signed_tx(&denied))),
+            Err(DropReason::SenderDenied)
+        );
+        assert_eq!(
+            sender_filter.check(&seq_action(0, &signed_tx(&other))),
+            Err(DropReason::SenderNotAllowed)
+        );
+        assert_eq!(
+            sender_filter.check(&seq_action(0, b"not rlp")),
+            Err(DropReason::Undecodable)
+        );
+        // rollups without lists are not decoded
+        sender_filter.check(&seq_action(1, b"not rlp")).unwrap();
+    }
+}
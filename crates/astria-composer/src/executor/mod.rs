@@ -3,15 +3,27 @@
 /// - Transaction signing
 /// - Managing the connection to the sequencer
 /// - Submitting transactions to the sequencer
+///
+/// Bundles are submitted over one or more lanes. Each lane signs with its own key and tracks
+/// its own nonce, so that several transactions can be in flight at once and a nonce error on
+/// one lane does not stall the others.
 use std::{
-    collections::VecDeque,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     pin::Pin,
+    sync::{
+        Arc,
+        Mutex,
+    },
     task::Poll,
     time::Duration,
 };
 
 use astria_core::{
     crypto::SigningKey,
+    primitive::v1::RollupId,
     protocol::{
         abci::AbciErrorCode,
         transaction::v1alpha1::{
@@ -29,11 +41,13 @@ use astria_eyre::eyre::{
 use futures::{
     future::{
         self,
-        Fuse,
-        FusedFuture as _,
         FutureExt as _,
     },
     ready,
+    stream::{
+        FuturesUnordered,
+        StreamExt as _,
+    },
     Future,
 };
 use pin_project_lite::pin_project;
@@ -73,22 +87,33 @@ use tracing::{
     info_span,
     instrument,
     instrument::Instrumented,
+    trace,
     warn,
     Instrument,
     Span,
 };
 
 use self::bundle_factory::SizedBundle;
+pub(crate) use self::{
+    bundle_factory::Fairness,
+    filter::TxFilter,
+};
 use crate::{
-    executor::bundle_factory::{
-        BundleFactory,
-        SizedBundleReport,
+    executor::{
+        bundle_factory::{
+            encoded_len,
+            BundleFactory,
+            BundleFactoryError,
+            SizedBundleReport,
+        },
+        filter::DropReason,
     },
     metrics::Metrics,
     utils::report_exit_reason,
 };
 
 mod bundle_factory;
+pub(crate) mod filter;
 
 pub(crate) mod builder;
 #[cfg(test)]
@@ -102,6 +127,10 @@ pub(crate) use builder::Builder;
 // executor has a chance to drain all the remaining bundles.
 const BUNDLE_DRAINING_DURATION: Duration = Duration::from_secs(16);
 
+// The max number of sequence actions that are received from the channel at once and queued in
+// the `BundleFactory` before being bundled in weighted fair order.
+const MAX_ACTIONS_PER_RECEIVE: usize = 256;
+
 type StdError = dyn std::error::Error;
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum EnsureChainIdError {
@@ -123,47 +152,160 @@ pub(super) struct Executor {
     status: watch::Sender<Status>,
     // Channel for receiving `SequenceAction`s to be bundled.
     serialized_rollup_transactions: mpsc::Receiver<SequenceAction>,
+    // The bytes of sequence actions each rollup has waiting in the channel.
+    rollup_quotas: Arc<RollupQuotas>,
     // The client for submitting wrapped and signed pending eth transactions to the astria
     // sequencer.
     sequencer_client: sequencer_client::HttpClient,
     // The chain id used for submission of transactions to the sequencer.
     sequencer_chain_id: String,
-    // Private key used to sign sequencer transactions
-    sequencer_key: SigningKey,
-    // The sequencer address associated with the private key
-    address: Address,
+    // The signing keys of the submission lanes, each with its own nonce.
+    lanes: Vec<LaneKey>,
     // Milliseconds for bundle timer to make sure bundles are submitted at least once per block.
     block_time: tokio::time::Duration,
     // Max bytes in a sequencer action bundle
     max_bytes_per_bundle: usize,
     // Max amount of `SizedBundle`s that can be in the `BundleFactory`'s `finished` queue.
     bundle_queue_capacity: usize,
+    // The per-rollup weights and quotas used to share bundle space between rollups.
+    fairness: Fairness,
+    // Deduplicates and pre-filters transactions before they are bundled.
+    tx_filter: TxFilter,
     // Token to signal the executor to stop upon shutdown.
     shutdown_token: CancellationToken,
     metrics: &'static Metrics,
 }
 
+/// The signing key of a single submission lane.
+struct LaneKey {
+    // Private key used to sign sequencer transactions submitted over this lane.
+    signing_key: SigningKey,
+    // The sequencer address associated with the private key.
+    address: Address,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SendError {
+    #[error("timed out sending the sequence action to the executor")]
+    Timeout,
+    #[error("the executor channel is closed")]
+    Closed,
+    #[error(
+        "rollup `{rollup_id}` has {pending} bytes of sequence actions waiting to be bundled; \
+         adding {size} bytes would exceed its quota of {quota} bytes"
+    )]
+    RollupQuotaFull {
+        rollup_id: RollupId,
+        pending: usize,
+        size: usize,
+        quota: usize,
+    },
+}
+
+/// Tracks the bytes of sequence actions that each rollup has sent to the executor and that the
+/// executor has not yet received.
+///
+/// Each rollup may have as many bytes waiting as fit into the executor's bundles, scaled by the
+/// rollup's weight, so that a single rollup cannot fill the channel to the executor.
+struct RollupQuotas {
+    /// The number of bytes each rollup may have waiting at weight 1.
+    bytes_per_weight: usize,
+    fairness: Fairness,
+    pending: Mutex<HashMap<RollupId, usize>>,
+}
+
+impl RollupQuotas {
+    fn new(max_bytes_per_bundle: usize, bundle_queue_capacity: usize, fairness: Fairness) -> Self {
+        Self {
+            bytes_per_weight: max_bytes_per_bundle
+                .saturating_mul(bundle_queue_capacity.saturating_add(1)),
+            fairness,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserves `size` bytes for `rollup_id`.
+    ///
+    /// A rollup without waiting sequence actions is always granted its reservation, so that a
+    /// quota smaller than a single sequence action cannot stall the rollup.
+    fn reserve(&self, rollup_id: RollupId, size: usize) -> Result<(), SendError> {
+        let quota = usize::try_from(self.fairness.weight(&rollup_id))
+            .unwrap_or(usize::MAX)
+            .saturating_mul(self.bytes_per_weight);
+        let mut pending = self
+            .pending
+            .lock()
+            .expect("the lock is never held across a panic");
+        let pending = pending.entry(rollup_id).or_default();
+        if *pending > 0 && pending.saturating_add(size) > quota {
+            return Err(SendError::RollupQuotaFull {
+                rollup_id,
+                pending: *pending,
+                size,
+                quota,
+            });
+        }
+        *pending = pending.saturating_add(size);
+        Ok(())
+    }
+
+    /// Releases `size` bytes previously reserved for `rollup_id`.
+    fn release(&self, rollup_id: RollupId, size: usize) {
+        let mut pending = self
+            .pending
+            .lock()
+            .expect("the lock is never held across a panic");
+        if let Some(pending_bytes) = pending.get_mut(&rollup_id) {
+            *pending_bytes = pending_bytes.saturating_sub(size);
+            if *pending_bytes == 0 {
+                pending.remove(&rollup_id);
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct Handle {
     serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>,
+    rollup_quotas: Arc<RollupQuotas>,
 }
 
 impl Handle {
-    fn new(serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>) -> Self {
+    fn new(
+        serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>,
+        rollup_quotas: Arc<RollupQuotas>,
+    ) -> Self {
         Self {
             serialized_rollup_transactions_tx,
+            rollup_quotas,
         }
     }
 
+    /// Sends `sequence_action` to the executor, waiting at most `timeout` for space in the
+    /// channel.
+    ///
+    /// # Errors
+    /// Returns an error if the rollup of `sequence_action` has used up its quota of bytes waiting
+    /// to be bundled, if the channel stays full for `timeout`, or if the channel is closed.
     #[instrument(skip_all, err)]
     pub(super) async fn send_timeout(
         &self,
         sequence_action: SequenceAction,
         timeout: Duration,
-    ) -> Result<(), SendTimeoutError<SequenceAction>> {
+    ) -> Result<(), SendError> {
+        let rollup_id = sequence_action.rollup_id;
+        let size = encoded_len(&sequence_action);
+        self.rollup_quotas.reserve(rollup_id, size)?;
         self.serialized_rollup_transactions_tx
             .send_timeout(sequence_action, timeout)
             .await
+            .map_err(|err| {
+                self.rollup_quotas.release(rollup_id, size);
+                match err {
+                    SendTimeoutError::Timeout(_) => SendError::Timeout,
+                    SendTimeoutError::Closed(_) => SendError::Closed,
+                }
+            })
     }
 }
 
@@ -190,26 +332,28 @@ impl Executor {
         self.status.subscribe()
     }
 
-    /// Create a future to submit a bundle to the sequencer.
-    #[instrument(skip_all, fields(nonce.initial = %nonce))]
+    /// Create a future to submit a bundle to the sequencer over `lane`.
+    #[instrument(skip_all, fields(lane = lane, nonce.initial = %nonce))]
     fn submit_bundle(
         &self,
+        lane: usize,
         nonce: u32,
         bundle: SizedBundle,
         metrics: &'static Metrics,
-    ) -> Fuse<Instrumented<SubmitFut>> {
+    ) -> Instrumented<SubmitFut> {
+        let lane_key = &self.lanes[lane];
         SubmitFut {
             client: self.sequencer_client.clone(),
-            address: self.address,
+            lane,
+            address: lane_key.address,
             nonce,
             chain_id: self.sequencer_chain_id.clone(),
-            signing_key: self.sequencer_key.clone(),
+            signing_key: lane_key.signing_key.clone(),
             state: SubmitState::NotStarted,
             bundle,
             metrics,
         }
         .in_current_span()
-        .fuse()
     }
 
     /// Run the Executor loop, calling `process_bundle` on each bundle received from the channel.
@@ -217,27 +361,36 @@ impl Executor {
     /// # Errors
     /// An error is returned if connecting to the sequencer fails.
     pub(super) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let mut nonce = select!(
+        let mut nonces = select!(
             biased;
             () = self.shutdown_token.cancelled() => {
                 report_exit_reason(Ok("received shutdown signal while running initialization routines; exiting"));
                 return Ok(())
             }
 
-            nonce = self.init() => {
-                nonce.wrap_err("initialization failed").inspect_err(|err| report_exit_reason(Err(err)))?
+            nonces = self.init() => {
+                nonces.wrap_err("initialization failed").inspect_err(|err| report_exit_reason(Err(err)))?
             }
         );
-        let mut submission_fut: Fuse<Instrumented<SubmitFut>> = Fuse::terminated();
+        // submissions in flight, at most one per lane
+        let mut submissions: FuturesUnordered<Instrumented<SubmitFut>> = FuturesUnordered::new();
+        // lanes without a submission in flight
+        let mut idle_lanes: VecDeque<usize> = (0..self.lanes.len()).collect();
 
-        self.metrics.set_current_nonce(nonce);
+        for (lane, nonce) in nonces.iter().enumerate() {
+            self.metrics.set_current_nonce(lane, *nonce);
+        }
 
         self.status.send_modify(|status| status.is_connected = true);
 
         let block_timer = time::sleep(self.block_time);
         tokio::pin!(block_timer);
-        let mut bundle_factory =
-            BundleFactory::new(self.max_bytes_per_bundle, self.bundle_queue_capacity);
+        let mut bundle_factory = BundleFactory::with_fairness(
+            self.max_bytes_per_bundle,
+            self.bundle_queue_capacity,
+            self.fairness.clone(),
+        );
+        let mut tx_filter = self.tx_filter.clone();
 
         let reset_time = || {
             Instant::now()
@@ -252,32 +405,47 @@ impl Executor {
                 () = self.shutdown_token.cancelled() => {
                     break Ok("received shutdown signal");
                 }
-                // process submission result and update nonce
-                rsp = &mut submission_fut, if !submission_fut.is_terminated() => {
-                    if let Err(err) = process_result_update_nonce(&mut nonce, rsp, &mut block_timer, reset_time) {
+                // process submission result and update the nonce of its lane
+                Some((lane, rsp)) = submissions.next(), if !submissions.is_empty() => {
+                    if let Err(err) = process_result_update_nonce(&mut nonces[lane], rsp, &mut block_timer, reset_time) {
                         break Err(err).wrap_err("failed submitting bundle to sequencer");
                     };
+                    idle_lanes.push_back(lane);
                 }
 
-                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if submission_fut.is_terminated() => {
+                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if !idle_lanes.is_empty() => {
                     let bundle = next_bundle.pop();
+                    self.report_queue_depths(&bundle_factory);
                     if !bundle.is_empty() {
-                        submission_fut = self.submit_bundle(nonce, bundle, self.metrics);
+                        let lane = idle_lanes.pop_front().expect("checked that a lane is idle");
+                        submissions.push(self.submit_bundle(lane, nonces[lane], bundle, self.metrics));
                     }
                 }
 
-                // receive new seq_action and bundle it. will not pull from the channel if `bundle_factory` is full
+                // receive new seq_action and bundle it with the others waiting in the channel.
+                // will not pull from the channel if `bundle_factory` is full
                 Some(seq_action) = self.serialized_rollup_transactions.recv(), if !bundle_factory.is_full() => {
-                    self.bundle_seq_action(seq_action, &mut bundle_factory);
+                    self.queue_seq_action(seq_action, &mut bundle_factory, &mut tx_filter);
+                    for _ in 1..MAX_ACTIONS_PER_RECEIVE {
+                        let Ok(seq_action) = self.serialized_rollup_transactions.try_recv() else {
+                            break;
+                        };
+                        self.queue_seq_action(seq_action, &mut bundle_factory, &mut tx_filter);
+                    }
+                    bundle_factory.schedule();
+                    self.report_queue_depths(&bundle_factory);
                 }
 
                 // try to preempt current bundle if the timer has ticked without submitting the next bundle
-                () = &mut block_timer, if submission_fut.is_terminated() => {
+                () = &mut block_timer, if !idle_lanes.is_empty() => {
                     let bundle = bundle_factory.pop_now();
-                    if bundle.is_empty() {
-                        block_timer.as_mut().reset(reset_time());
-                    } else {
-                        submission_fut = self.submit_bundle(nonce, bundle, self.metrics);
+                    self.report_queue_depths(&bundle_factory);
+                    // reset the timer so that the remaining idle lanes do not immediately
+                    // preempt the next bundle as well
+                    block_timer.as_mut().reset(reset_time());
+                    if !bundle.is_empty() {
+                        let lane = idle_lanes.pop_front().expect("checked that a lane is idle");
+                        submissions.push(self.submit_bundle(lane, nonces[lane], bundle, self.metrics));
                     }
                 }
             }
@@ -300,7 +468,8 @@ impl Executor {
 
         // drain the receiver channel
         while let Ok(seq_action) = self.serialized_rollup_transactions.try_recv() {
-            self.bundle_seq_action(seq_action, &mut bundle_factory);
+            self.queue_seq_action(seq_action, &mut bundle_factory, &mut tx_filter);
+            bundle_factory.schedule();
         }
 
         // when shutting down, drain all the remaining bundles and submit to the sequencer
@@ -315,8 +484,8 @@ impl Executor {
         }
 
         let shutdown_logic = self.run_shutdown_logic(
-            submission_fut,
-            nonce,
+            submissions,
+            nonces,
             &mut bundles_to_drain,
             &mut bundles_drained,
         );
@@ -330,14 +499,16 @@ impl Executor {
 
     /// Performs initialization checks prior to running the executor
     #[instrument(skip_all, err)]
-    async fn init(&self) -> eyre::Result<u32> {
+    async fn init(&self) -> eyre::Result<Vec<u32>> {
         self.ensure_chain_id_is_correct()
             .await
             .wrap_err("failed to validate chain id")?;
-        let nonce = get_latest_nonce(self.sequencer_client.clone(), self.address, self.metrics)
-            .await
-            .wrap_err("failed getting initial nonce from sequencer")?;
-        Ok(nonce)
+        let nonces = future::try_join_all(self.lanes.iter().map(|lane| {
+            get_latest_nonce(self.sequencer_client.clone(), lane.address, self.metrics)
+        }))
+        .await
+        .wrap_err("failed getting initial nonces from sequencer")?;
+        Ok(nonces)
     }
 
     /// Performs check to ensure the configured chain ID matches the remote chain ID
@@ -391,8 +562,8 @@ impl Executor {
     #[instrument(skip_all, err)]
     async fn run_shutdown_logic(
         &self,
-        submission_fut: Fuse<Instrumented<SubmitFut>>,
-        mut nonce: u32,
+        mut submissions: FuturesUnordered<Instrumented<SubmitFut>>,
+        mut nonces: Vec<u32>,
         bundles_to_drain: &mut VecDeque<SizedBundle>,
         bundles_drained: &mut Option<u64>,
     ) -> eyre::Result<()> {
@@ -401,14 +572,22 @@ impl Executor {
             "submitting remaining transaction bundles to sequencer"
         );
 
-        // wait for the last bundle to be submitted
-        if !submission_fut.is_terminated() {
-            info!("waiting for the last bundle of transactions to be submitted to the sequencer");
-            match submission_fut.await {
+        // wait for the bundles in flight to be submitted
+        if !submissions.is_empty() {
+            info!(
+                no_of_submissions_in_flight = submissions.len(),
+                "waiting for the last bundles of transactions to be submitted to the sequencer"
+            );
+        }
+        while let Some((lane, rsp)) = submissions.next().await {
+            match rsp {
                 Ok(new_nonce) => {
-                    debug!(new_nonce, "successfully submitted bundle of transactions");
+                    debug!(
+                        lane,
+                        new_nonce, "successfully submitted bundle of transactions"
+                    );
 
-                    nonce = new_nonce;
+                    nonces[lane] = new_nonce;
                 }
                 Err(error) => {
                     return Err(error.wrap_err(
@@ -417,11 +596,14 @@ impl Executor {
                 }
             }
         }
+        // submit the remaining bundles one after another, rotating through the lanes
+        let mut lanes = (0..nonces.len()).cycle();
         while let Some(bundle) = bundles_to_drain.pop_front() {
-            match self
-                .submit_bundle(nonce, bundle.clone(), self.metrics)
-                .await
-            {
+            let lane = lanes.next().expect("there is at least one lane");
+            let (_, rsp) = self
+                .submit_bundle(lane, nonces[lane], bundle.clone(), self.metrics)
+                .await;
+            match rsp {
                 Ok(new_nonce) => {
                     debug!(
                         bundle = %telemetry::display::json(&SizedBundleReport(&bundle)),
@@ -429,7 +611,7 @@ impl Executor {
                         "successfully submitted transaction bundle"
                     );
 
-                    nonce = new_nonce;
+                    nonces[lane] = new_nonce;
                     *bundles_drained = bundles_drained.and_then(|value| value.checked_add(1));
                 }
                 Err(error) => {
@@ -446,18 +628,62 @@ impl Executor {
         Ok(())
     }
 
-    /// Pushes sequence action into current bundle, warning and dropping on failure.
+    /// Queues `seq_action` in `bundle_factory`, to be bundled on the next call to
+    /// [`BundleFactory::schedule`].
+    ///
+    /// Sequence actions rejected by `tx_filter` are dropped without being queued. Sequence actions
+    /// that cannot be queued are dropped with a warning; they are not remembered by `tx_filter`,
+    /// so that they are accepted again if re-broadcast.
     #[instrument(skip_all)]
-    fn bundle_seq_action(&self, seq_action: SequenceAction, bundle_factory: &mut BundleFactory) {
+    fn queue_seq_action(
+        &self,
+        seq_action: SequenceAction,
+        bundle_factory: &mut BundleFactory,
+        tx_filter: &mut TxFilter,
+    ) {
         let rollup_id = seq_action.rollup_id;
+        self.rollup_quotas
+            .release(rollup_id, encoded_len(&seq_action));
+
+        let passed = match tx_filter.check(&seq_action) {
+            Ok(passed) => passed,
+            Err(reason) => {
+                self.metrics.increment_txs_filtered(&rollup_id, reason);
+                // duplicates are expected during normal operation and only logged at trace level
+                if reason == DropReason::Duplicate {
+                    trace!(%rollup_id, %reason, "filtered transaction, dropping it");
+                } else {
+                    debug!(%rollup_id, %reason, "filtered transaction, dropping it");
+                }
+                return;
+            }
+        };
 
-        if let Err(e) = bundle_factory.try_push(seq_action) {
-            self.metrics.increment_txs_dropped_too_large(&rollup_id);
-            warn!(
-                rollup_id = %rollup_id,
-                error = &e as &StdError,
-                "failed to bundle transaction, dropping it."
-            );
+        match bundle_factory.try_queue(seq_action) {
+            // only remember queued transactions so that dropped ones can be re-broadcast
+            Ok(()) => tx_filter.record(passed),
+            Err(e) => {
+                match &e {
+                    BundleFactoryError::SequenceActionTooLarge {
+                        ..
+                    } => self.metrics.increment_txs_dropped_too_large(&rollup_id),
+                    BundleFactoryError::FinishedQueueFull(_) => {
+                        self.metrics.increment_txs_dropped_queue_full(&rollup_id);
+                    }
+                }
+                warn!(
+                    rollup_id = %rollup_id,
+                    error = &e as &StdError,
+                    "failed to bundle transaction, dropping it."
+                );
+            }
+        }
+    }
+
+    /// Reports the number of sequence actions waiting in each rollup's queue.
+    fn report_queue_depths(&self, bundle_factory: &BundleFactory) {
+        for (rollup_id, depth) in bundle_factory.queue_depths() {
+            self.metrics.set_rollup_queue_depth(rollup_id, depth);
         }
     }
 }
@@ -513,6 +739,7 @@ async fn get_latest_nonce(
     name = "submit signed transaction",
     skip_all,
     fields(
+        lane = lane,
         nonce = tx.nonce(),
         transaction.hash = hex::encode(sha256(&tx.to_raw().encode_to_vec())),
     ),
@@ -520,11 +747,12 @@ async fn get_latest_nonce(
 )]
 async fn submit_tx(
     client: sequencer_client::HttpClient,
+    lane: usize,
     tx: SignedTransaction,
     metrics: &Metrics,
 ) -> eyre::Result<tx_sync::Response> {
     let nonce = tx.nonce();
-    metrics.set_current_nonce(nonce);
+    metrics.set_current_nonce(lane, nonce);
 
     // TODO: change to info and log tx hash (to match info log in `SubmitFut`'s response handling
     // logic)
@@ -629,7 +857,8 @@ fn report_if_bundles_drained(
 }
 
 pin_project! {
-    /// A future to submit a bundle to the sequencer, returning the next nonce that should be used for the next submission.
+    /// A future to submit a bundle to the sequencer over a lane, returning the lane and the next nonce that should be
+    /// used for the lane's next submission.
     ///
     /// The future will fetch a new nonce from the sequencer if a submission returned an `INVALID_NONCE` error code.
     ///
@@ -640,6 +869,7 @@ pin_project! {
     /// that nonce it used to submit the non-zero abci code request.
     struct SubmitFut {
         client: sequencer_client::HttpClient,
+        lane: usize,
         address: Address,
         chain_id: String,
         nonce: u32,
@@ -667,10 +897,20 @@ pin_project! {
 }
 
 impl Future for SubmitFut {
-    type Output = eyre::Result<u32>;
+    type Output = (usize, eyre::Result<u32>);
 
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        let lane = self.lane;
+        self.poll_submit(cx).map(|rsp| (lane, rsp))
+    }
+}
+
+impl SubmitFut {
     #[allow(clippy::too_many_lines)]
-    fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+    fn poll_submit(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<eyre::Result<u32>> {
         const INVALID_NONCE: Code = Code::Err(AbciErrorCode::INVALID_NONCE.value());
         loop {
             let this = self.as_mut().project();
@@ -693,7 +933,7 @@ impl Future for SubmitFut {
                         "submitting transaction to sequencer",
                     );
                     SubmitState::WaitingForSend {
-                        fut: submit_tx(this.client.clone(), tx, self.metrics).boxed(),
+                        fut: submit_tx(this.client.clone(), *this.lane, tx, self.metrics).boxed(),
                     }
                 }
 
@@ -771,7 +1011,8 @@ impl Future for SubmitFut {
                             "resubmitting transaction to sequencer with new nonce",
                         );
                         SubmitState::WaitingForSend {
-                            fut: submit_tx(this.client.clone(), tx, self.metrics).boxed(),
+                            fut: submit_tx(this.client.clone(), *this.lane, tx, self.metrics)
+                                .boxed(),
                         }
                     }
                     Err(error) => {
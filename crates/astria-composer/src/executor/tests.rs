@@ -21,6 +21,7 @@ use astria_core::{
     protocol::transaction::v1alpha1::action::SequenceAction,
 };
 use astria_eyre::eyre;
+use config::MaybeEncoded;
 use prost::{
     bytes::Bytes,
     Message as _,
@@ -66,7 +67,14 @@ use wiremock::{
 
 use crate::{
     executor,
-    executor::EnsureChainIdError,
+    executor::{
+        bundle_factory::BundleFactory,
+        filter::DropReason,
+        EnsureChainIdError,
+        Fairness,
+        SendError,
+        TxFilter,
+    },
     metrics::Metrics,
     test_utils::sequence_action_of_max_size,
     Config,
@@ -80,8 +88,16 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
         api_listen_addr: SocketAddr::new(IpAddr::from([0, 0, 0, 0]), 0),
         sequencer_url: String::new(),
         sequencer_chain_id: String::new(),
-        rollups: String::new(),
+        rollups: MaybeEncoded::default(),
+        txpool_poll_interval_ms: 0,
+        rollup_weights: MaybeEncoded::default(),
+        rollup_bundle_quotas: MaybeEncoded::default(),
+        dedup_cache_size: 0,
+        max_tx_bytes: 0,
+        sender_allow_list: MaybeEncoded::default(),
+        sender_deny_list: MaybeEncoded::default(),
         private_key_file: String::new(),
+        additional_private_key_files: MaybeEncoded::default(),
         sequencer_address_prefix: String::new(),
         block_time_ms: 0,
         max_bytes_per_bundle: 0,
@@ -132,10 +148,18 @@ async fn setup() -> (MockServer, Config, NamedTempFile) {
     let cfg = Config {
         log: String::new(),
         api_listen_addr: "127.0.0.1:0".parse().unwrap(),
-        rollups: String::new(),
+        rollups: MaybeEncoded::default(),
+        txpool_poll_interval_ms: 0,
+        rollup_weights: MaybeEncoded::default(),
+        rollup_bundle_quotas: MaybeEncoded::default(),
+        dedup_cache_size: 0,
+        max_tx_bytes: 0,
+        sender_allow_list: MaybeEncoded::default(),
+        sender_deny_list: MaybeEncoded::default(),
         sequencer_url: server.uri(),
         sequencer_chain_id: "test-chain-1".to_string(),
         private_key_file: keyfile.path().to_string_lossy().to_string(),
+        additional_private_key_files: MaybeEncoded::default(),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: 2000,
         max_bytes_per_bundle: 1000,
@@ -329,11 +353,13 @@ async fn full_bundle() {
     let (executor, executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
-        private_key_file: cfg.private_key_file.clone(),
+        private_key_files: vec![cfg.private_key_file.clone()],
         sequencer_address_prefix: "astria".into(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -420,11 +446,13 @@ async fn bundle_triggered_by_block_timer() {
     let (executor, executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
-        private_key_file: cfg.private_key_file.clone(),
+        private_key_files: vec![cfg.private_key_file.clone()],
         sequencer_address_prefix: "astria".into(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -508,11 +536,13 @@ async fn two_seq_actions_single_bundle() {
     let (executor, executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
-        private_key_file: cfg.private_key_file.clone(),
+        private_key_files: vec![cfg.private_key_file.clone()],
         sequencer_address_prefix: "astria".into(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -611,11 +641,13 @@ async fn chain_id_mismatch_returns_error() {
     let (executor, _executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
-        private_key_file: cfg.private_key_file.clone(),
+        private_key_files: vec![cfg.private_key_file.clone()],
         sequencer_address_prefix: cfg.sequencer_address_prefix.clone(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -645,3 +677,193 @@ async fn chain_id_mismatch_returns_error() {
         "expected `EnsureChainIdError::WrongChainId` in error chain, but it was not found"
     );
 }
+
+/// Test to check that the executor submits bundles over all of its lanes without waiting for the
+/// submissions of other lanes, each signed with the lane's own key.
+#[tokio::test]
+async fn bundles_are_submitted_over_multiple_lanes() {
+    // set up the executor with two signing keys, channel for writing seq actions, and the
+    // sequencer mock
+    let (sequencer, mut cfg, _keyfile) = setup().await;
+    let second_keyfile = NamedTempFile::new().unwrap();
+    (&second_keyfile)
+        .write_all("0b7c3e1f5a4d4b1e8f1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f708192a3b4c5d6".as_bytes())
+        .unwrap();
+    cfg.additional_private_key_files =
+        MaybeEncoded::Encoded(second_keyfile.path().to_string_lossy().to_string());
+    let shutdown_token = CancellationToken::new();
+    let metrics = Box::leak(Box::new(Metrics::noop_metrics(&cfg).unwrap()));
+    mount_genesis(&sequencer, &cfg.sequencer_chain_id).await;
+    let (executor, executor_handle) = executor::Builder {
+        sequencer_url: cfg.sequencer_url.clone(),
+        sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        private_key_files: cfg.private_key_files(),
+        sequencer_address_prefix: "astria".into(),
+        block_time_ms: cfg.block_time_ms,
+        max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+        bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
+        shutdown_token: shutdown_token.clone(),
+        metrics,
+    }
+    .build()
+    .unwrap();
+
+    // one nonce query per lane
+    let first_nonce_guard = mount_default_nonce_query_mock(&sequencer).await;
+    let second_nonce_guard = mount_default_nonce_query_mock(&sequencer).await;
+    let status = executor.subscribe();
+
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, first_nonce_guard).await.unwrap();
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        second_nonce_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+
+    let first_response_guard = mount_broadcast_tx_sync_seq_actions_mock(&sequencer).await;
+    let second_response_guard = mount_broadcast_tx_sync_seq_actions_mock(&sequencer).await;
+
+    // send three sequence actions that each fill a bundle, so that the first two bundles are
+    // finished and submitted right away
+    for rollup in 0..3 {
+        let seq_action = SequenceAction {
+            rollup_id: RollupId::new([rollup; ROLLUP_ID_LEN]),
+            ..sequence_action_of_max_size(cfg.max_bytes_per_bundle)
+        };
+        executor_handle
+            .send_timeout(seq_action, Duration::from_millis(1000))
+            .await
+            .unwrap();
+    }
+
+    // wait for the mock sequencer to receive both signed transactions
+    tokio::time::timeout(Duration::from_millis(100), async {
+        first_response_guard.wait_until_satisfied().await;
+        second_response_guard.wait_until_satisfied().await;
+    })
+    .await
+    .unwrap();
+
+    // verify the two transactions were signed by different keys, both with the initial nonce
+    let mut requests = first_response_guard.received_requests().await;
+    requests.extend(second_response_guard.received_requests().await);
+    assert_eq!(requests.len(), 2);
+    let first_tx = signed_tx_from_request(&requests[0]);
+    let second_tx = signed_tx_from_request(&requests[1]);
+    assert_ne!(first_tx.address_bytes(), second_tx.address_bytes());
+    assert_eq!(first_tx.nonce(), 0);
+    assert_eq!(second_tx.nonce(), 0);
+}
+
+/// Test to check that a rollup that has used up its quota of bytes waiting to be bundled has its
+/// sequence actions rejected, while other rollups can still send theirs.
+#[tokio::test]
+async fn rollup_over_quota_is_rejected() {
+    let (_sequencer, mut cfg, _keyfile) = setup().await;
+    cfg.bundle_queue_capacity = 1;
+    let shutdown_token = CancellationToken::new();
+    let metrics = Box::leak(Box::new(Metrics::noop_metrics(&cfg).unwrap()));
+    // the executor is not run, so that all sent sequence actions stay in the channel
+    let (_executor, executor_handle) = executor::Builder {
+        sequencer_url: cfg.sequencer_url.clone(),
+        sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        private_key_files: vec![cfg.private_key_file.clone()],
+        sequencer_address_prefix: cfg.sequencer_address_prefix.clone(),
+        block_time_ms: cfg.block_time_ms,
+        max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+        bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
+        shutdown_token: shutdown_token.clone(),
+        metrics,
+    }
+    .build()
+    .unwrap();
+
+    let seq_action_for_rollup = |rollup| SequenceAction {
+        rollup_id: RollupId::new([rollup; ROLLUP_ID_LEN]),
+        ..sequence_action_of_max_size(cfg.max_bytes_per_bundle)
+    };
+
+    // the rollup's quota is the current bundle plus one finished bundle
+    for _ in 0..2 {
+        executor_handle
+            .send_timeout(seq_action_for_rollup(0), Duration::from_millis(100))
+            .await
+            .unwrap();
+    }
+    let err = executor_handle
+        .send_timeout(seq_action_for_rollup(0), Duration::from_millis(100))
+        .await
+        .expect_err("the rollup's quota should be used up");
+    assert!(
+        matches!(err, SendError::RollupQuotaFull { .. }),
+        "expected `SendError::RollupQuotaFull`, but got `{err:?}`"
+    );
+
+    executor_handle
+        .send_timeout(seq_action_for_rollup(1), Duration::from_millis(100))
+        .await
+        .unwrap();
+}
+
+/// Test to check that a transaction dropped because the finished bundle queue is full is not
+/// dropped as a duplicate when it is re-broadcast.
+#[tokio::test]
+async fn transaction_dropped_on_full_queue_can_be_rebroadcast() {
+    let (_sequencer, cfg, _keyfile) = setup().await;
+    let shutdown_token = CancellationToken::new();
+    let metrics = Box::leak(Box::new(Metrics::noop_metrics(&cfg).unwrap()));
+    let (executor, _executor_handle) = executor::Builder {
+        sequencer_url: cfg.sequencer_url.clone(),
+        sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        private_key_files: vec![cfg.private_key_file.clone()],
+        sequencer_address_prefix: cfg.sequencer_address_prefix.clone(),
+        block_time_ms: cfg.block_time_ms,
+        max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+        bundle_queue_capacity: cfg.bundle_queue_capacity,
+        fairness: Fairness::default(),
+        tx_filter: TxFilter::default(),
+        shutdown_token: shutdown_token.clone(),
+        metrics,
+    }
+    .build()
+    .unwrap();
+
+    let mut tx_filter = TxFilter::new(10, vec![]);
+    let first = SequenceAction {
+        data: Bytes::from_static(b"first"),
+        ..sequence_action()
+    };
+    let second = SequenceAction {
+        data: Bytes::from_static(b"second"),
+        ..sequence_action()
+    };
+
+    // without room for finished bundles, only a single action can be queued
+    let mut full_bundle_factory = BundleFactory::new(cfg.max_bytes_per_bundle, 0);
+    executor.queue_seq_action(first.clone(), &mut full_bundle_factory, &mut tx_filter);
+    executor.queue_seq_action(second.clone(), &mut full_bundle_factory, &mut tx_filter);
+    assert_eq!(
+        tx_filter.check(&first).err(),
+        Some(DropReason::Duplicate),
+        "queued transactions should be remembered"
+    );
+    assert!(
+        tx_filter.check(&second).is_ok(),
+        "dropped transactions should not be remembered"
+    );
+
+    // the re-broadcast transaction is queued once there is room
+    let mut bundle_factory = BundleFactory::new(cfg.max_bytes_per_bundle, 1);
+    executor.queue_seq_action(second.clone(), &mut bundle_factory, &mut tx_filter);
+    assert_eq!(
+        bundle_factory.queue_depths().collect::<Vec<_>>(),
+        vec![(&second.rollup_id, 1)]
+    );
+    assert_eq!(tx_filter.check(&second).err(), Some(DropReason::Duplicate));
+}
@@ -46,6 +46,7 @@ pub mod config;
 mod executor;
 mod grpc;
 pub(crate) mod metrics;
+mod reload;
 mod rollup;
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -55,4 +56,5 @@ pub use build_info::BUILD_INFO;
 pub use composer::Composer;
 pub use config::Config;
 pub use metrics::Metrics;
+pub use reload::ReloadHandle;
 pub use telemetry;
@@ -6,9 +6,11 @@ use astria_composer::{
     BUILD_INFO,
 };
 use astria_eyre::eyre::WrapErr as _;
+use config::reload::Reloader;
 use tracing::{
     error,
     info,
+    warn,
 };
 
 #[tokio::main]
@@ -20,10 +22,17 @@ async fn main() -> ExitCode {
         serde_json::to_string(&BUILD_INFO)
             .expect("build info is serializable because it contains only unicode fields")
     );
-    let cfg: Config = match config::get() {
+    let config_file = match config::config_file_from_args() {
+        Ok(config_file) => config_file,
+        Err(e) => {
+            eprintln!("failed to read configuration:\n{e:?}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let cfg: Config = match config::get_with_file(config_file.as_deref()) {
         Ok(cfg) => cfg,
         Err(e) => {
-            eprintln!("failed to read configuration: {e}");
+            eprintln!("failed to read configuration:\n{e:?}");
             return ExitCode::FAILURE;
         }
     };
@@ -39,7 +48,7 @@ async fn main() -> ExitCode {
             telemetry_conf.set_metrics(&cfg.metrics_http_listener_addr, env!("CARGO_PKG_NAME"));
     }
 
-    let (metrics, _telemetry_guard) = match telemetry_conf
+    let (metrics, telemetry_guard) = match telemetry_conf
         .try_init(&cfg)
         .wrap_err("failed to setup telemetry")
     {
@@ -63,6 +72,34 @@ async fn main() -> ExitCode {
         Ok(composer) => composer,
     };
 
+    let reload_handle = composer.reload_handle();
+    let mut reloader = match Reloader::<Config>::new(config_file, reload_handle.trigger()) {
+        Err(error) => {
+            error!(%error, "failed installing SIGHUP listener to reload config");
+            return ExitCode::FAILURE;
+        }
+        Ok(reloader) => reloader,
+    };
+    let filter_handle = telemetry_guard.filter_handle();
+    tokio::spawn(async move {
+        loop {
+            match reloader.reload().await {
+                Ok(cfg) => match reload_handle.apply(&cfg, Some(&filter_handle)).await {
+                    Ok(true) => info!("applied reloaded config"),
+                    Ok(false) => info!("reloaded config did not change log or rollups"),
+                    Err(error) => {
+                        warn!(
+                            %error,
+                            "failed applying reloaded config; keeping the active config of the \
+                             affected fields"
+                        );
+                    }
+                },
+                Err(error) => warn!(?error, "failed reloading config; keeping active config"),
+            }
+        }
+    });
+
     return match composer.run_until_stopped().await {
         Ok(()) => {
             info!("composer stopped");
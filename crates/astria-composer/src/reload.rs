@@ -0,0 +1,230 @@
+//! Changing the log level and the rollups of a running composer without a restart.
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use config::reload::ReloadTrigger;
+use serde::Serialize;
+use tokio::sync::{
+    mpsc,
+    oneshot,
+    watch,
+};
+
+use crate::{
+    rollup::CollectorEndpoint,
+    Config,
+};
+
+/// The subset of the config that can be changed while composer is running.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct ReloadableConfig {
+    pub(crate) log: String,
+    pub(crate) rollups: HashMap<String, CollectorEndpoint>,
+}
+
+impl ReloadableConfig {
+    fn from_config(cfg: &Config) -> eyre::Result<Self> {
+        Ok(Self {
+            log: cfg.log.clone(),
+            rollups: cfg.parse_rollups().wrap_err("failed parsing rollups")?,
+        })
+    }
+}
+
+/// A request to the running composer to replace its rollups.
+///
+/// Composer replies on `result` once it started the collectors of all changed rollups, or with
+/// the rollups whose collectors could not be built.
+pub(crate) struct RollupsReload {
+    pub(crate) rollups: HashMap<String, CollectorEndpoint>,
+    pub(crate) result: oneshot::Sender<eyre::Result<()>>,
+}
+
+/// A handle to apply a reloaded config to a running composer.
+///
+/// Only the log level and the rollups are applied; changes to all other fields require a
+/// restart. Rollups added at runtime are not reported in per-rollup metrics until the next
+/// restart.
+#[derive(Clone)]
+pub struct ReloadHandle {
+    trigger: ReloadTrigger,
+    active: Arc<watch::Sender<ReloadableConfig>>,
+    rollups_reloads: mpsc::Sender<RollupsReload>,
+}
+
+impl ReloadHandle {
+    /// Returns the handle together with the receiver of the rollups to be applied by composer.
+    pub(crate) fn new(cfg: &Config) -> eyre::Result<(Self, mpsc::Receiver<RollupsReload>)> {
+        let (active, _) = watch::channel(ReloadableConfig::from_config(cfg)?);
+        let (rollups_reloads, rollups_reloads_rx) = mpsc::channel(1);
+        Ok((
+            Self {
+                trigger: ReloadTrigger::new(),
+                active: Arc::new(active),
+                rollups_reloads,
+            },
+            rollups_reloads_rx,
+        ))
+    }
+
+    /// Returns the trigger that is fired by the `/reload` endpoint of the API server.
+    #[must_use]
+    pub fn trigger(&self) -> ReloadTrigger {
+        self.trigger.clone()
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<ReloadableConfig> {
+        self.active.subscribe()
+    }
+
+    /// Records the rollups that composer is running collectors for.
+    pub(crate) fn set_active_rollups(&self, rollups: HashMap<String, CollectorEndpoint>) {
+        self.active.send_modify(|active| active.rollups = rollups);
+    }
+
+    /// Validates the reloadable fields of `cfg` and applies them to composer.
+    ///
+    /// Nothing is applied if `cfg` is invalid. Rollups whose collectors cannot be built keep
+    /// their previous collector, if any, while all other changes are applied. Returns `false` if
+    /// nothing changed.
+    ///
+    /// # Errors
+    /// Returns an error if the rollups or the log filter directives of `cfg` are invalid, or if
+    /// the collector of any changed rollup could not be built.
+    pub async fn apply(
+        &self,
+        cfg: &Config,
+        filter: Option<&telemetry::FilterHandle>,
+    ) -> eyre::Result<bool> {
+        let ReloadableConfig {
+            log,
+            rollups,
+        } = ReloadableConfig::from_config(cfg)?;
+        let active = self.active.borrow().clone();
+        if active.log == log && active.rollups == rollups {
+            return Ok(false);
+        }
+        if let Some(filter) = filter {
+            if filter.filter_directives() != log {
+                filter
+                    .set_filter_directives(&log)
+                    .wrap_err("failed applying log filter directives")?;
+            }
+        }
+        self.active.send_modify(|active| active.log = log);
+        if active.rollups != rollups {
+            let (result, result_rx) = oneshot::channel();
+            self.rollups_reloads
+                .send(RollupsReload {
+                    rollups,
+                    result,
+                })
+                .await
+                .map_err(|_| eyre!("composer is not running"))?;
+            result_rx
+                .await
+                .wrap_err("composer stopped before applying the reloaded rollups")??;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use config::MaybeEncoded;
+
+    use super::*;
+    use crate::rollup::CollectorKind;
+
+    fn config_with_rollups(rollups: &str) -> Config {
+        let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        file.write_all(include_bytes!("../config.example.toml"))
+            .unwrap();
+        let mut cfg: Config = config::get_with_file(Some(file.path())).unwrap();
+        cfg.rollups = MaybeEncoded::Encoded(rollups.to_string());
+        cfg
+    }
+
+    /// Stands in for the composer run loop, applying all rollups except `failing`.
+    fn spawn_composer(
+        handle: ReloadHandle,
+        mut rollups_reloads: mpsc::Receiver<RollupsReload>,
+        failing: &'static str,
+    ) {
+        tokio::spawn(async move {
+            while let Some(RollupsReload {
+                mut rollups,
+                result,
+            }) = rollups_reloads.recv().await
+            {
+                let failed = rollups.remove(failing).is_some();
+                handle.set_active_rollups(rollups);
+                let _ = result.send(if failed {
+                    Err(eyre!("failed building collector of `{failing}`"))
+                } else {
+                    Ok(())
+                });
+            }
+        });
+    }
+
+    #[tokio::test]
+    async fn changed_rollups_are_applied() {
+        let (handle, rollups_reloads) =
+            ReloadHandle::new(&config_with_rollups("a::ws://a")).unwrap();
+        spawn_composer(handle.clone(), rollups_reloads, "c");
+        let mut active = handle.subscribe();
+
+        assert!(!handle
+            .apply(&config_with_rollups("a::ws://a"), None)
+            .await
+            .unwrap());
+        assert!(!active.has_changed().unwrap());
+
+        assert!(handle
+            .apply(&config_with_rollups("a::ws://a,b::txpool::http://b"), None)
+            .await
+            .unwrap());
+        let rollups = active.borrow_and_update().rollups.clone();
+        assert_eq!(rollups.len(), 2);
+        assert_eq!(rollups["b"].kind, CollectorKind::Txpool);
+    }
+
+    #[tokio::test]
+    async fn rollups_that_failed_to_apply_are_reported() {
+        let (handle, rollups_reloads) =
+            ReloadHandle::new(&config_with_rollups("a::ws://a")).unwrap();
+        spawn_composer(handle.clone(), rollups_reloads, "c");
+
+        handle
+            .apply(&config_with_rollups("a::ws://a,c::ws://c"), None)
+            .await
+            .unwrap_err();
+        let rollups = handle.subscribe().borrow().rollups.clone();
+        assert_eq!(rollups.len(), 1);
+        assert!(rollups.contains_key("a"));
+    }
+
+    #[tokio::test]
+    async fn invalid_rollups_are_rejected() {
+        let (handle, _rollups_reloads) =
+            ReloadHandle::new(&config_with_rollups("a::ws://a")).unwrap();
+        let active = handle.subscribe();
+        handle
+            .apply(&config_with_rollups("a:ws://a"), None)
+            .await
+            .unwrap_err();
+        assert!(!active.has_changed().unwrap());
+        assert_eq!(active.borrow().rollups.len(), 1);
+    }
+}
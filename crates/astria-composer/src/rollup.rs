@@ -1,15 +1,74 @@
-//! Parsing strings of the form `<rollup_name>::<url>`
+//! Parsing strings of the form `<rollup_name>::[<collector>::]<url>`
 
 use std::{
+    collections::HashMap,
     fmt,
+    str::FromStr,
     sync::LazyLock,
 };
 
 use regex::Regex;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The kind of collector that feeds a rollup's transactions to composer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectorKind {
+    /// Subscribes to geth's `newPendingTransactions` full transaction websocket stream.
+    #[default]
+    Geth,
+    /// Polls the `txpool_content` JSON-RPC method of an EVM node over HTTP.
+    Txpool,
+    /// Connects to a websocket endpoint that emits raw rollup transactions.
+    Websocket,
+    /// Binds an HTTP server that accepts raw rollup transactions as POST request bodies.
+    Http,
+}
+
+impl CollectorKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Geth => "geth",
+            Self::Txpool => "txpool",
+            Self::Websocket => "websocket",
+            Self::Http => "http",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "geth" => Some(Self::Geth),
+            "txpool" => Some(Self::Txpool),
+            "websocket" => Some(Self::Websocket),
+            "http" => Some(Self::Http),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CollectorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// The collector and its endpoint configured for a rollup.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CollectorEndpoint {
+    #[serde(rename = "collector", default)]
+    pub kind: CollectorKind,
+    /// The URL the collector connects to or, for [`CollectorKind::Http`], the socket address
+    /// it listens on.
+    pub url: String,
+}
 
 #[derive(Debug)]
 pub(super) struct Rollup {
     rollup_name: String,
+    collector: CollectorKind,
     url: String,
 }
 
@@ -17,7 +76,7 @@ pub(super) struct Rollup {
 pub struct ParseError {}
 
 impl ParseError {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {}
     }
 }
@@ -25,14 +84,80 @@ impl ParseError {
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad(
-            "invalid rollup definition, must be `<chainid>::<url>, with <chainid> being \
-             alphanumeric ascii and -",
+            "invalid rollup definition, must be `<chainid>::[<collector>::]<url>, with <chainid> \
+             being alphanumeric ascii and - and <collector> one of geth, txpool, websocket, http",
         )
     }
 }
 
 impl std::error::Error for ParseError {}
 
+#[derive(Debug)]
+pub struct ParseValueError {
+    item: String,
+}
+
+impl fmt::Display for ParseValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid rollup setting `{}`, must be `<chainid>::<value>`, with <chainid> being \
+             alphanumeric ascii and - and <value> valid for the setting",
+            self.item,
+        )
+    }
+}
+
+impl std::error::Error for ParseValueError {}
+
+/// Parses a comma separated list of `<rollup_name>::<value>` pairs into a map of lowercased
+/// rollup names to values.
+pub(super) fn parse_rollup_values<T: FromStr>(
+    list: &str,
+) -> Result<HashMap<String, T>, ParseValueError> {
+    parse_rollup_pairs(list).collect()
+}
+
+/// Parses a comma separated list of `<rollup_name>::<value>` pairs into pairs of lowercased
+/// rollup names and values. The same rollup name may occur several times.
+pub(super) fn parse_rollup_pairs<T: FromStr>(
+    list: &str,
+) -> impl Iterator<Item = Result<(String, T), ParseValueError>> + '_ {
+    list.split(',').filter(|s| !s.is_empty()).map(|item| {
+        let err = || ParseValueError {
+            item: item.to_string(),
+        };
+        let (rollup_name, value) = item.split_once("::").ok_or_else(err)?;
+        let rollup_name = normalize_rollup_name(rollup_name).ok_or_else(err)?;
+        let value = value.trim().parse().map_err(|_| err())?;
+        Ok((rollup_name, value))
+    })
+}
+
+/// Lowercases the rollup names of structured, per-rollup settings read from a config file.
+pub(super) fn normalize_rollup_values<'a, T: Clone + 'a>(
+    values: impl IntoIterator<Item = (&'a String, &'a T)>,
+) -> impl Iterator<Item = Result<(String, T), ParseValueError>> {
+    values.into_iter().map(|(rollup_name, value)| {
+        let rollup_name = normalize_rollup_name(rollup_name).ok_or_else(|| ParseValueError {
+            item: rollup_name.clone(),
+        })?;
+        Ok((rollup_name, value.clone()))
+    })
+}
+
+/// Returns the lowercased rollup name, or `None` if it is not alphanumeric ascii and dashes.
+fn normalize_rollup_name(rollup_name: &str) -> Option<String> {
+    if rollup_name.is_empty()
+        || !rollup_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return None;
+    }
+    Some(rollup_name.to_lowercase())
+}
+
 impl Rollup {
     pub(super) fn parse(from: &str) -> Result<Self, ParseError> {
         static ROLLUP_RE: LazyLock<Regex> = LazyLock::new(|| {
@@ -42,6 +167,9 @@ impl Rollup {
                     # lazily match all alphanumeric ascii and dash;
                     # case insignificant, but we will lowercase later
                 ::
+                (?:(?P<collector>[[:alpha:]][[:alnum:]_-]*)::)?
+                    # optionally match the collector kind; defaults to geth if not
+                    # provided and must be one of the known kinds otherwise
                 (?P<url>.+)
                     # treat all following chars as the url without any verification;
                     # if there are bad chars, the downstream URL parser should
@@ -56,19 +184,31 @@ impl Rollup {
         // accesses will always be correct because the regex will only
         // match when these capture groups match.
         let rollup_name = caps["rollup_name"].to_string().to_lowercase();
+        let collector = match caps.name("collector") {
+            Some(kind) => CollectorKind::from_name(kind.as_str()).ok_or_else(ParseError::new)?,
+            None => CollectorKind::default(),
+        };
         let url = caps["url"].to_string();
         Ok(Self {
             rollup_name,
+            collector,
             url,
         })
     }
 
-    pub(super) fn into_parts(self) -> (String, String) {
+    pub(super) fn into_parts(self) -> (String, CollectorEndpoint) {
         let Self {
             rollup_name,
+            collector,
             url,
         } = self;
-        (rollup_name, url)
+        (
+            rollup_name,
+            CollectorEndpoint {
+                kind: collector,
+                url,
+            },
+        )
     }
 }
 
@@ -134,4 +274,48 @@ mod tests {
         assert_eq!(rollups[0].rollup_name, "chain-1");
         assert_eq!(rollups[0].url, ":http://some.url");
     }
+
+    #[test]
+    fn parse_without_collector_defaults_to_geth() {
+        let rollups = expect_parse_rollups("chain-1::ws://some.url");
+        assert_eq!(rollups[0].collector, CollectorKind::Geth);
+        assert_eq!(rollups[0].url, "ws://some.url");
+    }
+
+    #[test]
+    fn parse_rollups_with_collectors_valid() {
+        let rollups = expect_parse_rollups(
+            "a::geth::ws://a,b::txpool::http://b,c::websocket::ws://c,d::http::[::1]:9000",
+        );
+        assert_eq!(rollups.len(), 4, "\nparsed: {rollups:#?}");
+        assert_eq!(rollups[0].collector, CollectorKind::Geth);
+        assert_eq!(rollups[0].url, "ws://a");
+        assert_eq!(rollups[1].collector, CollectorKind::Txpool);
+        assert_eq!(rollups[1].url, "http://b");
+        assert_eq!(rollups[2].collector, CollectorKind::Websocket);
+        assert_eq!(rollups[2].url, "ws://c");
+        assert_eq!(rollups[3].collector, CollectorKind::Http);
+        assert_eq!(rollups[3].url, "[::1]:9000");
+    }
+
+    #[test]
+    fn parse_rollup_values_valid() {
+        let values: HashMap<String, u32> = parse_rollup_values("Chain-1::3,other::1").unwrap();
+        assert_eq!(values.len(), 2, "\nparsed: {values:#?}");
+        assert_eq!(values["chain-1"], 3);
+        assert_eq!(values["other"], 1);
+    }
+
+    #[test]
+    fn parse_rollup_values_with_invalid_value_fails() {
+        parse_rollup_values::<u32>("chain-1::-3").unwrap_err();
+        parse_rollup_values::<u32>("chain_1::3").unwrap_err();
+        parse_rollup_values::<u32>("chain-1:3").unwrap_err();
+    }
+
+    #[should_panic(expected = "rollup 'chain-1::reth::http://some.url' should parse: ParseError")]
+    #[test]
+    fn parse_with_unknown_collector_fails() {
+        expect_parse_rollups("chain-1::reth::http://some.url");
+    }
 }
@@ -28,6 +28,7 @@ use astria_core::{
     },
 };
 use astria_eyre::eyre;
+use config::MaybeEncoded;
 use ethers::prelude::Transaction;
 use telemetry::metrics;
 use tempfile::NamedTempFile;
@@ -58,8 +59,16 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
         api_listen_addr: SocketAddr::new(IpAddr::from([0, 0, 0, 0]), 0),
         sequencer_url: String::new(),
         sequencer_chain_id: String::new(),
-        rollups: String::new(),
+        rollups: MaybeEncoded::default(),
+        txpool_poll_interval_ms: 0,
+        rollup_weights: MaybeEncoded::default(),
+        rollup_bundle_quotas: MaybeEncoded::default(),
+        dedup_cache_size: 0,
+        max_tx_bytes: 0,
+        sender_allow_list: MaybeEncoded::default(),
+        sender_deny_list: MaybeEncoded::default(),
         private_key_file: String::new(),
+        additional_private_key_files: MaybeEncoded::default(),
         sequencer_address_prefix: String::new(),
         block_time_ms: 0,
         max_bytes_per_bundle: 0,
@@ -107,8 +116,6 @@ pub struct TestComposer {
 /// There is no explicit error handling in favour of panicking loudly
 /// and early.
 pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
-    LazyLock::force(&TELEMETRY);
-
     let mut rollup_nodes = HashMap::new();
     let mut rollups = String::new();
     for id in rollup_ids {
@@ -117,6 +124,21 @@ pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
         rollup_nodes.insert((*id).to_string(), geth);
         rollups.push_str(&format!("{id}::{execution_url},"));
     }
+    spawn_composer_with_rollups(rollups, rollup_nodes).await
+}
+
+/// Spawns composer in a test environment with the rollups and their collectors given by
+/// `rollups`, formatted like `ASTRIA_COMPOSER_ROLLUPS`.
+///
+/// # Panics
+/// There is no explicit error handling in favour of panicking loudly
+/// and early.
+pub async fn spawn_composer_with_rollups(
+    rollups: String,
+    rollup_nodes: HashMap<String, Geth>,
+) -> TestComposer {
+    LazyLock::force(&TELEMETRY);
+
     let (sequencer, sequencer_setup_guard) = mock_sequencer::start().await;
     let sequencer_url = sequencer.uri();
     let keyfile = NamedTempFile::new().unwrap();
@@ -127,9 +149,17 @@ pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
         log: String::new(),
         api_listen_addr: "127.0.0.1:0".parse().unwrap(),
         sequencer_chain_id: "test-chain-1".to_string(),
-        rollups,
+        rollups: MaybeEncoded::Encoded(rollups),
+        txpool_poll_interval_ms: 100,
+        rollup_weights: MaybeEncoded::default(),
+        rollup_bundle_quotas: MaybeEncoded::default(),
+        dedup_cache_size: 0,
+        max_tx_bytes: 0,
+        sender_allow_list: MaybeEncoded::default(),
+        sender_deny_list: MaybeEncoded::default(),
         sequencer_url,
         private_key_file: keyfile.path().to_string_lossy().to_string(),
+        additional_private_key_files: MaybeEncoded::default(),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: 2000,
         max_bytes_per_bundle: 200_000,
@@ -169,6 +199,43 @@ pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
     }
 }
 
+/// Mounts a `txpool_content` JSON-RPC response on `rollup_node` whose pending pool contains
+/// `pending`.
+///
+/// # Panics
+/// Panics if `pending` cannot be serialized to JSON.
+pub async fn mount_txpool_content(
+    rollup_node: &MockServer,
+    pending: Vec<Transaction>,
+) -> MockGuard {
+    use wiremock::matchers::body_partial_json;
+
+    let mut pool = serde_json::Map::new();
+    for tx in pending {
+        let txs_by_nonce = pool
+            .entry(format!("{:?}", tx.from))
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        txs_by_nonce
+            .as_object_mut()
+            .unwrap()
+            .insert(tx.nonce.to_string(), serde_json::to_value(&tx).unwrap());
+    }
+    let result = serde_json::json!({ "pending": pool, "queued": {} });
+    Mock::given(body_partial_json(
+        serde_json::json!({ "jsonrpc": "2.0", "method": "txpool_content" }),
+    ))
+    .respond_with(move |request: &Request| {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": body.get("id"),
+            "result": result,
+        }))
+    })
+    .mount_as_scoped(rollup_node)
+    .await
+}
+
 /// Query composer's `/readyz` endpoint until its ready.
 ///
 /// # Panics
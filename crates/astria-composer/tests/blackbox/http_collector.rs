@@ -0,0 +1,51 @@
+use std::{
+    collections::HashMap,
+    net::TcpListener,
+    time::Duration,
+};
+
+use ethers::types::Transaction;
+
+use crate::helper::{
+    mount_matcher_verifying_tx_integrity,
+    spawn_composer_with_rollups,
+    TEST_ETH_TX_JSON,
+};
+
+#[tokio::test]
+async fn raw_tx_posted_to_http_collector_is_received_by_sequencer() {
+    // reserve a free port for the http collector to bind to
+    let collector_addr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+
+    let test_composer =
+        spawn_composer_with_rollups(format!("test1::http::{collector_addr}"), HashMap::new()).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let tx: Transaction = serde_json::from_str(TEST_ETH_TX_JSON).unwrap();
+    let mock_guard =
+        mount_matcher_verifying_tx_integrity(&test_composer.sequencer, tx.clone()).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{collector_addr}/"))
+        .body(tx.rlp().to_vec())
+        .send()
+        .await
+        .expect("the http collector should have accepted the request");
+    assert_eq!(reqwest::StatusCode::OK, response.status());
+
+    // wait for 1 sequencer block time to make sure the bundle is preempted
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+}
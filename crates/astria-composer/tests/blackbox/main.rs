@@ -2,3 +2,5 @@ pub mod api;
 pub mod geth_collector;
 mod grpc_collector;
 pub mod helper;
+mod http_collector;
+mod txpool_collector;
@@ -0,0 +1,51 @@
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+use ethers::types::Transaction;
+use wiremock::MockServer;
+
+use crate::helper::{
+    mount_matcher_verifying_tx_integrity,
+    mount_txpool_content,
+    spawn_composer_with_rollups,
+    TEST_ETH_TX_JSON,
+};
+
+#[tokio::test]
+async fn pending_tx_from_txpool_is_received_by_sequencer() {
+    let rollup_node = MockServer::start().await;
+    // composer only becomes ready once the txpool collector has polled the node successfully
+    let empty_txpool = mount_txpool_content(&rollup_node, vec![]).await;
+
+    let test_composer = spawn_composer_with_rollups(
+        format!("test1::txpool::{}", rollup_node.uri()),
+        HashMap::new(),
+    )
+    .await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let tx: Transaction = serde_json::from_str(TEST_ETH_TX_JSON).unwrap();
+    let mock_guard =
+        mount_matcher_verifying_tx_integrity(&test_composer.sequencer, tx.clone()).await;
+
+    drop(empty_txpool);
+    let _txpool = mount_txpool_content(&rollup_node, vec![tx]).await;
+
+    // wait for 1 sequencer block time plus a poll interval to make sure the bundle is preempted.
+    // the transaction stays in the pool across polls, but must only be submitted once.
+    tokio::time::timeout(
+        Duration::from_millis(
+            test_composer.cfg.block_time_ms + test_composer.cfg.txpool_poll_interval_ms,
+        ),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+}
@@ -8,6 +8,7 @@ use astria_eyre::eyre::{
 };
 use jsonrpsee::http_client::HttpClient as CelestiaClient;
 use tendermint_rpc::HttpClient as SequencerClient;
+use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 
 use super::Reader;
@@ -17,12 +18,13 @@ use crate::{
 };
 
 pub(crate) struct Builder {
+    pub(crate) catch_up_concurrency: usize,
     pub(crate) celestia_block_time: Duration,
     pub(crate) celestia_http_endpoint: String,
     pub(crate) celestia_token: Option<String>,
     pub(crate) executor: executor::Handle,
     pub(crate) sequencer_cometbft_client: SequencerClient,
-    pub(crate) sequencer_requests_per_second: u32,
+    pub(crate) sequencer_requests_per_second: watch::Receiver<u32>,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -31,6 +33,7 @@ impl Builder {
     /// Creates a new [`Reader`] instance,
     pub(crate) fn build(self) -> eyre::Result<Reader> {
         let Self {
+            catch_up_concurrency,
             celestia_block_time,
             celestia_http_endpoint,
             celestia_token,
@@ -45,6 +48,7 @@ impl Builder {
             .wrap_err("failed initializing client for Celestia HTTP RPC")?;
 
         Ok(Reader {
+            catch_up_concurrency,
             celestia_block_time,
             celestia_client,
             executor,
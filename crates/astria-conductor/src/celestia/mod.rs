@@ -36,7 +36,10 @@ use telemetry::display::{
 };
 use tokio::{
     select,
-    sync::mpsc,
+    sync::{
+        mpsc,
+        watch,
+    },
     task::spawn_blocking,
     try_join,
 };
@@ -123,7 +126,15 @@ pub(super) struct ReconstructedBlocks {
     blocks: Vec<ReconstructedBlock>,
 }
 
+/// The maximum number of Celestia heights that are fetched and reconstructed concurrently
+/// outside of catch-up mode.
+const MAX_CONCURRENT_RECONSTRUCTION_TASKS: usize = 10;
+
 pub(crate) struct Reader {
+    /// The maximum number of Celestia heights that are fetched and reconstructed concurrently
+    /// while the executor is in catch-up mode.
+    catch_up_concurrency: usize,
+
     celestia_block_time: Duration,
 
     /// Client to fetch heights and blocks from Celestia.
@@ -137,7 +148,7 @@ pub(crate) struct Reader {
 
     /// The number of requests per second that will be sent to Sequencer
     /// (usually to verify block data retrieved from Celestia blobs).
-    sequencer_requests_per_second: u32,
+    sequencer_requests_per_second: watch::Receiver<u32>,
 
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
@@ -192,6 +203,13 @@ struct RunningReader {
 
     blob_verifier: Arc<BlobVerifier>,
 
+    /// The client to verify blocks, used to reconstruct `blob_verifier` if the rate limit changes.
+    sequencer_cometbft_client: SequencerClient,
+
+    /// The number of requests per second that will be sent to Sequencer. Can change while
+    /// conductor is running.
+    sequencer_requests_per_second: watch::Receiver<u32>,
+
     // Client to fetch heights and blocks from Celestia.
     celestia_client: CelestiaClient,
 
@@ -204,6 +222,9 @@ struct RunningReader {
     /// Tasks reconstructing Sequencer block information from Celestia blobs.
     reconstruction_tasks: JoinMap<u64, eyre::Result<ReconstructedBlocks>>,
 
+    /// The maximum number of `reconstruction_tasks` while the executor is in catch-up mode.
+    catch_up_concurrency: usize,
+
     /// The stream of latest Celestia head heights (so that only Celestia blobs up to that height
     /// are fetched).
     latest_heights: LatestHeightStream,
@@ -259,11 +280,12 @@ impl RunningReader {
         sequencer_chain_id: tendermint::chain::Id,
     ) -> eyre::Result<Self> {
         let Reader {
+            catch_up_concurrency,
             celestia_block_time,
             celestia_client,
             sequencer_cometbft_client,
             shutdown,
-            sequencer_requests_per_second,
+            mut sequencer_requests_per_second,
             metrics,
             ..
         } = exposed_reader;
@@ -284,15 +306,21 @@ impl RunningReader {
         Ok(Self {
             block_cache,
             blob_verifier: Arc::new(
-                BlobVerifier::try_new(sequencer_cometbft_client, sequencer_requests_per_second)
-                    .wrap_err("failed to construct blob verifier")?,
+                BlobVerifier::try_new(
+                    sequencer_cometbft_client.clone(),
+                    *sequencer_requests_per_second.borrow_and_update(),
+                )
+                .wrap_err("failed to construct blob verifier")?,
             ),
+            sequencer_cometbft_client,
+            sequencer_requests_per_second,
             celestia_client,
             enqueued_block: Fuse::terminated(),
             executor,
             latest_heights,
             shutdown,
             reconstruction_tasks: JoinMap::new(),
+            catch_up_concurrency,
 
             celestia_head_height: None,
             celestia_next_height,
@@ -363,6 +391,10 @@ impl RunningReader {
                     self.handle_latest_height(res);
                 }
 
+                Ok(()) = self.sequencer_requests_per_second.changed() => {
+                    self.update_blob_verifier();
+                }
+
             );
         };
 
@@ -387,6 +419,26 @@ impl RunningReader {
         }
     }
 
+    /// Replaces the blob verifier by one with the changed Sequencer rate limit.
+    ///
+    /// Reconstruction tasks that are already running finish with the previous verifier.
+    #[instrument(skip_all)]
+    fn update_blob_verifier(&mut self) {
+        let requests_per_second = *self.sequencer_requests_per_second.borrow_and_update();
+        match BlobVerifier::try_new(self.sequencer_cometbft_client.clone(), requests_per_second) {
+            Ok(blob_verifier) => {
+                self.blob_verifier = Arc::new(blob_verifier);
+                info!(requests_per_second, "applied new Sequencer rate limit");
+            }
+            Err(error) => warn!(
+                %error,
+                requests_per_second,
+                "failed constructing blob verifier with new Sequencer rate limit; keeping \
+                 the previous one",
+            ),
+        }
+    }
+
     #[instrument(skip_all)]
     fn cache_reconstructed_blocks(&mut self, reconstructed: ReconstructedBlocks) {
         for block in reconstructed.blocks {
@@ -412,7 +464,8 @@ impl RunningReader {
 
         let is_next_below_head = self.celestia_next_height <= head_height;
         let is_next_in_window = self.celestia_next_height <= self.max_permitted_celestia_height();
-        let is_capacity_in_task_set = self.reconstruction_tasks.len() < 10;
+        let is_capacity_in_task_set =
+            self.reconstruction_tasks.len() < self.max_concurrent_reconstruction_tasks();
 
         is_next_below_head && is_next_in_window && is_capacity_in_task_set
     }
@@ -483,8 +536,36 @@ impl RunningReader {
     /// - `variance` the `celestia_block_variance` received from the connected rollup genesis info,
     /// - and the factor 6 based on the assumption that there are up to 6 sequencer heights stored
     ///   per Celestia height.
+    ///
+    /// While the executor is catching up the window is widened to at least
+    /// `ref_height + catch_up_concurrency` so that the configured number of Celestia heights can
+    /// be fetched concurrently.
     fn max_permitted_celestia_height(&self) -> u64 {
-        max_permitted_celestia_height(self.celestia_reference_height, self.celestia_variance)
+        let max_height =
+            max_permitted_celestia_height(self.celestia_reference_height, self.celestia_variance);
+        if self.executor.is_catching_up() {
+            let catch_up_window = u64::try_from(self.catch_up_concurrency).unwrap_or(u64::MAX);
+            max(
+                max_height,
+                self.celestia_reference_height
+                    .saturating_add(catch_up_window),
+            )
+        } else {
+            max_height
+        }
+    }
+
+    /// Returns the maximum number of concurrently running reconstruction tasks.
+    ///
+    /// This is the configured catch-up concurrency while the executor is catching up, and
+    /// [`MAX_CONCURRENT_RECONSTRUCTION_TASKS`] otherwise.
+    fn max_concurrent_reconstruction_tasks(&self) -> usize {
+        if self.executor.is_catching_up() {
+            self.catch_up_concurrency
+                .max(MAX_CONCURRENT_RECONSTRUCTION_TASKS)
+        } else {
+            MAX_CONCURRENT_RECONSTRUCTION_TASKS
+        }
     }
 
     fn record_latest_celestia_height(&mut self, height: u64) {
@@ -13,6 +13,7 @@ use pin_project_lite::pin_project;
 use sequencer_client::HttpClient;
 use tokio::{
     select,
+    sync::watch,
     time::timeout,
 };
 use tokio_util::{
@@ -97,6 +98,7 @@ impl ConductorInner {
     /// This usually happens if the actors failed to connect to their respective endpoints.
     fn new(
         cfg: Config,
+        sequencer_requests_per_second: watch::Receiver<u32>,
         metrics: &'static Metrics,
         shutdown_token: CancellationToken,
     ) -> eyre::Result<Self> {
@@ -110,6 +112,10 @@ impl ConductorInner {
             let (executor, handle) = executor::Builder {
                 mode: cfg.execution_commit_level,
                 rollup_address: cfg.execution_rpc_url,
+                sequencer_cometbft_client: sequencer_cometbft_client.clone(),
+                sequencer_block_time: Duration::from_millis(cfg.sequencer_block_time_ms),
+                catch_up_threshold: cfg.catch_up_threshold,
+                catch_up_batch_size: cfg.catch_up_execution_batch_size,
                 shutdown: shutdown_token.clone(),
                 metrics,
             }
@@ -147,12 +153,13 @@ impl ConductorInner {
             };
 
             let reader = celestia::Builder {
+                catch_up_concurrency: cfg.catch_up_celestia_concurrency,
                 celestia_http_endpoint: cfg.celestia_node_http_url,
                 celestia_token,
                 celestia_block_time: Duration::from_millis(cfg.celestia_block_time_ms),
                 executor: executor_handle.clone(),
                 sequencer_cometbft_client: sequencer_cometbft_client.clone(),
-                sequencer_requests_per_second: cfg.sequencer_requests_per_second,
+                sequencer_requests_per_second,
                 shutdown: shutdown_token.clone(),
                 metrics,
             }
@@ -201,10 +208,11 @@ impl ConductorInner {
     /// running Conductor task.
     pub(super) fn spawn(
         cfg: Config,
+        sequencer_requests_per_second: watch::Receiver<u32>,
         metrics: &'static Metrics,
         shutdown_token: CancellationToken,
     ) -> eyre::Result<InnerHandle> {
-        let conductor = Self::new(cfg, metrics, shutdown_token)?;
+        let conductor = Self::new(cfg, sequencer_requests_per_second, metrics, shutdown_token)?;
         let shutdown_token = conductor.shutdown_token.clone();
         let task = tokio::spawn(conductor.run_until_stopped());
         Ok(InnerHandle {
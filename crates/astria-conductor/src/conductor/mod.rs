@@ -21,6 +21,7 @@ use tracing::{
 
 use crate::{
     metrics::Metrics,
+    reload::ReloadHandle,
     Config,
 };
 
@@ -80,25 +81,41 @@ pub struct Conductor {
 
     /// Metrics used by tasks, necessary upon a restart.
     metrics: &'static Metrics,
+
+    /// Handle to change parts of the config at runtime. Restarts pick up the changed values.
+    reload_handle: ReloadHandle,
 }
 
 impl Conductor {
     /// Creates a new `Conductor` from a [`Config`].
     ///
     /// # Errors
-    /// Returns an error if [`ConductorInner`] could not be created.
+    /// Returns an error if the reloadable fields of `cfg` are invalid or if [`ConductorInner`]
+    /// could not be created.
     pub fn new(cfg: Config, metrics: &'static Metrics) -> eyre::Result<Self> {
         let shutdown_token = CancellationToken::new();
-        let conductor_inner_handle =
-            ConductorInner::spawn(cfg.clone(), metrics, shutdown_token.child_token())?;
+        let reload_handle = ReloadHandle::new(&cfg)?;
+        let conductor_inner_handle = ConductorInner::spawn(
+            cfg.clone(),
+            reload_handle.subscribe_to_sequencer_requests_per_second(),
+            metrics,
+            shutdown_token.child_token(),
+        )?;
         Ok(Self {
             shutdown_token,
             inner: conductor_inner_handle,
             cfg,
             metrics,
+            reload_handle,
         })
     }
 
+    /// Returns a handle to change parts of the config of the running conductor.
+    #[must_use]
+    pub fn reload_handle(&self) -> ReloadHandle {
+        self.reload_handle.clone()
+    }
+
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
         loop {
             let exit_reason = (&mut self.inner).await;
@@ -117,6 +134,8 @@ impl Conductor {
         info!("restarting conductor");
         let new_handle = ConductorInner::spawn(
             self.cfg.clone(),
+            self.reload_handle
+                .subscribe_to_sequencer_requests_per_second(),
             self.metrics,
             self.shutdown_token.child_token(),
         )
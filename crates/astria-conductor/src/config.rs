@@ -61,6 +61,19 @@ pub struct Config {
     /// The number of requests per second that will be sent to Sequencer.
     pub sequencer_requests_per_second: u32,
 
+    /// The number of Sequencer heights that the next expected firm height may trail the
+    /// latest Sequencer height before conductor starts in catch-up mode. Setting this to 0
+    /// disables catch-up mode.
+    pub catch_up_threshold: u64,
+
+    /// The maximum number of Celestia heights that are fetched and verified concurrently
+    /// in catch-up mode.
+    pub catch_up_celestia_concurrency: usize,
+
+    /// The maximum number of firm blocks that are executed against the rollup in a single
+    /// `BatchExecuteBlocks` call in catch-up mode.
+    pub catch_up_execution_batch_size: usize,
+
     /// Address of the RPC server for execution
     pub execution_rpc_url: String,
 
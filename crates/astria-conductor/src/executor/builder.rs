@@ -1,14 +1,25 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
 
 use astria_eyre::eyre::{
     self,
     WrapErr as _,
 };
-use tokio::sync::mpsc;
+use sequencer_client::{
+    HttpClient,
+    StreamLatestHeight as _,
+};
+use tokio::sync::{
+    mpsc,
+    watch,
+};
 use tokio_util::sync::CancellationToken;
 
 use super::{
     state,
+    CatchUp,
     Executor,
     Handle,
     StateNotInit,
@@ -18,9 +29,16 @@ use crate::{
     metrics::Metrics,
 };
 
+/// The capacity of the channel over which firm blocks are sent to the executor.
+const FIRM_BLOCK_CHANNEL_CAPACITY: usize = 16;
+
 pub(crate) struct Builder {
     pub(crate) mode: CommitLevel,
     pub(crate) rollup_address: String,
+    pub(crate) sequencer_cometbft_client: HttpClient,
+    pub(crate) sequencer_block_time: Duration,
+    pub(crate) catch_up_threshold: u64,
+    pub(crate) catch_up_batch_size: usize,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -30,6 +48,10 @@ impl Builder {
         let Self {
             mode,
             rollup_address,
+            sequencer_cometbft_client,
+            sequencer_block_time,
+            catch_up_threshold,
+            catch_up_batch_size,
             shutdown,
             metrics,
         } = self;
@@ -41,10 +63,24 @@ impl Builder {
             )
         })?;
 
+        // Catch-up mode only applies to firm blocks and is disabled by setting its threshold to 0.
+        let catch_up = (mode.is_with_firm() && catch_up_threshold > 0).then(|| CatchUp {
+            threshold: catch_up_threshold,
+            batch_size: catch_up_batch_size.max(1),
+            latest_sequencer_heights: sequencer_cometbft_client
+                .stream_latest_height(sequencer_block_time),
+        });
+
         let mut firm_block_tx = None;
         let mut firm_block_rx = None;
         if mode.is_with_firm() {
-            let (tx, rx) = mpsc::channel(16);
+            // Make room for a full batch of firm blocks when catching up.
+            let capacity = catch_up
+                .as_ref()
+                .map_or(FIRM_BLOCK_CHANNEL_CAPACITY, |catch_up| {
+                    catch_up.batch_size.max(FIRM_BLOCK_CHANNEL_CAPACITY)
+                });
+            let (tx, rx) = mpsc::channel(capacity);
             firm_block_tx = Some(tx);
             firm_block_rx = Some(rx);
         }
@@ -58,6 +94,9 @@ impl Builder {
         }
 
         let (state_tx, state_rx) = state::channel();
+        // The executor starts in normal mode and decides on catch-up mode once it has compared the
+        // next expected firm height against the latest Sequencer height.
+        let (catching_up_tx, catching_up_rx) = watch::channel(false);
 
         let executor = Executor {
             client,
@@ -72,12 +111,15 @@ impl Builder {
             blocks_pending_finalization: HashMap::new(),
 
             max_spread: None,
+            catch_up,
+            catching_up: catching_up_tx,
             metrics,
         };
         let handle = Handle {
             firm_blocks: firm_block_tx,
             soft_blocks: soft_block_tx,
             state: state_rx,
+            catching_up: catching_up_rx,
             _state_init: StateNotInit,
         };
         Ok((executor, handle))
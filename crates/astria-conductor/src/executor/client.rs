@@ -151,6 +151,75 @@ impl Client {
         Ok(block)
     }
 
+    /// Calls remote procedure `astria.execution.v1alpha2.BatchExecuteBlocks`
+    ///
+    /// Returns `None` if the rollup does not implement the RPC, i.e. if it
+    /// responded with `Code::Unimplemented`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prev_block_hash` - Block hash of the parent of the first block in `blocks`
+    /// * `blocks` - List of transactions and timestamps of each block to execute, in order
+    #[instrument(skip_all, fields(uri = %self.uri, num_of_blocks = blocks.len()), err)]
+    pub(super) async fn batch_execute_blocks_with_retry(
+        &mut self,
+        prev_block_hash: Bytes,
+        blocks: Vec<(Vec<Bytes>, Timestamp)>,
+    ) -> eyre::Result<Option<Vec<Block>>> {
+        use prost::Message;
+
+        let blocks = blocks
+            .into_iter()
+            .map(|(transactions, timestamp)| {
+                let transactions = transactions
+                    .into_iter()
+                    .map(RollupData::decode)
+                    .collect::<Result<_, _>>()?;
+                Ok(raw::ExecutableBlock {
+                    transactions,
+                    timestamp: Some(timestamp),
+                })
+            })
+            .collect::<Result<Vec<_>, prost::DecodeError>>()
+            .wrap_err("failed to decode tx bytes as RollupData")?;
+        let num_requested = blocks.len();
+
+        let request = raw::BatchExecuteBlocksRequest {
+            prev_block_hash,
+            blocks,
+        };
+        let response = match tryhard::retry_fn(|| {
+            let mut client = self.inner.clone();
+            let request = request.clone();
+            async move { client.batch_execute_blocks(request).await }
+        })
+        .with_config(retry_config())
+        .in_current_span()
+        .await
+        {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == tonic::Code::Unimplemented => return Ok(None),
+            Err(status) => {
+                return Err(status).wrap_err(
+                    "failed to execute astria.execution.v1alpha2.BatchExecuteBlocks RPC because \
+                     of gRPC status code or because number of retries were exhausted",
+                );
+            }
+        };
+        ensure!(
+            num_requested == response.blocks.len(),
+            "requested execution of `{num_requested}` blocks, but received `{}`",
+            response.blocks.len(),
+        );
+        let blocks = response
+            .blocks
+            .into_iter()
+            .map(Block::try_from_raw)
+            .collect::<Result<_, _>>()
+            .wrap_err("failed converting raw response to validated blocks")?;
+        Ok(Some(blocks))
+    }
+
     /// Calls remote procedure `astria.execution.v1alpha2.GetCommitmentState`
     #[instrument(skip_all, fields(uri = %self.uri), err)]
     pub(crate) async fn get_commitment_state_with_retry(
@@ -18,15 +18,22 @@ use astria_eyre::eyre::{
     WrapErr as _,
 };
 use bytes::Bytes;
-use sequencer_client::tendermint::{
-    block::Height as SequencerHeight,
-    Time as TendermintTime,
+use futures::StreamExt as _;
+use sequencer_client::{
+    tendermint::{
+        block::Height as SequencerHeight,
+        Time as TendermintTime,
+    },
+    LatestHeightStream,
 };
 use tokio::{
     select,
     sync::{
         mpsc,
-        watch::error::RecvError,
+        watch::{
+            self,
+            error::RecvError,
+        },
     },
 };
 use tokio_util::sync::CancellationToken;
@@ -36,6 +43,7 @@ use tracing::{
     error,
     info,
     instrument,
+    warn,
 };
 
 use crate::{
@@ -118,6 +126,7 @@ pub(crate) struct Handle<TStateInit = StateNotInit> {
     firm_blocks: Option<mpsc::Sender<ReconstructedBlock>>,
     soft_blocks: Option<channel::Sender<FilteredSequencerBlock>>,
     state: StateReceiver,
+    catching_up: watch::Receiver<bool>,
     _state_init: TStateInit,
 }
 
@@ -131,12 +140,14 @@ impl<T: Clone> Handle<T> {
             firm_blocks,
             soft_blocks,
             state,
+            catching_up,
             ..
         } = self.clone();
         Ok(Handle {
             firm_blocks,
             soft_blocks,
             state,
+            catching_up,
             _state_init: StateIsInit,
         })
     }
@@ -218,6 +229,11 @@ impl Handle<StateIsInit> {
     pub(crate) fn celestia_block_variance(&mut self) -> u64 {
         self.state.celestia_block_variance()
     }
+
+    /// Returns if the executor is currently in catch-up mode.
+    pub(crate) fn is_catching_up(&self) -> bool {
+        *self.catching_up.borrow()
+    }
 }
 
 pub(crate) struct Executor {
@@ -252,9 +268,31 @@ pub(crate) struct Executor {
     /// The maximum permitted spread between firm and soft blocks.
     max_spread: Option<usize>,
 
+    /// Tracks the latest Sequencer height to decide if the executor enters or leaves catch-up
+    /// mode. Only set if `mode` is `FirmOnly` or `SoftAndFirm` and catch-up mode is enabled.
+    /// Unset once the executor leaves catch-up mode or if it was not behind the latest Sequencer
+    /// height by more than the threshold at startup.
+    catch_up: Option<CatchUp>,
+
+    /// Informs the other conductor tasks whether the executor is in catch-up mode.
+    catching_up: watch::Sender<bool>,
+
     metrics: &'static Metrics,
 }
 
+/// State required to run the executor in catch-up mode.
+pub(crate) struct CatchUp {
+    /// The maximum number of Sequencer heights the next expected firm height may trail
+    /// the latest Sequencer height before the executor leaves catch-up mode.
+    pub(crate) threshold: u64,
+
+    /// The maximum number of firm blocks executed in a single batch.
+    pub(crate) batch_size: usize,
+
+    /// The stream of latest Sequencer heights, used to decide when to leave catch-up mode.
+    pub(crate) latest_sequencer_heights: LatestHeightStream,
+}
+
 impl Executor {
     pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
         select!(
@@ -292,13 +330,26 @@ impl Executor {
                         block.hash = %telemetry::display::base64(&block.block_hash),
                         "received block from celestia reader",
                     ));
-                    if let Err(error) = self.execute_firm(block).await {
+                    if self.is_catching_up() {
+                        let blocks = self.collect_firm_batch(block);
+                        if let Err(error) = self.execute_firm_batch(blocks).await {
+                            break Err(error).wrap_err("failed executing batch of firm blocks");
+                        }
+                    } else if let Err(error) = self.execute_firm(block).await {
                         break Err(error).wrap_err("failed executing firm block");
                     }
                 }
 
+                Some(res) = async { self.catch_up.as_mut().unwrap().latest_sequencer_heights.next().await },
+                            if self.catch_up.is_some() =>
+                {
+                    self.handle_latest_sequencer_height(res);
+                }
+
                 Some(block) = async { self.soft_blocks.as_mut().unwrap().recv().await },
-                              if self.soft_blocks.is_some() && spread_not_too_large =>
+                              if self.soft_blocks.is_some()
+                                 && spread_not_too_large
+                                 && !self.is_catching_up() =>
                 {
                     debug_span!("conductor::Executor::run_until_stopped").in_scope(||debug!(
                         block.height = %block.height(),
@@ -324,6 +375,8 @@ impl Executor {
             .await
             .wrap_err("failed setting initial rollup node state")?;
 
+        self.decide_initial_catch_up().await;
+
         let max_spread: usize = self.calculate_max_spread();
         self.max_spread.replace(max_spread);
         if let Some(channel) = self.soft_blocks.as_mut() {
@@ -339,6 +392,116 @@ impl Executor {
         Ok(())
     }
 
+    /// Returns if the executor is in catch-up mode.
+    fn is_catching_up(&self) -> bool {
+        *self.catching_up.borrow()
+    }
+
+    /// Compares the next expected firm height against the first latest Sequencer height to
+    /// decide if the executor starts in catch-up mode.
+    ///
+    /// The executor starts in normal mode and only enters catch-up mode if the next expected
+    /// firm height trails the latest Sequencer height by more than the configured threshold.
+    async fn decide_initial_catch_up(&mut self) {
+        while let Some(catch_up) = self.catch_up.as_mut() {
+            let Some(res) = catch_up.latest_sequencer_heights.next().await else {
+                break;
+            };
+            match res {
+                Ok(latest_height) => {
+                    self.update_catch_up(latest_height);
+                    break;
+                }
+                Err(error) => warn!(
+                    error = &error as &dyn std::error::Error,
+                    "failed fetching latest height from sequencer to decide on catch-up mode; \
+                     waiting until next tick",
+                ),
+            }
+        }
+    }
+
+    /// Leaves catch-up mode and informs the other conductor tasks about it.
+    ///
+    /// Catch-up mode is never re-entered.
+    fn leave_catch_up(&mut self) {
+        if self.catch_up.take().is_some() {
+            self.catching_up.send_replace(false);
+        }
+    }
+
+    #[instrument(skip_all)]
+    fn handle_latest_sequencer_height(
+        &mut self,
+        res: Result<SequencerHeight, sequencer_client::tendermint_rpc::Error>,
+    ) {
+        match res {
+            Ok(latest_height) => self.update_catch_up(latest_height),
+            Err(error) => warn!(
+                error = &error as &dyn std::error::Error,
+                "failed fetching latest height from sequencer; waiting until next tick",
+            ),
+        }
+    }
+
+    /// Enters or leaves catch-up mode depending on the distance between the next expected firm
+    /// height and `latest_height`.
+    fn update_catch_up(&mut self, latest_height: SequencerHeight) {
+        let Some(catch_up) = self.catch_up.as_ref() else {
+            return;
+        };
+        let threshold = catch_up.threshold;
+        let next_firm = self.state.next_expected_firm_sequencer_height();
+        let distance = latest_height.value().saturating_sub(next_firm.value());
+        if distance <= threshold {
+            info!(
+                %latest_height,
+                %next_firm,
+                threshold,
+                "next expected firm height is within threshold of latest sequencer height; \
+                 not running in catch-up mode",
+            );
+            self.leave_catch_up();
+        } else if !self.is_catching_up() {
+            info!(
+                %latest_height,
+                %next_firm,
+                threshold,
+                "next expected firm height trails latest sequencer height by more than threshold; \
+                 entering catch-up mode",
+            );
+            self.catching_up.send_replace(true);
+        } else {
+            debug!(
+                %latest_height,
+                %next_firm,
+                distance,
+                "still catching up to latest sequencer height",
+            );
+        }
+    }
+
+    /// Collects up to [`CatchUp::batch_size`] firm blocks, starting with `first`.
+    ///
+    /// Only takes blocks that are immediately available in the channel and does not wait for
+    /// more to arrive.
+    fn collect_firm_batch(&mut self, first: ReconstructedBlock) -> Vec<ReconstructedBlock> {
+        let batch_size = self
+            .catch_up
+            .as_ref()
+            .map_or(1, |catch_up| catch_up.batch_size);
+        let mut blocks = vec![first];
+        if let Some(channel) = self.firm_blocks.as_mut() {
+            while blocks.len() < batch_size {
+                let Ok(block) = channel.try_recv() else {
+                    break;
+                };
+                blocks.push(block);
+            }
+        }
+        blocks
+    }
+
     /// Calculates the maximum allowed spread between firm and soft commitments heights.
     ///
     /// The maximum allowed spread is taken as `max_spread = variance * 6`, where `variance`
@@ -531,6 +694,110 @@ impl Executor {
         Ok(())
     }
 
+    /// Executes a batch of firm blocks in catch-up mode.
+    ///
+    /// Blocks that were already executed as soft blocks are only marked as firm, as in
+    /// [`Executor::execute_firm`]. All others are executed against the rollup in one
+    /// `BatchExecuteBlocks` call, followed by a single commitment state update setting both
+    /// soft and firm to the last executed block.
+    ///
+    /// Leaves catch-up mode and executes the blocks individually if the rollup does not
+    /// implement `BatchExecuteBlocks`.
+    #[instrument(skip_all, fields(
+        blocks.first_height = blocks.first().map(|block| block.sequencer_height().value()),
+        blocks.len = blocks.len(),
+        err,
+    ))]
+    async fn execute_firm_batch(&mut self, blocks: Vec<ReconstructedBlock>) -> eyre::Result<()> {
+        let mut blocks = blocks.into_iter().peekable();
+        while blocks.peek().is_some() && !self.should_execute_firm_block() {
+            let block = blocks.next().expect("peeked above");
+            self.execute_firm(block).await?;
+        }
+        let blocks: Vec<_> = blocks.collect();
+        let Some(last) = blocks.last() else {
+            return Ok(());
+        };
+        let celestia_height = last.celestia_height;
+
+        let expected_height = self.state.next_expected_firm_sequencer_height();
+        for (offset, block) in (0u64..).zip(&blocks) {
+            let expected = expected_height.value().saturating_add(offset);
+            let actual = block.sequencer_height().value();
+            ensure!(
+                actual == expected,
+                "expected block at sequencer height {expected} in batch, but got {actual}",
+            );
+        }
+
+        let executable_blocks: Vec<_> = blocks
+            .iter()
+            .cloned()
+            .map(|block| {
+                let ExecutableBlock {
+                    transactions,
+                    timestamp,
+                    ..
+                } = ExecutableBlock::from_reconstructed(block);
+                (transactions, timestamp)
+            })
+            .collect();
+        let n_transactions: Vec<_> = executable_blocks
+            .iter()
+            .map(|(transactions, _)| transactions.len())
+            .collect();
+
+        let parent_hash = self.state.firm_hash();
+        let Some(executed_blocks) = self
+            .client
+            .batch_execute_blocks_with_retry(parent_hash, executable_blocks)
+            .await
+            .wrap_err("failed to execute batch of blocks")?
+        else {
+            warn!(
+                "rollup does not implement BatchExecuteBlocks; leaving catch-up mode and \
+                 executing blocks individually",
+            );
+            self.leave_catch_up();
+            for block in blocks {
+                self.execute_firm(block).await?;
+            }
+            return Ok(());
+        };
+
+        does_batch_response_fulfill_contract(&mut self.state, &executed_blocks)
+            .wrap_err("execution API server violated contract")?;
+
+        for n in n_transactions {
+            self.metrics.record_transactions_per_executed_block(n);
+        }
+        self.metrics
+            .record_executed_blocks_per_batch(executed_blocks.len());
+
+        let last_executed = executed_blocks
+            .last()
+            .cloned()
+            .expect("the response contains as many blocks as were requested, which is not zero");
+        info!(
+            executed_blocks.len = executed_blocks.len(),
+            last_executed_block.hash = %telemetry::display::base64(&last_executed.hash()),
+            last_executed_block.number = last_executed.number(),
+            "executed batch of blocks",
+        );
+        let block_number = last_executed.number();
+
+        self.update_commitment_state(Update::ToSame(last_executed, celestia_height))
+            .await
+            .wrap_err("failed to setting both commitment states to last executed block")?;
+
+        self.metrics
+            .absolute_set_executed_firm_block_number(block_number);
+        self.metrics
+            .absolute_set_executed_soft_block_number(block_number);
+
+        Ok(())
+    }
+
     /// Executes `block` on top of its `parent_hash`.
     ///
     /// This function is called via [`Executor::execute_firm`] or [`Executor::execute_soft`],
@@ -810,6 +1077,35 @@ fn does_block_response_fulfill_contract(
     }
 }
 
+/// Checks that the blocks returned by `BatchExecuteBlocks` follow each other and the
+/// current firm block.
+fn does_batch_response_fulfill_contract(
+    state: &mut StateSender,
+    blocks: &[Block],
+) -> Result<(), ContractViolation> {
+    let kind = ExecutionKind::Firm;
+    let mut current = state.firm_number();
+    for block in blocks {
+        let actual = block.number();
+        let expected = current
+            .checked_add(1)
+            .ok_or(ContractViolation::CurrentBlockNumberIsMax {
+                kind,
+                actual,
+            })?;
+        if actual != expected {
+            return Err(ContractViolation::WrongBlock {
+                kind,
+                current,
+                expected,
+                actual,
+            });
+        }
+        current = actual;
+    }
+    Ok(())
+}
+
 fn should_execute_firm_block(
     firm_sequencer_height: u64,
     soft_sequencer_height: u64,
@@ -11,6 +11,7 @@ use astria_core::{
 use bytes::Bytes;
 
 use super::{
+    does_batch_response_fulfill_contract,
     should_execute_firm_block,
     state::{
         StateReceiver,
@@ -159,6 +160,44 @@ fn execute_block_contract_violation() {
     );
 }
 
+#[test]
+fn batch_execute_blocks_contract_violation() {
+    fn blocks(numbers: &[u32]) -> Vec<Block> {
+        numbers
+            .iter()
+            .map(|number| Block::try_from_raw(make_block(*number)).unwrap())
+            .collect()
+    }
+
+    let (mut tx, _rx) = make_state(MakeState {
+        firm: 2,
+        soft: 2,
+    });
+    does_batch_response_fulfill_contract(&mut tx, &blocks(&[3, 4, 5]))
+        .expect("batch of blocks following the firm block must fulfill the contract");
+
+    let (mut tx, _rx) = make_state(MakeState {
+        firm: 2,
+        soft: 2,
+    });
+    does_batch_response_fulfill_contract(&mut tx, &blocks(&[4, 5]))
+        .expect_err("batch not starting at the block after firm must violate the contract");
+
+    let (mut tx, _rx) = make_state(MakeState {
+        firm: 2,
+        soft: 2,
+    });
+    does_batch_response_fulfill_contract(&mut tx, &blocks(&[3, 5]))
+        .expect_err("batch with a gap must violate the contract");
+
+    let (mut tx, _rx) = make_state(MakeState {
+        firm: 2,
+        soft: 2,
+    });
+    does_batch_response_fulfill_contract(&mut tx, &blocks(&[3, 3]))
+        .expect_err("batch with a repeated block must violate the contract");
+}
+
 #[test]
 fn should_execute_firm() {
     use CommitLevel::{
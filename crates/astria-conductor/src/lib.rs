@@ -18,6 +18,7 @@ pub mod conductor;
 pub mod config;
 pub(crate) mod executor;
 pub(crate) mod metrics;
+mod reload;
 pub(crate) mod sequencer;
 mod utils;
 
@@ -25,3 +26,4 @@ pub use build_info::BUILD_INFO;
 pub use conductor::Conductor;
 pub use config::Config;
 pub use metrics::Metrics;
+pub use reload::ReloadHandle;
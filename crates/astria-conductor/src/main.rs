@@ -6,6 +6,7 @@ use astria_conductor::{
     BUILD_INFO,
 };
 use astria_eyre::eyre::WrapErr as _;
+use config::reload::Reloader;
 use tokio::{
     select,
     signal::unix::{
@@ -33,16 +34,24 @@ async fn main() -> ExitCode {
             .expect("build info is serializable because it contains only unicode fields")
     );
 
-    let cfg: Config = match config::get().wrap_err("failed reading config") {
+    let config_file = match config::config_file_from_args().wrap_err("failed reading config") {
         Err(e) => {
             eprintln!("failed to start conductor:\n{e}");
-            // FIXME (https://github.com/astriaorg/astria/issues/368):
-            //       might have to bubble up exit codes, since we might need
-            //       to exit with other exit codes if something else fails
             return ExitCode::from(EX_CONFIG);
         }
-        Ok(cfg) => cfg,
+        Ok(config_file) => config_file,
     };
+    let cfg: Config =
+        match config::get_with_file(config_file.as_deref()).wrap_err("failed reading config") {
+            Err(e) => {
+                eprintln!("failed to start conductor:\n{e}");
+                // FIXME (https://github.com/astriaorg/astria/issues/368):
+                //       might have to bubble up exit codes, since we might need
+                //       to exit with other exit codes if something else fails
+                return ExitCode::from(EX_CONFIG);
+            }
+            Ok(cfg) => cfg,
+        };
 
     let mut telemetry_conf = telemetry::configure()
         .set_no_otel(cfg.no_otel)
@@ -55,7 +64,7 @@ async fn main() -> ExitCode {
             telemetry_conf.set_metrics(&cfg.metrics_http_listener_addr, env!("CARGO_PKG_NAME"));
     }
 
-    let (metrics, _telemetry_guard) = match telemetry_conf
+    let (metrics, telemetry_guard) = match telemetry_conf
         .try_init(&())
         .wrap_err("failed to setup telemetry")
     {
@@ -79,6 +88,28 @@ async fn main() -> ExitCode {
         Ok(conductor) => conductor,
     };
 
+    let reload_handle = conductor.reload_handle();
+    let mut reloader = Reloader::<Config>::new(config_file, reload_handle.trigger())
+        .expect("setting a SIGHUP listener should always work on unix; is this running on unix?");
+    let filter_handle = telemetry_guard.filter_handle();
+    tokio::spawn(async move {
+        loop {
+            match reloader.reload().await {
+                Ok(cfg) => match reload_handle.apply(&cfg, Some(&filter_handle)) {
+                    Ok(true) => info!(
+                        active_config = reload_handle.active_config(),
+                        "applied reloaded config"
+                    ),
+                    Ok(false) => info!("reloaded config did not change log or rate limit"),
+                    Err(error) => {
+                        warn!(%error, "reloaded config is invalid; keeping active config");
+                    }
+                },
+                Err(error) => warn!(?error, "failed reloading config; keeping active config"),
+            }
+        }
+    });
+
     let mut sigterm = signal(SignalKind::terminate())
         .expect("setting a SIGTERM listener should always work on unix; is this running on unix?");
     let mut handle = conductor.spawn();
@@ -20,6 +20,7 @@ pub struct Metrics {
     executed_firm_block_number: Counter,
     executed_soft_block_number: Counter,
     transactions_per_executed_block: Histogram,
+    executed_blocks_per_batch: Histogram,
 }
 
 impl Metrics {
@@ -74,6 +75,10 @@ impl Metrics {
     pub(crate) fn record_transactions_per_executed_block(&self, tx_count: usize) {
         self.transactions_per_executed_block.record(tx_count);
     }
+
+    pub(crate) fn record_executed_blocks_per_batch(&self, block_count: usize) {
+        self.executed_blocks_per_batch.record(block_count);
+    }
 }
 
 impl telemetry::Metrics for Metrics {
@@ -149,6 +154,14 @@ impl telemetry::Metrics for Metrics {
             )?
             .register()?;
 
+        let executed_blocks_per_batch = builder
+            .new_histogram_factory(
+                EXECUTED_BLOCKS_PER_BATCH,
+                "The number of blocks executed against the rollup in a single batch while \
+                 catching up",
+            )?
+            .register()?;
+
         Ok(Self {
             metadata_blobs_per_celestia_fetch,
             rollup_data_blobs_per_celestia_fetch,
@@ -160,6 +173,7 @@ impl telemetry::Metrics for Metrics {
             executed_firm_block_number,
             executed_soft_block_number,
             transactions_per_executed_block,
+            executed_blocks_per_batch,
         })
     }
 }
@@ -173,7 +187,8 @@ metric_names!(const METRICS_NAMES:
 
     EXECUTED_FIRM_BLOCK_NUMBER,
     EXECUTED_SOFT_BLOCK_NUMBER,
-    TRANSACTIONS_PER_EXECUTED_BLOCK
+    TRANSACTIONS_PER_EXECUTED_BLOCK,
+    EXECUTED_BLOCKS_PER_BATCH
 );
 
 #[cfg(test)]
@@ -182,6 +197,7 @@ mod tests {
         BLOBS_PER_CELESTIA_FETCH,
         CELESTIA_BLOB_FETCH_ERROR_COUNT,
         DECODED_ITEMS_PER_CELESTIA_FETCH,
+        EXECUTED_BLOCKS_PER_BATCH,
         EXECUTED_FIRM_BLOCK_NUMBER,
         EXECUTED_SOFT_BLOCK_NUMBER,
         SEQUENCER_BLOCKS_METADATA_VERIFIED_PER_CELESTIA_FETCH,
@@ -224,5 +240,6 @@ mod tests {
             TRANSACTIONS_PER_EXECUTED_BLOCK,
             "transactions_per_executed_block",
         );
+        assert_const(EXECUTED_BLOCKS_PER_BATCH, "executed_blocks_per_batch");
     }
 }
@@ -0,0 +1,169 @@
+//! Changing the log level and the Sequencer rate limit of a running conductor without a restart.
+
+use std::sync::Arc;
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use config::reload::ReloadTrigger;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::Config;
+
+/// The subset of the config that can be changed while conductor is running.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct ReloadableConfig {
+    pub(crate) log: String,
+    pub(crate) sequencer_requests_per_second: u32,
+}
+
+impl ReloadableConfig {
+    fn from_config(cfg: &Config) -> eyre::Result<Self> {
+        ensure!(
+            cfg.sequencer_requests_per_second > 0,
+            "sequencer_requests_per_second must be greater than 0"
+        );
+        Ok(Self {
+            log: cfg.log.clone(),
+            sequencer_requests_per_second: cfg.sequencer_requests_per_second,
+        })
+    }
+}
+
+/// A handle to apply a reloaded config to a running conductor.
+///
+/// Only the log level and the Sequencer rate limit are applied; changes to all other fields
+/// require a restart. Conductor has no API server, so reloads are triggered by `SIGHUP` and the
+/// active config is logged after every applied reload.
+#[derive(Clone)]
+pub struct ReloadHandle {
+    trigger: ReloadTrigger,
+    active: Arc<watch::Sender<ReloadableConfig>>,
+    sequencer_requests_per_second: Arc<watch::Sender<u32>>,
+}
+
+impl ReloadHandle {
+    pub(crate) fn new(cfg: &Config) -> eyre::Result<Self> {
+        let active = ReloadableConfig::from_config(cfg)?;
+        let (sequencer_requests_per_second, _) =
+            watch::channel(active.sequencer_requests_per_second);
+        let (active, _) = watch::channel(active);
+        Ok(Self {
+            trigger: ReloadTrigger::new(),
+            active: Arc::new(active),
+            sequencer_requests_per_second: Arc::new(sequencer_requests_per_second),
+        })
+    }
+
+    /// Returns the trigger to re-read the config in addition to `SIGHUP`.
+    #[must_use]
+    pub fn trigger(&self) -> ReloadTrigger {
+        self.trigger.clone()
+    }
+
+    /// Returns the active config of the fields that can be changed while conductor is running,
+    /// serialized as JSON.
+    #[must_use]
+    pub fn active_config(&self) -> String {
+        serde_json::to_string(&*self.active.borrow()).expect(
+            "reloadable config is serializable because it contains only strings and numbers",
+        )
+    }
+
+    pub(crate) fn subscribe_to_sequencer_requests_per_second(&self) -> watch::Receiver<u32> {
+        self.sequencer_requests_per_second.subscribe()
+    }
+
+    /// Validates the reloadable fields of `cfg` and applies them to conductor.
+    ///
+    /// The changes are only applied if all reloadable fields are valid. A changed Sequencer rate
+    /// limit is picked up by the Celestia reader, which rebuilds its blob verifier in the
+    /// background; if that fails, the reader logs a warning and keeps verifying with the previous
+    /// rate limit, while the other changes stay applied. Returns `false` if nothing changed.
+    ///
+    /// # Errors
+    /// Returns an error if the rate limit or the log filter directives of `cfg` are invalid.
+    pub fn apply(
+        &self,
+        cfg: &Config,
+        filter: Option<&telemetry::FilterHandle>,
+    ) -> eyre::Result<bool> {
+        let reloaded = ReloadableConfig::from_config(cfg)?;
+        if *self.active.borrow() == reloaded {
+            return Ok(false);
+        }
+        if let Some(filter) = filter {
+            if filter.filter_directives() != reloaded.log {
+                filter
+                    .set_filter_directives(&reloaded.log)
+                    .wrap_err("failed applying log filter directives")?;
+            }
+        }
+        self.sequencer_requests_per_second
+            .send_if_modified(|active| {
+                let modified = *active != reloaded.sequencer_requests_per_second;
+                *active = reloaded.sequencer_requests_per_second;
+                modified
+            });
+        self.active.send_replace(reloaded);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_requests_per_second(sequencer_requests_per_second: u32) -> Config {
+        Config {
+            celestia_block_time_ms: 12000,
+            celestia_node_http_url: "http://127.0.0.1:26658".into(),
+            no_celestia_auth: true,
+            celestia_bearer_token: String::new(),
+            sequencer_grpc_url: "http://127.0.0.1:8080".into(),
+            sequencer_cometbft_url: "http://127.0.0.1:26657".into(),
+            sequencer_requests_per_second,
+            sequencer_block_time_ms: 2000,
+            catch_up_threshold: 0,
+            catch_up_celestia_concurrency: 50,
+            catch_up_execution_batch_size: 32,
+            execution_rpc_url: "http://127.0.0.1:50051".into(),
+            log: "info".into(),
+            execution_commit_level: crate::config::CommitLevel::SoftAndFirm,
+            force_stdout: false,
+            no_otel: true,
+            no_metrics: true,
+            metrics_http_listener_addr: String::new(),
+            pretty_print: false,
+        }
+    }
+
+    #[test]
+    fn changed_rate_limit_is_applied() {
+        let handle = ReloadHandle::new(&config_with_requests_per_second(500)).unwrap();
+        let mut requests_per_second = handle.subscribe_to_sequencer_requests_per_second();
+
+        assert!(!handle
+            .apply(&config_with_requests_per_second(500), None)
+            .unwrap());
+        assert!(!requests_per_second.has_changed().unwrap());
+
+        assert!(handle
+            .apply(&config_with_requests_per_second(100), None)
+            .unwrap());
+        assert_eq!(*requests_per_second.borrow_and_update(), 100);
+    }
+
+    #[test]
+    fn zero_rate_limit_is_rejected() {
+        let handle = ReloadHandle::new(&config_with_requests_per_second(500)).unwrap();
+        let requests_per_second = handle.subscribe_to_sequencer_requests_per_second();
+        handle
+            .apply(&config_with_requests_per_second(0), None)
+            .unwrap_err();
+        assert!(!requests_per_second.has_changed().unwrap());
+    }
+}
@@ -0,0 +1,215 @@
+use std::time::Duration;
+
+use astria_conductor::{
+    config::CommitLevel,
+    Config,
+};
+use futures::future::join;
+use tokio::time::timeout;
+
+use crate::{
+    helpers::{
+        make_config,
+        spawn_conductor_with_config,
+    },
+    mount_abci_info,
+    mount_batch_executed_blocks,
+    mount_celestia_blobs,
+    mount_celestia_header_network_head,
+    mount_executed_block,
+    mount_get_commitment_state,
+    mount_get_genesis_info,
+    mount_sequencer_commit,
+    mount_sequencer_genesis,
+    mount_sequencer_validator_set,
+    mount_update_commitment_state,
+};
+
+/// Tests that conductor in catch-up mode executes firm blocks through `BatchExecuteBlocks`
+/// when the rollup is further behind sequencer than the configured threshold.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn executes_firm_blocks_in_batch() {
+    let test_conductor = spawn_conductor_with_config(Config {
+        execution_commit_level: CommitLevel::FirmOnly,
+        catch_up_threshold: 1,
+        ..make_config()
+    })
+    .await;
+
+    // Mounted first so that conductor can decide on catch-up mode during initialization.
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 10,
+    );
+
+    mount_get_genesis_info!(
+        test_conductor,
+        sequencer_genesis_block_height: 1,
+        celestia_block_variance: 10,
+    );
+
+    mount_get_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 1,
+            hash: [1; 64],
+            parent: [0; 64],
+        ),
+        soft: (
+            number: 1,
+            hash: [1; 64],
+            parent: [0; 64],
+        ),
+        base_celestia_height: 1,
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_celestia_header_network_head!(
+        test_conductor,
+        height: 1u32,
+    );
+
+    mount_celestia_blobs!(
+        test_conductor,
+        celestia_height: 1,
+        sequencer_heights: [3],
+    );
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+
+    let batch_execute_blocks = mount_batch_executed_blocks!(
+        test_conductor,
+        parent: [1; 64],
+        blocks: [
+            (number: 2, hash: [2; 64], parent: [1; 64]),
+        ],
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 2,
+            hash: [2; 64],
+            parent: [1; 64],
+        ),
+        soft: (
+            number: 2,
+            hash: [2; 64],
+            parent: [1; 64],
+        ),
+        base_celestia_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(2000),
+        join(
+            batch_execute_blocks.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the firm block in a batch and updated the firm commitment \
+         state within 2000ms",
+    );
+}
+
+/// Tests that conductor does not enter catch-up mode and executes firm blocks individually
+/// when the rollup is within the configured threshold of the latest sequencer height.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn does_not_enter_catch_up_mode_within_threshold() {
+    let test_conductor = spawn_conductor_with_config(Config {
+        execution_commit_level: CommitLevel::FirmOnly,
+        catch_up_threshold: 10,
+        ..make_config()
+    })
+    .await;
+
+    // Mounted first so that conductor can decide on catch-up mode during initialization.
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 3,
+    );
+
+    mount_get_genesis_info!(
+        test_conductor,
+        sequencer_genesis_block_height: 1,
+        celestia_block_variance: 10,
+    );
+
+    mount_get_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 1,
+            hash: [1; 64],
+            parent: [0; 64],
+        ),
+        soft: (
+            number: 1,
+            hash: [1; 64],
+            parent: [0; 64],
+        ),
+        base_celestia_height: 1,
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_celestia_header_network_head!(
+        test_conductor,
+        height: 1u32,
+    );
+
+    mount_celestia_blobs!(
+        test_conductor,
+        celestia_height: 1,
+        sequencer_heights: [3],
+    );
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+
+    let execute_block = mount_executed_block!(
+        test_conductor,
+        number: 2,
+        hash: [2; 64],
+        parent: [1; 64],
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 2,
+            hash: [2; 64],
+            parent: [1; 64],
+        ),
+        soft: (
+            number: 2,
+            hash: [2; 64],
+            parent: [1; 64],
+        ),
+        base_celestia_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(2000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the firm block individually and updated the firm \
+         commitment state within 2000ms",
+    );
+}
@@ -304,6 +304,32 @@ macro_rules! mount_executed_block {
     };
 }
 
+#[macro_export]
+macro_rules! mount_batch_executed_blocks {
+    (
+        $test_env:ident,
+        parent: $parent:expr,
+        blocks: [ $( (number: $number:expr, hash: $hash:expr, parent: $block_parent:expr $(,)?) ),+ $(,)? ] $(,)?
+    ) => {{
+        use ::base64::prelude::*;
+        let blocks = vec![ $( $crate::block!(number: $number, hash: $hash, parent: $block_parent) ),+ ];
+        let executable_blocks: Vec<_> = blocks
+            .iter()
+            .map(|_| ::serde_json::json!({
+                "transactions": [{"sequencedData": BASE64_STANDARD.encode($crate::helpers::data())}],
+            }))
+            .collect();
+        $test_env.mount_batch_execute_blocks(
+            ::serde_json::json!({
+                "prevBlockHash": BASE64_STANDARD.encode($parent),
+                "blocks": executable_blocks,
+            }),
+            blocks,
+        )
+        .await
+    }};
+}
+
 #[macro_export]
 macro_rules! mount_get_filtered_sequencer_block {
     ($test_env:ident, sequencer_height: $height:expr, delay: $delay:expr $(,)?) => {
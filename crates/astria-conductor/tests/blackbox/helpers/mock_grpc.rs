@@ -9,6 +9,8 @@ use astria_core::generated::{
             ExecutionService,
             ExecutionServiceServer,
         },
+        BatchExecuteBlocksRequest,
+        BatchExecuteBlocksResponse,
         BatchGetBlocksRequest,
         BatchGetBlocksResponse,
         Block,
@@ -149,6 +151,7 @@ define_and_impl_service!(impl ExecutionService for ExecutionServiceImpl {
     (get_genesis_info: GetGenesisInfoRequest => GenesisInfo)
     (batch_get_blocks: BatchGetBlocksRequest => BatchGetBlocksResponse)
     (execute_block: ExecuteBlockRequest => Block)
+    (batch_execute_blocks: BatchExecuteBlocksRequest => BatchExecuteBlocksResponse)
     (get_commitment_state: GetCommitmentStateRequest => CommitmentState)
     (update_commitment_state: UpdateCommitmentStateRequest => CommitmentState)
 });
@@ -77,6 +77,16 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
 });
 
 pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConductor {
+    spawn_conductor_with_config(Config {
+        execution_commit_level,
+        ..make_config()
+    })
+    .await
+}
+
+/// Spawns conductor using `config`, overwriting all its endpoints with those of the mocked
+/// servers.
+pub async fn spawn_conductor_with_config(config: Config) -> TestConductor {
     assert_ne!(
         tokio::runtime::Handle::current().runtime_flavor(),
         tokio::runtime::RuntimeFlavor::CurrentThread,
@@ -94,8 +104,7 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
         execution_rpc_url: format!("http://{}", mock_grpc.local_addr),
         sequencer_cometbft_url: mock_http.uri(),
         sequencer_grpc_url: format!("http://{}", mock_grpc.local_addr),
-        execution_commit_level,
-        ..make_config()
+        ..config
     };
 
     let (metrics, metrics_handle) = metrics::ConfigBuilder::new()
@@ -405,6 +414,29 @@ impl TestConductor {
             .await
     }
 
+    pub async fn mount_batch_execute_blocks<S: serde::Serialize>(
+        &self,
+        expected_pbjson: S,
+        response: Vec<Block>,
+    ) -> astria_grpc_mock::MockGuard {
+        use astria_core::generated::execution::v1alpha2::BatchExecuteBlocksResponse;
+        use astria_grpc_mock::{
+            matcher::message_partial_pbjson,
+            response::constant_response,
+            Mock,
+        };
+        Mock::for_rpc_given(
+            "batch_execute_blocks",
+            message_partial_pbjson(&expected_pbjson),
+        )
+        .respond_with(constant_response(BatchExecuteBlocksResponse {
+            blocks: response,
+        }))
+        .expect(1)
+        .mount_as_scoped(&self.mock_grpc.mock_server)
+        .await
+    }
+
     pub async fn mount_get_filtered_sequencer_block<S: serde::Serialize>(
         &self,
         expected_pbjson: S,
@@ -501,7 +533,8 @@ impl TestConductor {
     }
 }
 
-fn make_config() -> Config {
+#[must_use]
+pub fn make_config() -> Config {
     Config {
         celestia_block_time_ms: 12000,
         celestia_node_http_url: "http://127.0.0.1:26658".into(),
@@ -511,6 +544,9 @@ fn make_config() -> Config {
         sequencer_cometbft_url: "http://127.0.0.1:26657".into(),
         sequencer_requests_per_second: 500,
         sequencer_block_time_ms: 2000,
+        catch_up_threshold: 0,
+        catch_up_celestia_concurrency: 50,
+        catch_up_execution_batch_size: 32,
         execution_rpc_url: "http://127.0.0.1:50051".into(),
         log: "info".into(),
         execution_commit_level: astria_conductor::config::CommitLevel::SoftAndFirm,
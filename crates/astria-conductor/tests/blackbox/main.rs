@@ -1,4 +1,5 @@
 // allow: clippy lints that are not ok in production code but acceptable or wanted in tests
+pub mod catch_up;
 pub mod firm_only;
 #[allow(clippy::missing_panics_doc)]
 pub mod helpers;
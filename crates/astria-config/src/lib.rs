@@ -38,18 +38,41 @@
 //! );
 //! ```
 //!
+//! ## Config files
+//!
+//! A config can also be read from a TOML or YAML file, in which case environment variables take
+//! precedence over the values in the file. Nested values, as found in tables of a config file,
+//! are set through environment variables by separating their keys with a double underscore, e.g.
+//! `MY_SERVICE_ROLLUPS__ASTRIACHAIN__URL`. Services take the path of the config file through a
+//! `--config <PATH>` command line argument, which is read by [`get_from_args`].
+//!
 //! ## Crate feature flags
 //!
+//! + `reload`: gives access to `reload::Reloader` to re-read a config at runtime, triggered by
+//!   `SIGHUP` or a `reload::ReloadTrigger`.
 //! + `tests`: gives access to test functions that to ensure that a crate's config is up-to-date and
 //!   in sync with its example. See [`tests`] for how to use them.
-use std::fmt::Display;
+use std::{
+    ffi::OsString,
+    fmt::Display,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
 
-use serde::de::DeserializeOwned;
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
 
+#[cfg(feature = "reload")]
+pub mod reload;
 #[cfg(feature = "tests")]
 pub mod tests;
 
-/// The error that is returned if reading a config from the environment fails.
+/// The error that is returned if reading a config from the environment or a config file fails.
 #[derive(Clone, Debug)]
 pub struct Error {
     inner: figment::Error,
@@ -57,7 +80,7 @@ pub struct Error {
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("failed reading config from process environment")
+        f.write_str("failed reading config from config file or process environment")
     }
 }
 
@@ -84,6 +107,124 @@ pub fn get<T: Config>() -> Result<T, Error> {
     T::get()
 }
 
+/// Utility function to get a config from an optional config file and the environment without
+/// having to import the `Config` trait.
+///
+/// # Errors
+///
+/// Returns the same error as `<T as Config>::get_with_file`.
+pub fn get_with_file<T: Config>(file: Option<&Path>) -> Result<T, Error> {
+    T::get_with_file(file)
+}
+
+/// Gets a config from the environment and the config file passed to the process as
+/// `--config <PATH>`, if any.
+///
+/// # Errors
+///
+/// Returns an error if the process was passed arguments other than `--config <PATH>`, or the
+/// same error as `<T as Config>::get_with_file`.
+pub fn get_from_args<T: Config>() -> Result<T, Error> {
+    let file = config_file_from_args()?;
+    T::get_with_file(file.as_deref())
+}
+
+/// Gets a config like [`get_from_args`], additionally accepting one of `subcommands` as the
+/// first argument passed to the process.
+///
+/// Returns the subcommand the process was invoked with, if any, together with the config.
+///
+/// # Errors
+///
+/// Returns an error if the process was passed arguments other than an optional subcommand
+/// followed by `--config <PATH>`, or the same error as `<T as Config>::get_with_file`.
+pub fn get_from_args_with_subcommand<'a, T: Config>(
+    subcommands: &[&'a str],
+) -> Result<(Option<&'a str>, T), Error> {
+    let (subcommand, file) = parse_subcommand_arg(std::env::args_os().skip(1), subcommands)?;
+    let config = T::get_with_file(file.as_deref())?;
+    Ok((subcommand, config))
+}
+
+/// Returns the path of the config file passed to the process as `--config <PATH>`, if any.
+///
+/// Useful to re-read the config from the same file later with `reload::Reloader`.
+///
+/// # Errors
+///
+/// Returns an error if the process was passed arguments other than `--config <PATH>`.
+pub fn config_file_from_args() -> Result<Option<PathBuf>, Error> {
+    parse_config_file_arg(std::env::args_os().skip(1))
+}
+
+/// Reads a leading subcommand out of `subcommands`, followed by the path of a config file as
+/// read by [`parse_config_file_arg`].
+fn parse_subcommand_arg<'a, I>(
+    args: I,
+    subcommands: &[&'a str],
+) -> Result<(Option<&'a str>, Option<PathBuf>), Error>
+where
+    I: IntoIterator<Item = OsString>,
+{
+    let mut args = args.into_iter().peekable();
+    let subcommand = args.peek().and_then(|arg| {
+        subcommands
+            .iter()
+            .copied()
+            .find(|subcommand| arg.as_os_str() == *subcommand)
+    });
+    if subcommand.is_some() {
+        args.next();
+    }
+    let file = parse_config_file_arg(args)?;
+    Ok((subcommand, file))
+}
+
+/// Reads the path of a config file from `--config <PATH>` or `--config=<PATH>`.
+fn parse_config_file_arg<I>(args: I) -> Result<Option<PathBuf>, Error>
+where
+    I: IntoIterator<Item = OsString>,
+{
+    let mut args = args.into_iter();
+    let mut file = None;
+    while let Some(arg) = args.next() {
+        let path = if arg == "--config" {
+            args.next()
+                .ok_or_else(|| figment::Error::from("`--config` requires a path"))?
+        } else if let Some(path) = arg.to_str().and_then(|arg| arg.strip_prefix("--config=")) {
+            path.into()
+        } else {
+            return Err(figment::Error::from(format!(
+                "unexpected argument `{}`; only `--config <PATH>` is supported",
+                arg.to_string_lossy()
+            ))
+            .into());
+        };
+        if file.replace(PathBuf::from(path)).is_some() {
+            return Err(figment::Error::from("`--config` was passed more than once").into());
+        }
+    }
+    Ok(file)
+}
+
+/// A config value that is either a delimiter-encoded string, as is convenient to set through an
+/// environment variable, or the structured value itself, as is natural in a config file.
+///
+/// The string form is what services accepted before config files were supported and is kept for
+/// compatibility. It is up to the service to decode it.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MaybeEncoded<T> {
+    Encoded(String),
+    Structured(T),
+}
+
+impl<T> Default for MaybeEncoded<T> {
+    fn default() -> Self {
+        Self::Encoded(String::new())
+    }
+}
+
 /// A utility trait for easily creating a config from the environment.
 ///
 /// Works for types allowing serde deserialization. Environment variables
@@ -120,21 +261,64 @@ pub trait Config: ::core::fmt::Debug + DeserializeOwned {
     /// # Errors
     /// Returns an error if a config field could not be read from the environment.
     fn get() -> Result<Self, Error> {
-        Ok(Self::get_with_prefix(Self::PREFIX, _internal::Internal)?)
+        Self::get_with_file(None)
+    }
+
+    /// Creates `Self` by reading its fields from a config file, if provided, and the environment.
+    ///
+    /// The format of the file is determined by its extension, which must be one of `toml`,
+    /// `yaml` or `yml`. Fields set in the environment override those in the file.
+    ///
+    /// # Errors
+    /// Returns an error if the file does not exist, has an unsupported extension, or if a config
+    /// field could not be read from the file or the environment.
+    fn get_with_file(file: Option<&Path>) -> Result<Self, Error> {
+        Ok(Self::get_with_prefix_and_file(
+            Self::PREFIX,
+            file,
+            _internal::Internal,
+        )?)
     }
 
     #[doc(hidden)]
     fn get_with_prefix(
         prefix: &str,
+        internal: _internal::Internal,
+    ) -> Result<Self, figment::Error> {
+        Self::get_with_prefix_and_file(prefix, None, internal)
+    }
+
+    #[doc(hidden)]
+    fn get_with_prefix_and_file(
+        prefix: &str,
+        file: Option<&Path>,
         _internal: _internal::Internal,
     ) -> Result<Self, figment::Error> {
         use figment::{
-            providers::Env as FigmentEnv,
+            providers::{
+                Env as FigmentEnv,
+                Format as _,
+                Toml,
+                Yaml,
+            },
             Figment,
         };
-        Figment::new()
+        let mut figment = Figment::new();
+        if let Some(file) = file {
+            figment = match file.extension().and_then(std::ffi::OsStr::to_str) {
+                Some("toml") => figment.merge(Toml::file_exact(file)),
+                Some("yaml" | "yml") => figment.merge(Yaml::file_exact(file)),
+                _ => {
+                    return Err(figment::Error::from(format!(
+                        "config file `{}` must have one of the extensions `toml`, `yaml` or `yml`",
+                        file.display()
+                    )));
+                }
+            };
+        }
+        figment
             .merge(FigmentEnv::prefixed("RUST_").split("_").only(&["log"]))
-            .merge(FigmentEnv::prefixed(prefix))
+            .merge(FigmentEnv::prefixed(prefix).split("__"))
             .extract()
     }
 }
@@ -142,3 +326,6 @@ pub trait Config: ::core::fmt::Debug + DeserializeOwned {
 mod _internal {
     pub struct Internal;
 }
+
+#[cfg(test)]
+mod unit_tests;
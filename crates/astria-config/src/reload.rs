@@ -0,0 +1,200 @@
+//! Re-reading a config at runtime.
+//!
+//! Services that allow changing a subset of their config without a restart construct a
+//! [`Reloader`] from the same config file they were started with. Every time the process
+//! receives `SIGHUP`, or a [`ReloadTrigger`] is triggered (for example by an admin endpoint),
+//! [`Reloader::reload`] re-reads the config from the file and the environment. Validating and
+//! applying the reloaded config is left to the service.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use astria_config as config;
+//! # use serde::Deserialize;
+//! #[derive(Debug, Deserialize)]
+//! pub struct MyConfig {
+//!     pub log: String,
+//! }
+//!
+//! impl config::Config for MyConfig {
+//!     const PREFIX: &'static str = "MY_SERVICE_";
+//! }
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let trigger = config::reload::ReloadTrigger::new();
+//! let mut reloader = config::reload::Reloader::<MyConfig>::new(None, trigger.clone())?;
+//! trigger.trigger();
+//! let reloaded = reloader.reload().await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    marker::PhantomData,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use tokio::sync::Notify;
+
+use crate::{
+    Config,
+    Error,
+};
+
+/// Triggers a [`Reloader`] to re-read its config.
+///
+/// Triggering while a reload is in progress queues up at most one further reload.
+#[derive(Clone, Debug, Default)]
+pub struct ReloadTrigger {
+    inner: Arc<Notify>,
+}
+
+impl ReloadTrigger {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Triggers a reload of the config.
+    pub fn trigger(&self) {
+        self.inner.notify_one();
+    }
+
+    async fn triggered(&self) {
+        self.inner.notified().await;
+    }
+}
+
+/// Re-reads a config `C` when the process receives `SIGHUP` or when its [`ReloadTrigger`] is
+/// triggered.
+pub struct Reloader<C> {
+    file: Option<PathBuf>,
+    trigger: ReloadTrigger,
+    #[cfg(unix)]
+    sighup: tokio::signal::unix::Signal,
+    _config: PhantomData<fn() -> C>,
+}
+
+impl<C: Config> Reloader<C> {
+    /// Creates a reloader reading the config from `file`, if provided, and the environment.
+    ///
+    /// Must be called from within a tokio runtime.
+    ///
+    /// # Errors
+    /// Returns an error if the `SIGHUP` listener could not be installed.
+    pub fn new(file: Option<PathBuf>, trigger: ReloadTrigger) -> std::io::Result<Self> {
+        Ok(Self {
+            file,
+            trigger,
+            #[cfg(unix)]
+            sighup: tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())?,
+            _config: PhantomData,
+        })
+    }
+
+    /// Returns the trigger of this reloader.
+    #[must_use]
+    pub fn trigger(&self) -> ReloadTrigger {
+        self.trigger.clone()
+    }
+
+    /// Waits for the next `SIGHUP` or trigger, then re-reads the config.
+    ///
+    /// # Errors
+    /// Returns the same error as `<C as Config>::get_with_file`.
+    pub async fn reload(&mut self) -> Result<C, Error> {
+        #[cfg(unix)]
+        tokio::select! {
+            _ = self.sighup.recv() => {}
+            () = self.trigger.triggered() => {}
+        }
+        #[cfg(not(unix))]
+        self.trigger.triggered().await;
+
+        C::get_with_file(self.file.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        time::Duration,
+    };
+
+    use figment::Jail;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct TestConfig {
+        log: String,
+    }
+
+    impl Config for TestConfig {
+        const PREFIX: &'static str = "CONFIG_RELOAD_TEST_";
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    fn reloader(trigger: ReloadTrigger) -> Reloader<TestConfig> {
+        Reloader::new(Some("config.toml".into()), trigger).unwrap()
+    }
+
+    #[test]
+    fn trigger_rereads_config_file() {
+        Jail::expect_with(|jail| {
+            jail.clear_env();
+            jail.create_file("config.toml", r#"log = "info""#)?;
+            let trigger = ReloadTrigger::new();
+            block_on(async {
+                let mut reloader = reloader(trigger.clone());
+                trigger.trigger();
+                assert_eq!(reloader.reload().await.unwrap().log, "info");
+
+                jail.create_file("config.toml", r#"log = "debug""#).unwrap();
+                trigger.trigger();
+                assert_eq!(reloader.reload().await.unwrap().log, "debug");
+            });
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn reload_waits_for_trigger() {
+        Jail::expect_with(|jail| {
+            jail.clear_env();
+            jail.create_file("config.toml", r#"log = "info""#)?;
+            block_on(async {
+                let mut reloader = reloader(ReloadTrigger::new());
+                tokio::time::timeout(Duration::from_millis(50), reloader.reload())
+                    .await
+                    .expect_err("reload must not complete without a trigger");
+            });
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn reload_reports_invalid_config() {
+        Jail::expect_with(|jail| {
+            jail.clear_env();
+            jail.create_file("config.toml", "log = 1")?;
+            let trigger = ReloadTrigger::new();
+            block_on(async {
+                let mut reloader = reloader(trigger.clone());
+                trigger.trigger();
+                assert!(reloader.reload().await.is_err());
+            });
+            Ok(())
+        });
+    }
+}
@@ -29,14 +29,17 @@
 //! }
 //! ```
 
-use std::sync::LazyLock;
+use std::{
+    path::Path,
+    sync::LazyLock,
+};
 
 use figment::Jail;
 use regex::Regex;
 
 use crate::{
-    Config,
     _internal,
+    Config,
 };
 
 static TEST_PREFIX: LazyLock<String> = LazyLock::new(|| {
@@ -82,3 +85,27 @@ pub fn example_env_config_is_up_to_date<C: Config>(example_env: &str) {
         Ok(())
     });
 }
+
+/// Asserts that a config `C` can be created from the contents of a config file alone.
+///
+/// `file_name` determines the format of `example_file`, e.g. `config.example.toml`. A config
+/// file example could, for example, be produced by the `include_str!` macro.
+///
+/// # Panics
+/// Panics if a config `C` could not be created from `example_file`.
+#[track_caller]
+pub fn example_file_config_is_up_to_date<C: Config>(file_name: &str, example_file: &str) {
+    let unique_test_prefix = LazyLock::force(&TEST_PREFIX);
+    let full_test_prefix = format!("{unique_test_prefix}_{}", C::PREFIX);
+
+    Jail::expect_with(|jail| {
+        jail.create_file(file_name, example_file)?;
+        C::get_with_prefix_and_file(
+            &full_test_prefix,
+            Some(Path::new(file_name)),
+            _internal::Internal,
+        )
+        .unwrap_or_else(|error| panic!("failed to parse config: {error}"));
+        Ok(())
+    });
+}
@@ -0,0 +1,164 @@
+use std::{
+    collections::BTreeMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use figment::Jail;
+use serde::Deserialize;
+
+use crate::{
+    parse_config_file_arg,
+    parse_subcommand_arg,
+    Config,
+    MaybeEncoded,
+};
+
+#[derive(Debug, Deserialize)]
+struct TestConfig {
+    log: String,
+    port: u16,
+    rollups: MaybeEncoded<BTreeMap<String, TestRollup>>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct TestRollup {
+    url: String,
+}
+
+impl Config for TestConfig {
+    const PREFIX: &'static str = "CONFIG_UNIT_TEST_";
+}
+
+const TOML_CONFIG: &str = r#"
+log = "info"
+port = 8080
+
+[rollups.astriachain]
+url = "ws://127.0.0.1:8545"
+"#;
+
+#[test]
+fn config_is_read_from_env_only() {
+    Jail::expect_with(|jail| {
+        jail.clear_env();
+        jail.set_env("CONFIG_UNIT_TEST_LOG", "debug");
+        jail.set_env("CONFIG_UNIT_TEST_PORT", "1234");
+        jail.set_env(
+            "CONFIG_UNIT_TEST_ROLLUPS",
+            "astriachain::ws://127.0.0.1:8545",
+        );
+        let config = TestConfig::get().unwrap();
+        assert_eq!(config.log, "debug");
+        assert_eq!(config.port, 1234);
+        assert_eq!(
+            config.rollups,
+            MaybeEncoded::Encoded("astriachain::ws://127.0.0.1:8545".to_string())
+        );
+        Ok(())
+    });
+}
+
+#[test]
+fn config_is_read_from_toml_file() {
+    Jail::expect_with(|jail| {
+        jail.clear_env();
+        jail.create_file("config.toml", TOML_CONFIG)?;
+        let config = TestConfig::get_with_file(Some(Path::new("config.toml"))).unwrap();
+        assert_eq!(config.log, "info");
+        assert_eq!(config.port, 8080);
+        let MaybeEncoded::Structured(rollups) = config.rollups else {
+            panic!("rollups in a table should be read as a structured value");
+        };
+        assert_eq!(
+            rollups["astriachain"],
+            TestRollup {
+                url: "ws://127.0.0.1:8545".to_string()
+            }
+        );
+        Ok(())
+    });
+}
+
+#[test]
+fn config_is_read_from_yaml_file() {
+    Jail::expect_with(|jail| {
+        jail.clear_env();
+        jail.create_file(
+            "config.yaml",
+            "log: info\nport: 8080\nrollups:\n  astriachain:\n    url: ws://127.0.0.1:8545\n",
+        )?;
+        let config = TestConfig::get_with_file(Some(Path::new("config.yaml"))).unwrap();
+        assert_eq!(config.port, 8080);
+        assert!(matches!(config.rollups, MaybeEncoded::Structured(_)));
+        Ok(())
+    });
+}
+
+#[test]
+fn env_overrides_config_file() {
+    Jail::expect_with(|jail| {
+        jail.clear_env();
+        jail.create_file("config.toml", TOML_CONFIG)?;
+        jail.set_env("CONFIG_UNIT_TEST_PORT", "1234");
+        jail.set_env(
+            "CONFIG_UNIT_TEST_ROLLUPS__ASTRIACHAIN__URL",
+            "ws://10.0.0.1:8545",
+        );
+        let config = TestConfig::get_with_file(Some(Path::new("config.toml"))).unwrap();
+        assert_eq!(config.log, "info");
+        assert_eq!(config.port, 1234);
+        let MaybeEncoded::Structured(rollups) = config.rollups else {
+            panic!("rollups in a table should be read as a structured value");
+        };
+        assert_eq!(rollups["astriachain"].url, "ws://10.0.0.1:8545");
+        Ok(())
+    });
+}
+
+#[test]
+fn missing_or_unsupported_config_file_is_rejected() {
+    Jail::expect_with(|jail| {
+        jail.clear_env();
+        assert!(TestConfig::get_with_file(Some(Path::new("missing.toml"))).is_err());
+        jail.create_file("config.json", "{}")?;
+        assert!(TestConfig::get_with_file(Some(Path::new("config.json"))).is_err());
+        Ok(())
+    });
+}
+
+#[test]
+fn config_file_is_read_from_args() {
+    let args = |args: &[&str]| parse_config_file_arg(args.iter().map(Into::into));
+    assert_eq!(args(&[]).unwrap(), None);
+    assert_eq!(
+        args(&["--config", "a.toml"]).unwrap(),
+        Some(PathBuf::from("a.toml"))
+    );
+    assert_eq!(
+        args(&["--config=a.yaml"]).unwrap(),
+        Some(PathBuf::from("a.yaml"))
+    );
+    assert!(args(&["--config"]).is_err());
+    assert!(args(&["--config", "a.toml", "--config", "b.toml"]).is_err());
+    assert!(args(&["--verbose"]).is_err());
+}
+
+#[test]
+fn subcommand_is_read_from_args() {
+    let args = |args: &[&str]| parse_subcommand_arg(args.iter().map(Into::into), &["reconcile"]);
+    assert_eq!(args(&[]).unwrap(), (None, None));
+    assert_eq!(args(&["reconcile"]).unwrap(), (Some("reconcile"), None));
+    assert_eq!(
+        args(&["reconcile", "--config", "a.toml"]).unwrap(),
+        (Some("reconcile"), Some(PathBuf::from("a.toml")))
+    );
+    assert_eq!(
+        args(&["--config", "a.toml"]).unwrap(),
+        (None, Some(PathBuf::from("a.toml")))
+    );
+    assert!(args(&["--config", "a.toml", "reconcile"]).is_err());
+    assert!(args(&["rebuild"]).is_err());
+}
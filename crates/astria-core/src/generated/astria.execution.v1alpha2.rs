@@ -152,6 +152,64 @@ impl ::prost::Name for ExecuteBlockRequest {
         ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
     }
 }
+/// The information needed to derive a single rollup block as part of a
+/// BatchExecuteBlocks call.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutableBlock {
+    /// List of transactions to include in the new block.
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<
+        super::super::sequencerblock::v1alpha1::RollupData,
+    >,
+    /// Timestamp to be used for new block.
+    #[prost(message, optional, tag = "2")]
+    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+impl ::prost::Name for ExecutableBlock {
+    const NAME: &'static str = "ExecutableBlock";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// BatchExecuteBlocksRequest contains the information needed to create a
+/// contiguous sequence of new rollup blocks.
+///
+/// The first block is created on top of `prev_block_hash`, every following block
+/// on top of the block created before it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchExecuteBlocksRequest {
+    /// The hash of the block the first of `blocks` will be created on top of.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub prev_block_hash: ::prost::bytes::Bytes,
+    /// The blocks to execute, in order of their sequencer heights.
+    #[prost(message, repeated, tag = "2")]
+    pub blocks: ::prost::alloc::vec::Vec<ExecutableBlock>,
+}
+impl ::prost::Name for BatchExecuteBlocksRequest {
+    const NAME: &'static str = "BatchExecuteBlocksRequest";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// The list of blocks created in response to BatchExecuteBlocks, in the same
+/// order as they were requested.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchExecuteBlocksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<Block>,
+}
+impl ::prost::Name for BatchExecuteBlocksResponse {
+    const NAME: &'static str = "BatchExecuteBlocksResponse";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
 /// The CommitmentState holds the block at each stage of sequencer commitment
 /// level
 ///
@@ -205,6 +263,74 @@ impl ::prost::Name for UpdateCommitmentStateRequest {
         ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
     }
 }
+/// A withdrawal from the rollup to be executed on the sequencer by the bridge
+/// withdrawer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupWithdrawal {
+    /// Uniquely identifies the withdrawal on the rollup, e.g. by the hash of the
+    /// transaction emitting it and its index within the transaction.
+    #[prost(string, tag = "1")]
+    pub rollup_withdrawal_event_id: ::prost::alloc::string::String,
+    /// The amount to withdraw, denominated in the base unit of the bridged asset
+    /// on the sequencer.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// The recipient of the withdrawal: an address on the sequencer, or an address
+    /// on the destination chain if `ics20` is set.
+    #[prost(string, tag = "3")]
+    pub destination_chain_address: ::prost::alloc::string::String,
+    /// Whether the withdrawal is an ICS20 transfer out of the sequencer.
+    #[prost(bool, tag = "4")]
+    pub ics20: bool,
+    /// The rollup address funds are returned to if an ICS20 withdrawal fails.
+    #[prost(string, tag = "5")]
+    pub rollup_return_address: ::prost::alloc::string::String,
+    /// A memo forwarded with an ICS20 withdrawal.
+    #[prost(string, tag = "6")]
+    pub memo: ::prost::alloc::string::String,
+}
+impl ::prost::Name for RollupWithdrawal {
+    const NAME: &'static str = "RollupWithdrawal";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// The withdrawals emitted by a single rollup block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupBlockWithdrawals {
+    #[prost(uint64, tag = "1")]
+    pub rollup_height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub withdrawals: ::prost::alloc::vec::Vec<RollupWithdrawal>,
+    /// The timestamp of the rollup block. ICS20 withdrawals time out relative to
+    /// it, so that all withdrawers derive the same actions from the block.
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+impl ::prost::Name for RollupBlockWithdrawals {
+    const NAME: &'static str = "RollupBlockWithdrawals";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamWithdrawalsRequest {
+    /// The rollup height to start streaming withdrawals from.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+}
+impl ::prost::Name for StreamWithdrawalsRequest {
+    const NAME: &'static str = "StreamWithdrawalsRequest";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "client")]
 pub mod execution_service_client {
@@ -413,6 +539,41 @@ pub mod execution_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// BatchExecuteBlocks executes a sequence of blocks on top of each other
+        /// in one call. Used by the conductor to catch up to the head of the chain
+        /// from data that is already firmly committed.
+        ///
+        /// Must be all or nothing: either all blocks are executed, or none are.
+        pub async fn batch_execute_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchExecuteBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchExecuteBlocksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.execution.v1alpha2.ExecutionService/BatchExecuteBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.execution.v1alpha2.ExecutionService",
+                        "BatchExecuteBlocks",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// GetCommitmentState fetches the current CommitmentState of the chain.
         pub async fn get_commitment_state(
             &mut self,
@@ -511,6 +672,18 @@ pub mod execution_service_server {
             self: std::sync::Arc<Self>,
             request: tonic::Request<super::ExecuteBlockRequest>,
         ) -> std::result::Result<tonic::Response<super::Block>, tonic::Status>;
+        /// BatchExecuteBlocks executes a sequence of blocks on top of each other
+        /// in one call. Used by the conductor to catch up to the head of the chain
+        /// from data that is already firmly committed.
+        ///
+        /// Must be all or nothing: either all blocks are executed, or none are.
+        async fn batch_execute_blocks(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::BatchExecuteBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchExecuteBlocksResponse>,
+            tonic::Status,
+        >;
         /// GetCommitmentState fetches the current CommitmentState of the chain.
         async fn get_commitment_state(
             self: std::sync::Arc<Self>,
@@ -793,6 +966,56 @@ pub mod execution_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/astria.execution.v1alpha2.ExecutionService/BatchExecuteBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchExecuteBlocksSvc<T: ExecutionService>(pub Arc<T>);
+                    impl<
+                        T: ExecutionService,
+                    > tonic::server::UnaryService<super::BatchExecuteBlocksRequest>
+                    for BatchExecuteBlocksSvc<T> {
+                        type Response = super::BatchExecuteBlocksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchExecuteBlocksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExecutionService>::batch_execute_blocks(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BatchExecuteBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/astria.execution.v1alpha2.ExecutionService/GetCommitmentState" => {
                     #[allow(non_camel_case_types)]
                     struct GetCommitmentStateSvc<T: ExecutionService>(pub Arc<T>);
@@ -934,3 +1157,324 @@ pub mod execution_service_server {
         const NAME: &'static str = "astria.execution.v1alpha2.ExecutionService";
     }
 }
+/// Generated client implementations.
+#[cfg(feature = "client")]
+pub mod withdrawal_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// WithdrawalService is implemented by rollups which are not EVM based to expose
+    /// withdrawals to the Astria bridge withdrawer.
+    #[derive(Debug, Clone)]
+    pub struct WithdrawalServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl WithdrawalServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> WithdrawalServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> WithdrawalServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            WithdrawalServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// StreamWithdrawals streams the withdrawals of all rollup blocks at or above
+        /// `start_height` in ascending order of rollup height. A block must only be
+        /// streamed once it can no longer be reorged out. Blocks without withdrawals
+        /// may be omitted.
+        pub async fn stream_withdrawals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StreamWithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::RollupBlockWithdrawals>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.execution.v1alpha2.WithdrawalService/StreamWithdrawals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.execution.v1alpha2.WithdrawalService",
+                        "StreamWithdrawals",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod withdrawal_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with WithdrawalServiceServer.
+    #[async_trait]
+    pub trait WithdrawalService: Send + Sync + 'static {
+        /// Server streaming response type for the StreamWithdrawals method.
+        type StreamWithdrawalsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::RollupBlockWithdrawals, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// StreamWithdrawals streams the withdrawals of all rollup blocks at or above
+        /// `start_height` in ascending order of rollup height. A block must only be
+        /// streamed once it can no longer be reorged out. Blocks without withdrawals
+        /// may be omitted.
+        async fn stream_withdrawals(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::StreamWithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamWithdrawalsStream>,
+            tonic::Status,
+        >;
+    }
+    /// WithdrawalService is implemented by rollups which are not EVM based to expose
+    /// withdrawals to the Astria bridge withdrawer.
+    #[derive(Debug)]
+    pub struct WithdrawalServiceServer<T: WithdrawalService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: WithdrawalService> WithdrawalServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for WithdrawalServiceServer<T>
+    where
+        T: WithdrawalService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/astria.execution.v1alpha2.WithdrawalService/StreamWithdrawals" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamWithdrawalsSvc<T: WithdrawalService>(pub Arc<T>);
+                    impl<
+                        T: WithdrawalService,
+                    > tonic::server::ServerStreamingService<
+                        super::StreamWithdrawalsRequest,
+                    > for StreamWithdrawalsSvc<T> {
+                        type Response = super::RollupBlockWithdrawals;
+                        type ResponseStream = T::StreamWithdrawalsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StreamWithdrawalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WithdrawalService>::stream_withdrawals(inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamWithdrawalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: WithdrawalService> Clone for WithdrawalServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: WithdrawalService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: WithdrawalService> tonic::server::NamedService for WithdrawalServiceServer<T> {
+        const NAME: &'static str = "astria.execution.v1alpha2.WithdrawalService";
+    }
+}
@@ -1,3 +1,206 @@
+impl serde::Serialize for BatchExecuteBlocksRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.prev_block_hash.is_empty() {
+            len += 1;
+        }
+        if !self.blocks.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.BatchExecuteBlocksRequest", len)?;
+        if !self.prev_block_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("prevBlockHash", pbjson::private::base64::encode(&self.prev_block_hash).as_str())?;
+        }
+        if !self.blocks.is_empty() {
+            struct_ser.serialize_field("blocks", &self.blocks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchExecuteBlocksRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "prev_block_hash",
+            "prevBlockHash",
+            "blocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PrevBlockHash,
+            Blocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "prevBlockHash" | "prev_block_hash" => Ok(GeneratedField::PrevBlockHash),
+                            "blocks" => Ok(GeneratedField::Blocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchExecuteBlocksRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.BatchExecuteBlocksRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchExecuteBlocksRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut prev_block_hash__ = None;
+                let mut blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PrevBlockHash => {
+                            if prev_block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevBlockHash"));
+                            }
+                            prev_block_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Blocks => {
+                            if blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocks"));
+                            }
+                            blocks__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchExecuteBlocksRequest {
+                    prev_block_hash: prev_block_hash__.unwrap_or_default(),
+                    blocks: blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.BatchExecuteBlocksRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchExecuteBlocksResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.blocks.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.BatchExecuteBlocksResponse", len)?;
+        if !self.blocks.is_empty() {
+            struct_ser.serialize_field("blocks", &self.blocks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchExecuteBlocksResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "blocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Blocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blocks" => Ok(GeneratedField::Blocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchExecuteBlocksResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.BatchExecuteBlocksResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchExecuteBlocksResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Blocks => {
+                            if blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocks"));
+                            }
+                            blocks__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BatchExecuteBlocksResponse {
+                    blocks: blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.BatchExecuteBlocksResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BatchGetBlocksRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -570,7 +773,7 @@ impl<'de> serde::Deserialize<'de> for CommitmentState {
         deserializer.deserialize_struct("astria.execution.v1alpha2.CommitmentState", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ExecuteBlockRequest {
+impl serde::Serialize for ExecutableBlock {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -578,20 +781,13 @@ impl serde::Serialize for ExecuteBlockRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.prev_block_hash.is_empty() {
-            len += 1;
-        }
         if !self.transactions.is_empty() {
             len += 1;
         }
         if self.timestamp.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.ExecuteBlockRequest", len)?;
-        if !self.prev_block_hash.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("prevBlockHash", pbjson::private::base64::encode(&self.prev_block_hash).as_str())?;
-        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.ExecutableBlock", len)?;
         if !self.transactions.is_empty() {
             struct_ser.serialize_field("transactions", &self.transactions)?;
         }
@@ -601,22 +797,19 @@ impl serde::Serialize for ExecuteBlockRequest {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ExecuteBlockRequest {
+impl<'de> serde::Deserialize<'de> for ExecutableBlock {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "prev_block_hash",
-            "prevBlockHash",
             "transactions",
             "timestamp",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PrevBlockHash,
             Transactions,
             Timestamp,
         }
@@ -640,7 +833,6 @@ impl<'de> serde::Deserialize<'de> for ExecuteBlockRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "prevBlockHash" | "prev_block_hash" => Ok(GeneratedField::PrevBlockHash),
                             "transactions" => Ok(GeneratedField::Transactions),
                             "timestamp" => Ok(GeneratedField::Timestamp),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
@@ -652,29 +844,20 @@ impl<'de> serde::Deserialize<'de> for ExecuteBlockRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ExecuteBlockRequest;
+            type Value = ExecutableBlock;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.execution.v1alpha2.ExecuteBlockRequest")
+                formatter.write_str("struct astria.execution.v1alpha2.ExecutableBlock")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExecuteBlockRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExecutableBlock, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut prev_block_hash__ = None;
                 let mut transactions__ = None;
                 let mut timestamp__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PrevBlockHash => {
-                            if prev_block_hash__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("prevBlockHash"));
-                            }
-                            prev_block_hash__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::Transactions => {
                             if transactions__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("transactions"));
@@ -689,17 +872,16 @@ impl<'de> serde::Deserialize<'de> for ExecuteBlockRequest {
                         }
                     }
                 }
-                Ok(ExecuteBlockRequest {
-                    prev_block_hash: prev_block_hash__.unwrap_or_default(),
+                Ok(ExecutableBlock {
                     transactions: transactions__.unwrap_or_default(),
                     timestamp: timestamp__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.execution.v1alpha2.ExecuteBlockRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.execution.v1alpha2.ExecutableBlock", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisInfo {
+impl serde::Serialize for ExecuteBlockRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -707,50 +889,179 @@ impl serde::Serialize for GenesisInfo {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rollup_id.is_some() {
+        if !self.prev_block_hash.is_empty() {
             len += 1;
         }
-        if self.sequencer_genesis_block_height != 0 {
+        if !self.transactions.is_empty() {
             len += 1;
         }
-        if self.celestia_block_variance != 0 {
+        if self.timestamp.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.GenesisInfo", len)?;
-        if let Some(v) = self.rollup_id.as_ref() {
-            struct_ser.serialize_field("rollupId", v)?;
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.ExecuteBlockRequest", len)?;
+        if !self.prev_block_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("prevBlockHash", pbjson::private::base64::encode(&self.prev_block_hash).as_str())?;
         }
-        if self.sequencer_genesis_block_height != 0 {
-            struct_ser.serialize_field("sequencerGenesisBlockHeight", &self.sequencer_genesis_block_height)?;
+        if !self.transactions.is_empty() {
+            struct_ser.serialize_field("transactions", &self.transactions)?;
         }
-        if self.celestia_block_variance != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("celestiaBlockVariance", ToString::to_string(&self.celestia_block_variance).as_str())?;
+        if let Some(v) = self.timestamp.as_ref() {
+            struct_ser.serialize_field("timestamp", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisInfo {
+impl<'de> serde::Deserialize<'de> for ExecuteBlockRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rollup_id",
-            "rollupId",
-            "sequencer_genesis_block_height",
-            "sequencerGenesisBlockHeight",
-            "celestia_block_variance",
-            "celestiaBlockVariance",
+            "prev_block_hash",
+            "prevBlockHash",
+            "transactions",
+            "timestamp",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RollupId,
-            SequencerGenesisBlockHeight,
-            CelestiaBlockVariance,
-        }
+            PrevBlockHash,
+            Transactions,
+            Timestamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "prevBlockHash" | "prev_block_hash" => Ok(GeneratedField::PrevBlockHash),
+                            "transactions" => Ok(GeneratedField::Transactions),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExecuteBlockRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.ExecuteBlockRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExecuteBlockRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut prev_block_hash__ = None;
+                let mut transactions__ = None;
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PrevBlockHash => {
+                            if prev_block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevBlockHash"));
+                            }
+                            prev_block_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Transactions => {
+                            if transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactions"));
+                            }
+                            transactions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ExecuteBlockRequest {
+                    prev_block_hash: prev_block_hash__.unwrap_or_default(),
+                    transactions: transactions__.unwrap_or_default(),
+                    timestamp: timestamp__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.ExecuteBlockRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_id.is_some() {
+            len += 1;
+        }
+        if self.sequencer_genesis_block_height != 0 {
+            len += 1;
+        }
+        if self.celestia_block_variance != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.GenesisInfo", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollupId", v)?;
+        }
+        if self.sequencer_genesis_block_height != 0 {
+            struct_ser.serialize_field("sequencerGenesisBlockHeight", &self.sequencer_genesis_block_height)?;
+        }
+        if self.celestia_block_variance != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("celestiaBlockVariance", ToString::to_string(&self.celestia_block_variance).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_id",
+            "rollupId",
+            "sequencer_genesis_block_height",
+            "sequencerGenesisBlockHeight",
+            "celestia_block_variance",
+            "celestiaBlockVariance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupId,
+            SequencerGenesisBlockHeight,
+            CelestiaBlockVariance,
+        }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
             where
@@ -1065,6 +1376,409 @@ impl<'de> serde::Deserialize<'de> for GetGenesisInfoRequest {
         deserializer.deserialize_struct("astria.execution.v1alpha2.GetGenesisInfoRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for RollupBlockWithdrawals {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_height != 0 {
+            len += 1;
+        }
+        if !self.withdrawals.is_empty() {
+            len += 1;
+        }
+        if self.timestamp.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.RollupBlockWithdrawals", len)?;
+        if self.rollup_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rollupHeight", ToString::to_string(&self.rollup_height).as_str())?;
+        }
+        if !self.withdrawals.is_empty() {
+            struct_ser.serialize_field("withdrawals", &self.withdrawals)?;
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            struct_ser.serialize_field("timestamp", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RollupBlockWithdrawals {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_height",
+            "rollupHeight",
+            "withdrawals",
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupHeight,
+            Withdrawals,
+            Timestamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupHeight" | "rollup_height" => Ok(GeneratedField::RollupHeight),
+                            "withdrawals" => Ok(GeneratedField::Withdrawals),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RollupBlockWithdrawals;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.RollupBlockWithdrawals")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupBlockWithdrawals, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_height__ = None;
+                let mut withdrawals__ = None;
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupHeight => {
+                            if rollup_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupHeight"));
+                            }
+                            rollup_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Withdrawals => {
+                            if withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawals"));
+                            }
+                            withdrawals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RollupBlockWithdrawals {
+                    rollup_height: rollup_height__.unwrap_or_default(),
+                    withdrawals: withdrawals__.unwrap_or_default(),
+                    timestamp: timestamp__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.RollupBlockWithdrawals", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RollupWithdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rollup_withdrawal_event_id.is_empty() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        if self.ics20 {
+            len += 1;
+        }
+        if !self.rollup_return_address.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.RollupWithdrawal", len)?;
+        if !self.rollup_withdrawal_event_id.is_empty() {
+            struct_ser.serialize_field("rollupWithdrawalEventId", &self.rollup_withdrawal_event_id)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
+        }
+        if self.ics20 {
+            struct_ser.serialize_field("ics20", &self.ics20)?;
+        }
+        if !self.rollup_return_address.is_empty() {
+            struct_ser.serialize_field("rollupReturnAddress", &self.rollup_return_address)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RollupWithdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_withdrawal_event_id",
+            "rollupWithdrawalEventId",
+            "amount",
+            "destination_chain_address",
+            "destinationChainAddress",
+            "ics20",
+            "rollup_return_address",
+            "rollupReturnAddress",
+            "memo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupWithdrawalEventId,
+            Amount,
+            DestinationChainAddress,
+            Ics20,
+            RollupReturnAddress,
+            Memo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupWithdrawalEventId" | "rollup_withdrawal_event_id" => Ok(GeneratedField::RollupWithdrawalEventId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "ics20" => Ok(GeneratedField::Ics20),
+                            "rollupReturnAddress" | "rollup_return_address" => Ok(GeneratedField::RollupReturnAddress),
+                            "memo" => Ok(GeneratedField::Memo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RollupWithdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.RollupWithdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupWithdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_withdrawal_event_id__ = None;
+                let mut amount__ = None;
+                let mut destination_chain_address__ = None;
+                let mut ics20__ = None;
+                let mut rollup_return_address__ = None;
+                let mut memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupWithdrawalEventId => {
+                            if rollup_withdrawal_event_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupWithdrawalEventId"));
+                            }
+                            rollup_withdrawal_event_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                            }
+                            destination_chain_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Ics20 => {
+                            if ics20__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20"));
+                            }
+                            ics20__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RollupReturnAddress => {
+                            if rollup_return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupReturnAddress"));
+                            }
+                            rollup_return_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RollupWithdrawal {
+                    rollup_withdrawal_event_id: rollup_withdrawal_event_id__.unwrap_or_default(),
+                    amount: amount__,
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                    ics20: ics20__.unwrap_or_default(),
+                    rollup_return_address: rollup_return_address__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.RollupWithdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StreamWithdrawalsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.execution.v1alpha2.StreamWithdrawalsRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StreamWithdrawalsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StreamWithdrawalsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.execution.v1alpha2.StreamWithdrawalsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StreamWithdrawalsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(StreamWithdrawalsRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.execution.v1alpha2.StreamWithdrawalsRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for UpdateCommitmentStateRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -45,3 +45,21 @@ impl ::prost::Name for BridgeAccountInfoResponse {
         ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
     }
 }
+/// A response to the `bridge/withdrawal_event` ABCI query
+/// containing the rollup block number at which a withdrawal event
+/// of a bridge account was processed, if it was.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountWithdrawalEventResponse {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(uint64, optional, tag = "3")]
+    pub rollup_block_number: ::core::option::Option<u64>,
+}
+impl ::prost::Name for BridgeAccountWithdrawalEventResponse {
+    const NAME: &'static str = "BridgeAccountWithdrawalEventResponse";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
@@ -0,0 +1,42 @@
+/// A misbehaviour of a validator recorded by the sequencer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Misbehaviour {
+    /// The kind of misbehaviour, one of `duplicate_vote`, `light_client_attack`, `downtime` or
+    /// `unknown`.
+    #[prost(string, tag = "1")]
+    pub kind: ::prost::alloc::string::String,
+    /// The height at which the misbehaviour was committed.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for Misbehaviour {
+    const NAME: &'static str = "Misbehaviour";
+    const PACKAGE: &'static str = "astria.protocol.evidence.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.evidence.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A response containing the jail status of a validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JailStatusResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The height at which the validator is released from jail, or 0 if it is not jailed.
+    #[prost(uint64, tag = "2")]
+    pub jailed_until: u64,
+    /// The number of consecutive blocks the validator did not sign.
+    #[prost(uint64, tag = "3")]
+    pub missed_blocks: u64,
+    /// All misbehaviour recorded for the validator.
+    #[prost(message, repeated, tag = "4")]
+    pub misbehaviours: ::prost::alloc::vec::Vec<Misbehaviour>,
+}
+impl ::prost::Name for JailStatusResponse {
+    const NAME: &'static str = "JailStatusResponse";
+    const PACKAGE: &'static str = "astria.protocol.evidence.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.evidence.v1alpha1.{}", Self::NAME)
+    }
+}
@@ -27,6 +27,21 @@ pub struct GenesisAppState {
     pub allowed_fee_assets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(message, optional, tag = "10")]
     pub fees: ::core::option::Option<Fees>,
+    /// How the fees collected in a block are distributed. If unset, all fees are sent to
+    /// `authority_sudo_address`.
+    #[prost(message, optional, tag = "11")]
+    pub fee_distribution: ::core::option::Option<
+        super::super::transactions::v1alpha1::FeeDistribution,
+    >,
+    /// Parameters for native staking. If set, the validator set is derived from the stake bonded
+    /// to validators and can no longer be changed by `authority_sudo_address`. If unset, the
+    /// validator set is managed by `authority_sudo_address`.
+    #[prost(message, optional, tag = "12")]
+    pub staking_parameters: ::core::option::Option<StakingParameters>,
+    /// Parameters for jailing and slashing validators on evidence of misbehaviour. If unset,
+    /// misbehaving validators are removed from the validator set but are not jailed.
+    #[prost(message, optional, tag = "13")]
+    pub evidence_parameters: ::core::option::Option<EvidenceParameters>,
 }
 impl ::prost::Name for GenesisAppState {
     const NAME: &'static str = "GenesisAppState";
@@ -129,3 +144,50 @@ impl ::prost::Name for Fees {
         ::prost::alloc::format!("astria.protocol.genesis.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingParameters {
+    /// The number of blocks after which unbonded stake is returned to its delegator.
+    #[prost(uint64, tag = "1")]
+    pub unbonding_period_blocks: u64,
+    /// The amount of the native asset that must be bonded to a validator per unit of voting power.
+    #[prost(message, optional, tag = "2")]
+    pub stake_per_power: ::core::option::Option<
+        super::super::super::primitive::v1::Uint128,
+    >,
+}
+impl ::prost::Name for StakingParameters {
+    const NAME: &'static str = "StakingParameters";
+    const PACKAGE: &'static str = "astria.protocol.genesis.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.genesis.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvidenceParameters {
+    /// The number of blocks a validator stays jailed after misbehaving. Must not be 0.
+    #[prost(uint64, tag = "1")]
+    pub jail_period_blocks: u64,
+    /// The number of consecutive blocks a validator may fail to sign before it is jailed.
+    /// Liveness is not tracked if this is 0.
+    #[prost(uint64, tag = "2")]
+    pub max_missed_blocks: u64,
+    /// The percentage of the stake bonded to a validator that is burned if evidence of
+    /// misbehaviour is submitted against it. Must not exceed 100. Has no effect if staking is
+    /// not enabled.
+    #[prost(uint32, tag = "3")]
+    pub slash_percent: u32,
+    /// The number of blocks after which recorded misbehaviour of a validator is pruned. Should
+    /// match the `evidence.max_age_num_blocks` consensus parameter of CometBFT. Misbehaviour is
+    /// never pruned if this is 0.
+    #[prost(uint64, tag = "4")]
+    pub max_age_blocks: u64,
+}
+impl ::prost::Name for EvidenceParameters {
+    const NAME: &'static str = "EvidenceParameters";
+    const PACKAGE: &'static str = "astria.protocol.genesis.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.genesis.v1alpha1.{}", Self::NAME)
+    }
+}
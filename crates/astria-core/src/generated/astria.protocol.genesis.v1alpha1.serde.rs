@@ -215,6 +215,163 @@ impl<'de> serde::Deserialize<'de> for AddressPrefixes {
         deserializer.deserialize_struct("astria.protocol.genesis.v1alpha1.AddressPrefixes", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EvidenceParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.jail_period_blocks != 0 {
+            len += 1;
+        }
+        if self.max_missed_blocks != 0 {
+            len += 1;
+        }
+        if self.slash_percent != 0 {
+            len += 1;
+        }
+        if self.max_age_blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1alpha1.EvidenceParameters", len)?;
+        if self.jail_period_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("jailPeriodBlocks", ToString::to_string(&self.jail_period_blocks).as_str())?;
+        }
+        if self.max_missed_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxMissedBlocks", ToString::to_string(&self.max_missed_blocks).as_str())?;
+        }
+        if self.slash_percent != 0 {
+            struct_ser.serialize_field("slashPercent", &self.slash_percent)?;
+        }
+        if self.max_age_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxAgeBlocks", ToString::to_string(&self.max_age_blocks).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EvidenceParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "jail_period_blocks",
+            "jailPeriodBlocks",
+            "max_missed_blocks",
+            "maxMissedBlocks",
+            "slash_percent",
+            "slashPercent",
+            "max_age_blocks",
+            "maxAgeBlocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            JailPeriodBlocks,
+            MaxMissedBlocks,
+            SlashPercent,
+            MaxAgeBlocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "jailPeriodBlocks" | "jail_period_blocks" => Ok(GeneratedField::JailPeriodBlocks),
+                            "maxMissedBlocks" | "max_missed_blocks" => Ok(GeneratedField::MaxMissedBlocks),
+                            "slashPercent" | "slash_percent" => Ok(GeneratedField::SlashPercent),
+                            "maxAgeBlocks" | "max_age_blocks" => Ok(GeneratedField::MaxAgeBlocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EvidenceParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.genesis.v1alpha1.EvidenceParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EvidenceParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut jail_period_blocks__ = None;
+                let mut max_missed_blocks__ = None;
+                let mut slash_percent__ = None;
+                let mut max_age_blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::JailPeriodBlocks => {
+                            if jail_period_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jailPeriodBlocks"));
+                            }
+                            jail_period_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxMissedBlocks => {
+                            if max_missed_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxMissedBlocks"));
+                            }
+                            max_missed_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SlashPercent => {
+                            if slash_percent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashPercent"));
+                            }
+                            slash_percent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxAgeBlocks => {
+                            if max_age_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxAgeBlocks"));
+                            }
+                            max_age_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(EvidenceParameters {
+                    jail_period_blocks: jail_period_blocks__.unwrap_or_default(),
+                    max_missed_blocks: max_missed_blocks__.unwrap_or_default(),
+                    slash_percent: slash_percent__.unwrap_or_default(),
+                    max_age_blocks: max_age_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.genesis.v1alpha1.EvidenceParameters", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Fees {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -453,6 +610,15 @@ impl serde::Serialize for GenesisAppState {
         if self.fees.is_some() {
             len += 1;
         }
+        if self.fee_distribution.is_some() {
+            len += 1;
+        }
+        if self.staking_parameters.is_some() {
+            len += 1;
+        }
+        if self.evidence_parameters.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1alpha1.GenesisAppState", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -484,6 +650,15 @@ impl serde::Serialize for GenesisAppState {
         if let Some(v) = self.fees.as_ref() {
             struct_ser.serialize_field("fees", v)?;
         }
+        if let Some(v) = self.fee_distribution.as_ref() {
+            struct_ser.serialize_field("feeDistribution", v)?;
+        }
+        if let Some(v) = self.staking_parameters.as_ref() {
+            struct_ser.serialize_field("stakingParameters", v)?;
+        }
+        if let Some(v) = self.evidence_parameters.as_ref() {
+            struct_ser.serialize_field("evidenceParameters", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -512,6 +687,12 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             "allowed_fee_assets",
             "allowedFeeAssets",
             "fees",
+            "fee_distribution",
+            "feeDistribution",
+            "staking_parameters",
+            "stakingParameters",
+            "evidence_parameters",
+            "evidenceParameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -526,6 +707,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             IbcParameters,
             AllowedFeeAssets,
             Fees,
+            FeeDistribution,
+            StakingParameters,
+            EvidenceParameters,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -557,6 +741,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             "ibcParameters" | "ibc_parameters" => Ok(GeneratedField::IbcParameters),
                             "allowedFeeAssets" | "allowed_fee_assets" => Ok(GeneratedField::AllowedFeeAssets),
                             "fees" => Ok(GeneratedField::Fees),
+                            "feeDistribution" | "fee_distribution" => Ok(GeneratedField::FeeDistribution),
+                            "stakingParameters" | "staking_parameters" => Ok(GeneratedField::StakingParameters),
+                            "evidenceParameters" | "evidence_parameters" => Ok(GeneratedField::EvidenceParameters),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -586,6 +773,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                 let mut ibc_parameters__ = None;
                 let mut allowed_fee_assets__ = None;
                 let mut fees__ = None;
+                let mut fee_distribution__ = None;
+                let mut staking_parameters__ = None;
+                let mut evidence_parameters__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -648,6 +838,24 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             }
                             fees__ = map_.next_value()?;
                         }
+                        GeneratedField::FeeDistribution => {
+                            if fee_distribution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistribution"));
+                            }
+                            fee_distribution__ = map_.next_value()?;
+                        }
+                        GeneratedField::StakingParameters => {
+                            if staking_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingParameters"));
+                            }
+                            staking_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::EvidenceParameters => {
+                            if evidence_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("evidenceParameters"));
+                            }
+                            evidence_parameters__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenesisAppState {
@@ -661,6 +869,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                     ibc_parameters: ibc_parameters__,
                     allowed_fee_assets: allowed_fee_assets__.unwrap_or_default(),
                     fees: fees__,
+                    fee_distribution: fee_distribution__,
+                    staking_parameters: staking_parameters__,
+                    evidence_parameters: evidence_parameters__,
                 })
             }
         }
@@ -795,3 +1006,116 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
         deserializer.deserialize_struct("astria.protocol.genesis.v1alpha1.IbcParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for StakingParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.unbonding_period_blocks != 0 {
+            len += 1;
+        }
+        if self.stake_per_power.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1alpha1.StakingParameters", len)?;
+        if self.unbonding_period_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("unbondingPeriodBlocks", ToString::to_string(&self.unbonding_period_blocks).as_str())?;
+        }
+        if let Some(v) = self.stake_per_power.as_ref() {
+            struct_ser.serialize_field("stakePerPower", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "unbonding_period_blocks",
+            "unbondingPeriodBlocks",
+            "stake_per_power",
+            "stakePerPower",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UnbondingPeriodBlocks,
+            StakePerPower,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "unbondingPeriodBlocks" | "unbonding_period_blocks" => Ok(GeneratedField::UnbondingPeriodBlocks),
+                            "stakePerPower" | "stake_per_power" => Ok(GeneratedField::StakePerPower),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.genesis.v1alpha1.StakingParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut unbonding_period_blocks__ = None;
+                let mut stake_per_power__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UnbondingPeriodBlocks => {
+                            if unbonding_period_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingPeriodBlocks"));
+                            }
+                            unbonding_period_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StakePerPower => {
+                            if stake_per_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakePerPower"));
+                            }
+                            stake_per_power__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StakingParameters {
+                    unbonding_period_blocks: unbonding_period_blocks__.unwrap_or_default(),
+                    stake_per_power: stake_per_power__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.genesis.v1alpha1.StakingParameters", FIELDS, GeneratedVisitor)
+    }
+}
@@ -59,7 +59,7 @@ impl ::prost::Name for TransactionParams {
 pub struct Action {
     #[prost(
         oneof = "action::Value",
-        tags = "1, 2, 11, 12, 13, 14, 21, 22, 50, 51, 52, 53, 55, 56"
+        tags = "1, 2, 3, 4, 11, 12, 13, 14, 21, 22, 50, 51, 52, 53, 55, 56, 57"
     )]
     pub value: ::core::option::Option<action::Value>,
 }
@@ -73,6 +73,10 @@ pub mod action {
         TransferAction(super::TransferAction),
         #[prost(message, tag = "2")]
         SequenceAction(super::SequenceAction),
+        #[prost(message, tag = "3")]
+        BondAction(super::BondAction),
+        #[prost(message, tag = "4")]
+        UnbondAction(super::UnbondAction),
         /// Bridge actions are defined on 11-20
         #[prost(message, tag = "11")]
         InitBridgeAccountAction(super::InitBridgeAccountAction),
@@ -102,6 +106,10 @@ pub mod action {
         FeeChangeAction(super::FeeChangeAction),
         #[prost(message, tag = "56")]
         IbcSudoChangeAction(super::IbcSudoChangeAction),
+        #[prost(message, tag = "57")]
+        FeeDistributionChangeAction(super::FeeDistributionChangeAction),
+        #[prost(message, tag = "58")]
+        UpgradeScheduleAction(super::UpgradeScheduleAction),
     }
 }
 impl ::prost::Name for Action {
@@ -506,6 +514,120 @@ impl ::prost::Name for IbcSudoChangeAction {
         ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
     }
 }
+/// Describes how the fees collected in a block are distributed at the end of the block.
+///
+/// The percentages must add up to 100.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeDistribution {
+    /// The share of fees sent to the proposer of the block.
+    #[prost(uint32, tag = "1")]
+    pub proposer_percent: u32,
+    /// The share of fees split between the validators that signed the previous block's commit,
+    /// weighted by their voting power.
+    #[prost(uint32, tag = "2")]
+    pub validators_percent: u32,
+    /// The share of fees sent to `treasury_address`.
+    #[prost(uint32, tag = "3")]
+    pub treasury_percent: u32,
+    /// The share of fees that is burned. Only fees paid in the native asset are burned; the burn
+    /// share of all other assets is sent to `treasury_address`.
+    #[prost(uint32, tag = "4")]
+    pub burn_percent: u32,
+    #[prost(message, optional, tag = "5")]
+    pub treasury_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+}
+impl ::prost::Name for FeeDistribution {
+    const NAME: &'static str = "FeeDistribution";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeDistributionChangeAction {
+    #[prost(message, optional, tag = "1")]
+    pub new_distribution: ::core::option::Option<FeeDistribution>,
+}
+impl ::prost::Name for FeeDistributionChangeAction {
+    const NAME: &'static str = "FeeDistributionChangeAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
+/// `UpgradeScheduleAction` schedules a named chain upgrade at a future height.
+///
+/// Nodes halt before finalizing the block at `height` unless they run a binary that
+/// registered a migration for the upgrade named `name`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeScheduleAction {
+    /// The name of the upgrade; must not be empty.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The height at which the upgrade takes effect; must be greater than the current height.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for UpgradeScheduleAction {
+    const NAME: &'static str = "UpgradeScheduleAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
+/// `BondAction` bonds an amount of the native asset to a validator.
+///
+/// If the signer's address is the address of `validator_public_key`, this registers the
+/// validator if it is not yet registered. Otherwise the amount is delegated to an already
+/// registered validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BondAction {
+    /// The ed25519 public key of the validator.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub validator_public_key: ::prost::bytes::Bytes,
+    /// The amount of the native asset to bond.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for BondAction {
+    const NAME: &'static str = "BondAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
+/// `UnbondAction` starts unbonding an amount of the signer's stake from a validator.
+///
+/// The amount is returned to the signer once the unbonding period has passed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondAction {
+    /// The ed25519 public key of the validator.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub validator_public_key: ::prost::bytes::Bytes,
+    /// The amount of the native asset to unbond.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for UnbondAction {
+    const NAME: &'static str = "UnbondAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Response to a transaction fee ABCI query.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
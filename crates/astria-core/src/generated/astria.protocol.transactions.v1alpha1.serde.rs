@@ -18,6 +18,12 @@ impl serde::Serialize for Action {
                 action::Value::SequenceAction(v) => {
                     struct_ser.serialize_field("sequenceAction", v)?;
                 }
+                action::Value::BondAction(v) => {
+                    struct_ser.serialize_field("bondAction", v)?;
+                }
+                action::Value::UnbondAction(v) => {
+                    struct_ser.serialize_field("unbondAction", v)?;
+                }
                 action::Value::InitBridgeAccountAction(v) => {
                     struct_ser.serialize_field("initBridgeAccountAction", v)?;
                 }
@@ -54,6 +60,12 @@ impl serde::Serialize for Action {
                 action::Value::IbcSudoChangeAction(v) => {
                     struct_ser.serialize_field("ibcSudoChangeAction", v)?;
                 }
+                action::Value::FeeDistributionChangeAction(v) => {
+                    struct_ser.serialize_field("feeDistributionChangeAction", v)?;
+                }
+                action::Value::UpgradeScheduleAction(v) => {
+                    struct_ser.serialize_field("upgradeScheduleAction", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -70,6 +82,10 @@ impl<'de> serde::Deserialize<'de> for Action {
             "transferAction",
             "sequence_action",
             "sequenceAction",
+            "bond_action",
+            "bondAction",
+            "unbond_action",
+            "unbondAction",
             "init_bridge_account_action",
             "initBridgeAccountAction",
             "bridge_lock_action",
@@ -94,12 +110,18 @@ impl<'de> serde::Deserialize<'de> for Action {
             "feeChangeAction",
             "ibc_sudo_change_action",
             "ibcSudoChangeAction",
+            "fee_distribution_change_action",
+            "feeDistributionChangeAction",
+            "upgrade_schedule_action",
+            "upgradeScheduleAction",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TransferAction,
             SequenceAction,
+            BondAction,
+            UnbondAction,
             InitBridgeAccountAction,
             BridgeLockAction,
             BridgeUnlockAction,
@@ -112,6 +134,8 @@ impl<'de> serde::Deserialize<'de> for Action {
             FeeAssetChangeAction,
             FeeChangeAction,
             IbcSudoChangeAction,
+            FeeDistributionChangeAction,
+            UpgradeScheduleAction,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -135,6 +159,8 @@ impl<'de> serde::Deserialize<'de> for Action {
                         match value {
                             "transferAction" | "transfer_action" => Ok(GeneratedField::TransferAction),
                             "sequenceAction" | "sequence_action" => Ok(GeneratedField::SequenceAction),
+                            "bondAction" | "bond_action" => Ok(GeneratedField::BondAction),
+                            "unbondAction" | "unbond_action" => Ok(GeneratedField::UnbondAction),
                             "initBridgeAccountAction" | "init_bridge_account_action" => Ok(GeneratedField::InitBridgeAccountAction),
                             "bridgeLockAction" | "bridge_lock_action" => Ok(GeneratedField::BridgeLockAction),
                             "bridgeUnlockAction" | "bridge_unlock_action" => Ok(GeneratedField::BridgeUnlockAction),
@@ -147,6 +173,8 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "feeAssetChangeAction" | "fee_asset_change_action" => Ok(GeneratedField::FeeAssetChangeAction),
                             "feeChangeAction" | "fee_change_action" => Ok(GeneratedField::FeeChangeAction),
                             "ibcSudoChangeAction" | "ibc_sudo_change_action" => Ok(GeneratedField::IbcSudoChangeAction),
+                            "feeDistributionChangeAction" | "fee_distribution_change_action" => Ok(GeneratedField::FeeDistributionChangeAction),
+                            "upgradeScheduleAction" | "upgrade_schedule_action" => Ok(GeneratedField::UpgradeScheduleAction),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -181,6 +209,20 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("sequenceAction"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::SequenceAction)
+;
+                        }
+                        GeneratedField::BondAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bondAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::BondAction)
+;
+                        }
+                        GeneratedField::UnbondAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::UnbondAction)
 ;
                         }
                         GeneratedField::InitBridgeAccountAction => {
@@ -265,6 +307,20 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("ibcSudoChangeAction"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::IbcSudoChangeAction)
+;
+                        }
+                        GeneratedField::FeeDistributionChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistributionChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::FeeDistributionChangeAction)
+;
+                        }
+                        GeneratedField::UpgradeScheduleAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradeScheduleAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::UpgradeScheduleAction)
 ;
                         }
                     }
@@ -277,6 +333,136 @@ impl<'de> serde::Deserialize<'de> for Action {
         deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.Action", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for BondAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.validator_public_key.is_empty() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.BondAction", len)?;
+        if !self.validator_public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("validatorPublicKey", pbjson::private::base64::encode(&self.validator_public_key).as_str())?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BondAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_public_key",
+            "validatorPublicKey",
+            "amount",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorPublicKey,
+            Amount,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorPublicKey" | "validator_public_key" => Ok(GeneratedField::ValidatorPublicKey),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BondAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.BondAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BondAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_public_key__ = None;
+                let mut amount__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorPublicKey => {
+                            if validator_public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorPublicKey"));
+                            }
+                            validator_public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BondAction {
+                    validator_public_key: validator_public_key__.unwrap_or_default(),
+                    amount: amount__,
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.BondAction", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BridgeLockAction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1072,6 +1258,270 @@ impl<'de> serde::Deserialize<'de> for FeeChangeAction {
         deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.FeeChangeAction", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for FeeDistribution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposer_percent != 0 {
+            len += 1;
+        }
+        if self.validators_percent != 0 {
+            len += 1;
+        }
+        if self.treasury_percent != 0 {
+            len += 1;
+        }
+        if self.burn_percent != 0 {
+            len += 1;
+        }
+        if self.treasury_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.FeeDistribution", len)?;
+        if self.proposer_percent != 0 {
+            struct_ser.serialize_field("proposerPercent", &self.proposer_percent)?;
+        }
+        if self.validators_percent != 0 {
+            struct_ser.serialize_field("validatorsPercent", &self.validators_percent)?;
+        }
+        if self.treasury_percent != 0 {
+            struct_ser.serialize_field("treasuryPercent", &self.treasury_percent)?;
+        }
+        if self.burn_percent != 0 {
+            struct_ser.serialize_field("burnPercent", &self.burn_percent)?;
+        }
+        if let Some(v) = self.treasury_address.as_ref() {
+            struct_ser.serialize_field("treasuryAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeDistribution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposer_percent",
+            "proposerPercent",
+            "validators_percent",
+            "validatorsPercent",
+            "treasury_percent",
+            "treasuryPercent",
+            "burn_percent",
+            "burnPercent",
+            "treasury_address",
+            "treasuryAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposerPercent,
+            ValidatorsPercent,
+            TreasuryPercent,
+            BurnPercent,
+            TreasuryAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposerPercent" | "proposer_percent" => Ok(GeneratedField::ProposerPercent),
+                            "validatorsPercent" | "validators_percent" => Ok(GeneratedField::ValidatorsPercent),
+                            "treasuryPercent" | "treasury_percent" => Ok(GeneratedField::TreasuryPercent),
+                            "burnPercent" | "burn_percent" => Ok(GeneratedField::BurnPercent),
+                            "treasuryAddress" | "treasury_address" => Ok(GeneratedField::TreasuryAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeDistribution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.FeeDistribution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeDistribution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposer_percent__ = None;
+                let mut validators_percent__ = None;
+                let mut treasury_percent__ = None;
+                let mut burn_percent__ = None;
+                let mut treasury_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposerPercent => {
+                            if proposer_percent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposerPercent"));
+                            }
+                            proposer_percent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorsPercent => {
+                            if validators_percent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorsPercent"));
+                            }
+                            validators_percent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TreasuryPercent => {
+                            if treasury_percent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("treasuryPercent"));
+                            }
+                            treasury_percent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BurnPercent => {
+                            if burn_percent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("burnPercent"));
+                            }
+                            burn_percent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TreasuryAddress => {
+                            if treasury_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("treasuryAddress"));
+                            }
+                            treasury_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeDistribution {
+                    proposer_percent: proposer_percent__.unwrap_or_default(),
+                    validators_percent: validators_percent__.unwrap_or_default(),
+                    treasury_percent: treasury_percent__.unwrap_or_default(),
+                    burn_percent: burn_percent__.unwrap_or_default(),
+                    treasury_address: treasury_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.FeeDistribution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeDistributionChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_distribution.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction", len)?;
+        if let Some(v) = self.new_distribution.as_ref() {
+            struct_ser.serialize_field("newDistribution", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeDistributionChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_distribution",
+            "newDistribution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewDistribution,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newDistribution" | "new_distribution" => Ok(GeneratedField::NewDistribution),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeDistributionChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeDistributionChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_distribution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewDistribution => {
+                            if new_distribution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newDistribution"));
+                            }
+                            new_distribution__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeDistributionChangeAction {
+                    new_distribution: new_distribution__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for IbcHeight {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2651,6 +3101,136 @@ impl<'de> serde::Deserialize<'de> for TransferAction {
         deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.TransferAction", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UnbondAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.validator_public_key.is_empty() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.UnbondAction", len)?;
+        if !self.validator_public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("validatorPublicKey", pbjson::private::base64::encode(&self.validator_public_key).as_str())?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnbondAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator_public_key",
+            "validatorPublicKey",
+            "amount",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ValidatorPublicKey,
+            Amount,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validatorPublicKey" | "validator_public_key" => Ok(GeneratedField::ValidatorPublicKey),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnbondAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.UnbondAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator_public_key__ = None;
+                let mut amount__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValidatorPublicKey => {
+                            if validator_public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorPublicKey"));
+                            }
+                            validator_public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(UnbondAction {
+                    validator_public_key: validator_public_key__.unwrap_or_default(),
+                    amount: amount__,
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.UnbondAction", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for UnsignedTransaction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2759,3 +3339,114 @@ impl<'de> serde::Deserialize<'de> for UnsignedTransaction {
         deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.UnsignedTransaction", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpgradeScheduleAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.UpgradeScheduleAction", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpgradeScheduleAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpgradeScheduleAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.UpgradeScheduleAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpgradeScheduleAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(UpgradeScheduleAction {
+                    name: name__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.UpgradeScheduleAction", FIELDS, GeneratedVisitor)
+    }
+}
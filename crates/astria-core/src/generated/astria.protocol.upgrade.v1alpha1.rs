@@ -0,0 +1,35 @@
+/// A chain upgrade scheduled by the sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Upgrade {
+    /// The name of the upgrade.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The height at which the chain halts unless the node runs a binary supporting the upgrade.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for Upgrade {
+    const NAME: &'static str = "Upgrade";
+    const PACKAGE: &'static str = "astria.protocol.upgrade.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.upgrade.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A response containing the upgrades that were scheduled but not yet applied.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingUpgradesResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The pending upgrades, ordered by height.
+    #[prost(message, repeated, tag = "2")]
+    pub upgrades: ::prost::alloc::vec::Vec<Upgrade>,
+}
+impl ::prost::Name for PendingUpgradesResponse {
+    const NAME: &'static str = "PendingUpgradesResponse";
+    const PACKAGE: &'static str = "astria.protocol.upgrade.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.upgrade.v1alpha1.{}", Self::NAME)
+    }
+}
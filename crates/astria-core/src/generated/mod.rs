@@ -84,6 +84,11 @@ pub mod protocol {
         pub mod v1alpha1;
     }
     #[path = ""]
+    pub mod evidence {
+        #[path = "astria.protocol.evidence.v1alpha1.rs"]
+        pub mod v1alpha1;
+    }
+    #[path = ""]
     pub mod genesis {
         pub mod v1alpha1 {
             include!("astria.protocol.genesis.v1alpha1.rs");
@@ -119,6 +124,11 @@ pub mod protocol {
             }
         }
     }
+    #[path = ""]
+    pub mod upgrade {
+        #[path = "astria.protocol.upgrade.v1alpha1.rs"]
+        pub mod v1alpha1;
+    }
 }
 
 #[path = ""]
@@ -233,3 +233,45 @@ impl BridgeAccountInfoResponseError {
         Self(BridgeAccountInfoResponseErrorKind::InvalidDenom(err))
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeAccountWithdrawalEventResponse {
+    pub height: u64,
+    /// The rollup block number recorded when the withdrawal event was processed, or `None` if the
+    /// event was not processed for the bridge account.
+    pub rollup_block_number: Option<u64>,
+}
+
+impl BridgeAccountWithdrawalEventResponse {
+    /// Converts a protobuf [`raw::BridgeAccountWithdrawalEventResponse`] to a native
+    /// [`BridgeAccountWithdrawalEventResponse`].
+    #[must_use]
+    pub fn from_raw(raw: raw::BridgeAccountWithdrawalEventResponse) -> Self {
+        Self {
+            height: raw.height,
+            rollup_block_number: raw.rollup_block_number,
+        }
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountWithdrawalEventResponse {
+        raw::BridgeAccountWithdrawalEventResponse {
+            height: self.height,
+            rollup_block_number: self.rollup_block_number,
+        }
+    }
+}
+
+impl raw::BridgeAccountWithdrawalEventResponse {
+    #[must_use]
+    pub fn into_native(self) -> BridgeAccountWithdrawalEventResponse {
+        BridgeAccountWithdrawalEventResponse::from_raw(self)
+    }
+
+    #[must_use]
+    pub fn from_native(
+        native: BridgeAccountWithdrawalEventResponse,
+    ) -> raw::BridgeAccountWithdrawalEventResponse {
+        native.into_raw()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod v1alpha1;
+
+use crate::generated::protocol::evidence::v1alpha1 as raw;
@@ -0,0 +1,220 @@
+use super::raw;
+
+/// The kind of misbehaviour a validator was jailed for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MisbehaviourKind {
+    /// The validator signed two conflicting votes at the same height and round.
+    DuplicateVote,
+    /// The validator took part in an attack on light clients.
+    LightClientAttack,
+    /// The validator failed to sign too many consecutive blocks.
+    Downtime,
+    /// Evidence of misbehaviour of a kind unknown to cometbft.
+    Unknown,
+}
+
+impl MisbehaviourKind {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DuplicateVote => "duplicate_vote",
+            Self::LightClientAttack => "light_client_attack",
+            Self::Downtime => "downtime",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for MisbehaviourKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for MisbehaviourKind {
+    type Err = ParseMisbehaviourKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "duplicate_vote" => Ok(Self::DuplicateVote),
+            "light_client_attack" => Ok(Self::LightClientAttack),
+            "downtime" => Ok(Self::Downtime),
+            "unknown" => Ok(Self::Unknown),
+            other => Err(ParseMisbehaviourKindError {
+                kind: other.to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("unknown misbehaviour kind `{kind}`")]
+pub struct ParseMisbehaviourKindError {
+    kind: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct MisbehaviourError(MisbehaviourErrorKind);
+
+impl MisbehaviourError {
+    #[must_use]
+    fn kind(source: ParseMisbehaviourKindError) -> Self {
+        Self(MisbehaviourErrorKind::Kind {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum MisbehaviourErrorKind {
+    #[error("`kind` field was invalid")]
+    Kind { source: ParseMisbehaviourKindError },
+}
+
+/// A misbehaviour of a validator recorded by the sequencer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub kind: MisbehaviourKind,
+    pub height: u64,
+}
+
+impl Misbehaviour {
+    /// Converts a protobuf [`raw::Misbehaviour`] to an astria
+    /// native [`Misbehaviour`].
+    ///
+    /// # Errors
+    /// Returns an error if the protobuf `kind` field is not a known misbehaviour kind.
+    pub fn try_from_raw(proto: &raw::Misbehaviour) -> Result<Self, MisbehaviourError> {
+        let raw::Misbehaviour {
+            kind,
+            height,
+        } = proto;
+        Ok(Self {
+            kind: kind.parse().map_err(MisbehaviourError::kind)?,
+            height: *height,
+        })
+    }
+
+    /// Converts an astria native [`Misbehaviour`] to a
+    /// protobuf [`raw::Misbehaviour`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Misbehaviour {
+        raw::Misbehaviour {
+            kind: self.kind.to_string(),
+            height: self.height,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct JailStatusResponseError(JailStatusResponseErrorKind);
+
+impl JailStatusResponseError {
+    #[must_use]
+    fn misbehaviour(source: MisbehaviourError) -> Self {
+        Self(JailStatusResponseErrorKind::Misbehaviour {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum JailStatusResponseErrorKind {
+    #[error("`misbehaviours` contained an invalid misbehaviour")]
+    Misbehaviour { source: MisbehaviourError },
+}
+
+/// The sequencer response to a jail status request for a given validator at a given height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JailStatusResponse {
+    pub height: u64,
+    /// The height at which the validator is released from jail, or `None` if it is not jailed.
+    pub jailed_until: Option<u64>,
+    /// The number of consecutive blocks the validator did not sign.
+    pub missed_blocks: u64,
+    pub misbehaviours: Vec<Misbehaviour>,
+}
+
+impl JailStatusResponse {
+    /// Converts a protobuf [`raw::JailStatusResponse`] to an astria
+    /// native [`JailStatusResponse`].
+    ///
+    /// # Errors
+    /// Returns an error if one or more of the protobuf `misbehaviours` has an unknown kind.
+    pub fn try_from_raw(proto: &raw::JailStatusResponse) -> Result<Self, JailStatusResponseError> {
+        let raw::JailStatusResponse {
+            height,
+            jailed_until,
+            missed_blocks,
+            misbehaviours,
+        } = proto;
+        Ok(Self {
+            height: *height,
+            jailed_until: Some(*jailed_until).filter(|height| *height > 0),
+            missed_blocks: *missed_blocks,
+            misbehaviours: misbehaviours
+                .iter()
+                .map(Misbehaviour::try_from_raw)
+                .collect::<Result<_, _>>()
+                .map_err(JailStatusResponseError::misbehaviour)?,
+        })
+    }
+
+    /// Converts an astria native [`JailStatusResponse`] to a
+    /// protobuf [`raw::JailStatusResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::JailStatusResponse {
+        let Self {
+            height,
+            jailed_until,
+            missed_blocks,
+            misbehaviours,
+        } = self;
+        raw::JailStatusResponse {
+            height,
+            jailed_until: jailed_until.unwrap_or_default(),
+            missed_blocks,
+            misbehaviours: misbehaviours
+                .into_iter()
+                .map(Misbehaviour::into_raw)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        JailStatusResponse,
+        Misbehaviour,
+        MisbehaviourKind,
+    };
+
+    #[test]
+    fn jail_status_roundtrip_is_correct() {
+        let expected = JailStatusResponse {
+            height: 42,
+            jailed_until: Some(52),
+            missed_blocks: 3,
+            misbehaviours: vec![
+                Misbehaviour {
+                    kind: MisbehaviourKind::DuplicateVote,
+                    height: 40,
+                },
+                Misbehaviour {
+                    kind: MisbehaviourKind::Downtime,
+                    height: 41,
+                },
+            ],
+        };
+        let actual = JailStatusResponse::try_from_raw(&expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn unknown_misbehaviour_kind_is_rejected() {
+        assert!("double_sign".parse::<MisbehaviourKind>().is_err());
+    }
+}
@@ -16,6 +16,10 @@ use crate::{
         Bech32,
         Bech32m,
     },
+    protocol::transaction::v1alpha1::action::{
+        FeeDistribution,
+        FeeDistributionError,
+    },
     Protobuf,
 };
 
@@ -40,6 +44,9 @@ pub struct GenesisAppState {
     ibc_parameters: IBCParameters,
     allowed_fee_assets: Vec<asset::Denom>,
     fees: Fees,
+    fee_distribution: Option<FeeDistribution>,
+    staking_parameters: Option<StakingParameters>,
+    evidence_parameters: Option<EvidenceParameters>,
 }
 
 impl GenesisAppState {
@@ -93,6 +100,31 @@ impl GenesisAppState {
         &self.fees
     }
 
+    /// How the fees collected in a block are distributed.
+    ///
+    /// If not set, all fees are sent to the authority sudo address.
+    #[must_use]
+    pub fn fee_distribution(&self) -> Option<&FeeDistribution> {
+        self.fee_distribution.as_ref()
+    }
+
+    /// The parameters of native staking.
+    ///
+    /// If set, the validator set is derived from the native asset bonded to validators.
+    /// If not set, the validator set is managed by the authority sudo address.
+    #[must_use]
+    pub fn staking_parameters(&self) -> Option<&StakingParameters> {
+        self.staking_parameters.as_ref()
+    }
+
+    /// The parameters for jailing and slashing misbehaving validators.
+    ///
+    /// If not set, misbehaving validators are removed from the validator set but not jailed.
+    #[must_use]
+    pub fn evidence_parameters(&self) -> Option<&EvidenceParameters> {
+        self.evidence_parameters.as_ref()
+    }
+
     fn ensure_address_has_base_prefix(
         &self,
         address: &Address,
@@ -123,6 +155,12 @@ impl GenesisAppState {
         for (i, address) in self.ibc_relayer_addresses.iter().enumerate() {
             self.ensure_address_has_base_prefix(address, &format!(".ibc_relayer_addresses[{i}]"))?;
         }
+        if let Some(fee_distribution) = &self.fee_distribution {
+            self.ensure_address_has_base_prefix(
+                fee_distribution.treasury_address(),
+                ".fee_distribution.treasury_address",
+            )?;
+        }
         Ok(())
     }
 }
@@ -143,6 +181,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            fee_distribution,
+            staking_parameters,
+            evidence_parameters,
         } = raw;
         let address_prefixes = address_prefixes
             .as_ref()
@@ -195,6 +236,24 @@ impl Protobuf for GenesisAppState {
             .ok_or_else(|| Self::Error::field_not_set("fees"))
             .and_then(|fees| Fees::try_from_raw_ref(fees).map_err(Self::Error::fees))?;
 
+        let fee_distribution = fee_distribution
+            .as_ref()
+            .map(FeeDistribution::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::fee_distribution)?;
+
+        let staking_parameters = staking_parameters
+            .as_ref()
+            .map(StakingParameters::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::staking_parameters)?;
+
+        let evidence_parameters = evidence_parameters
+            .as_ref()
+            .map(EvidenceParameters::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::evidence_parameters)?;
+
         let this = Self {
             address_prefixes,
             accounts,
@@ -206,6 +265,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            fee_distribution,
+            staking_parameters,
+            evidence_parameters,
         };
         this.ensure_all_addresses_have_base_prefix()
             .map_err(Self::Error::address_does_not_match_base)?;
@@ -224,6 +286,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            fee_distribution,
+            staking_parameters,
+            evidence_parameters,
         } = self;
         Self::Raw {
             address_prefixes: Some(address_prefixes.to_raw()),
@@ -236,6 +301,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters: Some(ibc_parameters.to_raw()),
             allowed_fee_assets: allowed_fee_assets.iter().map(ToString::to_string).collect(),
             fees: Some(fees.to_raw()),
+            fee_distribution: fee_distribution.as_ref().map(FeeDistribution::to_raw),
+            staking_parameters: staking_parameters.as_ref().map(StakingParameters::to_raw),
+            evidence_parameters: evidence_parameters.as_ref().map(EvidenceParameters::to_raw),
         }
     }
 }
@@ -295,6 +363,12 @@ impl GenesisAppStateError {
         })
     }
 
+    fn fee_distribution(source: FeeDistributionError) -> Self {
+        Self(GenesisAppStateErrorKind::FeeDistribution {
+            source,
+        })
+    }
+
     fn field_not_set(name: &'static str) -> Self {
         Self(GenesisAppStateErrorKind::FieldNotSet {
             name,
@@ -318,6 +392,18 @@ impl GenesisAppStateError {
             source,
         })
     }
+
+    fn staking_parameters(source: StakingParametersError) -> Self {
+        Self(GenesisAppStateErrorKind::StakingParameters {
+            source,
+        })
+    }
+
+    fn evidence_parameters(source: EvidenceParametersError) -> Self {
+        Self(GenesisAppStateErrorKind::EvidenceParameters {
+            source,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -335,8 +421,12 @@ enum GenesisAppStateErrorKind {
     AllowedFeeAssets { source: ParseDenomError },
     #[error("`authority_sudo_address` field was invalid")]
     AuthoritySudoAddress { source: AddressError },
+    #[error("`evidence_parameters` field was invalid")]
+    EvidenceParameters { source: EvidenceParametersError },
     #[error("`fees` field was invalid")]
     Fees { source: FeesError },
+    #[error("`fee_distribution` field was invalid")]
+    FeeDistribution { source: FeeDistributionError },
     #[error("`ibc_sudo_address` field was invalid")]
     IbcSudoAddress { source: AddressError },
     #[error("`ibc_relayer_addresses` field was invalid")]
@@ -345,6 +435,8 @@ enum GenesisAppStateErrorKind {
     FieldNotSet { name: &'static str },
     #[error("`native_asset_base_denomination` field was invalid")]
     NativeAssetBaseDenomination { source: ParseTracePrefixedError },
+    #[error("`staking_parameters` field was invalid")]
+    StakingParameters { source: StakingParametersError },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -633,6 +725,152 @@ enum FeesErrorKind {
     FieldNotSet { name: &'static str },
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakingParameters {
+    /// The number of blocks after which unbonded stake is returned to its owner.
+    pub unbonding_period_blocks: u64,
+    /// The amount of the native asset that is bonded per unit of validator voting power.
+    pub stake_per_power: u128,
+}
+
+impl Protobuf for StakingParameters {
+    type Error = StakingParametersError;
+    type Raw = raw::StakingParameters;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            unbonding_period_blocks,
+            stake_per_power,
+        } = raw;
+        let stake_per_power: u128 = stake_per_power
+            .ok_or_else(|| Self::Error::field_not_set("stake_per_power"))?
+            .into();
+        if stake_per_power == 0 {
+            return Err(Self::Error::zero_stake_per_power());
+        }
+        Ok(Self {
+            unbonding_period_blocks: *unbonding_period_blocks,
+            stake_per_power,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            unbonding_period_blocks,
+            stake_per_power,
+        } = self;
+        Self::Raw {
+            unbonding_period_blocks: *unbonding_period_blocks,
+            stake_per_power: Some(stake_per_power.into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct StakingParametersError(StakingParametersErrorKind);
+
+impl StakingParametersError {
+    fn field_not_set(name: &'static str) -> Self {
+        Self(StakingParametersErrorKind::FieldNotSet {
+            name,
+        })
+    }
+
+    fn zero_stake_per_power() -> Self {
+        Self(StakingParametersErrorKind::ZeroStakePerPower)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", StakingParameters::full_name())]
+enum StakingParametersErrorKind {
+    #[error("field was not set: `{name}`")]
+    FieldNotSet { name: &'static str },
+    #[error("`stake_per_power` must not be zero")]
+    ZeroStakePerPower,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvidenceParameters {
+    /// The number of blocks a validator stays jailed after misbehaving. Never zero.
+    pub jail_period_blocks: u64,
+    /// The number of consecutive blocks a validator may fail to sign before it is jailed.
+    /// Liveness is not tracked if this is 0.
+    pub max_missed_blocks: u64,
+    /// The percentage of a validator's stake that is burned on evidence of misbehaviour.
+    pub slash_percent: u32,
+    /// The number of blocks after which recorded misbehaviour is pruned. Misbehaviour is never
+    /// pruned if this is 0.
+    pub max_age_blocks: u64,
+}
+
+impl Protobuf for EvidenceParameters {
+    type Error = EvidenceParametersError;
+    type Raw = raw::EvidenceParameters;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            jail_period_blocks,
+            max_missed_blocks,
+            slash_percent,
+            max_age_blocks,
+        } = raw;
+        if *jail_period_blocks == 0 {
+            return Err(Self::Error::zero_jail_period());
+        }
+        if *slash_percent > 100 {
+            return Err(Self::Error::slash_percent_too_large(*slash_percent));
+        }
+        Ok(Self {
+            jail_period_blocks: *jail_period_blocks,
+            max_missed_blocks: *max_missed_blocks,
+            slash_percent: *slash_percent,
+            max_age_blocks: *max_age_blocks,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            jail_period_blocks,
+            max_missed_blocks,
+            slash_percent,
+            max_age_blocks,
+        } = self;
+        Self::Raw {
+            jail_period_blocks: *jail_period_blocks,
+            max_missed_blocks: *max_missed_blocks,
+            slash_percent: *slash_percent,
+            max_age_blocks: *max_age_blocks,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct EvidenceParametersError(EvidenceParametersErrorKind);
+
+impl EvidenceParametersError {
+    fn zero_jail_period() -> Self {
+        Self(EvidenceParametersErrorKind::ZeroJailPeriod)
+    }
+
+    fn slash_percent_too_large(percent: u32) -> Self {
+        Self(EvidenceParametersErrorKind::SlashPercentTooLarge {
+            percent,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", EvidenceParameters::full_name())]
+enum EvidenceParametersErrorKind {
+    #[error("`jail_period_blocks` must not be zero")]
+    ZeroJailPeriod,
+    #[error("`slash_percent` must not exceed 100, but was {percent}")]
+    SlashPercentTooLarge { percent: u32 },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -712,6 +950,9 @@ mod tests {
                 bridge_sudo_change_fee: Some(24.into()),
                 ics20_withdrawal_base_fee: Some(24.into()),
             }),
+            fee_distribution: None,
+            staking_parameters: None,
+            evidence_parameters: None,
         }
     }
 
@@ -768,6 +1009,19 @@ mod tests {
             },
             ".ibc_relayer_addresses[1]",
         );
+        assert_bad_prefix(
+            raw::GenesisAppState {
+                fee_distribution: Some(
+                    crate::generated::protocol::transactions::v1alpha1::FeeDistribution {
+                        proposer_percent: 100,
+                        treasury_address: Some(mallory().to_raw()),
+                        ..Default::default()
+                    },
+                ),
+                ..proto_genesis_state()
+            },
+            ".fee_distribution.treasury_address",
+        );
         assert_bad_prefix(
             raw::GenesisAppState {
                 accounts: vec![
@@ -786,6 +1040,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fee_distribution_percentages_must_add_up_to_100() {
+        let fee_distribution = |proposer_percent, burn_percent| {
+            crate::generated::protocol::transactions::v1alpha1::FeeDistribution {
+                proposer_percent,
+                validators_percent: 20,
+                treasury_percent: 30,
+                burn_percent,
+                treasury_address: Some(bob().to_raw()),
+            }
+        };
+        let genesis_state = GenesisAppState::try_from(raw::GenesisAppState {
+            fee_distribution: Some(fee_distribution(40, 10)),
+            ..proto_genesis_state()
+        })
+        .unwrap();
+        assert_eq!(
+            genesis_state.fee_distribution().unwrap().proposer_percent(),
+            40
+        );
+
+        let error = GenesisAppState::try_from(raw::GenesisAppState {
+            fee_distribution: Some(fee_distribution(40, 20)),
+            ..proto_genesis_state()
+        })
+        .expect_err("fee distribution adding up to 110 percent must be rejected");
+        assert!(
+            matches!(error.0, GenesisAppStateErrorKind::FeeDistribution { .. }),
+            "expected a fee distribution error, got: {error:?}"
+        );
+    }
+
+    #[test]
+    fn staking_parameters_require_non_zero_stake_per_power() {
+        let staking_parameters = |stake_per_power: u128| raw::StakingParameters {
+            unbonding_period_blocks: 10,
+            stake_per_power: Some(stake_per_power.into()),
+        };
+        let genesis_state = GenesisAppState::try_from(raw::GenesisAppState {
+            staking_parameters: Some(staking_parameters(1_000)),
+            ..proto_genesis_state()
+        })
+        .unwrap();
+        assert_eq!(
+            genesis_state.staking_parameters(),
+            Some(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 1_000,
+            })
+        );
+
+        let error = GenesisAppState::try_from(raw::GenesisAppState {
+            staking_parameters: Some(staking_parameters(0)),
+            ..proto_genesis_state()
+        })
+        .expect_err("zero stake per power must be rejected");
+        assert!(
+            matches!(error.0, GenesisAppStateErrorKind::StakingParameters { .. }),
+            "expected a staking parameters error, got: {error:?}"
+        );
+    }
+
+    #[test]
+    fn evidence_parameters_reject_slash_percent_above_100() {
+        let evidence_parameters = |slash_percent: u32| raw::EvidenceParameters {
+            jail_period_blocks: 10,
+            max_missed_blocks: 5,
+            slash_percent,
+            max_age_blocks: 100,
+        };
+        let genesis_state = GenesisAppState::try_from(raw::GenesisAppState {
+            evidence_parameters: Some(evidence_parameters(100)),
+            ..proto_genesis_state()
+        })
+        .unwrap();
+        assert_eq!(
+            genesis_state.evidence_parameters(),
+            Some(&EvidenceParameters {
+                jail_period_blocks: 10,
+                max_missed_blocks: 5,
+                slash_percent: 100,
+                max_age_blocks: 100,
+            })
+        );
+
+        let error = GenesisAppState::try_from(raw::GenesisAppState {
+            evidence_parameters: Some(evidence_parameters(101)),
+            ..proto_genesis_state()
+        })
+        .expect_err("slashing more than 100 percent must be rejected");
+        assert!(
+            matches!(error.0, GenesisAppStateErrorKind::EvidenceParameters { .. }),
+            "expected an evidence parameters error, got: {error:?}"
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn genesis_state_is_unchanged() {
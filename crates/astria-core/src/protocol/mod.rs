@@ -8,9 +8,11 @@ pub mod abci;
 pub mod account;
 pub mod asset;
 pub mod bridge;
+pub mod evidence;
 pub mod genesis;
 pub mod memos;
 pub mod transaction;
+pub mod upgrade;
 
 #[cfg(any(feature = "test-utils", test))]
 pub mod test_utils;
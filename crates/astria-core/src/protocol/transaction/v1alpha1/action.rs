@@ -32,6 +32,8 @@ use crate::{
 pub enum Action {
     Sequence(SequenceAction),
     Transfer(TransferAction),
+    Bond(BondAction),
+    Unbond(UnbondAction),
     ValidatorUpdate(ValidatorUpdate),
     SudoAddressChange(SudoAddressChangeAction),
     Ibc(IbcRelay),
@@ -44,6 +46,8 @@ pub enum Action {
     BridgeUnlock(BridgeUnlockAction),
     BridgeSudoChange(BridgeSudoChangeAction),
     FeeChange(FeeChangeAction),
+    FeeDistributionChange(FeeDistributionChangeAction),
+    UpgradeSchedule(UpgradeScheduleAction),
 }
 
 impl Protobuf for Action {
@@ -56,6 +60,8 @@ impl Protobuf for Action {
         let kind = match self {
             Action::Sequence(act) => Value::SequenceAction(act.to_raw()),
             Action::Transfer(act) => Value::TransferAction(act.to_raw()),
+            Action::Bond(act) => Value::BondAction(act.to_raw()),
+            Action::Unbond(act) => Value::UnbondAction(act.to_raw()),
             Action::ValidatorUpdate(act) => Value::ValidatorUpdateAction(act.to_raw()),
             Action::SudoAddressChange(act) => {
                 Value::SudoAddressChangeAction(act.clone().into_raw())
@@ -70,6 +76,8 @@ impl Protobuf for Action {
             Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.to_raw()),
             Action::BridgeSudoChange(act) => Value::BridgeSudoChangeAction(act.to_raw()),
             Action::FeeChange(act) => Value::FeeChangeAction(act.to_raw()),
+            Action::FeeDistributionChange(act) => Value::FeeDistributionChangeAction(act.to_raw()),
+            Action::UpgradeSchedule(act) => Value::UpgradeScheduleAction(act.to_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -107,6 +115,12 @@ impl Protobuf for Action {
             Value::TransferAction(act) => {
                 Self::Transfer(TransferAction::try_from_raw(act).map_err(ActionError::transfer)?)
             }
+            Value::BondAction(act) => {
+                Self::Bond(BondAction::try_from_raw(act).map_err(ActionError::bond)?)
+            }
+            Value::UnbondAction(act) => {
+                Self::Unbond(UnbondAction::try_from_raw(act).map_err(ActionError::unbond)?)
+            }
             Value::ValidatorUpdateAction(act) => Self::ValidatorUpdate(
                 ValidatorUpdate::try_from_raw(act).map_err(ActionError::validator_update)?,
             ),
@@ -148,6 +162,13 @@ impl Protobuf for Action {
             Value::FeeChangeAction(act) => Self::FeeChange(
                 FeeChangeAction::try_from_raw_ref(&act).map_err(ActionError::fee_change)?,
             ),
+            Value::FeeDistributionChangeAction(act) => Self::FeeDistributionChange(
+                FeeDistributionChangeAction::try_from_raw_ref(&act)
+                    .map_err(ActionError::fee_distribution_change)?,
+            ),
+            Value::UpgradeScheduleAction(act) => Self::UpgradeSchedule(
+                UpgradeScheduleAction::try_from_raw(act).map_err(ActionError::upgrade_schedule)?,
+            ),
         };
         Ok(action)
     }
@@ -183,6 +204,18 @@ impl From<TransferAction> for Action {
     }
 }
 
+impl From<BondAction> for Action {
+    fn from(value: BondAction) -> Self {
+        Self::Bond(value)
+    }
+}
+
+impl From<UnbondAction> for Action {
+    fn from(value: UnbondAction) -> Self {
+        Self::Unbond(value)
+    }
+}
+
 impl From<SudoAddressChangeAction> for Action {
     fn from(value: SudoAddressChangeAction) -> Self {
         Self::SudoAddressChange(value)
@@ -249,6 +282,18 @@ impl From<FeeChangeAction> for Action {
     }
 }
 
+impl From<FeeDistributionChangeAction> for Action {
+    fn from(value: FeeDistributionChangeAction) -> Self {
+        Self::FeeDistributionChange(value)
+    }
+}
+
+impl From<UpgradeScheduleAction> for Action {
+    fn from(value: UpgradeScheduleAction) -> Self {
+        Self::UpgradeSchedule(value)
+    }
+}
+
 impl From<Action> for raw::Action {
     fn from(value: Action) -> Self {
         value.into_raw()
@@ -281,6 +326,14 @@ impl ActionError {
         Self(ActionErrorKind::Transfer(inner))
     }
 
+    fn bond(inner: BondActionError) -> Self {
+        Self(ActionErrorKind::Bond(inner))
+    }
+
+    fn unbond(inner: UnbondActionError) -> Self {
+        Self(ActionErrorKind::Unbond(inner))
+    }
+
     fn validator_update(inner: ValidatorUpdateError) -> Self {
         Self(ActionErrorKind::ValidatorUpdate(inner))
     }
@@ -328,6 +381,14 @@ impl ActionError {
     fn fee_change(inner: FeeChangeActionError) -> Self {
         Self(ActionErrorKind::FeeChange(inner))
     }
+
+    fn fee_distribution_change(inner: FeeDistributionChangeActionError) -> Self {
+        Self(ActionErrorKind::FeeDistributionChange(inner))
+    }
+
+    fn upgrade_schedule(inner: UpgradeScheduleActionError) -> Self {
+        Self(ActionErrorKind::UpgradeSchedule(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -338,6 +399,10 @@ enum ActionErrorKind {
     Sequence(#[source] SequenceActionError),
     #[error("transfer action was not valid")]
     Transfer(#[source] TransferActionError),
+    #[error("bond action was not valid")]
+    Bond(#[source] BondActionError),
+    #[error("unbond action was not valid")]
+    Unbond(#[source] UnbondActionError),
     #[error("validator update action was not valid")]
     ValidatorUpdate(#[source] ValidatorUpdateError),
     #[error("sudo address change action was not valid")]
@@ -362,6 +427,10 @@ enum ActionErrorKind {
     BridgeSudoChange(#[source] BridgeSudoChangeActionError),
     #[error("fee change action was not valid")]
     FeeChange(#[source] FeeChangeActionError),
+    #[error("fee distribution change action was not valid")]
+    FeeDistributionChange(#[source] FeeDistributionChangeActionError),
+    #[error("upgrade schedule action was not valid")]
+    UpgradeSchedule(#[source] UpgradeScheduleActionError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -538,6 +607,157 @@ enum TransferActionErrorKind {
     FeeAsset(#[source] asset::ParseDenomError),
 }
 
+/// Bonds `amount` of the native asset of the signer to `validator`.
+///
+/// If the signer is the validator itself (that is, the address derived from `validator`),
+/// the validator is registered; otherwise the bonded amount is delegated to the validator.
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct BondAction {
+    pub validator: crate::crypto::VerificationKey,
+    pub amount: u128,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for BondAction {
+    type Error = BondActionError;
+    type Raw = raw::BondAction;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::BondAction {
+        let Self {
+            validator,
+            amount,
+            fee_asset,
+        } = self;
+        raw::BondAction {
+            validator_public_key: Bytes::copy_from_slice(&validator.to_bytes()),
+            amount: Some((*amount).into()),
+            fee_asset: fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `BondActionError` if the raw action's `validator_public_key` is not a valid
+    /// ed25519 verification key or if `fee_asset` is not a valid asset.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::BondAction {
+            validator_public_key,
+            amount,
+            fee_asset,
+        } = raw;
+        let validator = crate::crypto::VerificationKey::try_from(&**validator_public_key)
+            .map_err(BondActionError::validator)?;
+        let amount = amount.map_or(0, Into::into);
+        let fee_asset = fee_asset.parse().map_err(BondActionError::fee_asset)?;
+        Ok(Self {
+            validator,
+            amount,
+            fee_asset,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BondActionError(BondActionErrorKind);
+
+impl BondActionError {
+    fn validator(inner: crate::crypto::Error) -> Self {
+        Self(BondActionErrorKind::Validator(inner))
+    }
+
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(BondActionErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BondActionErrorKind {
+    #[error("`validator_public_key` field did not contain a valid ed25519 verification key")]
+    Validator(#[source] crate::crypto::Error),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+}
+
+/// Unbonds `amount` of the native asset that the signer bonded to `validator`.
+///
+/// The unbonded amount is returned to the signer after the unbonding period has passed.
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct UnbondAction {
+    pub validator: crate::crypto::VerificationKey,
+    pub amount: u128,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for UnbondAction {
+    type Error = UnbondActionError;
+    type Raw = raw::UnbondAction;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::UnbondAction {
+        let Self {
+            validator,
+            amount,
+            fee_asset,
+        } = self;
+        raw::UnbondAction {
+            validator_public_key: Bytes::copy_from_slice(&validator.to_bytes()),
+            amount: Some((*amount).into()),
+            fee_asset: fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `UnbondActionError` if the raw action's `validator_public_key` is not a valid
+    /// ed25519 verification key or if `fee_asset` is not a valid asset.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::UnbondAction {
+            validator_public_key,
+            amount,
+            fee_asset,
+        } = raw;
+        let validator = crate::crypto::VerificationKey::try_from(&**validator_public_key)
+            .map_err(UnbondActionError::validator)?;
+        let amount = amount.map_or(0, Into::into);
+        let fee_asset = fee_asset.parse().map_err(UnbondActionError::fee_asset)?;
+        Ok(Self {
+            validator,
+            amount,
+            fee_asset,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct UnbondActionError(UnbondActionErrorKind);
+
+impl UnbondActionError {
+    fn validator(inner: crate::crypto::Error) -> Self {
+        Self(UnbondActionErrorKind::Validator(inner))
+    }
+
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(UnbondActionErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum UnbondActionErrorKind {
+    #[error("`validator_public_key` field did not contain a valid ed25519 verification key")]
+    Validator(#[source] crate::crypto::Error),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct ValidatorUpdateError(ValidatorUpdateErrorKind);
@@ -2012,3 +2232,289 @@ enum FeeChangeActionErrorKind {
     #[error("the value which to change was missing")]
     MissingValueToChange,
 }
+
+/// Describes how the fees collected in a block are distributed at the end of the block.
+///
+/// The percentages are guaranteed to add up to 100.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeDistribution {
+    proposer_percent: u32,
+    validators_percent: u32,
+    treasury_percent: u32,
+    burn_percent: u32,
+    treasury_address: Address,
+}
+
+impl FeeDistribution {
+    /// Constructs a new fee distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the percentages do not add up to 100.
+    pub fn try_new(
+        proposer_percent: u32,
+        validators_percent: u32,
+        treasury_percent: u32,
+        burn_percent: u32,
+        treasury_address: Address,
+    ) -> Result<Self, FeeDistributionError> {
+        // summing four u32 values in a u64 cannot overflow
+        let total = [
+            proposer_percent,
+            validators_percent,
+            treasury_percent,
+            burn_percent,
+        ]
+        .into_iter()
+        .map(u64::from)
+        .fold(0u64, u64::saturating_add);
+        if total != 100 {
+            return Err(FeeDistributionError::percentages_do_not_add_up(total));
+        }
+        Ok(Self {
+            proposer_percent,
+            validators_percent,
+            treasury_percent,
+            burn_percent,
+            treasury_address,
+        })
+    }
+
+    /// The share of fees sent to the proposer of the block.
+    #[must_use]
+    pub fn proposer_percent(&self) -> u32 {
+        self.proposer_percent
+    }
+
+    /// The share of fees split between the validators that signed the previous block's commit,
+    /// weighted by their voting power.
+    #[must_use]
+    pub fn validators_percent(&self) -> u32 {
+        self.validators_percent
+    }
+
+    /// The share of fees sent to the treasury address.
+    #[must_use]
+    pub fn treasury_percent(&self) -> u32 {
+        self.treasury_percent
+    }
+
+    /// The share of fees that is burned. Only fees paid in the native asset are burned; the burn
+    /// share of all other assets is sent to the treasury address.
+    #[must_use]
+    pub fn burn_percent(&self) -> u32 {
+        self.burn_percent
+    }
+
+    #[must_use]
+    pub fn treasury_address(&self) -> &Address {
+        &self.treasury_address
+    }
+}
+
+impl Protobuf for FeeDistribution {
+    type Error = FeeDistributionError;
+    type Raw = raw::FeeDistribution;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::FeeDistribution {
+        raw::FeeDistribution {
+            proposer_percent: self.proposer_percent,
+            validators_percent: self.validators_percent,
+            treasury_percent: self.treasury_percent,
+            burn_percent: self.burn_percent,
+            treasury_address: Some(self.treasury_address.to_raw()),
+        }
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::FeeDistribution`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `treasury_address` field is not set or invalid
+    /// - if the percentages do not add up to 100
+    fn try_from_raw_ref(proto: &raw::FeeDistribution) -> Result<Self, FeeDistributionError> {
+        let raw::FeeDistribution {
+            proposer_percent,
+            validators_percent,
+            treasury_percent,
+            burn_percent,
+            treasury_address,
+        } = proto;
+        let Some(treasury_address) = treasury_address else {
+            return Err(FeeDistributionError::field_not_set("treasury_address"));
+        };
+        let treasury_address = Address::try_from_raw(treasury_address)
+            .map_err(FeeDistributionError::treasury_address)?;
+        Self::try_new(
+            *proposer_percent,
+            *validators_percent,
+            *treasury_percent,
+            *burn_percent,
+            treasury_address,
+        )
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeDistributionError(FeeDistributionErrorKind);
+
+impl FeeDistributionError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(FeeDistributionErrorKind::FieldNotSet(field))
+    }
+
+    fn treasury_address(source: AddressError) -> Self {
+        Self(FeeDistributionErrorKind::TreasuryAddress {
+            source,
+        })
+    }
+
+    fn percentages_do_not_add_up(total: u64) -> Self {
+        Self(FeeDistributionErrorKind::PercentagesDoNotAddUp {
+            total,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeDistributionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`treasury_address` field did not contain a valid address")]
+    TreasuryAddress { source: AddressError },
+    #[error("the fee distribution percentages must add up to 100, but they add up to {total}")]
+    PercentagesDoNotAddUp { total: u64 },
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct FeeDistributionChangeAction {
+    pub new_distribution: FeeDistribution,
+}
+
+impl Protobuf for FeeDistributionChangeAction {
+    type Error = FeeDistributionChangeActionError;
+    type Raw = raw::FeeDistributionChangeAction;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::FeeDistributionChangeAction {
+        raw::FeeDistributionChangeAction {
+            new_distribution: Some(self.new_distribution.to_raw()),
+        }
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf
+    /// [`raw::FeeDistributionChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `new_distribution` field is not set or invalid
+    fn try_from_raw_ref(
+        proto: &raw::FeeDistributionChangeAction,
+    ) -> Result<Self, FeeDistributionChangeActionError> {
+        let raw::FeeDistributionChangeAction {
+            new_distribution,
+        } = proto;
+        let Some(new_distribution) = new_distribution else {
+            return Err(FeeDistributionChangeActionError::field_not_set(
+                "new_distribution",
+            ));
+        };
+        let new_distribution = FeeDistribution::try_from_raw_ref(new_distribution)
+            .map_err(FeeDistributionChangeActionError::new_distribution)?;
+        Ok(Self {
+            new_distribution,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeDistributionChangeActionError(FeeDistributionChangeActionErrorKind);
+
+impl FeeDistributionChangeActionError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(FeeDistributionChangeActionErrorKind::FieldNotSet(field))
+    }
+
+    fn new_distribution(source: FeeDistributionError) -> Self {
+        Self(FeeDistributionChangeActionErrorKind::NewDistribution {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeDistributionChangeActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`new_distribution` field was invalid")]
+    NewDistribution { source: FeeDistributionError },
+}
+
+/// Schedules a named chain upgrade at a future height.
+///
+/// Nodes refuse to finalize the block at `height` unless they run a binary that registered a
+/// migration for the upgrade.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct UpgradeScheduleAction {
+    pub name: String,
+    pub height: u64,
+}
+
+impl Protobuf for UpgradeScheduleAction {
+    type Error = UpgradeScheduleActionError;
+    type Raw = raw::UpgradeScheduleAction;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::UpgradeScheduleAction {
+        let Self {
+            name,
+            height,
+        } = self;
+        raw::UpgradeScheduleAction {
+            name: name.clone(),
+            height: *height,
+        }
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::UpgradeScheduleAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `name` field is empty
+    fn try_from_raw_ref(
+        proto: &raw::UpgradeScheduleAction,
+    ) -> Result<Self, UpgradeScheduleActionError> {
+        let raw::UpgradeScheduleAction {
+            name,
+            height,
+        } = proto;
+        if name.is_empty() {
+            return Err(UpgradeScheduleActionError::empty_name());
+        }
+        Ok(Self {
+            name: name.clone(),
+            height: *height,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct UpgradeScheduleActionError(UpgradeScheduleActionErrorKind);
+
+impl UpgradeScheduleActionError {
+    fn empty_name() -> Self {
+        Self(UpgradeScheduleActionErrorKind::EmptyName)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum UpgradeScheduleActionErrorKind {
+    #[error("the `name` field was empty")]
+    EmptyName,
+}
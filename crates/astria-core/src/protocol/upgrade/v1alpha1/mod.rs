@@ -0,0 +1,104 @@
+use super::raw;
+
+/// A chain upgrade scheduled by the sudo address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub name: String,
+    /// The height at which the chain halts unless the node runs a binary supporting the upgrade.
+    pub height: u64,
+}
+
+impl Upgrade {
+    /// Converts a protobuf [`raw::Upgrade`] to an astria
+    /// native [`Upgrade`].
+    #[must_use]
+    pub fn from_raw(proto: &raw::Upgrade) -> Self {
+        let raw::Upgrade {
+            name,
+            height,
+        } = proto;
+        Self {
+            name: name.clone(),
+            height: *height,
+        }
+    }
+
+    /// Converts an astria native [`Upgrade`] to a
+    /// protobuf [`raw::Upgrade`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Upgrade {
+        let Self {
+            name,
+            height,
+        } = self;
+        raw::Upgrade {
+            name,
+            height,
+        }
+    }
+}
+
+/// The sequencer response to a pending upgrades request at a given height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingUpgradesResponse {
+    pub height: u64,
+    /// The upgrades that were scheduled but not yet applied, ordered by height.
+    pub upgrades: Vec<Upgrade>,
+}
+
+impl PendingUpgradesResponse {
+    /// Converts a protobuf [`raw::PendingUpgradesResponse`] to an astria
+    /// native [`PendingUpgradesResponse`].
+    #[must_use]
+    pub fn from_raw(proto: &raw::PendingUpgradesResponse) -> Self {
+        let raw::PendingUpgradesResponse {
+            height,
+            upgrades,
+        } = proto;
+        Self {
+            height: *height,
+            upgrades: upgrades.iter().map(Upgrade::from_raw).collect(),
+        }
+    }
+
+    /// Converts an astria native [`PendingUpgradesResponse`] to a
+    /// protobuf [`raw::PendingUpgradesResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::PendingUpgradesResponse {
+        let Self {
+            height,
+            upgrades,
+        } = self;
+        raw::PendingUpgradesResponse {
+            height,
+            upgrades: upgrades.into_iter().map(Upgrade::into_raw).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PendingUpgradesResponse,
+        Upgrade,
+    };
+
+    #[test]
+    fn pending_upgrades_roundtrip_is_correct() {
+        let expected = PendingUpgradesResponse {
+            height: 42,
+            upgrades: vec![
+                Upgrade {
+                    name: "first".to_string(),
+                    height: 50,
+                },
+                Upgrade {
+                    name: "second".to_string(),
+                    height: 60,
+                },
+            ],
+        };
+        let actual = PendingUpgradesResponse::from_raw(&expected.clone().into_raw());
+        assert_eq!(expected, actual);
+    }
+}
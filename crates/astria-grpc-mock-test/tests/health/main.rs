@@ -3,14 +3,20 @@
 
 use std::{
     net::SocketAddr,
-    pin::Pin,
     sync::Arc,
+    time::Duration,
 };
 
 use astria_grpc_mock::{
     matcher,
-    response,
+    response::{
+        self,
+        StreamScript,
+    },
+    ExpectedRequest,
     Mock,
+    ResponseStream,
+    Scenario,
 };
 use astria_grpc_mock_test::health::{
     health_client::HealthClient,
@@ -27,7 +33,7 @@ use tokio::{
 };
 use tokio_stream::{
     wrappers::TcpListenerStream,
-    Stream,
+    StreamExt as _,
 };
 use tonic::{
     transport::Server,
@@ -70,8 +76,7 @@ struct HealthService {
 
 #[tonic::async_trait]
 impl Health for HealthService {
-    type WatchStream =
-        Pin<Box<dyn Stream<Item = Result<HealthCheckResponse, Status>> + Send + 'static>>;
+    type WatchStream = ResponseStream<HealthCheckResponse>;
 
     async fn check(
         self: Arc<Self>,
@@ -82,9 +87,11 @@ impl Health for HealthService {
 
     async fn watch(
         self: Arc<Self>,
-        _request: Request<HealthCheckRequest>,
+        request: Request<HealthCheckRequest>,
     ) -> Result<Response<Self::WatchStream>, Status> {
-        unimplemented!()
+        self.mock_server
+            .handle_server_streaming_request("watch", request)
+            .await
     }
 }
 
@@ -268,3 +275,312 @@ async fn incorrect_mock_response_fails_guard() {
 
     let _ = join!(guard.wait_until_satisfied(), check);
 }
+
+fn health_check_request(service: &str) -> HealthCheckRequest {
+    HealthCheckRequest {
+        service: service.to_string(),
+    }
+}
+
+fn health_check_response(status: i32) -> HealthCheckResponse {
+    HealthCheckResponse {
+        status,
+    }
+}
+
+#[tokio::test]
+async fn stream_response_works() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("watch", matcher::message_type::<HealthCheckRequest>())
+        .respond_with_stream(response::stream_response(
+            StreamScript::new()
+                .message(health_check_response(1))
+                .delay(Duration::from_millis(10))
+                .messages([health_check_response(2), health_check_response(3)]),
+        ))
+        .expect(1);
+    server.mocked.register(mock).await;
+    let rsp = client
+        .watch(health_check_request("helloworld"))
+        .await
+        .unwrap()
+        .into_inner();
+    let statuses: Vec<_> = rsp.map(|rsp| rsp.unwrap().status).collect().await;
+    assert_eq!(vec![1, 2, 3], statuses);
+}
+
+#[tokio::test]
+async fn stream_response_with_error_works() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("watch", matcher::message_type::<HealthCheckRequest>())
+        .respond_with_stream(response::stream_response(
+            StreamScript::new()
+                .message(health_check_response(1))
+                .end_with_error(tonic::Code::Unavailable),
+        ));
+    server.mocked.register(mock).await;
+    let mut rsp = client
+        .watch(health_check_request("helloworld"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(1, rsp.message().await.unwrap().unwrap().status);
+    assert_eq!(
+        tonic::Code::Unavailable,
+        rsp.message().await.unwrap_err().code()
+    );
+}
+
+#[tokio::test]
+async fn stream_response_kept_open_works() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("watch", matcher::message_type::<HealthCheckRequest>())
+        .respond_with_stream(response::stream_response(
+            StreamScript::new()
+                .message(health_check_response(1))
+                .keep_open(),
+        ));
+    server.mocked.register(mock).await;
+    let mut rsp = client
+        .watch(health_check_request("helloworld"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(1, rsp.message().await.unwrap().unwrap().status);
+    tokio::time::timeout(Duration::from_millis(100), rsp.message())
+        .await
+        .expect_err("stream should be kept open");
+}
+
+#[tokio::test]
+#[should_panic]
+async fn incorrect_mock_stream_message_fails_server() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("watch", matcher::message_type::<HealthCheckRequest>())
+        .respond_with_stream(response::stream_response(
+            StreamScript::new().message(health_check_request("wrong type")),
+        ));
+    server.mocked.register(mock).await;
+    let _ = client.watch(health_check_request("helloworld")).await;
+}
+
+#[tokio::test]
+async fn bidi_stream_matches_each_inbound_message() {
+    let server = start_mock_server().await;
+    let first = Mock::for_rpc_given(
+        "bidi",
+        matcher::message_exact_pbjson(&health_check_request("first")),
+    )
+    .respond_with_stream(response::stream_response(
+        StreamScript::new().messages([health_check_response(1), health_check_response(2)]),
+    ))
+    .expect(1);
+    let second = Mock::for_rpc_given(
+        "bidi",
+        matcher::message_exact_pbjson(&health_check_request("second")),
+    )
+    .respond_with_stream(response::dynamic_stream_response(
+        |req: &HealthCheckRequest| {
+            StreamScript::new().message(health_check_response(
+                i32::try_from(req.service.len()).unwrap(),
+            ))
+        },
+    ))
+    .expect(1);
+    server.mocked.register(first).await;
+    server.mocked.register(second).await;
+
+    let inbound = tokio_stream::iter([
+        Ok(health_check_request("first")),
+        Ok(health_check_request("second")),
+        Ok(health_check_request("unexpected")),
+    ]);
+    let outbound: Vec<_> = server
+        .mocked
+        .handle_bidi_streaming_request::<_, HealthCheckResponse, _>("bidi", Request::new(inbound))
+        .await
+        .unwrap()
+        .into_inner()
+        .collect()
+        .await;
+    let (messages, errors): (Vec<_>, Vec<_>) = outbound.into_iter().partition(Result::is_ok);
+    let statuses: Vec<_> = messages
+        .into_iter()
+        .map(|rsp| rsp.unwrap().status)
+        .collect();
+    assert_eq!(vec![1, 2, 6], statuses);
+    assert_eq!(1, errors.len());
+    assert_eq!(
+        tonic::Code::NotFound,
+        errors[0].as_ref().unwrap_err().code()
+    );
+}
+
+#[tokio::test]
+async fn client_stream_responds_to_last_inbound_message() {
+    let server = start_mock_server().await;
+    let mock = Mock::for_rpc_given(
+        "client_stream",
+        matcher::message_type::<HealthCheckRequest>(),
+    )
+    .respond_with(response::dynamic_response(|req: &HealthCheckRequest| {
+        health_check_response(i32::try_from(req.service.len()).unwrap())
+    }))
+    .expect(2);
+    server.mocked.register(mock).await;
+
+    let inbound = tokio_stream::iter([
+        Ok(health_check_request("first")),
+        Ok(health_check_request("second")),
+    ]);
+    let rsp = server
+        .mocked
+        .handle_client_streaming_request::<_, HealthCheckResponse, _>(
+            "client_stream",
+            Request::new(inbound),
+        )
+        .await
+        .unwrap();
+    assert_eq!(6, rsp.get_ref().status);
+}
+
+#[tokio::test]
+async fn metadata_match_works() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("check", matcher::metadata_exact("Authorization", "secret"))
+        .and(matcher::metadata_exists("x-request-id"))
+        .respond_with(response::constant_response(health_check_response(1)))
+        .expect(1);
+    server.mocked.register(mock).await;
+
+    let mut authorized = Request::new(health_check_request("helloworld"));
+    authorized
+        .metadata_mut()
+        .insert("authorization", "secret".parse().unwrap());
+    authorized
+        .metadata_mut()
+        .insert("x-request-id", "1".parse().unwrap());
+    let rsp = client.check(authorized).await.unwrap();
+    assert_eq!(1, rsp.get_ref().status);
+
+    let mut unauthorized = Request::new(health_check_request("helloworld"));
+    unauthorized
+        .metadata_mut()
+        .insert("authorization", "wrong".parse().unwrap());
+    let err = client.check(unauthorized).await.unwrap_err();
+    assert_eq!(tonic::Code::NotFound, err.code());
+}
+
+#[tokio::test]
+async fn scenario_transitions_between_mocks() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let not_serving = Mock::for_rpc_given("check", matcher::message_type::<HealthCheckRequest>())
+        .respond_with(response::constant_response(health_check_response(2)))
+        .in_scenario(
+            Scenario::named("serving status")
+                .when_state_is(Scenario::STARTED)
+                .will_set_state("serving"),
+        )
+        .expect(1);
+    let serving = Mock::for_rpc_given("check", matcher::message_type::<HealthCheckRequest>())
+        .respond_with(response::constant_response(health_check_response(1)))
+        .in_scenario(Scenario::named("serving status").when_state_is("serving"))
+        .expect(2);
+    server.mocked.register(not_serving).await;
+    server.mocked.register(serving).await;
+
+    let mut statuses = Vec::new();
+    for _ in 0..3 {
+        let rsp = client
+            .check(health_check_request("helloworld"))
+            .await
+            .unwrap();
+        statuses.push(rsp.get_ref().status);
+    }
+    assert_eq!(vec![2, 1, 1], statuses);
+    assert_eq!(
+        "serving",
+        server.mocked.scenario_state("serving status").await
+    );
+}
+
+#[tokio::test]
+async fn received_requests_in_order_works() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("check", matcher::message_type::<HealthCheckRequest>())
+        .respond_with(response::default_response::<HealthCheckResponse>());
+    server.mocked.register(mock).await;
+    for service in ["first", "other", "second"] {
+        client.check(health_check_request(service)).await.unwrap();
+    }
+
+    let received = server.mocked.received_requests().await.unwrap();
+    assert_eq!(3, received.len());
+    assert_eq!(
+        "other",
+        received[1].message::<HealthCheckRequest>().unwrap().service
+    );
+    server
+        .mocked
+        .assert_received_in_order([
+            ExpectedRequest::for_rpc_given(
+                "check",
+                matcher::message_exact_pbjson(&health_check_request("first")),
+            ),
+            ExpectedRequest::for_rpc_given(
+                "check",
+                matcher::message_exact_pbjson(&health_check_request("second")),
+            ),
+        ])
+        .await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn received_requests_out_of_order_fails() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let mock = Mock::for_rpc_given("check", matcher::message_type::<HealthCheckRequest>())
+        .respond_with(response::default_response::<HealthCheckResponse>());
+    server.mocked.register(mock).await;
+    for service in ["first", "second"] {
+        client.check(health_check_request(service)).await.unwrap();
+    }
+
+    server
+        .mocked
+        .assert_received_in_order([
+            ExpectedRequest::for_rpc_given(
+                "check",
+                matcher::message_exact_pbjson(&health_check_request("second")),
+            ),
+            ExpectedRequest::for_rpc_given(
+                "check",
+                matcher::message_exact_pbjson(&health_check_request("first")),
+            ),
+        ])
+        .await;
+}
@@ -13,7 +13,9 @@ mod mock;
 mod mock_server;
 mod mock_set;
 mod mounted_mock;
+mod received_request;
 pub mod response;
+mod scenario;
 mod verification;
 
 pub use mock::{
@@ -24,11 +26,22 @@ pub use mock_server::{
     MockGuard,
     MockServer,
 };
-pub use response::Respond;
+pub use received_request::{
+    ExpectedRequest,
+    ReceivedRequest,
+};
+pub use response::{
+    Respond,
+    RespondStream,
+};
+pub use scenario::Scenario;
 
 pub type AnyMessage = Box<dyn ErasedMessage + Send + Sync>;
 pub type AnyRequest = tonic::Request<AnyMessage>;
 pub type AnyResponse = tonic::Response<AnyMessage>;
+/// The stream of messages returned by server-streaming and bidirectional streaming RPCs.
+pub type ResponseStream<T> =
+    std::pin::Pin<Box<dyn futures::Stream<Item = tonic::Result<T>> + Send + 'static>>;
 
 pub trait ErasedName {
     fn full_name(&self) -> String;
@@ -67,3 +67,43 @@ impl Match for MessageTypeMatcher {
         self.type_name == req.get_ref().as_any().type_id()
     }
 }
+
+/// Matches requests carrying the ASCII metadata entry `key` with exactly `value`.
+#[must_use = "a matcher must be used in a mock to be useful"]
+pub fn metadata_exact(key: &str, value: impl Into<String>) -> MetadataExactMatcher {
+    MetadataExactMatcher {
+        key: key.to_ascii_lowercase(),
+        value: value.into(),
+    }
+}
+
+pub struct MetadataExactMatcher {
+    key: String,
+    value: String,
+}
+
+impl Match for MetadataExactMatcher {
+    fn matches(&self, req: &tonic::Request<crate::AnyMessage>) -> bool {
+        req.metadata()
+            .get(self.key.as_str())
+            .is_some_and(|value| value.to_str().is_ok_and(|value| value == self.value))
+    }
+}
+
+/// Matches requests carrying the metadata entry `key`, regardless of its value.
+#[must_use = "a matcher must be used in a mock to be useful"]
+pub fn metadata_exists(key: &str) -> MetadataExistsMatcher {
+    MetadataExistsMatcher {
+        key: key.to_ascii_lowercase(),
+    }
+}
+
+pub struct MetadataExistsMatcher {
+    key: String,
+}
+
+impl Match for MetadataExistsMatcher {
+    fn matches(&self, req: &tonic::Request<crate::AnyMessage>) -> bool {
+        req.metadata().contains_key(self.key.as_str())
+    }
+}
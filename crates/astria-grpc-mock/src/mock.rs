@@ -11,7 +11,11 @@ use std::ops::{
 use super::AnyMessage;
 use crate::{
     mock_server::MockGuard,
-    response::ResponseTemplate,
+    response::{
+        ResponseTemplate,
+        StreamResponseTemplate,
+    },
+    scenario::Scenario,
     MockServer,
 };
 
@@ -19,7 +23,7 @@ pub trait Match: Send + Sync {
     fn matches(&self, req: &tonic::Request<AnyMessage>) -> bool;
 }
 
-pub(crate) struct Matcher(Box<dyn Match>);
+pub(crate) struct Matcher(pub(crate) Box<dyn Match>);
 
 impl Match for Matcher {
     fn matches(&self, request: &tonic::Request<AnyMessage>) -> bool {
@@ -27,13 +31,22 @@ impl Match for Matcher {
     }
 }
 
+/// How a mock responds to a matched request.
+pub(crate) enum Responder {
+    /// Responds with a single message, for unary and client-streaming RPCs.
+    Unary(ResponseTemplate),
+    /// Responds with a stream of messages, for server-streaming and bidirectional streaming RPCs.
+    Stream(StreamResponseTemplate),
+}
+
 pub struct Mock {
     pub(crate) rpc: &'static str,
     pub(crate) matchers: Vec<Matcher>,
-    pub(crate) response: ResponseTemplate,
+    pub(crate) response: Responder,
     pub(crate) max_n_matches: Option<u64>,
     pub(crate) expectation_range: Times,
     pub(crate) name: Option<String>,
+    pub(crate) scenario: Option<Scenario>,
 }
 
 impl Mock {
@@ -58,6 +71,13 @@ impl Mock {
         self
     }
 
+    /// Adds the mock to `scenario`, making it respond depending on the state of the scenario.
+    #[must_use = "a mock must be mounted on a server to be useful"]
+    pub fn in_scenario(mut self, scenario: Scenario) -> Self {
+        self.scenario.replace(scenario);
+        self
+    }
+
     #[must_use = "a mock must be mounted on a server to be useful"]
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
         self.name.replace(name.into());
@@ -85,6 +105,18 @@ impl MockBuilder {
     }
 
     pub fn respond_with(self, rsp: ResponseTemplate) -> Mock {
+        self.into_mock(Responder::Unary(rsp))
+    }
+
+    /// Responds with a stream of messages.
+    ///
+    /// For bidirectional streaming RPCs the mock's matchers are applied to each inbound message,
+    /// and the streams of all matched messages are sent one after the other.
+    pub fn respond_with_stream(self, rsp: StreamResponseTemplate) -> Mock {
+        self.into_mock(Responder::Stream(rsp))
+    }
+
+    fn into_mock(self, response: Responder) -> Mock {
         let Self {
             rpc,
             matchers,
@@ -92,9 +124,10 @@ impl MockBuilder {
         Mock {
             rpc,
             matchers,
-            response: rsp,
+            response,
             max_n_matches: None,
             name: None,
+            scenario: None,
             expectation_range: Times(TimesEnum::Unbounded(RangeFull)),
         }
     }
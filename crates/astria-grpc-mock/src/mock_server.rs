@@ -7,10 +7,15 @@ use std::{
     },
 };
 
+use futures::{
+    Stream,
+    StreamExt as _,
+};
 use tokio::sync::{
     Notify,
     RwLock,
 };
+use tonic::metadata::MetadataMap;
 use tracing::debug;
 
 use super::clone_request;
@@ -21,8 +26,12 @@ use crate::{
         MockId,
         MockSet,
     },
+    received_request::{
+        ExpectedRequest,
+        ReceivedRequest,
+    },
     verification::VerificationOutcome,
-    AnyRequest,
+    ResponseStream,
 };
 
 #[derive(Clone, Default)]
@@ -61,6 +70,142 @@ impl MockServer {
         response
     }
 
+    /// Handles a server-streaming request by responding with the stream of the first matching
+    /// mock.
+    pub async fn handle_server_streaming_request<
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+        U: Send + 'static,
+    >(
+        &self,
+        rpc: &'static str,
+        req: tonic::Request<T>,
+    ) -> tonic::Result<tonic::Response<ResponseStream<U>>> {
+        self.state.write().await.handle_stream_request(rpc, req)
+    }
+
+    /// Handles a client-streaming request by matching each inbound message against the mocks.
+    ///
+    /// Returns the response of the mock matching the last message, or the first error returned
+    /// by the inbound stream or a mock.
+    pub async fn handle_client_streaming_request<T, U, S>(
+        &self,
+        rpc: &'static str,
+        req: tonic::Request<S>,
+    ) -> tonic::Result<tonic::Response<U>>
+    where
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+        U: Send + Sync + 'static,
+        S: Stream<Item = tonic::Result<T>>,
+    {
+        let (metadata, _, inbound) = req.into_parts();
+        let mut inbound = pin!(inbound);
+        let mut response = None;
+        while let Some(message) = inbound.next().await {
+            let req = request_with_metadata(metadata.clone(), message?);
+            response = Some(self.handle_request(rpc, req).await?);
+        }
+        response.ok_or_else(|| {
+            tonic::Status::invalid_argument("client stream ended without sending a message")
+        })
+    }
+
+    /// Handles a bidirectional streaming request by matching each inbound message against the
+    /// mocks.
+    ///
+    /// The streams of the mocks matching the inbound messages are sent one after the other. The
+    /// response stream ends with an error if a message did not match any mock.
+    pub async fn handle_bidi_streaming_request<T, U, S>(
+        &self,
+        rpc: &'static str,
+        req: tonic::Request<S>,
+    ) -> tonic::Result<tonic::Response<ResponseStream<U>>>
+    where
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+        U: Send + 'static,
+        S: Stream<Item = tonic::Result<T>> + Send + 'static,
+    {
+        // Only hold on to the state and not the server itself because dropping the server
+        // verifies all its mocks.
+        let state = self.state.clone();
+        let (metadata, _, inbound) = req.into_parts();
+        let outbound = inbound
+            .then(move |message| {
+                let state = state.clone();
+                let metadata = metadata.clone();
+                async move {
+                    let response = match message {
+                        Ok(message) => state
+                            .write()
+                            .await
+                            .handle_stream_request(rpc, request_with_metadata(metadata, message)),
+                        Err(status) => Err(status),
+                    };
+                    match response {
+                        Ok(response) => response.into_inner(),
+                        Err(status) => Box::pin(futures::stream::once(async move { Err(status) }))
+                            as ResponseStream<U>,
+                    }
+                }
+            })
+            .flatten();
+        Ok(tonic::Response::new(Box::pin(outbound)))
+    }
+
+    /// Returns all requests received by the server, or `None` if request recording is disabled.
+    pub async fn received_requests(&self) -> Option<Vec<ReceivedRequest>> {
+        self.state.read().await.received_requests.clone()
+    }
+
+    /// Asserts that the server received requests matching `expected` in the given order.
+    ///
+    /// Other requests may have been received before, between, or after the expected ones.
+    ///
+    /// # Panics
+    /// Panics if request recording is disabled or if the expected requests were not received in
+    /// order.
+    pub async fn assert_received_in_order(
+        &self,
+        expected: impl IntoIterator<Item = ExpectedRequest>,
+    ) {
+        let state = self.state.read().await;
+        let received_requests = state
+            .received_requests
+            .as_ref()
+            .expect("request recording must be enabled to assert the order of requests");
+        let mut received = received_requests.iter();
+        for (expected_index, expected) in expected.into_iter().enumerate() {
+            if !received.any(|request| expected.matches(request)) {
+                let received_requests_message = received_requests_message(&state.received_requests);
+                panic!(
+                    "Expected request #{expected_index} for RPC {} was not received in \
+                     order.\n\n{received_requests_message}",
+                    expected.rpc,
+                );
+            }
+        }
+    }
+
+    /// Returns the current state of the scenario `name`.
+    pub async fn scenario_state(&self, name: &str) -> String {
+        self.state
+            .read()
+            .await
+            .mock_set
+            .scenario_states()
+            .get(name)
+            .to_string()
+    }
+
+    /// Moves the scenario `name` to `state`, regardless of its current state.
+    pub async fn set_scenario_state(&self, name: impl Into<String>, state: impl Into<String>) {
+        self.state
+            .write()
+            .await
+            .mock_set
+            .scenario_states_mut()
+            .set(name, state);
+    }
+
     pub async fn register(&self, mock: Mock) {
         self.state.write().await.mock_set.register(mock);
     }
@@ -131,45 +276,10 @@ impl MockGuard {
     }
 }
 
-struct MockRequest {
-    inner: AnyRequest,
-}
-
-impl MockRequest {
-    fn print(&self, mut buffer: impl std::fmt::Write) -> std::fmt::Result {
-        // TODO(https://github.com/astriaorg/astria/issues/900): Print the metadata map
-
-        // for name in self.inner.iter() {
-        //     let values = self
-        //         .headers
-        //         .get_all(name)
-        //         .iter()
-        //         .map(|value| String::from_utf8_lossy(value.as_bytes()))
-        //         .collect::<Vec<_>>();
-        //     let values = values.join(",");
-        //     writeln!(buffer, "{}: {}", name, values)?;
-        // }
-
-        if let Ok(body) = serde_json::to_string_pretty(self.inner.get_ref().as_serialize()) {
-            writeln!(buffer, "{body}")
-        } else {
-            writeln!(buffer, "Could not map the gRPC body to JSON",)
-        }
-    }
-}
-
-impl From<AnyRequest> for MockRequest {
-    fn from(value: AnyRequest) -> Self {
-        Self {
-            inner: value,
-        }
-    }
-}
-
 #[derive(Default)]
 struct MockServerState {
     mock_set: MockSet,
-    received_requests: Option<Vec<(&'static str, MockRequest)>>,
+    received_requests: Option<Vec<ReceivedRequest>>,
 }
 
 impl MockServerState {
@@ -185,16 +295,43 @@ impl MockServerState {
         Option<std::time::Duration>,
     ) {
         if let Some(received_requests) = &mut self.received_requests {
-            received_requests.push((rpc, erase_request(clone_request(&req)).into()));
+            received_requests.push(ReceivedRequest {
+                rpc,
+                inner: erase_request(clone_request(&req)),
+            });
         }
         self.mock_set.handle_request(rpc, req)
     }
 
+    fn handle_stream_request<
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+        U: Send + 'static,
+    >(
+        &mut self,
+        rpc: &'static str,
+        req: tonic::Request<T>,
+    ) -> tonic::Result<tonic::Response<ResponseStream<U>>> {
+        if let Some(received_requests) = &mut self.received_requests {
+            received_requests.push(ReceivedRequest {
+                rpc,
+                inner: erase_request(clone_request(&req)),
+            });
+        }
+        self.mock_set.handle_stream_request(rpc, req)
+    }
+
     fn verify(&self) -> VerificationOutcome {
         self.mock_set.verify_all()
     }
 }
 
+/// Wraps a message of an inbound stream in a request carrying the metadata of the stream.
+fn request_with_metadata<T>(metadata: MetadataMap, message: T) -> tonic::Request<T> {
+    let mut req = tonic::Request::new(message);
+    *req.metadata_mut() = metadata;
+    req
+}
+
 impl Drop for MockGuard {
     fn drop(&mut self) {
         let future = async move {
@@ -227,18 +364,16 @@ impl Drop for MockGuard {
     }
 }
 
-fn received_requests_message(
-    received_requests: &Option<Vec<(&'static str, MockRequest)>>,
-) -> String {
+fn received_requests_message(received_requests: &Option<Vec<ReceivedRequest>>) -> String {
     if let Some(received_requests) = received_requests {
         if received_requests.is_empty() {
             "The server did not receive any request.".into()
         } else {
             received_requests.iter().enumerate().fold(
                 "Received requests:\n".to_string(),
-                |mut message, (index, (rpc, request))| {
+                |mut message, (index, request)| {
                     _ = writeln!(message, "- Request #{index}");
-                    _ = writeln!(message, "\tRPC name: {rpc}");
+                    _ = writeln!(message, "\tRPC name: {}", request.rpc);
                     _ = writeln!(message, "\tRequests Protobuf as JSON");
                     _ = request.print(indenter::indented(&mut message).with_str("\t"));
                     message
@@ -19,10 +19,13 @@ use super::{
 use crate::{
     erase_request,
     mounted_mock::MockResult,
+    scenario::ScenarioStates,
     verification::{
         VerificationOutcome,
         VerificationReport,
     },
+    AnyRequest,
+    ResponseStream,
 };
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -39,15 +42,25 @@ pub(crate) struct MockId {
 #[derive(Default)]
 pub(crate) struct MockSet {
     mocks: Vec<(MountedMock, MountedMockState)>,
+    scenario_states: ScenarioStates,
 }
 
 impl MockSet {
     pub(crate) fn new() -> Self {
         Self {
             mocks: Vec::new(),
+            scenario_states: ScenarioStates::default(),
         }
     }
 
+    pub(crate) fn scenario_states(&self) -> &ScenarioStates {
+        &self.scenario_states
+    }
+
+    pub(crate) fn scenario_states_mut(&mut self) -> &mut ScenarioStates {
+        &mut self.scenario_states
+    }
+
     pub(crate) fn handle_request<
         T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
         U: Send + Sync + 'static,
@@ -60,15 +73,55 @@ impl MockSet {
         Option<std::time::Duration>,
     ) {
         debug!(rpc, "handling request.");
+        self.respond_with_first_match(rpc, req, |mock, rpc, erased| {
+            mock.match_and_respond::<U>(rpc, erased)
+        })
+    }
+
+    pub(crate) fn handle_stream_request<
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+        U: Send + 'static,
+    >(
+        &mut self,
+        rpc: &'static str,
+        req: tonic::Request<T>,
+    ) -> tonic::Result<tonic::Response<ResponseStream<U>>> {
+        debug!(rpc, "handling request with streamed response.");
+        let (result, _) = self.respond_with_first_match(rpc, req, |mock, rpc, erased| {
+            (mock.match_and_respond_stream::<U>(rpc, erased), None)
+        });
+        result
+    }
+
+    /// Responds to `req` with the first in-scope mock that matches it.
+    fn respond_with_first_match<T, R>(
+        &mut self,
+        rpc: &'static str,
+        req: tonic::Request<T>,
+        mut match_and_respond: impl FnMut(
+            &mut MountedMock,
+            &'static str,
+            &AnyRequest,
+        ) -> (MockResult<R>, Option<std::time::Duration>),
+    ) -> (tonic::Result<R>, Option<std::time::Duration>)
+    where
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+    {
         // perform erasure here so that it's not done in every single `Mock::matches` call.
         let erased = erase_request(req);
-        let mut mock_response: Option<tonic::Result<tonic::Response<U>>> = None;
+        let mut mock_response: Option<tonic::Result<R>> = None;
         let mut delay = None;
         for (mock, mock_state) in &mut self.mocks {
             if let MountedMockState::OutOfScope = mock_state {
                 continue;
             }
-            match mock.match_and_respond::<U>(rpc, &erased) {
+            if mock
+                .scenario()
+                .is_some_and(|scenario| !self.scenario_states.allows(scenario))
+            {
+                continue;
+            }
+            match match_and_respond(mock, rpc, &erased) {
                 (MockResult::NoMatch, _) => continue,
                 (MockResult::BadResponse(status), _) => {
                     mock_response.replace(Err(status));
@@ -77,6 +130,9 @@ impl MockSet {
                 (MockResult::Success(response), response_delay) => {
                     mock_response.replace(response);
                     delay = response_delay;
+                    if let Some(scenario) = mock.scenario() {
+                        self.scenario_states.transition(scenario);
+                    }
                     break;
                 }
             }
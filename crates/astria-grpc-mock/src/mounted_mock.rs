@@ -10,6 +10,7 @@ use super::{
     mock::{
         Match as _,
         Mock,
+        Responder,
     },
     response::MockResponse,
     AnyMessage,
@@ -17,13 +18,15 @@ use super::{
 use crate::{
     clone_request,
     clone_response,
-    response::ResponseResult,
+    received_request::print_metadata,
+    scenario::Scenario,
     verification::VerificationReport,
+    ResponseStream,
 };
 
-pub(crate) enum MockResult<U> {
+pub(crate) enum MockResult<R> {
     NoMatch,
-    Success(tonic::Result<tonic::Response<U>>),
+    Success(tonic::Result<R>),
     BadResponse(tonic::Status),
 }
 
@@ -37,18 +40,8 @@ pub(crate) struct BadResponse {
 
 impl BadResponse {
     pub(crate) fn print(&self, mut buffer: impl std::fmt::Write) -> std::fmt::Result {
-        // TODO(https://github.com/astriaorg/astria/issues/900): Print the metadata map
-
-        // for name in self.inner.iter() {
-        //     let values = self
-        //         .headers
-        //         .get_all(name)
-        //         .iter()
-        //         .map(|value| String::from_utf8_lossy(value.as_bytes()))
-        //         .collect::<Vec<_>>();
-        //     let values = values.join(",");
-        //     writeln!(buffer, "{}: {}", name, values)?;
-        // }
+        writeln!(buffer, "Matched request metadata")?;
+        print_metadata(self.request.metadata(), &mut buffer)?;
 
         writeln!(buffer, "Matched request (Protobuf as JSON)")?;
         writeln!(
@@ -102,34 +95,45 @@ pub(crate) struct MountedMock {
     inner: Mock,
     position_in_set: usize,
     notify: Arc<(Notify, AtomicBool)>,
-    successful_responses: Vec<(Request<AnyMessage>, ResponseResult)>,
+    successful_requests: Vec<Request<AnyMessage>>,
     bad_responses: Vec<BadResponse>,
 }
 
 impl MountedMock {
-    pub(crate) fn match_and_respond<U: 'static>(
-        &mut self,
-        rpc: &'static str,
-        request: &Request<AnyMessage>,
-    ) -> (MockResult<U>, Option<std::time::Duration>) {
+    fn matches(&self, rpc: &'static str, request: &Request<AnyMessage>) -> bool {
         let n_matches =
-            u64::try_from(self.successful_responses.len() + self.bad_responses.len()).ok();
-        if self.inner.max_n_matches == n_matches
-            || self.inner.rpc != rpc
-            || !self
+            u64::try_from(self.successful_requests.len() + self.bad_responses.len()).ok();
+        self.inner.max_n_matches != n_matches
+            && self.inner.rpc == rpc
+            && self
                 .inner
                 .matchers
                 .iter()
                 .all(|matcher| matcher.matches(request))
-        {
+    }
+
+    pub(crate) fn match_and_respond<U: 'static>(
+        &mut self,
+        rpc: &'static str,
+        request: &Request<AnyMessage>,
+    ) -> (MockResult<tonic::Response<U>>, Option<std::time::Duration>) {
+        if !self.matches(rpc, request) {
             return (MockResult::NoMatch, None);
         }
+        let Responder::Unary(template) = &self.inner.response else {
+            return (
+                MockResult::BadResponse(tonic::Status::internal(format!(
+                    "mock for RPC `{rpc}` responds with a stream, but the RPC responds with a \
+                     single message"
+                ))),
+                None,
+            );
+        };
 
         let mut delay = None;
-        let response = match self.inner.response.respond(request) {
+        let response = match template.respond(request) {
             (Err(status), _) => {
-                self.successful_responses
-                    .push((clone_request(request), Err(status.clone())));
+                self.successful_requests.push(clone_request(request));
                 Ok(Err(status))
             }
             (Ok(mock_response), rsp_delay) => {
@@ -138,8 +142,7 @@ impl MountedMock {
                     clone_response(&mock_response.inner).into_parts();
                 if let Ok(message) = erased_message.clone_box().into_any().downcast::<U>() {
                     let rsp = tonic::Response::from_parts(metadata, *message, extensions);
-                    self.successful_responses
-                        .push((clone_request(request), Ok(mock_response)));
+                    self.successful_requests.push(clone_request(request));
                     Ok(Ok(rsp))
                 } else {
                     let actual = mock_response.type_name;
@@ -161,6 +164,68 @@ impl MountedMock {
             }
         };
 
+        self.notify_if_done();
+        match response {
+            Ok(ok) => (MockResult::Success(ok), delay),
+            Err(err) => (MockResult::BadResponse(err), None),
+        }
+    }
+
+    pub(crate) fn match_and_respond_stream<U: Send + 'static>(
+        &mut self,
+        rpc: &'static str,
+        request: &Request<AnyMessage>,
+    ) -> MockResult<tonic::Response<ResponseStream<U>>> {
+        if !self.matches(rpc, request) {
+            return MockResult::NoMatch;
+        }
+        let Responder::Stream(template) = &self.inner.response else {
+            return MockResult::BadResponse(tonic::Status::internal(format!(
+                "mock for RPC `{rpc}` responds with a single message, but the RPC responds with a \
+                 stream"
+            )));
+        };
+
+        let response = match template.respond(request) {
+            Err(status) => {
+                self.successful_requests.push(clone_request(request));
+                Ok(Err(status))
+            }
+            Ok(script) => match script.into_stream::<U>() {
+                Ok(stream) => {
+                    self.successful_requests.push(clone_request(request));
+                    Ok(Ok(tonic::Response::new(stream)))
+                }
+                Err(mock_response) => {
+                    let actual = mock_response.type_name;
+                    let expected = std::any::type_name::<U>();
+                    #[rustfmt::skip]
+                        let msg = format!(
+                            "failed downcasting message of mock stream to concrete type:\n\
+                             required type of gRPC stream message: `{expected}`\n\
+                             type of mock stream message: `{actual}`\n\
+                             JSON serialization:\n\
+                             {}",
+                            serde_json::to_string_pretty(
+                                mock_response.inner.get_ref().as_serialize()
+                            )
+                            .expect("can map registered protobuf response to json")
+                        );
+                    self.bad_responses
+                        .push((clone_request(request), mock_response).into());
+                    Err(tonic::Status::internal(msg))
+                }
+            },
+        };
+
+        self.notify_if_done();
+        match response {
+            Ok(ok) => MockResult::Success(ok),
+            Err(err) => MockResult::BadResponse(err),
+        }
+    }
+
+    fn notify_if_done(&self) {
         let verification = self.verify();
         // if a bad response was received notify and exist immediately, don't even
         // set the satisfaction flag.
@@ -174,10 +239,6 @@ impl MountedMock {
                 .store(true, std::sync::atomic::Ordering::Release);
             self.notify.0.notify_waiters();
         }
-        match response {
-            Ok(ok) => (MockResult::Success(ok), delay),
-            Err(err) => (MockResult::BadResponse(err), None),
-        }
     }
 
     pub(crate) fn new(inner: Mock, position_in_set: usize) -> Self {
@@ -185,11 +246,15 @@ impl MountedMock {
             inner,
             position_in_set,
             notify: Arc::new((Notify::new(), AtomicBool::new(false))),
-            successful_responses: Vec::new(),
+            successful_requests: Vec::new(),
             bad_responses: Vec::new(),
         }
     }
 
+    pub(crate) fn scenario(&self) -> Option<&Scenario> {
+        self.inner.scenario.as_ref()
+    }
+
     pub(crate) fn notify(&self) -> Arc<(Notify, AtomicBool)> {
         self.notify.clone()
     }
@@ -198,7 +263,7 @@ impl MountedMock {
         VerificationReport {
             mock_name: self.inner.name.clone(),
             rpc: self.inner.rpc,
-            n_successful_requests: self.successful_responses.len() as u64,
+            n_successful_requests: self.successful_requests.len() as u64,
             bad_responses: self.bad_responses.clone(),
             expectation_range: self.inner.expectation_range.clone(),
             position_in_set: self.position_in_set,
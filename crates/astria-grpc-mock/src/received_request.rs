@@ -0,0 +1,110 @@
+use tonic::metadata::{
+    KeyAndValueRef,
+    MetadataMap,
+};
+
+use crate::{
+    clone_request,
+    mock::{
+        Match,
+        Matcher,
+    },
+    AnyRequest,
+};
+
+/// A request received by the mock server, as returned by
+/// [`MockServer::received_requests`](crate::MockServer::received_requests).
+pub struct ReceivedRequest {
+    pub(crate) rpc: &'static str,
+    pub(crate) inner: AnyRequest,
+}
+
+impl ReceivedRequest {
+    /// The name of the RPC that received the request.
+    #[must_use]
+    pub fn rpc(&self) -> &'static str {
+        self.rpc
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &MetadataMap {
+        self.inner.metadata()
+    }
+
+    /// Returns the message of the request if it is of type `T`.
+    #[must_use]
+    pub fn message<T: 'static>(&self) -> Option<&T> {
+        self.inner.get_ref().as_any().downcast_ref::<T>()
+    }
+
+    pub(crate) fn print(&self, mut buffer: impl std::fmt::Write) -> std::fmt::Result {
+        print_metadata(self.inner.metadata(), &mut buffer)?;
+        if let Ok(body) = serde_json::to_string_pretty(self.inner.get_ref().as_serialize()) {
+            writeln!(buffer, "{body}")
+        } else {
+            writeln!(buffer, "Could not map the gRPC body to JSON",)
+        }
+    }
+}
+
+impl Clone for ReceivedRequest {
+    fn clone(&self) -> Self {
+        Self {
+            rpc: self.rpc,
+            inner: clone_request(&self.inner),
+        }
+    }
+}
+
+/// A request expected to have been received by the mock server.
+///
+/// Used to assert the order of received requests with
+/// [`MockServer::assert_received_in_order`](crate::MockServer::assert_received_in_order).
+pub struct ExpectedRequest {
+    pub(crate) rpc: &'static str,
+    matchers: Vec<Matcher>,
+}
+
+impl ExpectedRequest {
+    pub fn for_rpc_given(rpc: &'static str, matcher: impl Match + 'static) -> Self {
+        Self {
+            rpc,
+            matchers: vec![Matcher(Box::new(matcher))],
+        }
+    }
+
+    #[must_use]
+    pub fn and(mut self, matcher: impl Match + 'static) -> Self {
+        self.matchers.push(Matcher(Box::new(matcher)));
+        self
+    }
+
+    pub(crate) fn matches(&self, received: &ReceivedRequest) -> bool {
+        self.rpc == received.rpc
+            && self
+                .matchers
+                .iter()
+                .all(|matcher| matcher.matches(&received.inner))
+    }
+}
+
+/// Writes the metadata of a request, one `key: value` pair per line.
+pub(crate) fn print_metadata(
+    metadata: &MetadataMap,
+    mut buffer: impl std::fmt::Write,
+) -> std::fmt::Result {
+    for key_and_value in metadata.iter() {
+        match key_and_value {
+            KeyAndValueRef::Ascii(key, value) => writeln!(
+                buffer,
+                "{}: {}",
+                key.as_str(),
+                String::from_utf8_lossy(value.as_encoded_bytes())
+            )?,
+            KeyAndValueRef::Binary(key, value) => {
+                writeln!(buffer, "{}: {value:?}", key.as_str())?;
+            }
+        }
+    }
+    Ok(())
+}
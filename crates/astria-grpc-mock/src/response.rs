@@ -1,13 +1,22 @@
 use std::{
+    collections::VecDeque,
     marker::PhantomData,
     time::Duration,
 };
 
+use futures::{
+    stream,
+    StreamExt as _,
+};
+
 use super::{
     clone_response,
     AnyMessage,
 };
-use crate::erase_response;
+use crate::{
+    erase_response,
+    ResponseStream,
+};
 
 pub fn constant_response<
     T: erased_serde::Serialize + prost::Name + Clone + Default + Send + Sync + 'static,
@@ -161,3 +170,206 @@ impl ResponseTemplate {
 pub trait Respond: Send + Sync {
     fn respond(&self, req: &tonic::Request<AnyMessage>) -> ResponseResult;
 }
+
+/// Responds to a matched request with the messages of a constant [`StreamScript`].
+#[must_use]
+pub fn stream_response(script: StreamScript) -> StreamResponseTemplate {
+    StreamResponseTemplate {
+        response: Box::new(script),
+    }
+}
+
+/// Responds to a matched request with the messages of the [`StreamScript`] returned by
+/// `responder`.
+pub fn dynamic_stream_response<I, F>(responder: F) -> StreamResponseTemplate
+where
+    F: Send + Sync + 'static + Fn(&I) -> StreamScript,
+    I: Send + Sync + 'static,
+{
+    StreamResponseTemplate {
+        response: Box::new(DynamicStreamResponse {
+            responder: Box::new(responder),
+            _phantom_data: PhantomData,
+        }),
+    }
+}
+
+pub struct DynamicStreamResponse<I, F> {
+    responder: Box<F>,
+    _phantom_data: PhantomData<I>,
+}
+
+impl<I, F> RespondStream for DynamicStreamResponse<I, F>
+where
+    I: Send + Sync + 'static,
+    F: Send + Sync + Fn(&I) -> StreamScript,
+{
+    fn respond(&self, outer_req: &tonic::Request<AnyMessage>) -> StreamResponseResult {
+        let erased_req = outer_req.get_ref();
+        let Some(req) = erased_req.as_any().downcast_ref::<I>() else {
+            let actual = erased_req.as_name().full_name();
+            let expected = std::any::type_name::<I>();
+            let req_as_json = serde_json::to_string(erased_req.as_serialize())
+                .expect("can map registered protobuf response to json");
+            let msg = format!(
+                "failed downcasting request to concrete type; expected type of request: \
+                 `{expected}`, actual type of request: `{actual}`, request: {req_as_json}",
+            );
+            return Err(tonic::Status::internal(msg));
+        };
+        Ok((self.responder)(req))
+    }
+}
+
+/// A script of the messages, delays and termination of a streamed response.
+///
+/// Steps are played back in the order they were added. By default the stream is closed after
+/// the last step.
+#[derive(Clone)]
+pub struct StreamScript {
+    steps: Vec<StreamStep>,
+    end: StreamEnd,
+}
+
+#[derive(Clone)]
+enum StreamStep {
+    Message {
+        type_name: &'static str,
+        message: AnyMessage,
+    },
+    Delay(Duration),
+}
+
+#[derive(Clone)]
+enum StreamEnd {
+    Close,
+    Error(tonic::Code),
+    KeepOpen,
+}
+
+impl StreamScript {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            end: StreamEnd::Close,
+        }
+    }
+
+    /// Sends `value` as the next message of the stream.
+    #[must_use]
+    pub fn message<T>(mut self, value: T) -> Self
+    where
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+    {
+        self.steps.push(StreamStep::Message {
+            type_name: std::any::type_name::<T>(),
+            message: Box::new(value),
+        });
+        self
+    }
+
+    /// Sends each of `values` as the next messages of the stream.
+    #[must_use]
+    pub fn messages<T>(self, values: impl IntoIterator<Item = T>) -> Self
+    where
+        T: erased_serde::Serialize + prost::Name + Clone + Send + Sync + 'static,
+    {
+        values.into_iter().fold(self, Self::message)
+    }
+
+    /// Waits for `delay` before playing back the next step.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.steps.push(StreamStep::Delay(delay));
+        self
+    }
+
+    /// Terminates the stream with an error status after the last step.
+    #[must_use]
+    pub fn end_with_error(mut self, code: tonic::Code) -> Self {
+        self.end = StreamEnd::Error(code);
+        self
+    }
+
+    /// Keeps the stream open after the last step until the client hangs up.
+    #[must_use]
+    pub fn keep_open(mut self) -> Self {
+        self.end = StreamEnd::KeepOpen;
+        self
+    }
+
+    /// Converts the script into a stream of concrete messages of type `U`.
+    ///
+    /// Returns the first message that is not of type `U` as an error.
+    pub(crate) fn into_stream<U: Send + 'static>(self) -> Result<ResponseStream<U>, MockResponse> {
+        enum Step<U> {
+            Message(U),
+            Delay(Duration),
+        }
+
+        let mut steps = VecDeque::with_capacity(self.steps.len());
+        for step in self.steps {
+            match step {
+                StreamStep::Message {
+                    type_name,
+                    message,
+                } => {
+                    let Ok(concrete) = message.clone_box().into_any().downcast::<U>() else {
+                        return Err(MockResponse {
+                            type_name,
+                            inner: tonic::Response::new(message),
+                        });
+                    };
+                    steps.push_back(Step::Message(*concrete));
+                }
+                StreamStep::Delay(delay) => steps.push_back(Step::Delay(delay)),
+            }
+        }
+
+        let body = stream::unfold(steps, |mut steps| async move {
+            loop {
+                match steps.pop_front()? {
+                    Step::Message(message) => return Some((Ok(message), steps)),
+                    Step::Delay(delay) => tokio::time::sleep(delay).await,
+                }
+            }
+        });
+        let end: stream::BoxStream<'static, tonic::Result<U>> = match self.end {
+            StreamEnd::Close => stream::empty().boxed(),
+            StreamEnd::Error(code) => {
+                stream::once(async move { Err(tonic::Status::new(code, "error")) }).boxed()
+            }
+            StreamEnd::KeepOpen => stream::pending().boxed(),
+        };
+        Ok(Box::pin(body.chain(end)))
+    }
+}
+
+impl Default for StreamScript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RespondStream for StreamScript {
+    fn respond(&self, _req: &tonic::Request<AnyMessage>) -> StreamResponseResult {
+        Ok(self.clone())
+    }
+}
+
+pub type StreamResponseResult = Result<StreamScript, tonic::Status>;
+
+pub struct StreamResponseTemplate {
+    response: Box<dyn RespondStream>,
+}
+
+impl StreamResponseTemplate {
+    pub(crate) fn respond(&self, req: &tonic::Request<AnyMessage>) -> StreamResponseResult {
+        self.response.respond(req)
+    }
+}
+
+pub trait RespondStream: Send + Sync {
+    fn respond(&self, req: &tonic::Request<AnyMessage>) -> StreamResponseResult;
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+/// A named state machine shared by the mocks registered in it.
+///
+/// A mock in a scenario only matches requests while the scenario is in the state required by the
+/// mock, and moves the scenario to a new state after it responded. All scenarios start out in
+/// [`Scenario::STARTED`].
+///
+/// This allows responding differently to the same request depending on which requests were
+/// received before, e.g. returning an updated commitment state only after it was set.
+#[derive(Clone, Debug)]
+pub struct Scenario {
+    pub(crate) name: String,
+    pub(crate) required_state: Option<String>,
+    pub(crate) new_state: Option<String>,
+}
+
+impl Scenario {
+    /// The state every scenario is in before any of its mocks changed it.
+    pub const STARTED: &'static str = "Started";
+
+    #[must_use = "a scenario must be added to a mock to be useful"]
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            required_state: None,
+            new_state: None,
+        }
+    }
+
+    /// Only match requests while the scenario is in `state`.
+    #[must_use = "a scenario must be added to a mock to be useful"]
+    pub fn when_state_is(mut self, state: impl Into<String>) -> Self {
+        self.required_state.replace(state.into());
+        self
+    }
+
+    /// Move the scenario to `state` after responding to a matched request.
+    #[must_use = "a scenario must be added to a mock to be useful"]
+    pub fn will_set_state(mut self, state: impl Into<String>) -> Self {
+        self.new_state.replace(state.into());
+        self
+    }
+}
+
+/// The current states of all scenarios of a mock server.
+#[derive(Default)]
+pub(crate) struct ScenarioStates {
+    states: HashMap<String, String>,
+}
+
+impl ScenarioStates {
+    pub(crate) fn get(&self, name: &str) -> &str {
+        self.states
+            .get(name)
+            .map_or(Scenario::STARTED, String::as_str)
+    }
+
+    pub(crate) fn set(&mut self, name: impl Into<String>, state: impl Into<String>) {
+        self.states.insert(name.into(), state.into());
+    }
+
+    /// Returns if `scenario` is in the state required by it, if any.
+    pub(crate) fn allows(&self, scenario: &Scenario) -> bool {
+        match &scenario.required_state {
+            Some(required) => self.get(&scenario.name) == required,
+            None => true,
+        }
+    }
+
+    /// Moves `scenario` to its new state, if any.
+    pub(crate) fn transition(&mut self, scenario: &Scenario) {
+        if let Some(new_state) = &scenario.new_state {
+            self.set(scenario.name.clone(), new_state.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenarios_start_out_in_started_state() {
+        let states = ScenarioStates::default();
+        assert_eq!(Scenario::STARTED, states.get("commitment state"));
+        assert!(states.allows(&Scenario::named("commitment state").when_state_is("Started")));
+        assert!(!states.allows(&Scenario::named("commitment state").when_state_is("updated")));
+    }
+
+    #[test]
+    fn transition_only_affects_named_scenario() {
+        let mut states = ScenarioStates::default();
+        let update = Scenario::named("commitment state").will_set_state("updated");
+        states.transition(&update);
+        assert_eq!("updated", states.get("commitment state"));
+        assert_eq!(Scenario::STARTED, states.get("other"));
+        assert!(states.allows(&Scenario::named("commitment state").when_state_is("updated")));
+    }
+}
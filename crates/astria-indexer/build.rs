@@ -0,0 +1,4 @@
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    astria_build_info::emit("indexer-v")?;
+    Ok(())
+}
@@ -0,0 +1,372 @@
+use std::net::SocketAddr;
+
+use astria_core::primitive::v1::{
+    Address,
+    RollupId,
+};
+use axum::{
+    extract::{
+        FromRef,
+        Path,
+        Query,
+        State,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::{
+        get,
+        IntoMakeService,
+    },
+    Json,
+    Router,
+};
+use http::status::StatusCode;
+use hyper::server::conn::AddrIncoming;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tokio::sync::watch;
+use tracing::{
+    instrument,
+    warn,
+};
+
+use crate::{
+    follower::StateSnapshot,
+    store::{
+        ActionEntry,
+        Cursor,
+        DepositEntry,
+        FeeEntry,
+        Page,
+        Store,
+    },
+};
+
+pub(crate) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
+
+type IndexerState = watch::Receiver<StateSnapshot>;
+
+/// The number of entries returned by a query if no `limit` is given.
+const DEFAULT_PAGE_LIMIT: u32 = 100;
+
+#[derive(Clone)]
+/// `AppState` is used for as an axum extractor in its method handlers.
+struct AppState {
+    indexer_state: IndexerState,
+    store: Store,
+    address_prefix: String,
+}
+
+impl FromRef<AppState> for IndexerState {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.indexer_state.clone()
+    }
+}
+
+pub(crate) fn start(
+    socket_addr: SocketAddr,
+    indexer_state: IndexerState,
+    store: Store,
+    address_prefix: String,
+) -> ApiServer {
+    let app = Router::new()
+        .route("/healthz", get(get_healthz))
+        .route("/readyz", get(get_readyz))
+        .route("/status", get(get_status))
+        .route("/v1/accounts/:address/history", get(get_account_history))
+        .route(
+            "/v1/bridge-accounts/:address/history",
+            get(get_bridge_account_history),
+        )
+        .route("/v1/rollups/:rollup_id/deposits", get(get_rollup_deposits))
+        .with_state(AppState {
+            indexer_state,
+            store,
+            address_prefix,
+        });
+    axum::Server::bind(&socket_addr).serve(app.into_make_service())
+}
+
+/// Query parameters for paging through the entries of a query, newest first.
+///
+/// The next page starts before the `height`, `txIndex` and `actionIndex` (`feeIndex` for fees,
+/// `sourceTransactionIndex` and `sourceActionIndex` for deposits) of the last entry returned.
+#[derive(Debug, Deserialize)]
+struct PageParams {
+    /// The maximum number of entries to return.
+    limit: Option<u32>,
+    /// Only return entries strictly before this sequencer height, or before the transaction and
+    /// action at this height if given.
+    before_height: Option<u64>,
+    before_tx_index: Option<u32>,
+    before_action_index: Option<u32>,
+}
+
+impl From<PageParams> for Page {
+    fn from(params: PageParams) -> Self {
+        Self {
+            limit: params.limit.unwrap_or(DEFAULT_PAGE_LIMIT),
+            before: params.before_height.map(|height| Cursor {
+                height,
+                tx_index: params.before_tx_index.unwrap_or(0),
+                action_index: params.before_action_index.unwrap_or(0),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountHistory {
+    actions: Vec<ActionEntry>,
+    fees: Vec<FeeEntry>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BridgeAccountHistory {
+    actions: Vec<ActionEntry>,
+    deposits: Vec<DepositEntry>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RollupDeposits {
+    deposits: Vec<DepositEntry>,
+}
+
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn get_healthz(State(indexer_state): State<IndexerState>) -> Healthz {
+    if indexer_state.borrow().is_ready() {
+        Healthz::Ok
+    } else {
+        Healthz::Degraded
+    }
+}
+
+/// Handler of a call to `/readyz`.
+///
+/// Returns `Readyz::Ok` once the sequencer chain ID was confirmed and the last indexed height was
+/// read from the database.
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn get_readyz(State(indexer_state): State<IndexerState>) -> Readyz {
+    if indexer_state.borrow().is_ready() {
+        Readyz::Ok
+    } else {
+        Readyz::NotReady
+    }
+}
+
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn get_status(State(indexer_state): State<IndexerState>) -> Json<StateSnapshot> {
+    Json(*indexer_state.borrow())
+}
+
+/// Returns the actions the account was involved in and the fees it paid.
+#[instrument(skip_all, fields(%address))]
+async fn get_account_history(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Query(page): Query<PageParams>,
+) -> Result<Json<AccountHistory>, QueryError> {
+    let address = parse_address(&address, &state.address_prefix)?;
+    let page = Page::from(page);
+    let actions = state
+        .store
+        .account_history(address.clone(), page)
+        .await
+        .map_err(QueryError::internal)?;
+    let fees = state
+        .store
+        .account_fees(address, page)
+        .await
+        .map_err(QueryError::internal)?;
+    Ok(Json(AccountHistory {
+        actions,
+        fees,
+    }))
+}
+
+/// Returns the actions acting upon the bridge account and the deposits made through it.
+#[instrument(skip_all, fields(%address))]
+async fn get_bridge_account_history(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Query(page): Query<PageParams>,
+) -> Result<Json<BridgeAccountHistory>, QueryError> {
+    let address = parse_address(&address, &state.address_prefix)?;
+    let page = Page::from(page);
+    let actions = state
+        .store
+        .bridge_account_history(address.clone(), page)
+        .await
+        .map_err(QueryError::internal)?;
+    let deposits = state
+        .store
+        .deposits_by_bridge_account(address, page)
+        .await
+        .map_err(QueryError::internal)?;
+    Ok(Json(BridgeAccountHistory {
+        actions,
+        deposits,
+    }))
+}
+
+/// Returns the deposits made to the rollup.
+#[instrument(skip_all, fields(%rollup_id))]
+async fn get_rollup_deposits(
+    State(state): State<AppState>,
+    Path(rollup_id): Path<String>,
+    Query(page): Query<PageParams>,
+) -> Result<Json<RollupDeposits>, QueryError> {
+    let rollup_id = parse_rollup_id(&rollup_id)?;
+    let deposits = state
+        .store
+        .deposits_by_rollup(rollup_id, page.into())
+        .await
+        .map_err(QueryError::internal)?;
+    Ok(Json(RollupDeposits {
+        deposits,
+    }))
+}
+
+/// Parses `address` and converts it to the prefix under which addresses are stored.
+fn parse_address(address: &str, prefix: &str) -> Result<String, QueryError> {
+    address
+        .parse::<Address>()
+        .and_then(|address| address.to_prefix(prefix))
+        .map(|address| address.to_string())
+        .map_err(|_| QueryError::BadRequest(format!("`{address}` is not a bech32m address")))
+}
+
+/// Parses a rollup ID given as base64 (as used in the database) or as hex.
+fn parse_rollup_id(rollup_id: &str) -> Result<String, QueryError> {
+    use base64::{
+        prelude::BASE64_STANDARD,
+        Engine as _,
+    };
+    let bytes = hex::decode(rollup_id.trim_start_matches("0x"))
+        .ok()
+        .or_else(|| BASE64_STANDARD.decode(rollup_id).ok())
+        .ok_or_else(|| {
+            QueryError::BadRequest(format!("`{rollup_id}` is neither hex nor base64 encoded"))
+        })?;
+    RollupId::try_from_slice(&bytes)
+        .map(|rollup_id| rollup_id.to_string())
+        .map_err(|_| QueryError::BadRequest(format!("`{rollup_id}` is not a 32 byte rollup ID")))
+}
+
+enum QueryError {
+    BadRequest(String),
+    Internal,
+}
+
+impl QueryError {
+    fn internal(error: astria_eyre::eyre::Report) -> Self {
+        warn!(%error, "failed querying database");
+        Self::Internal
+    }
+}
+
+impl IntoResponse for QueryError {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ErrorBody {
+            error: String,
+        }
+        let (status, error) = match self {
+            Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            Self::Internal => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed querying database".to_string(),
+            ),
+        };
+        let mut response = Json(ErrorBody {
+            error,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
+enum Healthz {
+    Ok,
+    Degraded,
+}
+
+impl IntoResponse for Healthz {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct HealthzBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Ok => (StatusCode::OK, "ok"),
+            Self::Degraded => (StatusCode::INTERNAL_SERVER_ERROR, "degraded"),
+        };
+        let mut response = Json(HealthzBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
+enum Readyz {
+    Ok,
+    NotReady,
+}
+
+impl IntoResponse for Readyz {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ReadyzBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Ok => (StatusCode::OK, "ok"),
+            Self::NotReady => (StatusCode::SERVICE_UNAVAILABLE, "not ready"),
+        };
+        let mut response = Json(ReadyzBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_is_converted_to_configured_prefix() {
+        let address = Address::builder()
+            .array([1; 20])
+            .prefix("other")
+            .try_build()
+            .unwrap();
+        let parsed = parse_address(&address.to_string(), "astria").unwrap();
+        assert_eq!(parsed, address.to_prefix("astria").unwrap().to_string());
+        assert!(parse_address("not-an-address", "astria").is_err());
+    }
+
+    #[test]
+    fn rollup_id_is_accepted_as_hex_and_base64() {
+        let rollup_id = RollupId::new([7; 32]);
+        let expected = rollup_id.to_string();
+        assert_eq!(parse_rollup_id(&hex::encode([7; 32])).unwrap(), expected);
+        assert_eq!(parse_rollup_id(&expected).unwrap(), expected);
+        assert!(parse_rollup_id("abcd").is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+// Allowed `struct_excessive_bools` because this is used as a container
+// for deserialization. Making this a builder-pattern is not actionable.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+/// The single config for creating an astria-indexer service.
+pub struct Config {
+    /// The chain ID of the sequencer network that is indexed.
+    pub sequencer_chain_id: String,
+    /// The URL of the Sequencer CometBFT HTTP RPC, used to fetch transactions and their events.
+    pub sequencer_cometbft_endpoint: String,
+    /// The URL of the Sequencer gRPC service, used to fetch sequencer blocks and their deposits.
+    pub sequencer_grpc_endpoint: String,
+    /// The bech32m prefix of sequencer addresses as they are stored and queried.
+    pub sequencer_address_prefix: String,
+    /// The duration in milliseconds that indexer waits before polling sequencer for a new height.
+    pub block_time_ms: u64,
+    /// The path to the sqlite database holding the indexed data. It is created if it does not
+    /// exist.
+    pub database_path: PathBuf,
+    /// The socket address at which indexer serves its query API and healthz, readyz, and status
+    /// calls.
+    pub api_addr: String,
+    pub log: String,
+    /// Forces writing trace data to stdout no matter if connected to a tty or not.
+    pub force_stdout: bool,
+    /// Disables writing trace data to an opentelemetry endpoint.
+    pub no_otel: bool,
+    /// Set to true to disable the metrics server
+    pub no_metrics: bool,
+    /// The endpoint which will be listened on for serving prometheus metrics
+    pub metrics_http_listener_addr: String,
+    /// Writes a human readable format to stdout instead of JSON formatted OTEL trace data.
+    pub pretty_print: bool,
+}
+
+impl config::Config for Config {
+    const PREFIX: &'static str = "ASTRIA_INDEXER_";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+
+    const EXAMPLE_ENV: &str = include_str!("../local.env.example");
+
+    #[test]
+    fn example_env_config_is_up_to_date() {
+        config::tests::example_env_config_is_up_to_date::<Config>(EXAMPLE_ENV);
+    }
+}
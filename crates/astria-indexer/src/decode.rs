@@ -0,0 +1,507 @@
+//! Decoding of sequencer blocks into the records stored by the indexer.
+//!
+//! A sequencer block is assembled from three sources at the same height:
+//!
+//! + the CometBFT block, whose data holds the raw signed transactions (after the two injected
+//!   rollup transactions and rollup IDs commitments);
+//! + the CometBFT block results, which hold the ABCI events emitted per transaction and by
+//!   finalizing the block;
+//! + the sequencer block served by the sequencer gRPC API, which holds the bridge deposits per
+//!   rollup.
+
+use astria_core::{
+    generated::protocol::transactions::v1alpha1 as raw,
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::{
+        Action,
+        SignedTransaction,
+    },
+    sequencerblock::v1alpha1::{
+        block::{
+            Deposit,
+            RollupData,
+        },
+        SequencerBlock,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    OptionExt as _,
+    WrapErr as _,
+};
+use prost::Message as _;
+use sequencer_client::tendermint::abci;
+use serde::Serialize;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+/// The number of transactions at the start of a sequencer block's data that are injected by the
+/// proposer and are not signed transactions.
+const INJECTED_TRANSACTIONS_COUNT: usize = 2;
+
+/// The ABCI event kind emitted for every fee paid by a transaction.
+const FEE_EVENT_KIND: &str = "tx.fees";
+
+/// All records of a sequencer block that are written to the database in one transaction.
+#[derive(Debug)]
+pub(crate) struct IndexedBlock {
+    pub(crate) height: u64,
+    pub(crate) block_hash: String,
+    pub(crate) time: String,
+    pub(crate) transactions: Vec<IndexedTransaction>,
+    pub(crate) deposits: Vec<DepositRecord>,
+    /// Events emitted while finalizing the block that are not attributed to a transaction.
+    pub(crate) block_events: Vec<EventRecord>,
+}
+
+impl IndexedBlock {
+    /// Returns the number of transactions in the block that could not be decoded.
+    pub(crate) fn undecodable_transactions(&self) -> usize {
+        self.transactions
+            .iter()
+            .filter(|tx| tx.signer.is_none())
+            .count()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct IndexedTransaction {
+    /// The hex-encoded sha256 hash of the transaction bytes, same as used by CometBFT.
+    pub(crate) hash: String,
+    /// The position of the transaction among the signed transactions of the block.
+    pub(crate) index: u32,
+    /// The address of the signer. `None` if the transaction could not be decoded.
+    pub(crate) signer: Option<String>,
+    pub(crate) nonce: Option<u32>,
+    /// The ABCI result code of executing the transaction; 0 means success.
+    pub(crate) code: u32,
+    pub(crate) actions: Vec<ActionRecord>,
+    pub(crate) events: Vec<EventRecord>,
+    pub(crate) fees: Vec<FeeRecord>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ActionRecord {
+    pub(crate) index: u32,
+    pub(crate) kind: &'static str,
+    /// The action encoded as JSON.
+    pub(crate) action: String,
+    /// The accounts involved in the action and the role they play in it.
+    pub(crate) accounts: Vec<(String, Role)>,
+}
+
+/// The role an account plays in an action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Role {
+    /// The account signed the transaction containing the action.
+    Signer,
+    /// The account received funds through the action.
+    Recipient,
+    /// The account is the bridge account acted upon.
+    Bridge,
+}
+
+impl Role {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Signer => "signer",
+            Self::Recipient => "recipient",
+            Self::Bridge => "bridge",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EventRecord {
+    pub(crate) kind: String,
+    pub(crate) attributes: Vec<(String, String)>,
+}
+
+impl From<abci::Event> for EventRecord {
+    fn from(event: abci::Event) -> Self {
+        Self {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct FeeRecord {
+    pub(crate) asset: String,
+    pub(crate) amount: String,
+    pub(crate) action_type: String,
+}
+
+impl FeeRecord {
+    fn from_event(event: &EventRecord) -> Option<Self> {
+        if event.kind != FEE_EVENT_KIND {
+            return None;
+        }
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find_map(|(k, v)| (k == key).then(|| v.clone()))
+        };
+        Some(Self {
+            asset: attribute("asset")?,
+            amount: attribute("feeAmount")?,
+            action_type: attribute("actionType")?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct DepositRecord {
+    pub(crate) rollup_id: String,
+    pub(crate) bridge_address: String,
+    pub(crate) amount: String,
+    pub(crate) asset: String,
+    pub(crate) destination_chain_address: String,
+    pub(crate) source_transaction_id: String,
+    /// The index of the source transaction in the block.
+    pub(crate) source_transaction_index: u32,
+    pub(crate) source_action_index: u64,
+}
+
+/// Decodes the records of a block from its three sources, which must be at the same height.
+///
+/// Transactions that cannot be decoded are kept with their hash, result code and events, but
+/// without signer and actions.
+///
+/// # Errors
+/// Returns an error if the number of transaction results does not match the number of
+/// transactions, or if an address cannot be converted to `address_prefix`.
+pub(crate) fn decode_block(
+    sequencer_block: SequencerBlock,
+    block_data: &[Vec<u8>],
+    tx_results: Vec<abci::types::ExecTxResult>,
+    finalize_block_events: Vec<abci::Event>,
+    address_prefix: &str,
+) -> eyre::Result<IndexedBlock> {
+    ensure!(
+        block_data.len() == tx_results.len(),
+        "block contains {} transactions but {} transaction results",
+        block_data.len(),
+        tx_results.len(),
+    );
+    let height = sequencer_block.height().value();
+    let block_hash = hex::encode(sequencer_block.block_hash());
+    let time = sequencer_block.header().time().to_rfc3339();
+
+    let transactions: Vec<IndexedTransaction> = block_data
+        .iter()
+        .zip(tx_results)
+        .skip(INJECTED_TRANSACTIONS_COUNT)
+        .enumerate()
+        .map(|(index, (bytes, result))| {
+            decode_transaction(
+                index
+                    .try_into()
+                    .expect("a block holds fewer than u32::MAX transactions"),
+                bytes,
+                result,
+                address_prefix,
+            )
+        })
+        .collect::<eyre::Result<_>>()?;
+
+    let deposits = sequencer_block
+        .into_rollup_transactions()
+        .into_values()
+        .flat_map(|rollup_transactions| rollup_transactions.into_parts().transactions)
+        .filter_map(|bytes| {
+            let raw =
+                astria_core::generated::sequencerblock::v1alpha1::RollupData::decode(bytes).ok()?;
+            match RollupData::try_from_raw(raw).ok()? {
+                RollupData::Deposit(deposit) => Some(*deposit),
+                RollupData::SequencedData(_) => None,
+            }
+        })
+        .map(|deposit| deposit_record(deposit, &transactions, address_prefix))
+        .collect::<eyre::Result<_>>()?;
+
+    Ok(IndexedBlock {
+        height,
+        block_hash,
+        time,
+        transactions,
+        deposits,
+        block_events: finalize_block_events
+            .into_iter()
+            .map(EventRecord::from)
+            .collect(),
+    })
+}
+
+fn decode_transaction(
+    index: u32,
+    bytes: &[u8],
+    result: abci::types::ExecTxResult,
+    address_prefix: &str,
+) -> eyre::Result<IndexedTransaction> {
+    let events: Vec<EventRecord> = result.events.into_iter().map(EventRecord::from).collect();
+    let fees = events.iter().filter_map(FeeRecord::from_event).collect();
+    let mut tx = IndexedTransaction {
+        hash: hex::encode(Sha256::digest(bytes)),
+        index,
+        signer: None,
+        nonce: None,
+        code: result.code.value(),
+        actions: vec![],
+        events,
+        fees,
+    };
+    let Some(signed) = raw::SignedTransaction::decode(bytes)
+        .ok()
+        .and_then(|raw| SignedTransaction::try_from_raw(raw).ok())
+    else {
+        return Ok(tx);
+    };
+    let signer = Address::builder()
+        .array(signed.address_bytes())
+        .prefix(address_prefix)
+        .try_build()
+        .wrap_err("failed to construct signer address from configured prefix")?
+        .to_string();
+    tx.actions = signed
+        .actions()
+        .iter()
+        .zip(0u32..)
+        .map(|(action, index)| action_record(index, action, &signer, address_prefix))
+        .collect::<eyre::Result<_>>()?;
+    tx.signer = Some(signer);
+    tx.nonce = Some(signed.nonce());
+    Ok(tx)
+}
+
+fn action_record(
+    index: u32,
+    action: &Action,
+    signer: &str,
+    address_prefix: &str,
+) -> eyre::Result<ActionRecord> {
+    let mut accounts = vec![(signer.to_string(), Role::Signer)];
+    let mut push = |address: &Address, role| -> eyre::Result<()> {
+        accounts.push((with_prefix(address, address_prefix)?, role));
+        Ok(())
+    };
+    let kind = match action {
+        Action::Sequence(_) => "sequence",
+        Action::Transfer(act) => {
+            push(&act.to, Role::Recipient)?;
+            "transfer"
+        }
+        Action::Bond(_) => "bond",
+        Action::Unbond(_) => "unbond",
+        Action::ValidatorUpdate(_) => "validator_update",
+        Action::SudoAddressChange(_) => "sudo_address_change",
+        Action::Ibc(_) => "ibc",
+        Action::IbcSudoChange(_) => "ibc_sudo_change",
+        Action::Ics20Withdrawal(act) => {
+            if let Some(bridge_address) = &act.bridge_address {
+                push(bridge_address, Role::Bridge)?;
+            }
+            "ics20_withdrawal"
+        }
+        Action::IbcRelayerChange(_) => "ibc_relayer_change",
+        Action::FeeAssetChange(_) => "fee_asset_change",
+        Action::InitBridgeAccount(_) => {
+            accounts.push((signer.to_string(), Role::Bridge));
+            "init_bridge_account"
+        }
+        Action::BridgeLock(act) => {
+            push(&act.to, Role::Bridge)?;
+            "bridge_lock"
+        }
+        Action::BridgeUnlock(act) => {
+            push(&act.to, Role::Recipient)?;
+            push(&act.bridge_address, Role::Bridge)?;
+            "bridge_unlock"
+        }
+        Action::BridgeSudoChange(act) => {
+            push(&act.bridge_address, Role::Bridge)?;
+            "bridge_sudo_change"
+        }
+        Action::FeeChange(_) => "fee_change",
+        Action::FeeDistributionChange(_) => "fee_distribution_change",
+        Action::UpgradeSchedule(_) => "upgrade_schedule",
+    };
+    Ok(ActionRecord {
+        index,
+        kind,
+        action: serde_json::to_string(action).wrap_err("failed encoding action as JSON")?,
+        accounts,
+    })
+}
+
+/// Converts `deposit`, looking up its source transaction among the `transactions` of its block.
+fn deposit_record(
+    deposit: Deposit,
+    transactions: &[IndexedTransaction],
+    address_prefix: &str,
+) -> eyre::Result<DepositRecord> {
+    let source_transaction_id = deposit.source_transaction_id.to_string();
+    let source_transaction_index = transactions
+        .iter()
+        .find(|tx| tx.hash == source_transaction_id)
+        .map(|tx| tx.index)
+        .ok_or_eyre("source transaction of deposit is not part of its block")?;
+    Ok(DepositRecord {
+        rollup_id: deposit.rollup_id.to_string(),
+        bridge_address: with_prefix(&deposit.bridge_address, address_prefix)?,
+        amount: deposit.amount.to_string(),
+        asset: deposit.asset.to_string(),
+        destination_chain_address: deposit.destination_chain_address,
+        source_transaction_id,
+        source_transaction_index,
+        source_action_index: deposit.source_action_index,
+    })
+}
+
+fn with_prefix(address: &Address, address_prefix: &str) -> eyre::Result<String> {
+    address
+        .to_prefix(address_prefix)
+        .map(|address| address.to_string())
+        .wrap_err("failed to convert address to configured prefix")
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        protocol::transaction::v1alpha1::{
+            action::{
+                BridgeUnlockAction,
+                TransferAction,
+            },
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    };
+    use sequencer_client::tendermint::abci::{
+        types::ExecTxResult,
+        Event,
+        EventAttributeIndexExt as _,
+    };
+
+    use super::*;
+
+    const PREFIX: &str = "astria";
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix("other")
+            .try_build()
+            .unwrap()
+    }
+
+    fn signed_transaction(actions: Vec<Action>) -> Vec<u8> {
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(7)
+                .chain_id("test-chain")
+                .build(),
+            actions,
+        }
+        .into_signed(&SigningKey::from([1; 32]))
+        .into_raw()
+        .encode_to_vec()
+    }
+
+    fn fee_event(amount: u128) -> Event {
+        Event::new(
+            FEE_EVENT_KIND,
+            [
+                ("asset", "nria").index(),
+                ("feeAmount", amount.to_string()).index(),
+                ("actionType", "TransferAction").index(),
+            ],
+        )
+    }
+
+    #[test]
+    fn transfer_is_attributed_to_signer_and_recipient() {
+        let bytes = signed_transaction(vec![Action::Transfer(TransferAction {
+            to: address(2),
+            amount: 100,
+            asset: "nria".parse().unwrap(),
+            fee_asset: "nria".parse().unwrap(),
+        })]);
+        let result = ExecTxResult {
+            events: vec![fee_event(12)],
+            ..ExecTxResult::default()
+        };
+
+        let tx = decode_transaction(0, &bytes, result, PREFIX).unwrap();
+
+        assert_eq!(tx.hash, hex::encode(Sha256::digest(&bytes)));
+        assert_eq!(tx.nonce, Some(7));
+        let signer = tx.signer.clone().unwrap();
+        assert!(signer.starts_with(PREFIX));
+        let [action] = &tx.actions[..] else {
+            panic!("expected exactly one action, got {:?}", tx.actions);
+        };
+        assert_eq!(action.kind, "transfer");
+        assert_eq!(
+            action.accounts,
+            vec![
+                (signer, Role::Signer),
+                (with_prefix(&address(2), PREFIX).unwrap(), Role::Recipient),
+            ]
+        );
+        assert_eq!(
+            tx.fees,
+            vec![FeeRecord {
+                asset: "nria".to_string(),
+                amount: "12".to_string(),
+                action_type: "TransferAction".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn bridge_unlock_is_attributed_to_bridge_account() {
+        let bytes = signed_transaction(vec![Action::BridgeUnlock(BridgeUnlockAction {
+            to: address(2),
+            amount: 100,
+            fee_asset: "nria".parse().unwrap(),
+            bridge_address: address(3),
+            memo: String::new(),
+            rollup_block_number: 1,
+            rollup_withdrawal_event_id: "event-1".to_string(),
+        })]);
+
+        let tx = decode_transaction(0, &bytes, ExecTxResult::default(), PREFIX).unwrap();
+
+        assert!(tx.actions[0]
+            .accounts
+            .contains(&(with_prefix(&address(3), PREFIX).unwrap(), Role::Bridge)));
+    }
+
+    #[test]
+    fn undecodable_transaction_keeps_its_events() {
+        let result = ExecTxResult {
+            events: vec![fee_event(1)],
+            ..ExecTxResult::default()
+        };
+
+        let tx = decode_transaction(3, &[0xff, 0xff], result, PREFIX).unwrap();
+
+        assert_eq!(tx.index, 3);
+        assert!(tx.signer.is_none());
+        assert!(tx.actions.is_empty());
+        assert_eq!(tx.events.len(), 1);
+    }
+}
@@ -0,0 +1,335 @@
+//! Follows the sequencer chain block by block and writes the decoded records to the database.
+
+use std::time::Duration;
+
+use astria_core::{
+    generated::sequencerblock::v1alpha1::{
+        sequencer_service_client::SequencerServiceClient,
+        GetSequencerBlockRequest,
+    },
+    sequencerblock::v1alpha1::SequencerBlock,
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    Report,
+    WrapErr as _,
+};
+use futures::{
+    future::{
+        BoxFuture,
+        Fuse,
+        FusedFuture as _,
+    },
+    FutureExt as _,
+};
+use sequencer_client::{
+    tendermint::block::Height,
+    tendermint_rpc::Client as _,
+    HttpClient as SequencerClient,
+    StreamLatestHeight as _,
+};
+use tokio::{
+    select,
+    sync::watch,
+};
+use tokio_stream::StreamExt as _;
+use tokio_util::sync::CancellationToken;
+use tonic::transport::Channel;
+use tracing::{
+    info,
+    instrument,
+    warn,
+    Instrument as _,
+    Span,
+};
+
+use crate::{
+    decode::{
+        decode_block,
+        IndexedBlock,
+    },
+    metrics::Metrics,
+    store::Store,
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct StateSnapshot {
+    ready: bool,
+    latest_observed_sequencer_height: Option<u64>,
+    last_indexed_sequencer_height: Option<u64>,
+}
+
+impl StateSnapshot {
+    pub(crate) fn is_ready(&self) -> bool {
+        self.ready
+    }
+}
+
+pub(crate) struct Follower {
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) sequencer_chain_id: String,
+    pub(crate) sequencer_cometbft_client: SequencerClient,
+    pub(crate) sequencer_grpc_client: SequencerServiceClient<Channel>,
+    pub(crate) sequencer_address_prefix: String,
+    pub(crate) sequencer_poll_period: Duration,
+    pub(crate) store: Store,
+    pub(crate) state: watch::Sender<StateSnapshot>,
+    pub(crate) metrics: &'static Metrics,
+}
+
+impl Follower {
+    /// Indexes sequencer blocks, starting after the last block in the database, until shut down.
+    ///
+    /// # Errors
+    /// Returns an error if the sequencer chain ID does not match the configured one, if a block
+    /// could not be decoded, or if writing to the database failed.
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        select!(
+            () = self.shutdown_token.cancelled() => return Ok(()),
+            res = confirm_sequencer_chain_id(
+                self.sequencer_chain_id.clone(),
+                self.sequencer_cometbft_client.clone(),
+            ) => res?,
+        );
+
+        let last_indexed_height = self
+            .store
+            .last_indexed_height()
+            .await
+            .wrap_err("failed reading last indexed height from database")?;
+        let mut next_height = last_indexed_height.map_or(1, |height| height.saturating_add(1));
+        let mut latest_height = None;
+        info!(next_height, "starting to index sequencer blocks");
+
+        self.state.send_modify(|state| {
+            state.ready = true;
+            state.last_indexed_sequencer_height = last_indexed_height;
+        });
+
+        let mut latest_heights = self
+            .sequencer_cometbft_client
+            .stream_latest_height(self.sequencer_poll_period);
+
+        let mut next_block: Fuse<BoxFuture<'static, eyre::Result<IndexedBlock>>> =
+            Fuse::terminated();
+
+        let reason = loop {
+            if next_block.is_terminated() && latest_height.is_some_and(|h| next_height <= h) {
+                next_block = fetch_and_decode(
+                    self.sequencer_cometbft_client.clone(),
+                    self.sequencer_grpc_client.clone(),
+                    next_height,
+                    self.sequencer_address_prefix.clone(),
+                    self.sequencer_poll_period,
+                    self.metrics,
+                )
+                .boxed()
+                .fuse();
+            }
+
+            select!(
+                biased;
+
+                () = self.shutdown_token.cancelled() => break Ok("received shutdown signal"),
+
+                res = &mut next_block => {
+                    let block = match res {
+                        Ok(block) => block,
+                        Err(error) => break Err(error),
+                    };
+                    if let Err(error) = self.index_block(block).await {
+                        break Err(error);
+                    }
+                    next_height = next_height.saturating_add(1);
+                }
+
+                Some(res) = latest_heights.next() => match res {
+                    Ok(height) => {
+                        latest_height = Some(height.value());
+                        self.state.send_modify(|state| {
+                            state.latest_observed_sequencer_height = Some(height.value());
+                        });
+                    }
+                    Err(error) => warn!(
+                        %error,
+                        "failed fetching latest height from sequencer; waiting until next tick",
+                    ),
+                },
+            );
+        };
+
+        match &reason {
+            Ok(reason) => info!(reason, "shutting down"),
+            Err(reason) => warn!(%reason, "shutting down"),
+        }
+        reason.map(|_| ())
+    }
+
+    #[instrument(skip_all, fields(height = block.height), err)]
+    async fn index_block(&self, block: IndexedBlock) -> eyre::Result<()> {
+        let height = block.height;
+        let transactions = block.transactions.len();
+        let undecodable = block.undecodable_transactions();
+        self.store
+            .insert_block(block)
+            .await
+            .wrap_err("failed writing block to database")?;
+        self.metrics.absolute_set_indexed_sequencer_height(height);
+        self.metrics
+            .increment_indexed_transaction_count(transactions);
+        if undecodable > 0 {
+            warn!(
+                undecodable,
+                "block contained transactions that could not be decoded; only their events were \
+                 indexed"
+            );
+            self.metrics
+                .increment_undecodable_transaction_count(undecodable);
+        }
+        self.state.send_modify(|state| {
+            state.last_indexed_sequencer_height = Some(height);
+        });
+        info!(transactions, "indexed block");
+        Ok(())
+    }
+}
+
+/// Fetches the block at `height` from all its sources and decodes it.
+///
+/// Fetching is retried with exponential backoff, up to a maximum of `poll_period` between
+/// subsequent attempts.
+#[instrument(skip_all, fields(%height), err)]
+async fn fetch_and_decode(
+    cometbft_client: SequencerClient,
+    grpc_client: SequencerServiceClient<Channel>,
+    height: u64,
+    address_prefix: String,
+    poll_period: Duration,
+    metrics: &'static Metrics,
+) -> eyre::Result<IndexedBlock> {
+    let cometbft_height =
+        Height::try_from(height).wrap_err("sequencer height is not a valid cometbft height")?;
+
+    // Moving the span into `on_retry`, because tryhard spawns these in a tokio
+    // task, losing the span.
+    let span = Span::current();
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .exponential_backoff(Duration::from_millis(100))
+        .max_delay(poll_period)
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &eyre::Report| {
+                metrics.increment_sequencer_block_fetch_failure_count();
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    parent: &span,
+                    attempt,
+                    wait_duration,
+                    %error,
+                    "failed fetching block from sequencer; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let (sequencer_block, block, block_results) = tryhard::retry_fn(move || {
+        let cometbft_client = cometbft_client.clone();
+        let mut grpc_client = grpc_client.clone();
+        async move {
+            let sequencer_block = async {
+                grpc_client
+                    .get_sequencer_block(GetSequencerBlockRequest {
+                        height,
+                    })
+                    .await
+                    .map_err(Report::new)
+            };
+            let block = async {
+                cometbft_client
+                    .block(cometbft_height)
+                    .await
+                    .map_err(Report::new)
+            };
+            let block_results = async {
+                cometbft_client
+                    .block_results(cometbft_height)
+                    .await
+                    .map_err(Report::new)
+            };
+            tokio::try_join!(sequencer_block, block, block_results)
+        }
+    })
+    .with_config(retry_config)
+    .in_current_span()
+    .await
+    .wrap_err("retry attempts exhausted; bailing")?;
+
+    let sequencer_block = SequencerBlock::try_from_raw(sequencer_block.into_inner())
+        .wrap_err("failed to parse raw proto block from grpc response")?;
+    ensure!(
+        sequencer_block.height() == cometbft_height
+            && block.block.header.height == cometbft_height
+            && block_results.height == cometbft_height,
+        "requested sources at height `{height}` but received them at different heights",
+    );
+    decode_block(
+        sequencer_block,
+        &block.block.data,
+        block_results.txs_results.unwrap_or_default(),
+        block_results.finalize_block_events,
+        &address_prefix,
+    )
+}
+
+#[instrument(skip_all, err)]
+async fn confirm_sequencer_chain_id(
+    configured_sequencer_chain_id: String,
+    sequencer_cometbft_client: SequencerClient,
+) -> eyre::Result<()> {
+    let span = Span::current();
+
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .max_delay(Duration::from_secs(30))
+        .exponential_backoff(Duration::from_secs(1))
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &eyre::Report| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    parent: &span,
+                    attempt,
+                    wait_duration,
+                    %error,
+                    "failed to fetch sequencer chain id; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let received_sequencer_chain_id = tryhard::retry_fn(move || {
+        let client = sequencer_cometbft_client.clone();
+        async move {
+            client
+                .status()
+                .await
+                .map(|status| status.node_info.network.to_string())
+                .map_err(Report::new)
+        }
+    })
+    .with_config(retry_config)
+    .in_current_span()
+    .await
+    .wrap_err("retry attempts exhausted; bailing")?;
+
+    ensure!(
+        received_sequencer_chain_id == configured_sequencer_chain_id,
+        "configured sequencer chain ID does not match received; configured: \
+         `{configured_sequencer_chain_id}`, received: `{received_sequencer_chain_id}`"
+    );
+    info!(sequencer_chain_id = %configured_sequencer_chain_id, "confirmed sequencer chain id");
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+
+use astria_core::generated::sequencerblock::v1alpha1::sequencer_service_client::SequencerServiceClient;
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use sequencer_client::HttpClient as SequencerClient;
+use tokio::{
+    select,
+    sync::{
+        oneshot,
+        watch,
+    },
+    task::{
+        JoinError,
+        JoinHandle,
+    },
+    time::timeout,
+};
+use tokio_util::sync::{
+    CancellationToken,
+    WaitForCancellationFuture,
+};
+use tonic::transport::{
+    Endpoint,
+    Uri,
+};
+use tracing::{
+    error,
+    info,
+    instrument,
+};
+
+use crate::{
+    api,
+    config::Config,
+    follower::{
+        Follower,
+        StateSnapshot,
+    },
+    metrics::Metrics,
+    store::Store,
+};
+
+pub struct Indexer {
+    api_server: api::ApiServer,
+    follower: Follower,
+    shutdown_handle: ShutdownHandle,
+}
+
+impl Indexer {
+    /// Instantiates a new `Indexer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequencer endpoints or the API address could not be parsed, or if
+    /// the database could not be opened.
+    pub fn new(cfg: Config, metrics: &'static Metrics) -> eyre::Result<(Self, ShutdownHandle)> {
+        let shutdown_handle = ShutdownHandle::new();
+        let Config {
+            sequencer_chain_id,
+            sequencer_cometbft_endpoint,
+            sequencer_grpc_endpoint,
+            sequencer_address_prefix,
+            block_time_ms,
+            database_path,
+            api_addr,
+            ..
+        } = cfg;
+
+        let sequencer_cometbft_client = SequencerClient::new(&*sequencer_cometbft_endpoint)
+            .wrap_err("failed constructing cometbft http client")?;
+
+        let sequencer_grpc_client = {
+            let uri: Uri = sequencer_grpc_endpoint
+                .parse()
+                .wrap_err("failed parsing provided sequencer grpc endpoint as Uri")?;
+            let endpoint = Endpoint::from(uri);
+            SequencerServiceClient::new(endpoint.connect_lazy())
+        };
+
+        let store = Store::open(&database_path).wrap_err_with(|| {
+            format!("failed opening database at `{}`", database_path.display())
+        })?;
+
+        let (state_tx, state_rx) = watch::channel(StateSnapshot::default());
+
+        let follower = Follower {
+            shutdown_token: shutdown_handle.token.child_token(),
+            sequencer_chain_id,
+            sequencer_cometbft_client,
+            sequencer_grpc_client,
+            sequencer_address_prefix: sequencer_address_prefix.clone(),
+            sequencer_poll_period: Duration::from_millis(block_time_ms),
+            store: store.clone(),
+            state: state_tx,
+            metrics,
+        };
+
+        let api_socket_addr = api_addr.parse::<SocketAddr>().wrap_err_with(|| {
+            format!("failed to parse provided `api_addr` string as socket address: `{api_addr}`",)
+        })?;
+        let api_server = api::start(api_socket_addr, state_rx, store, sequencer_address_prefix);
+        let indexer = Self {
+            api_server,
+            follower,
+            shutdown_handle: shutdown_handle.clone(),
+        };
+        Ok((indexer, shutdown_handle))
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.api_server.local_addr()
+    }
+
+    /// Runs Indexer.
+    pub async fn run(self) {
+        let Self {
+            api_server,
+            follower,
+            shutdown_handle,
+        } = self;
+        // Separate the API shutdown signal from the cancellation token because we want it to live
+        // until the very end.
+        let (api_shutdown_signal, api_shutdown_signal_rx) = oneshot::channel::<()>();
+        let mut api_task = tokio::spawn(async move {
+            api_server
+                .with_graceful_shutdown(async move {
+                    let _ = api_shutdown_signal_rx.await;
+                })
+                .await
+                .wrap_err("api server ended unexpectedly")
+        });
+        info!("spawned API server");
+        let mut follower_task = tokio::spawn(follower.run_until_stopped());
+        info!("spawned follower task");
+
+        let shutdown = select!(
+            o = &mut api_task => {
+                report_exit("api server", o);
+                ShutDown {
+                    api_task: None,
+                    follower_task: Some(follower_task),
+                    api_shutdown_signal,
+                    shutdown_handle,
+                }
+            }
+            o = &mut follower_task => {
+                report_exit("follower", o);
+                ShutDown {
+                    api_task: Some(api_task),
+                    follower_task: None,
+                    api_shutdown_signal,
+                    shutdown_handle,
+                }
+            }
+        );
+        shutdown.run().await;
+    }
+}
+
+/// A handle for instructing the [`Indexer`] to shut down.
+///
+/// It is returned along with its related `Indexer` from [`Indexer::new`]. The `Indexer` will
+/// begin to shut down as soon as [`ShutdownHandle::shutdown`] is called or when the
+/// `ShutdownHandle` is dropped.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+        }
+    }
+
+    /// Returns a `Future` that gets fulfilled when cancellation is requested.
+    ///
+    /// See [`CancellationToken::cancelled`] for further details.
+    pub fn cancelled(&self) -> WaitForCancellationFuture<'_> {
+        self.token.cancelled()
+    }
+
+    /// Consumes `self` and cancels the wrapped cancellation token.
+    ///
+    /// See [`CancellationToken::cancel`] for further details.
+    pub fn shutdown(self) {
+        self.token.cancel();
+    }
+}
+
+impl Drop for ShutdownHandle {
+    fn drop(&mut self) {
+        if !self.token.is_cancelled() {
+            info!("shutdown handle dropped, issuing shutdown to all services");
+        }
+        self.token.cancel();
+    }
+}
+
+fn report_exit(task_name: &str, outcome: Result<eyre::Result<()>, JoinError>) {
+    match outcome {
+        Ok(Ok(())) => info!(task = task_name, "task has exited"),
+        Ok(Err(error)) => {
+            error!(task = task_name, %error, "task returned with error");
+        }
+        Err(e) => {
+            error!(
+                task = task_name,
+                error = &e as &dyn std::error::Error,
+                "task failed to complete"
+            );
+        }
+    }
+}
+
+fn flatten<T>(res: Result<eyre::Result<T>, JoinError>) -> eyre::Result<T> {
+    match res {
+        Ok(Ok(val)) => Ok(val),
+        Ok(Err(err)) => Err(err).wrap_err("task returned with error"),
+        Err(err) => Err(err).wrap_err("task panicked"),
+    }
+}
+
+struct ShutDown {
+    api_task: Option<JoinHandle<eyre::Result<()>>>,
+    follower_task: Option<JoinHandle<eyre::Result<()>>>,
+    api_shutdown_signal: oneshot::Sender<()>,
+    shutdown_handle: ShutdownHandle,
+}
+
+impl ShutDown {
+    #[instrument(skip_all)]
+    async fn run(self) {
+        let Self {
+            api_task,
+            follower_task,
+            api_shutdown_signal,
+            shutdown_handle,
+        } = self;
+        shutdown_handle.shutdown();
+        // Giving the follower 25 seconds to shutdown because Kubernetes issues a SIGKILL after 30.
+        // A block being written to the database is committed in a single transaction, so
+        // aborting the follower never leaves a partially indexed block behind.
+        if let Some(mut follower_task) = follower_task {
+            info!("waiting for follower task to shut down");
+            let limit = Duration::from_secs(25);
+            match timeout(limit, &mut follower_task).await.map(flatten) {
+                Ok(Ok(())) => info!("follower exited gracefully"),
+                Ok(Err(error)) => error!(%error, "follower exited with an error"),
+                Err(_) => {
+                    error!(
+                        timeout_secs = limit.as_secs(),
+                        "follower did not shut down within timeout; killing it"
+                    );
+                    follower_task.abort();
+                }
+            }
+        } else {
+            info!("follower task was already dead");
+        }
+
+        // Giving the API task another 4 seconds. 25 for follower + 4s = 29s (out of 30s for k8s).
+        if let Some(mut api_task) = api_task {
+            info!("sending shutdown signal to API server");
+            let _ = api_shutdown_signal.send(());
+            let limit = Duration::from_secs(4);
+            match timeout(limit, &mut api_task).await.map(flatten) {
+                Ok(Ok(())) => info!("API server exited gracefully"),
+                Ok(Err(error)) => error!(%error, "API server exited with an error"),
+                Err(_) => {
+                    error!(
+                        timeout_secs = limit.as_secs(),
+                        "API server did not shut down within timeout; killing it"
+                    );
+                    api_task.abort();
+                }
+            }
+        } else {
+            info!("API server was already dead");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub(crate) mod api;
+mod build_info;
+pub mod config;
+pub(crate) mod decode;
+pub(crate) mod follower;
+pub mod indexer;
+pub(crate) mod metrics;
+pub(crate) mod store;
+
+pub use build_info::BUILD_INFO;
+pub use config::Config;
+pub use indexer::{
+    Indexer,
+    ShutdownHandle,
+};
+pub use metrics::Metrics;
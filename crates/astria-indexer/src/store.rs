@@ -0,0 +1,733 @@
+//! The sqlite database holding the indexed records.
+//!
+//! All heights are stored as sqlite integers (`i64`). Amounts are stored as decimal strings
+//! because they do not fit into an `i64`.
+
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use rusqlite::{
+    params,
+    Connection,
+    OptionalExtension as _,
+};
+use serde::Serialize;
+
+use crate::decode::IndexedBlock;
+
+const SCHEMA: &str = r"
+CREATE TABLE IF NOT EXISTS blocks (
+    height INTEGER PRIMARY KEY,
+    block_hash TEXT NOT NULL,
+    time TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS transactions (
+    height INTEGER NOT NULL,
+    tx_index INTEGER NOT NULL,
+    hash TEXT NOT NULL,
+    signer TEXT,
+    nonce INTEGER,
+    code INTEGER NOT NULL,
+    PRIMARY KEY (height, tx_index)
+);
+CREATE INDEX IF NOT EXISTS transactions_by_hash ON transactions (hash);
+
+CREATE TABLE IF NOT EXISTS account_actions (
+    address TEXT NOT NULL,
+    role TEXT NOT NULL,
+    height INTEGER NOT NULL,
+    tx_index INTEGER NOT NULL,
+    action_index INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    action TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS account_actions_by_address ON account_actions (address, height);
+
+CREATE TABLE IF NOT EXISTS fee_payments (
+    height INTEGER NOT NULL,
+    tx_index INTEGER NOT NULL,
+    fee_index INTEGER NOT NULL,
+    asset TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    action_type TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS fee_payments_by_transaction ON fee_payments (height, tx_index);
+
+CREATE TABLE IF NOT EXISTS deposits (
+    height INTEGER NOT NULL,
+    rollup_id TEXT NOT NULL,
+    bridge_address TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    asset TEXT NOT NULL,
+    destination_chain_address TEXT NOT NULL,
+    source_transaction_id TEXT NOT NULL,
+    source_transaction_index INTEGER NOT NULL,
+    source_action_index INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS deposits_by_rollup ON deposits (rollup_id, height);
+CREATE INDEX IF NOT EXISTS deposits_by_bridge_address ON deposits (bridge_address, height);
+
+CREATE TABLE IF NOT EXISTS events (
+    height INTEGER NOT NULL,
+    tx_index INTEGER,
+    event_index INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    attributes TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS events_by_kind ON events (kind, height);
+";
+
+/// The maximum number of entries returned by a single query.
+pub(crate) const MAX_PAGE_LIMIT: u32 = 1000;
+
+/// The position of an entry in execution order.
+///
+/// `action_index` is the index of the action within its transaction. For fees it is the index of
+/// the fee among the fees paid by its transaction, and for deposits the index of their source
+/// action.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Cursor {
+    pub(crate) height: u64,
+    pub(crate) tx_index: u32,
+    pub(crate) action_index: u32,
+}
+
+/// Selects the newest entries strictly before `before`, or the newest entries overall.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Page {
+    pub(crate) limit: u32,
+    pub(crate) before: Option<Cursor>,
+}
+
+impl Page {
+    /// Returns the cursor as the `(height, tx_index, action_index)` row value to compare against.
+    fn before(self) -> (i64, u32, u32) {
+        self.before.map_or((i64::MAX, 0, 0), |cursor| {
+            (
+                cursor.height.try_into().unwrap_or(i64::MAX),
+                cursor.tx_index,
+                cursor.action_index,
+            )
+        })
+    }
+
+    fn limit(self) -> u32 {
+        self.limit.min(MAX_PAGE_LIMIT)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ActionEntry {
+    pub(crate) height: u64,
+    pub(crate) tx_hash: String,
+    pub(crate) tx_index: u32,
+    pub(crate) action_index: u32,
+    /// The ABCI result code of the transaction; 0 means success.
+    pub(crate) code: u32,
+    pub(crate) signer: String,
+    pub(crate) role: String,
+    pub(crate) kind: String,
+    pub(crate) action: serde_json::Value,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FeeEntry {
+    pub(crate) height: u64,
+    pub(crate) tx_hash: String,
+    pub(crate) tx_index: u32,
+    pub(crate) fee_index: u32,
+    pub(crate) asset: String,
+    pub(crate) amount: String,
+    pub(crate) action_type: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DepositEntry {
+    pub(crate) height: u64,
+    pub(crate) rollup_id: String,
+    pub(crate) bridge_address: String,
+    pub(crate) amount: String,
+    pub(crate) asset: String,
+    pub(crate) destination_chain_address: String,
+    pub(crate) source_transaction_id: String,
+    pub(crate) source_transaction_index: u32,
+    pub(crate) source_action_index: u64,
+}
+
+/// A handle to the indexer database, shared between the indexing task and the API server.
+#[derive(Clone)]
+pub(crate) struct Store {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl Store {
+    /// Opens the database at `path`, creating it and its tables if they do not exist.
+    pub(crate) fn open(path: &Path) -> eyre::Result<Self> {
+        let connection = Connection::open(path)
+            .wrap_err_with(|| format!("failed opening database at `{}`", path.display()))?;
+        Self::with_connection(connection)
+    }
+
+    #[cfg(test)]
+    fn open_in_memory() -> eyre::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> eyre::Result<Self> {
+        connection
+            .execute_batch(SCHEMA)
+            .wrap_err("failed creating database schema")?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Runs `f` against the database on the blocking thread pool.
+    async fn run<T, F>(&self, f: F) -> eyre::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let connection = self.connection.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection
+                .lock()
+                .expect("the database connection is never used in a panicking task");
+            f(&mut connection)
+        })
+        .await
+        .wrap_err("database task panicked")?
+        .wrap_err("database query failed")
+    }
+
+    /// Returns the height of the last block written to the database.
+    pub(crate) async fn last_indexed_height(&self) -> eyre::Result<Option<u64>> {
+        self.run(|connection| {
+            connection.query_row("SELECT MAX(height) FROM blocks", [], |row| {
+                row.get::<_, Option<u64>>(0)
+            })
+        })
+        .await
+    }
+
+    /// Writes all records of `block` in a single database transaction.
+    pub(crate) async fn insert_block(&self, block: IndexedBlock) -> eyre::Result<()> {
+        self.run(move |connection| {
+            let db_tx = connection.transaction()?;
+            let height = block.height;
+            db_tx.execute(
+                "INSERT INTO blocks (height, block_hash, time) VALUES (?1, ?2, ?3)",
+                params![height, block.block_hash, block.time],
+            )?;
+            for tx in &block.transactions {
+                db_tx.execute(
+                    "INSERT INTO transactions (height, tx_index, hash, signer, nonce, code) \
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![height, tx.index, tx.hash, tx.signer, tx.nonce, tx.code],
+                )?;
+                for action in &tx.actions {
+                    for (address, role) in &action.accounts {
+                        db_tx.execute(
+                            "INSERT INTO account_actions (address, role, height, tx_index, \
+                             action_index, kind, action) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                            params![
+                                address,
+                                role.as_str(),
+                                height,
+                                tx.index,
+                                action.index,
+                                action.kind,
+                                action.action,
+                            ],
+                        )?;
+                    }
+                }
+                for (fee_index, fee) in tx.fees.iter().enumerate() {
+                    db_tx.execute(
+                        "INSERT INTO fee_payments (height, tx_index, fee_index, asset, amount, \
+                         action_type) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                        params![
+                            height,
+                            tx.index,
+                            fee_index,
+                            fee.asset,
+                            fee.amount,
+                            fee.action_type
+                        ],
+                    )?;
+                }
+                for (event_index, event) in tx.events.iter().enumerate() {
+                    insert_event(&db_tx, height, Some(tx.index), event_index, event)?;
+                }
+            }
+            for (event_index, event) in block.block_events.iter().enumerate() {
+                insert_event(&db_tx, height, None, event_index, event)?;
+            }
+            for deposit in &block.deposits {
+                db_tx.execute(
+                    "INSERT INTO deposits (height, rollup_id, bridge_address, amount, asset, \
+                     destination_chain_address, source_transaction_id, source_transaction_index, \
+                     source_action_index) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    params![
+                        height,
+                        deposit.rollup_id,
+                        deposit.bridge_address,
+                        deposit.amount,
+                        deposit.asset,
+                        deposit.destination_chain_address,
+                        deposit.source_transaction_id,
+                        deposit.source_transaction_index,
+                        deposit.source_action_index,
+                    ],
+                )?;
+            }
+            db_tx.commit()
+        })
+        .await
+    }
+
+    /// Returns the actions `address` was involved in, newest first.
+    pub(crate) async fn account_history(
+        &self,
+        address: String,
+        page: Page,
+    ) -> eyre::Result<Vec<ActionEntry>> {
+        self.actions(address, None, page).await
+    }
+
+    /// Returns the actions acting upon the bridge account at `address`, newest first.
+    pub(crate) async fn bridge_account_history(
+        &self,
+        address: String,
+        page: Page,
+    ) -> eyre::Result<Vec<ActionEntry>> {
+        self.actions(address, Some("bridge"), page).await
+    }
+
+    async fn actions(
+        &self,
+        address: String,
+        role: Option<&'static str>,
+        page: Page,
+    ) -> eyre::Result<Vec<ActionEntry>> {
+        let (before_height, before_tx_index, before_action_index) = page.before();
+        self.run(move |connection| {
+            let mut statement = connection.prepare_cached(
+                "SELECT a.height, t.hash, a.tx_index, a.action_index, t.code, t.signer, a.role, \
+                 a.kind, a.action FROM account_actions a JOIN transactions t ON a.height = \
+                 t.height AND a.tx_index = t.tx_index WHERE a.address = ?1 AND (?2 IS NULL OR \
+                 a.role = ?2) AND (a.height, a.tx_index, a.action_index) < (?3, ?4, ?5) ORDER BY \
+                 a.height DESC, a.tx_index DESC, a.action_index DESC LIMIT ?6",
+            )?;
+            let rows = statement.query_map(
+                params![
+                    address,
+                    role,
+                    before_height,
+                    before_tx_index,
+                    before_action_index,
+                    page.limit()
+                ],
+                |row| {
+                    let action: String = row.get(8)?;
+                    Ok(ActionEntry {
+                        height: row.get(0)?,
+                        tx_hash: row.get(1)?,
+                        tx_index: row.get(2)?,
+                        action_index: row.get(3)?,
+                        code: row.get(4)?,
+                        signer: row.get(5)?,
+                        role: row.get(6)?,
+                        kind: row.get(7)?,
+                        action: serde_json::from_str(&action).map_err(|e| {
+                            rusqlite::Error::FromSqlConversionFailure(
+                                8,
+                                rusqlite::types::Type::Text,
+                                Box::new(e),
+                            )
+                        })?,
+                    })
+                },
+            )?;
+            rows.collect()
+        })
+        .await
+    }
+
+    /// Returns the fees paid by transactions signed by `address`, newest first.
+    pub(crate) async fn account_fees(
+        &self,
+        address: String,
+        page: Page,
+    ) -> eyre::Result<Vec<FeeEntry>> {
+        let (before_height, before_tx_index, before_fee_index) = page.before();
+        self.run(move |connection| {
+            let mut statement = connection.prepare_cached(
+                "SELECT f.height, t.hash, f.tx_index, f.fee_index, f.asset, f.amount, \
+                 f.action_type FROM fee_payments f JOIN transactions t ON f.height = t.height AND \
+                 f.tx_index = t.tx_index WHERE t.signer = ?1 AND (f.height, f.tx_index, \
+                 f.fee_index) < (?2, ?3, ?4) ORDER BY f.height DESC, f.tx_index DESC, f.fee_index \
+                 DESC LIMIT ?5",
+            )?;
+            let rows = statement.query_map(
+                params![
+                    address,
+                    before_height,
+                    before_tx_index,
+                    before_fee_index,
+                    page.limit()
+                ],
+                |row| {
+                    Ok(FeeEntry {
+                        height: row.get(0)?,
+                        tx_hash: row.get(1)?,
+                        tx_index: row.get(2)?,
+                        fee_index: row.get(3)?,
+                        asset: row.get(4)?,
+                        amount: row.get(5)?,
+                        action_type: row.get(6)?,
+                    })
+                },
+            )?;
+            rows.collect()
+        })
+        .await
+    }
+
+    /// Returns the deposits to the rollup with the base64 encoded `rollup_id`, newest first.
+    pub(crate) async fn deposits_by_rollup(
+        &self,
+        rollup_id: String,
+        page: Page,
+    ) -> eyre::Result<Vec<DepositEntry>> {
+        self.deposits("rollup_id", rollup_id, page).await
+    }
+
+    /// Returns the deposits made through the bridge account at `address`, newest first.
+    pub(crate) async fn deposits_by_bridge_account(
+        &self,
+        address: String,
+        page: Page,
+    ) -> eyre::Result<Vec<DepositEntry>> {
+        self.deposits("bridge_address", address, page).await
+    }
+
+    async fn deposits(
+        &self,
+        column: &'static str,
+        value: String,
+        page: Page,
+    ) -> eyre::Result<Vec<DepositEntry>> {
+        let (before_height, before_tx_index, before_action_index) = page.before();
+        self.run(move |connection| {
+            let mut statement = connection.prepare_cached(&format!(
+                "SELECT height, rollup_id, bridge_address, amount, asset, \
+                 destination_chain_address, source_transaction_id, source_transaction_index, \
+                 source_action_index FROM deposits WHERE {column} = ?1 AND (height, \
+                 source_transaction_index, source_action_index) < (?2, ?3, ?4) ORDER BY height \
+                 DESC, source_transaction_index DESC, source_action_index DESC LIMIT ?5"
+            ))?;
+            let rows = statement.query_map(
+                params![
+                    value,
+                    before_height,
+                    before_tx_index,
+                    before_action_index,
+                    page.limit()
+                ],
+                |row| {
+                    Ok(DepositEntry {
+                        height: row.get(0)?,
+                        rollup_id: row.get(1)?,
+                        bridge_address: row.get(2)?,
+                        amount: row.get(3)?,
+                        asset: row.get(4)?,
+                        destination_chain_address: row.get(5)?,
+                        source_transaction_id: row.get(6)?,
+                        source_transaction_index: row.get(7)?,
+                        source_action_index: row.get(8)?,
+                    })
+                },
+            )?;
+            rows.collect()
+        })
+        .await
+    }
+}
+
+fn insert_event(
+    db_tx: &rusqlite::Transaction<'_>,
+    height: u64,
+    tx_index: Option<u32>,
+    event_index: usize,
+    event: &crate::decode::EventRecord,
+) -> rusqlite::Result<()> {
+    let attributes = serde_json::to_string(&event.attributes)
+        .expect("event attributes are serializable because they only contain strings");
+    db_tx.execute(
+        "INSERT INTO events (height, tx_index, event_index, kind, attributes) VALUES (?1, ?2, ?3, \
+         ?4, ?5)",
+        params![height, tx_index, event_index, event.kind, attributes],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::{
+        ActionRecord,
+        DepositRecord,
+        FeeRecord,
+        IndexedTransaction,
+        Role,
+    };
+
+    const ALICE: &str = "alice";
+    const BRIDGE: &str = "bridge";
+
+    fn block(height: u64) -> IndexedBlock {
+        IndexedBlock {
+            height,
+            block_hash: format!("hash-{height}"),
+            time: "2024-01-01T00:00:00Z".to_string(),
+            transactions: vec![IndexedTransaction {
+                hash: format!("tx-{height}"),
+                index: 0,
+                signer: Some(ALICE.to_string()),
+                nonce: Some(1),
+                code: 0,
+                actions: vec![ActionRecord {
+                    index: 0,
+                    kind: "bridge_lock",
+                    action: r#"{"amount":"10"}"#.to_string(),
+                    accounts: vec![
+                        (ALICE.to_string(), Role::Signer),
+                        (BRIDGE.to_string(), Role::Bridge),
+                    ],
+                }],
+                events: vec![],
+                fees: vec![FeeRecord {
+                    asset: "nria".to_string(),
+                    amount: "12".to_string(),
+                    action_type: "BridgeLockAction".to_string(),
+                }],
+            }],
+            deposits: vec![DepositRecord {
+                rollup_id: "rollup".to_string(),
+                bridge_address: BRIDGE.to_string(),
+                amount: "10".to_string(),
+                asset: "nria".to_string(),
+                destination_chain_address: "0xabc".to_string(),
+                source_transaction_id: format!("tx-{height}"),
+                source_transaction_index: 0,
+                source_action_index: 0,
+            }],
+            block_events: vec![],
+        }
+    }
+
+    fn page(limit: u32, before_height: Option<u64>) -> Page {
+        Page {
+            limit,
+            before: before_height.map(|height| Cursor {
+                height,
+                ..Cursor::default()
+            }),
+        }
+    }
+
+    /// A block at `height` holding two transactions by alice with three actions and fees each.
+    fn busy_block(height: u64) -> IndexedBlock {
+        let transactions = (0..2)
+            .map(|index| IndexedTransaction {
+                hash: format!("tx-{height}-{index}"),
+                index,
+                signer: Some(ALICE.to_string()),
+                nonce: Some(index),
+                code: 0,
+                actions: (0..3)
+                    .map(|action_index| ActionRecord {
+                        index: action_index,
+                        kind: "transfer",
+                        action: r#"{"amount":"1"}"#.to_string(),
+                        accounts: vec![(ALICE.to_string(), Role::Signer)],
+                    })
+                    .collect(),
+                events: vec![],
+                fees: (0..3)
+                    .map(|_| FeeRecord {
+                        asset: "nria".to_string(),
+                        amount: "1".to_string(),
+                        action_type: "TransferAction".to_string(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        IndexedBlock {
+            transactions,
+            deposits: vec![],
+            ..block(height)
+        }
+    }
+
+    #[tokio::test]
+    async fn inserted_blocks_are_queryable() {
+        let store = Store::open_in_memory().unwrap();
+        assert_eq!(store.last_indexed_height().await.unwrap(), None);
+        for height in 1..=3 {
+            store.insert_block(block(height)).await.unwrap();
+        }
+        assert_eq!(store.last_indexed_height().await.unwrap(), Some(3));
+
+        let history = store
+            .account_history(ALICE.to_string(), page(10, None))
+            .await
+            .unwrap();
+        assert_eq!(
+            history.iter().map(|entry| entry.height).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(history[0].action, serde_json::json!({"amount": "10"}));
+
+        let bridge_history = store
+            .bridge_account_history(BRIDGE.to_string(), page(10, None))
+            .await
+            .unwrap();
+        assert_eq!(bridge_history.len(), 3);
+        assert!(store
+            .bridge_account_history(ALICE.to_string(), page(10, None))
+            .await
+            .unwrap()
+            .is_empty());
+
+        let fees = store
+            .account_fees(ALICE.to_string(), page(10, None))
+            .await
+            .unwrap();
+        assert_eq!(fees.len(), 3);
+        assert_eq!(fees[0].tx_hash, "tx-3");
+
+        let deposits = store
+            .deposits_by_rollup("rollup".to_string(), page(10, None))
+            .await
+            .unwrap();
+        assert_eq!(deposits.len(), 3);
+        assert_eq!(
+            store
+                .deposits_by_bridge_account(BRIDGE.to_string(), page(10, None))
+                .await
+                .unwrap(),
+            deposits
+        );
+    }
+
+    #[tokio::test]
+    async fn pages_are_limited_and_start_below_height() {
+        let store = Store::open_in_memory().unwrap();
+        for height in 1..=5 {
+            store.insert_block(block(height)).await.unwrap();
+        }
+        let deposits = store
+            .deposits_by_rollup("rollup".to_string(), page(2, Some(4)))
+            .await
+            .unwrap();
+        assert_eq!(
+            deposits
+                .iter()
+                .map(|entry| entry.height)
+                .collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn pages_continue_within_a_height() {
+        let store = Store::open_in_memory().unwrap();
+        store.insert_block(block(1)).await.unwrap();
+        store.insert_block(busy_block(2)).await.unwrap();
+
+        let mut actions = vec![];
+        let mut page = page(4, None);
+        loop {
+            let entries = store
+                .account_history(ALICE.to_string(), page)
+                .await
+                .unwrap();
+            let Some(last) = entries.last() else {
+                break;
+            };
+            page.before = Some(Cursor {
+                height: last.height,
+                tx_index: last.tx_index,
+                action_index: last.action_index,
+            });
+            actions.extend(
+                entries
+                    .iter()
+                    .map(|entry| (entry.height, entry.tx_index, entry.action_index)),
+            );
+        }
+        assert_eq!(
+            actions,
+            vec![
+                (2, 1, 2),
+                (2, 1, 1),
+                (2, 1, 0),
+                (2, 0, 2),
+                (2, 0, 1),
+                (2, 0, 0),
+                (1, 0, 0)
+            ]
+        );
+
+        let fees = store
+            .account_fees(
+                ALICE.to_string(),
+                Page {
+                    limit: 2,
+                    before: Some(Cursor {
+                        height: 2,
+                        tx_index: 1,
+                        action_index: 1,
+                    }),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            fees.iter()
+                .map(|entry| (entry.height, entry.tx_index, entry.fee_index))
+                .collect::<Vec<_>>(),
+            vec![(2, 1, 0), (2, 0, 2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn block_cannot_be_indexed_twice() {
+        let store = Store::open_in_memory().unwrap();
+        store.insert_block(block(1)).await.unwrap();
+        store.insert_block(block(1)).await.unwrap_err();
+        assert_eq!(
+            store
+                .account_history(ALICE.to_string(), page(10, None))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
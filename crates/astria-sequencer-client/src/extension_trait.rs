@@ -38,6 +38,7 @@ use astria_core::protocol::{
     bridge::v1alpha1::{
         BridgeAccountInfoResponse,
         BridgeAccountLastTxHashResponse,
+        BridgeAccountWithdrawalEventResponse,
     },
     transaction::v1alpha1::{
         TransactionFeeResponse,
@@ -615,6 +616,43 @@ pub trait SequencerClientExt: Client {
         Ok(native)
     }
 
+    /// Returns the rollup block number at which the withdrawal event with ID `event_id` was
+    /// processed for the bridge account at `address`, if it was.
+    ///
+    /// The event ID is hex encoded in the query path so that it may contain `/`.
+    ///
+    /// # Errors
+    ///
+    /// - If calling the tendermint RPC endpoint fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   [`astria_core::generated::protocol::bridge::v1alpha1::BridgeAccountWithdrawalEventResponse`].
+    async fn get_bridge_account_withdrawal_event(
+        &self,
+        address: Address,
+        event_id: &str,
+    ) -> Result<BridgeAccountWithdrawalEventResponse, Error> {
+        const PREFIX: &str = "bridge/withdrawal_event";
+        let path = format!("{PREFIX}/{address}/{}", hex::encode(event_id));
+
+        let response = self
+            .abci_query(Some(path), vec![], None, false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::protocol::bridge::v1alpha1::BridgeAccountWithdrawalEventResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.protocol.bridge.v1alpha1.BridgeAccountWithdrawalEventResponse",
+                    response,
+                    e,
+                )
+            })?;
+        Ok(proto_response.into_native())
+    }
+
     async fn get_transaction_fee(
         &self,
         tx: UnsignedTransaction,
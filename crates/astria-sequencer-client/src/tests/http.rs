@@ -321,6 +321,36 @@ async fn get_bridge_account_last_transaction_hash() {
     assert_eq!(expected_response, actual_response);
 }
 
+#[tokio::test]
+async fn get_bridge_account_withdrawal_event() {
+    use astria_core::generated::protocol::bridge::v1alpha1::BridgeAccountWithdrawalEventResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = BridgeAccountWithdrawalEventResponse {
+        height: 10,
+        rollup_block_number: Some(42),
+    };
+
+    let _guard = register_abci_query_response(
+        &server,
+        "bridge/withdrawal_event",
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_bridge_account_withdrawal_event(alice_address(), "event-0")
+        .await
+        .unwrap()
+        .into_raw();
+
+    assert_eq!(expected_response, actual_response);
+}
+
 #[tokio::test]
 async fn get_transaction_fee() {
     use astria_core::generated::protocol::transactions::v1alpha1::{
@@ -11,6 +11,7 @@ use axum::{
     },
     routing::{
         get,
+        post,
         IntoMakeService,
     },
     Json,
@@ -22,7 +23,13 @@ use serde::Serialize;
 use tokio::sync::watch;
 use tracing::instrument;
 
-use crate::relayer;
+use crate::{
+    relayer,
+    reload::{
+        ReloadHandle,
+        ReloadableConfig,
+    },
+};
 
 pub(crate) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
 
@@ -32,6 +39,7 @@ type RelayerState = watch::Receiver<relayer::StateSnapshot>;
 /// `AppState` is used for as an axum extractor in its method handlers.
 struct AppState {
     relayer_state: RelayerState,
+    reload_handle: ReloadHandle,
 }
 
 impl FromRef<AppState> for RelayerState {
@@ -40,13 +48,26 @@ impl FromRef<AppState> for RelayerState {
     }
 }
 
-pub(crate) fn start(socket_addr: SocketAddr, relayer_state: RelayerState) -> ApiServer {
+impl FromRef<AppState> for ReloadHandle {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.reload_handle.clone()
+    }
+}
+
+pub(crate) fn start(
+    socket_addr: SocketAddr,
+    relayer_state: RelayerState,
+    reload_handle: ReloadHandle,
+) -> ApiServer {
     let app = Router::new()
         .route("/healthz", get(get_healthz))
         .route("/readyz", get(get_readyz))
         .route("/status", get(get_status))
+        .route("/config", get(get_config))
+        .route("/reload", post(post_reload))
         .with_state(AppState {
             relayer_state,
+            reload_handle,
         });
     axum::Server::bind(&socket_addr).serve(app.into_make_service())
 }
@@ -84,6 +105,21 @@ async fn get_status(State(relayer_state): State<RelayerState>) -> Json<relayer::
     Json(*relayer_state.borrow())
 }
 
+/// Returns the active config of the fields that can be changed while relayer is running.
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn get_config(State(reload_handle): State<ReloadHandle>) -> Json<ReloadableConfig> {
+    Json(reload_handle.active_config())
+}
+
+/// Triggers relayer to re-read its config, same as sending it `SIGHUP`.
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+#[instrument(skip_all)]
+async fn post_reload(State(reload_handle): State<ReloadHandle>) -> StatusCode {
+    reload_handle.trigger().trigger();
+    StatusCode::ACCEPTED
+}
+
 enum Healthz {
     Ok,
     Degraded,
@@ -3,6 +3,7 @@ mod build_info;
 pub mod config;
 pub(crate) mod metrics;
 pub(crate) mod relayer;
+mod reload;
 pub mod sequencer_relayer;
 pub(crate) mod utils;
 
@@ -12,6 +13,7 @@ pub use config::{
     IncludeRollup,
 };
 pub use metrics::Metrics;
+pub use reload::ReloadHandle;
 pub use sequencer_relayer::{
     SequencerRelayer,
     ShutdownHandle,
@@ -6,6 +6,7 @@ use astria_sequencer_relayer::{
     SequencerRelayer,
     BUILD_INFO,
 };
+use config::reload::Reloader;
 use tokio::signal::unix::{
     signal,
     SignalKind,
@@ -22,7 +23,9 @@ async fn main() -> ExitCode {
 
     eprintln!("{}", telemetry::display::json(&BUILD_INFO),);
 
-    let cfg: Config = config::get().expect("failed to read configuration");
+    let config_file = config::config_file_from_args().expect("failed to read configuration");
+    let cfg: Config =
+        config::get_with_file(config_file.as_deref()).expect("failed to read configuration");
     eprintln!("{}", telemetry::display::json(&cfg),);
 
     let mut telemetry_conf = telemetry::configure()
@@ -36,7 +39,7 @@ async fn main() -> ExitCode {
             telemetry_conf.set_metrics(&cfg.metrics_http_listener_addr, env!("CARGO_PKG_NAME"));
     }
 
-    let (metrics, _telemetry_guard) = match telemetry_conf
+    let (metrics, telemetry_guard) = match telemetry_conf
         .try_init(&())
         .wrap_err("failed to setup telemetry")
     {
@@ -56,6 +59,26 @@ async fn main() -> ExitCode {
         .expect("setting a SIGTERM listener should always work on Unix");
     let (sequencer_relayer, shutdown_handle) =
         SequencerRelayer::new(cfg, metrics).expect("could not initialize sequencer relayer");
+
+    let reload_handle = sequencer_relayer.reload_handle();
+    let mut reloader = Reloader::<Config>::new(config_file, reload_handle.trigger())
+        .expect("setting a SIGHUP listener should always work on Unix");
+    let filter_handle = telemetry_guard.filter_handle();
+    tokio::spawn(async move {
+        loop {
+            match reloader.reload().await {
+                Ok(cfg) => match reload_handle.apply(&cfg, Some(&filter_handle)) {
+                    Ok(true) => info!("applied reloaded config"),
+                    Ok(false) => info!("reloaded config did not change log or rollup filter"),
+                    Err(error) => {
+                        warn!(%error, "reloaded config is invalid; keeping active config");
+                    }
+                },
+                Err(error) => warn!(?error, "failed reloading config; keeping active config"),
+            }
+        }
+    });
+
     let sequencer_relayer_handle = tokio::spawn(sequencer_relayer.run());
 
     tokio::select!(
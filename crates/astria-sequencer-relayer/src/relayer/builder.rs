@@ -10,6 +10,7 @@ use astria_eyre::eyre::{
     WrapErr as _,
 };
 use sequencer_client::HttpClient as SequencerClient;
+use tokio::sync::watch;
 use tonic::transport::{
     Endpoint,
     Uri,
@@ -34,7 +35,7 @@ pub(crate) struct Builder {
     pub(crate) cometbft_endpoint: String,
     pub(crate) sequencer_poll_period: Duration,
     pub(crate) sequencer_grpc_endpoint: String,
-    pub(crate) rollup_filter: IncludeRollup,
+    pub(crate) rollup_filter: watch::Receiver<IncludeRollup>,
     pub(crate) submission_state_path: PathBuf,
     pub(crate) metrics: &'static Metrics,
 }
@@ -108,7 +108,7 @@ pub(crate) struct Relayer {
     celestia_client_builder: CelestiaClientBuilder,
 
     /// The rollups whose data should be included in submissions.
-    rollup_filter: IncludeRollup,
+    rollup_filter: watch::Receiver<IncludeRollup>,
 
     /// A watch channel to track the state of the relayer. Used by the API service.
     state: Arc<State>,
@@ -369,7 +369,7 @@ async fn fetch_sequencer_chain_id(
 
 fn spawn_submitter(
     client_builder: CelestiaClientBuilder,
-    rollup_filter: IncludeRollup,
+    rollup_filter: watch::Receiver<IncludeRollup>,
     state: Arc<State>,
     submission_state_at_startup: SubmissionStateAtStartup,
     submitter_shutdown_token: CancellationToken,
@@ -26,6 +26,7 @@ use futures::Future;
 use pin_project_lite::pin_project;
 use sequencer_client::SequencerBlock;
 use tendermint::block::Height as SequencerHeight;
+use tokio::sync::watch;
 use tracing::{
     error,
     trace,
@@ -303,7 +304,8 @@ impl Input {
 }
 
 pub(super) struct NextSubmission {
-    rollup_filter: IncludeRollup,
+    /// The rollups whose data should be included, which can change while the relayer runs.
+    rollup_filter: watch::Receiver<IncludeRollup>,
     input: Input,
     payload: Payload,
     metrics: &'static Metrics,
@@ -327,7 +329,10 @@ pub(super) enum TryAddError {
 }
 
 impl NextSubmission {
-    pub(super) fn new(rollup_filter: IncludeRollup, metrics: &'static Metrics) -> Self {
+    pub(super) fn new(
+        rollup_filter: watch::Receiver<IncludeRollup>,
+        metrics: &'static Metrics,
+    ) -> Self {
         Self {
             rollup_filter,
             input: Input::new(),
@@ -344,7 +349,8 @@ impl NextSubmission {
     /// size it is returned as an error.
     pub(super) fn try_add(&mut self, block: SequencerBlock) -> Result<(), TryAddError> {
         let mut input_candidate = self.input.clone();
-        input_candidate.extend_from_sequencer_block(block.clone(), &self.rollup_filter);
+        let rollup_filter = self.rollup_filter.borrow().clone();
+        input_candidate.extend_from_sequencer_block(block.clone(), &rollup_filter);
 
         let payload_creation_start = std::time::Instant::now();
         let payload_candidate = input_candidate.clone().try_into_payload()?;
@@ -541,7 +547,8 @@ mod tests {
 
     #[tokio::test]
     async fn add_sequencer_block_to_empty_next_submission() {
-        let mut next_submission = NextSubmission::new(include_all_rollups(), metrics());
+        let mut next_submission =
+            NextSubmission::new(watch::channel(include_all_rollups()).1, metrics());
         next_submission.try_add(block(1)).unwrap();
         let submission = next_submission.take().await.unwrap();
         assert_eq!(1, submission.num_blocks());
@@ -550,7 +557,8 @@ mod tests {
 
     #[test]
     fn adding_three_sequencer_blocks_with_same_ids_doesnt_change_number_of_blobs() {
-        let mut next_submission = NextSubmission::new(include_all_rollups(), metrics());
+        let mut next_submission =
+            NextSubmission::new(watch::channel(include_all_rollups()).1, metrics());
         next_submission.try_add(block(1)).unwrap();
         next_submission.try_add(block(2)).unwrap();
         next_submission.try_add(block(3)).unwrap();
@@ -564,7 +572,8 @@ mod tests {
         // this test makes use of the fact that random data is essentially incompressible so
         // that size(uncompressed_payload) ~= size(compressed_payload).
         let mut rng = ChaChaRng::seed_from_u64(0);
-        let mut next_submission = NextSubmission::new(include_all_rollups(), metrics());
+        let mut next_submission =
+            NextSubmission::new(watch::channel(include_all_rollups()).1, metrics());
         // adding 9 blocks with 100KB random data each, which gives a (compressed) payload slightly
         // above 900KB.
         let num_bytes = 100_000usize;
@@ -586,7 +595,8 @@ mod tests {
         // this test makes use of the fact that random data is essentially incompressible so
         // that size(uncompressed_payload) ~= size(compressed_payload).
         let mut rng = ChaChaRng::seed_from_u64(0);
-        let mut next_submission = NextSubmission::new(include_all_rollups(), metrics());
+        let mut next_submission =
+            NextSubmission::new(watch::channel(include_all_rollups()).1, metrics());
 
         // using the upper limit defined in the constant and add 1KB of extra bytes to ensure
         // the block is too large
@@ -138,7 +138,7 @@ pub(super) struct BlobSubmitter {
 impl BlobSubmitter {
     pub(super) fn new(
         client_builder: CelestiaClientBuilder,
-        rollup_filter: IncludeRollup,
+        rollup_filter: watch::Receiver<IncludeRollup>,
         state: Arc<super::State>,
         submission_state_at_startup: SubmissionStateAtStartup,
         submitter_shutdown_token: CancellationToken,
@@ -0,0 +1,159 @@
+//! Changing the log level and the rollup filter of a running relayer without a restart.
+
+use std::sync::Arc;
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use config::reload::ReloadTrigger;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::{
+    config::Config,
+    IncludeRollup,
+};
+
+/// The subset of the config that can be changed while relayer is running.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct ReloadableConfig {
+    pub(crate) log: String,
+    pub(crate) only_include_rollups: String,
+}
+
+impl ReloadableConfig {
+    fn from_config(cfg: &Config) -> Self {
+        Self {
+            log: cfg.log.clone(),
+            only_include_rollups: cfg.only_include_rollups.clone(),
+        }
+    }
+}
+
+/// A handle to apply a reloaded config to a running relayer.
+///
+/// Only the log level and the rollup filter are applied; changes to all other fields require a
+/// restart. A changed rollup filter takes effect from the next sequencer block that is converted
+/// into blobs.
+#[derive(Clone)]
+pub struct ReloadHandle {
+    trigger: ReloadTrigger,
+    active: Arc<watch::Sender<ReloadableConfig>>,
+    rollup_filter: Arc<watch::Sender<IncludeRollup>>,
+}
+
+impl ReloadHandle {
+    pub(crate) fn new(cfg: &Config) -> eyre::Result<Self> {
+        let (rollup_filter, _) = watch::channel(cfg.only_include_rollups()?);
+        let (active, _) = watch::channel(ReloadableConfig::from_config(cfg));
+        Ok(Self {
+            trigger: ReloadTrigger::new(),
+            active: Arc::new(active),
+            rollup_filter: Arc::new(rollup_filter),
+        })
+    }
+
+    /// Returns the trigger that is fired by the `/reload` endpoint of the API server.
+    #[must_use]
+    pub fn trigger(&self) -> ReloadTrigger {
+        self.trigger.clone()
+    }
+
+    pub(crate) fn active_config(&self) -> ReloadableConfig {
+        self.active.borrow().clone()
+    }
+
+    pub(crate) fn subscribe_to_rollup_filter(&self) -> watch::Receiver<IncludeRollup> {
+        self.rollup_filter.subscribe()
+    }
+
+    /// Validates the reloadable fields of `cfg` and applies them to relayer.
+    ///
+    /// Either all or none of the changes are applied. Returns `false` if nothing changed.
+    ///
+    /// # Errors
+    /// Returns an error if the rollup filter or the log filter directives of `cfg` are invalid.
+    pub fn apply(
+        &self,
+        cfg: &Config,
+        filter: Option<&telemetry::FilterHandle>,
+    ) -> eyre::Result<bool> {
+        let reloaded = ReloadableConfig::from_config(cfg);
+        if *self.active.borrow() == reloaded {
+            return Ok(false);
+        }
+        let rollup_filter = cfg
+            .only_include_rollups()
+            .wrap_err("failed parsing rollup filter")?;
+        if let Some(filter) = filter {
+            if filter.filter_directives() != reloaded.log {
+                filter
+                    .set_filter_directives(&reloaded.log)
+                    .wrap_err("failed applying log filter directives")?;
+            }
+        }
+        if self.active.borrow().only_include_rollups != reloaded.only_include_rollups {
+            self.rollup_filter.send_replace(rollup_filter);
+        }
+        self.active.send_replace(reloaded);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::RollupId;
+
+    use super::*;
+
+    fn config_with_rollups(only_include_rollups: &str) -> Config {
+        Config {
+            sequencer_chain_id: "sequencer".to_string(),
+            celestia_chain_id: "celestia".to_string(),
+            cometbft_endpoint: "http://127.0.0.1:26657".to_string(),
+            sequencer_grpc_endpoint: "http://127.0.0.1:8080".to_string(),
+            celestia_app_grpc_endpoint: "http://127.0.0.1:9090".to_string(),
+            celestia_app_key_file: ".celestia-app/keyring-test/key.txt".to_string(),
+            block_time: 1000,
+            only_include_rollups: only_include_rollups.to_string(),
+            api_addr: "127.0.0.1:0".to_string(),
+            log: "info".to_string(),
+            force_stdout: false,
+            no_otel: true,
+            no_metrics: true,
+            metrics_http_listener_addr: "127.0.0.1:9000".to_string(),
+            pretty_print: false,
+            submission_state_path: "submission-state.json".into(),
+        }
+    }
+
+    #[test]
+    fn changed_rollup_filter_is_applied() {
+        let included = RollupId::new([1; 32]);
+        let excluded = RollupId::new([2; 32]);
+        let handle = ReloadHandle::new(&config_with_rollups("")).unwrap();
+        let mut rollup_filter = handle.subscribe_to_rollup_filter();
+
+        assert!(!handle.apply(&config_with_rollups(""), None).unwrap());
+        assert!(!rollup_filter.has_changed().unwrap());
+
+        assert!(handle
+            .apply(&config_with_rollups(&included.to_string()), None)
+            .unwrap());
+        let rollup_filter = rollup_filter.borrow_and_update().clone();
+        assert!(rollup_filter.should_include(&included));
+        assert!(!rollup_filter.should_include(&excluded));
+    }
+
+    #[test]
+    fn invalid_rollup_filter_is_rejected() {
+        let handle = ReloadHandle::new(&config_with_rollups("")).unwrap();
+        let rollup_filter = handle.subscribe_to_rollup_filter();
+        handle
+            .apply(&config_with_rollups("not-a-rollup-id"), None)
+            .unwrap_err();
+        assert!(!rollup_filter.has_changed().unwrap());
+        assert_eq!(handle.active_config().only_include_rollups, "");
+    }
+}
@@ -43,11 +43,13 @@ use crate::{
         self,
         Relayer,
     },
+    reload::ReloadHandle,
 };
 
 pub struct SequencerRelayer {
     api_server: api::ApiServer,
     relayer: Relayer,
+    reload_handle: ReloadHandle,
     shutdown_handle: ShutdownHandle,
 }
 
@@ -59,7 +61,7 @@ impl SequencerRelayer {
     /// Returns an error if constructing the inner relayer type failed.
     pub fn new(cfg: Config, metrics: &'static Metrics) -> eyre::Result<(Self, ShutdownHandle)> {
         let shutdown_handle = ShutdownHandle::new();
-        let rollup_filter = cfg.only_include_rollups()?;
+        let reload_handle = ReloadHandle::new(&cfg)?;
         let Config {
             sequencer_chain_id,
             celestia_chain_id,
@@ -82,7 +84,7 @@ impl SequencerRelayer {
             cometbft_endpoint,
             sequencer_poll_period: Duration::from_millis(block_time),
             sequencer_grpc_endpoint,
-            rollup_filter,
+            rollup_filter: reload_handle.subscribe_to_rollup_filter(),
             submission_state_path,
             metrics,
         }
@@ -93,10 +95,11 @@ impl SequencerRelayer {
         let api_socket_addr = api_addr.parse::<SocketAddr>().wrap_err_with(|| {
             format!("failed to parse provided `api_addr` string as socket address: `{api_addr}`",)
         })?;
-        let api_server = api::start(api_socket_addr, state_rx);
+        let api_server = api::start(api_socket_addr, state_rx, reload_handle.clone());
         let relayer = Self {
             api_server,
             relayer,
+            reload_handle,
             shutdown_handle: shutdown_handle.clone(),
         };
         Ok((relayer, shutdown_handle))
@@ -106,12 +109,19 @@ impl SequencerRelayer {
         self.api_server.local_addr()
     }
 
+    /// Returns a handle to change parts of the config of the running relayer.
+    #[must_use]
+    pub fn reload_handle(&self) -> ReloadHandle {
+        self.reload_handle.clone()
+    }
+
     /// Runs Sequencer Relayer.
     pub async fn run(self) {
         let Self {
             api_server,
             relayer,
             shutdown_handle,
+            ..
         } = self;
         // Separate the API shutdown signal from the cancellation token because we want it to live
         // until the very end.
@@ -125,7 +125,7 @@ fn parse(input: &str, verbose: bool) -> Result<ParsedBlob> {
     })
 }
 
-fn get_decoded_blob_data(input: &str) -> Result<Vec<u8>> {
+pub(crate) fn get_decoded_blob_data(input: &str) -> Result<Vec<u8>> {
     if input == "-" {
         let encoded = io::read_to_string(io::stdin().lock()).wrap_err("failed to read stdin")?;
         return BASE64_STANDARD
@@ -0,0 +1,378 @@
+use std::{
+    collections::HashMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use astria_core::{
+    brotli::decompress_bytes,
+    generated::sequencerblock::v1alpha1::{
+        SubmittedMetadata as RawSubmittedMetadata,
+        SubmittedMetadataList as RawSubmittedMetadataList,
+        SubmittedRollupData as RawSubmittedRollupData,
+        SubmittedRollupDataList as RawSubmittedRollupDataList,
+    },
+    primitive::v1::RollupId,
+    sequencerblock::v1alpha1::{
+        celestia::UncheckedSubmittedMetadata,
+        SubmittedMetadata,
+        SubmittedRollupData,
+    },
+};
+use astria_eyre::eyre::{
+    bail,
+    Report,
+    Result,
+    WrapErr,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine,
+};
+use prost::{
+    bytes::Bytes,
+    Message,
+};
+use serde::Serialize;
+
+use crate::blob_parser::{
+    get_decoded_blob_data,
+    Format,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Base64-encoded sequencer metadata blob data, or a file containing this, or stdin if `-`
+    #[arg(long, value_name = "BLOB|PATH")]
+    metadata: String,
+
+    /// Base64-encoded rollup data blob data, or a file containing this, or stdin if `-`
+    #[arg(long, value_name = "BLOB|PATH")]
+    rollup_data: Option<String>,
+
+    /// The hex or base64 encoded ID of the rollup whose data is verified. Metadata listing this
+    /// rollup without matching rollup data fail verification
+    #[arg(long, value_parser = parse_rollup_id)]
+    rollup_id: Option<RollupId>,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Verifies sequencer metadata and rollup data blobs like Conductor does before executing them.
+///
+/// The metadata's proofs of its rollup transactions root and rollup IDs are checked against its
+/// data hash. Each rollup data entry is matched to metadata by sequencer block hash and its proof
+/// is checked against the metadata's rollup transactions root.
+///
+/// This does not check the metadata against the commits of the sequencer network.
+///
+/// # Errors
+///
+/// Returns an error if the inputs cannot be decoded or if any blob fails verification.
+pub fn run(
+    Args {
+        metadata,
+        rollup_data,
+        rollup_id,
+        format,
+    }: Args,
+) -> Result<()> {
+    let metadata = decode_metadata(&decompress(&metadata)?)?;
+    let rollup_data = match rollup_data {
+        Some(rollup_data) => decode_rollup_data(&decompress(&rollup_data)?)?,
+        None => vec![],
+    };
+    let report = verify(metadata, rollup_data, rollup_id);
+    match format {
+        Format::Display => println!("{report}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&report).wrap_err("failed to json-encode")?
+        ),
+    }
+    if !report.is_valid() {
+        bail!("verification failed");
+    }
+    Ok(())
+}
+
+fn parse_rollup_id(input: &str) -> Result<RollupId> {
+    let bytes = hex::decode(input.trim_start_matches("0x"))
+        .or_else(|_| BASE64_STANDARD.decode(input))
+        .wrap_err("rollup ID is neither hex nor base64 encoded")?;
+    RollupId::try_from_slice(&bytes).wrap_err("rollup ID must be 32 bytes")
+}
+
+fn decompress(input: &str) -> Result<Bytes> {
+    let raw = get_decoded_blob_data(input)?;
+    Ok(Bytes::from(
+        decompress_bytes(&raw).wrap_err("failed to decompress decoded bytes")?,
+    ))
+}
+
+fn decode_metadata(decompressed: &Bytes) -> Result<Vec<UncheckedSubmittedMetadata>> {
+    if let Some(list) = RawSubmittedMetadataList::decode(decompressed.clone())
+        .ok()
+        .and_then(|list| {
+            list.entries
+                .into_iter()
+                .map(|raw| UncheckedSubmittedMetadata::try_from_raw(raw).ok())
+                .collect::<Option<Vec<_>>>()
+        })
+    {
+        return Ok(list);
+    }
+    RawSubmittedMetadata::decode(decompressed.clone())
+        .map_err(Report::new)
+        .and_then(|raw| UncheckedSubmittedMetadata::try_from_raw(raw).map_err(Report::new))
+        .map(|metadata| vec![metadata])
+        .wrap_err("failed to decode as sequencer metadata or a list thereof")
+}
+
+fn decode_rollup_data(decompressed: &Bytes) -> Result<Vec<SubmittedRollupData>> {
+    if let Some(list) = RawSubmittedRollupDataList::decode(decompressed.clone())
+        .ok()
+        .and_then(|list| {
+            list.entries
+                .into_iter()
+                .map(|raw| SubmittedRollupData::try_from_raw(raw).ok())
+                .collect::<Option<Vec<_>>>()
+        })
+    {
+        return Ok(list);
+    }
+    RawSubmittedRollupData::decode(decompressed.clone())
+        .map_err(Report::new)
+        .and_then(|raw| SubmittedRollupData::try_from_raw(raw).map_err(Report::new))
+        .map(|rollup_data| vec![rollup_data])
+        .wrap_err("failed to decode as rollup data or a list thereof")
+}
+
+fn verify(
+    metadata: Vec<UncheckedSubmittedMetadata>,
+    rollup_data: Vec<SubmittedRollupData>,
+    rollup_id: Option<RollupId>,
+) -> VerificationReport {
+    let mut metadata_reports = Vec::with_capacity(metadata.len());
+    // Maps the block hash of verified metadata to the metadata and if matching rollup data was
+    // found.
+    let mut verified_metadata = HashMap::new();
+    for unchecked in metadata {
+        let block_hash = unchecked.block_hash;
+        let height = unchecked.header.height().value();
+        let error = match SubmittedMetadata::try_from_unchecked(unchecked) {
+            Ok(metadata) => {
+                verified_metadata.insert(block_hash, (metadata, false));
+                None
+            }
+            Err(error) => Some(format!("{:#}", Report::new(error))),
+        };
+        metadata_reports.push((
+            block_hash,
+            MetadataReport {
+                sequencer_block_hash: BASE64_STANDARD.encode(block_hash),
+                sequencer_height: height,
+                error,
+            },
+        ));
+    }
+
+    let mut rollup_data_reports = Vec::with_capacity(rollup_data.len());
+    for rollup_data in rollup_data {
+        let block_hash = rollup_data.sequencer_block_hash();
+        let error = match verified_metadata.get_mut(&block_hash) {
+            None => Some("no verified sequencer metadata with the same block hash found".into()),
+            Some((metadata, _)) if !metadata.contains_rollup_id(rollup_data.rollup_id()) => {
+                Some("rollup ID is not listed in the sequencer metadata".into())
+            }
+            Some((metadata, _)) if !verify_rollup_data_against_metadata(&rollup_data, metadata) => {
+                Some(
+                    "rollup transactions proof does not lead to the metadata's rollup \
+                     transactions root"
+                        .into(),
+                )
+            }
+            Some((_, has_rollup_data)) => {
+                *has_rollup_data = true;
+                None
+            }
+        };
+        rollup_data_reports.push(RollupDataReport {
+            sequencer_block_hash: BASE64_STANDARD.encode(block_hash),
+            rollup_id: rollup_data.rollup_id().to_string(),
+            number_of_transactions: rollup_data.transactions().len(),
+            error,
+        });
+    }
+
+    // Mirrors Conductor dropping metadata that lists its rollup but has no matching rollup data.
+    if let Some(rollup_id) = rollup_id {
+        for (block_hash, report) in &mut metadata_reports {
+            if let Some((metadata, false)) = verified_metadata.get(block_hash) {
+                if metadata.contains_rollup_id(rollup_id) {
+                    report.error = Some(format!(
+                        "metadata lists rollup ID `{rollup_id}`, but no matching rollup data was \
+                         found"
+                    ));
+                }
+            }
+        }
+    }
+
+    VerificationReport {
+        metadata: metadata_reports
+            .into_iter()
+            .map(|(_, report)| report)
+            .collect(),
+        rollup_data: rollup_data_reports,
+    }
+}
+
+/// Checks that the rollup data's rollup ID and transactions are included in the metadata's
+/// rollup transactions root, exactly as Conductor does.
+fn verify_rollup_data_against_metadata(
+    rollup_data: &SubmittedRollupData,
+    metadata: &SubmittedMetadata,
+) -> bool {
+    rollup_data
+        .proof()
+        .audit()
+        .with_root(metadata.rollup_transactions_root())
+        .with_leaf_builder()
+        .write(&rollup_data.rollup_id().get())
+        .write(&astria_merkle::Tree::from_leaves(rollup_data.transactions()).root())
+        .finish_leaf()
+        .perform()
+}
+
+#[derive(Serialize, Debug)]
+struct VerificationReport {
+    metadata: Vec<MetadataReport>,
+    rollup_data: Vec<RollupDataReport>,
+}
+
+impl VerificationReport {
+    fn is_valid(&self) -> bool {
+        self.metadata.iter().all(|report| report.error.is_none())
+            && self.rollup_data.iter().all(|report| report.error.is_none())
+    }
+}
+
+impl Display for VerificationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, report) in self.metadata.iter().enumerate() {
+            writeln!(
+                f,
+                "metadata #{index} (sequencer height {}, block hash {}): {}",
+                report.sequencer_height,
+                report.sequencer_block_hash,
+                outcome(report.error.as_deref()),
+            )?;
+        }
+        for (index, report) in self.rollup_data.iter().enumerate() {
+            writeln!(
+                f,
+                "rollup data #{index} (rollup ID {}, block hash {}, {} transactions): {}",
+                report.rollup_id,
+                report.sequencer_block_hash,
+                report.number_of_transactions,
+                outcome(report.error.as_deref()),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn outcome(error: Option<&str>) -> String {
+    error.map_or_else(|| "valid".to_string(), |error| format!("INVALID: {error}"))
+}
+
+#[derive(Serialize, Debug)]
+struct MetadataReport {
+    sequencer_block_hash: String,
+    sequencer_height: u64,
+    error: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+struct RollupDataReport {
+    sequencer_block_hash: String,
+    rollup_id: String,
+    number_of_transactions: usize,
+    error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::test_utils::ConfigureSequencerBlock;
+
+    use super::*;
+
+    const ROLLUP_ID: RollupId = RollupId::new([42; 32]);
+
+    fn make_blobs(height: u32) -> (UncheckedSubmittedMetadata, Vec<SubmittedRollupData>) {
+        let (metadata, rollup_data) = ConfigureSequencerBlock {
+            block_hash: Some([height.try_into().unwrap(); 32]),
+            height,
+            sequence_data: vec![(ROLLUP_ID, b"hello world".to_vec())],
+            ..ConfigureSequencerBlock::default()
+        }
+        .make()
+        .split_for_celestia();
+        (metadata.into_unchecked(), rollup_data)
+    }
+
+    #[test]
+    fn matching_blobs_are_valid() {
+        let (metadata, rollup_data) = make_blobs(1);
+        let report = verify(vec![metadata], rollup_data, Some(ROLLUP_ID));
+        assert!(report.is_valid(), "{report}");
+        assert_eq!(1, report.rollup_data.len());
+    }
+
+    #[test]
+    fn tampered_rollup_data_is_invalid() {
+        let (metadata, rollup_data) = make_blobs(1);
+        let mut tampered = rollup_data[0].clone().into_unchecked();
+        tampered.transactions.push(Bytes::from_static(b"injected"));
+        let report = verify(
+            vec![metadata],
+            vec![tampered.into_celestia_rollup_blob()],
+            None,
+        );
+        assert!(!report.is_valid());
+        assert!(report.metadata[0].error.is_none());
+        assert!(report.rollup_data[0].error.is_some());
+    }
+
+    #[test]
+    fn rollup_data_of_other_block_is_invalid() {
+        let (metadata, _) = make_blobs(1);
+        let (_, rollup_data) = make_blobs(2);
+        let report = verify(vec![metadata], rollup_data, None);
+        assert!(report.rollup_data[0].error.is_some());
+    }
+
+    #[test]
+    fn missing_rollup_data_for_listed_rollup_is_invalid() {
+        let (metadata, _) = make_blobs(1);
+        let report = verify(vec![metadata.clone()], vec![], None);
+        assert!(report.is_valid(), "{report}");
+        let report = verify(vec![metadata], vec![], Some(ROLLUP_ID));
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn tampered_metadata_is_invalid() {
+        let (mut metadata, rollup_data) = make_blobs(1);
+        metadata.rollup_ids.push(RollupId::new([1; 32]));
+        let report = verify(vec![metadata], rollup_data, None);
+        assert!(report.metadata[0].error.is_some());
+        assert!(report.rollup_data[0].error.is_some());
+    }
+}
@@ -5,8 +5,11 @@ use clap::{
 
 use super::{
     blob_parser,
+    blob_verifier,
+    genesis_diff,
     genesis_example,
     genesis_parser,
+    tx_decoder,
 };
 
 /// Utilities for working with the Astria sequencer network
@@ -29,6 +32,18 @@ pub enum Command {
     /// Parse blob data from an arg, a file, or stdin
     #[command(arg_required_else_help = true)]
     ParseBlob(blob_parser::Args),
+
+    /// Verify the proofs of sequencer metadata and rollup data blobs
+    #[command(arg_required_else_help = true)]
+    VerifyBlob(blob_verifier::Args),
+
+    /// Decode a signed sequencer transaction from an arg, a file, or stdin
+    #[command(arg_required_else_help = true)]
+    DecodeTx(tx_decoder::Args),
+
+    /// Compare two genesis app states
+    #[command(arg_required_else_help = true)]
+    DiffGenesis(genesis_diff::Args),
 }
 
 #[must_use]
@@ -0,0 +1,285 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    fs::File,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::protocol::genesis::v1alpha1::GenesisAppState;
+use astria_eyre::eyre::{
+    Result,
+    WrapErr,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::blob_parser::Format;
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Path to the first genesis app state or genesis file
+    #[arg(value_name = "PATH")]
+    left: PathBuf,
+
+    /// Path to the second genesis app state or genesis file
+    #[arg(value_name = "PATH")]
+    right: PathBuf,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Compares two sequencer genesis app states and prints their semantic differences.
+///
+/// Either file may be a bare genesis app state or a full genesis file, in which case the app
+/// state is read from its `app_state` key. Both are parsed as [`GenesisAppState`] before being
+/// compared, so formatting, key order and the order of accounts do not result in differences.
+///
+/// # Errors
+///
+/// Returns an error if either file cannot be read or does not contain a valid genesis app state.
+pub fn run(
+    Args {
+        left,
+        right,
+        format,
+    }: Args,
+) -> Result<()> {
+    let left = load(&left)?;
+    let right = load(&right)?;
+    let diff = diff(&left, &right);
+    match format {
+        Format::Display => print!("{diff}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&diff).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<Value> {
+    let file = File::open(path).wrap_err_with(|| format!("failed to open `{}`", path.display()))?;
+    let value: Value = serde_json::from_reader(file)
+        .wrap_err_with(|| format!("failed deserializing `{}` as json", path.display()))?;
+    normalize(value).wrap_err_with(|| {
+        format!(
+            "`{}` does not contain a valid genesis app state",
+            path.display()
+        )
+    })
+}
+
+/// Parses the (possibly nested) app state as [`GenesisAppState`] and serializes it back, which
+/// fills in defaults and brings all values into their canonical encoding.
+fn normalize(mut value: Value) -> Result<Value> {
+    if let Some(app_state) = value.get_mut("app_state") {
+        value = app_state.take();
+    }
+    let app_state: GenesisAppState =
+        serde_json::from_value(value).wrap_err("failed to parse genesis app state")?;
+    serde_json::to_value(app_state).wrap_err("failed to serialize genesis app state")
+}
+
+fn diff(left: &Value, right: &Value) -> GenesisDiff {
+    let mut changes = vec![];
+    diff_values("", left, right, &mut changes);
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    GenesisDiff {
+        changes,
+    }
+}
+
+fn diff_values(path: &str, left: &Value, right: &Value, changes: &mut Vec<Change>) {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            for (key, left_value) in left {
+                let path = join(path, key);
+                match right.get(key) {
+                    Some(right_value) => diff_values(&path, left_value, right_value, changes),
+                    None => changes.push(Change::removed(path, left_value)),
+                }
+            }
+            for (key, right_value) in right {
+                if !left.contains_key(key) {
+                    changes.push(Change::added(join(path, key), right_value));
+                }
+            }
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            let left = keyed_entries(left);
+            let right = keyed_entries(right);
+            for (key, left_value) in &left {
+                let path = join(path, key);
+                match right.get(key) {
+                    Some(right_value) => diff_values(&path, left_value, right_value, changes),
+                    None => changes.push(Change::removed(path, left_value)),
+                }
+            }
+            for (key, right_value) in &right {
+                if !left.contains_key(key) {
+                    changes.push(Change::added(join(path, key), right_value));
+                }
+            }
+        }
+        (left, right) if left != right => changes.push(Change {
+            path: path.to_string(),
+            left: Some(left.clone()),
+            right: Some(right.clone()),
+        }),
+        _ => {}
+    }
+}
+
+/// Keys the entries of an array so that they can be compared irrespective of their order.
+///
+/// Entries with an `address` field (accounts, for example) are keyed by their address so that
+/// changes to them are reported as changes to the account; all others are keyed by their value.
+fn keyed_entries(entries: &[Value]) -> BTreeMap<String, &Value> {
+    entries
+        .iter()
+        .map(|entry| {
+            let key = match entry.get("address") {
+                Some(Value::String(address)) => address.clone(),
+                Some(address) => address_key(address).unwrap_or_else(|| address.to_string()),
+                None => entry.to_string(),
+            };
+            (format!("[{key}]"), entry)
+        })
+        .collect()
+}
+
+/// Returns the bech32m string of an address given as a protobuf JSON object.
+fn address_key(address: &Value) -> Option<String> {
+    address.get("bech32m")?.as_str().map(ToString::to_string)
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else if key.starts_with('[') {
+        format!("{path}{key}")
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct GenesisDiff {
+    changes: Vec<Change>,
+}
+
+impl Display for GenesisDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no differences");
+        }
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct Change {
+    path: String,
+    left: Option<Value>,
+    right: Option<Value>,
+}
+
+impl Change {
+    fn added(path: String, value: &Value) -> Self {
+        Self {
+            path,
+            left: None,
+            right: Some(value.clone()),
+        }
+    }
+
+    fn removed(path: String, value: &Value) -> Self {
+        Self {
+            path,
+            left: Some(value.clone()),
+            right: None,
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (&self.left, &self.right) {
+            (Some(left), Some(right)) => write!(f, "~ {}: {left} -> {right}", self.path),
+            (None, Some(right)) => write!(f, "+ {}: {right}", self.path),
+            (Some(left), None) => write!(f, "- {}: {left}", self.path),
+            (None, None) => write!(f, "  {}", self.path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::genesis_example::genesis_state;
+
+    fn example() -> Value {
+        serde_json::to_value(genesis_state()).unwrap()
+    }
+
+    #[test]
+    fn identical_states_have_no_differences() {
+        let diff = diff(&example(), &example());
+        assert!(diff.changes.is_empty(), "{diff}");
+    }
+
+    #[test]
+    fn nested_app_state_is_extracted() {
+        let nested = json!({ "chain_id": "test", "app_state": example() });
+        assert_eq!(normalize(example()).unwrap(), normalize(nested).unwrap());
+    }
+
+    #[test]
+    fn account_order_is_ignored() {
+        let left = example();
+        let mut right = example();
+        right["accounts"].as_array_mut().unwrap().reverse();
+        assert!(diff(&left, &right).changes.is_empty());
+    }
+
+    #[test]
+    fn changed_added_and_removed_values_are_reported() {
+        let left = json!({ "chainId": "a", "accounts": [{ "address": "x", "balance": 1 }] });
+        let right = json!({ "chainId": "b", "accounts": [{ "address": "y", "balance": 1 }] });
+        let changes = diff(&left, &right).changes;
+        assert_eq!(
+            vec![
+                Change {
+                    path: "accounts[x]".to_string(),
+                    left: Some(json!({ "address": "x", "balance": 1 })),
+                    right: None,
+                },
+                Change {
+                    path: "accounts[y]".to_string(),
+                    left: None,
+                    right: Some(json!({ "address": "y", "balance": 1 })),
+                },
+                Change {
+                    path: "chainId".to_string(),
+                    left: Some(json!("a")),
+                    right: Some(json!("b")),
+                },
+            ],
+            changes,
+        );
+    }
+}
@@ -10,10 +10,13 @@ use astria_core::{
         IbcParameters,
     },
     primitive::v1::Address,
-    protocol::genesis::v1alpha1::{
-        Account,
-        Fees,
-        GenesisAppState,
+    protocol::{
+        genesis::v1alpha1::{
+            Account,
+            Fees,
+            GenesisAppState,
+        },
+        transaction::v1alpha1::action::FeeDistribution,
     },
     Protobuf,
 };
@@ -99,10 +102,17 @@ fn proto_genesis_state() -> astria_core::generated::protocol::genesis::v1alpha1:
             }
             .into_raw(),
         ),
+        fee_distribution: Some(
+            FeeDistribution::try_new(50, 30, 20, 0, bob())
+                .unwrap()
+                .into_raw(),
+        ),
+        staking_parameters: None,
+        evidence_parameters: None,
     }
 }
 
-fn genesis_state() -> GenesisAppState {
+pub(crate) fn genesis_state() -> GenesisAppState {
     GenesisAppState::try_from_raw(proto_genesis_state()).unwrap()
 }
 
@@ -1,4 +1,7 @@
 pub mod blob_parser;
+pub mod blob_verifier;
 pub mod cli;
+pub mod genesis_diff;
 pub mod genesis_example;
 pub mod genesis_parser;
+pub mod tx_decoder;
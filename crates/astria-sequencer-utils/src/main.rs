@@ -1,12 +1,15 @@
 use astria_eyre::eyre::Result;
 use astria_sequencer_utils::{
     blob_parser,
+    blob_verifier,
     cli::{
         self,
         Command,
     },
+    genesis_diff,
     genesis_example,
     genesis_parser,
+    tx_decoder,
 };
 
 fn main() -> Result<()> {
@@ -16,5 +19,8 @@ fn main() -> Result<()> {
         Command::CopyGenesisState(args) => genesis_parser::run(args),
         Command::GenerateGenesisState(args) => genesis_example::run(&args),
         Command::ParseBlob(args) => blob_parser::run(args),
+        Command::VerifyBlob(args) => blob_verifier::run(args),
+        Command::DecodeTx(args) => tx_decoder::run(args),
+        Command::DiffGenesis(args) => genesis_diff::run(args),
     }
 }
@@ -0,0 +1,202 @@
+use std::{
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    fs,
+    io,
+    path::Path,
+};
+
+use astria_core::{
+    generated::protocol::transactions::v1alpha1::SignedTransaction as RawSignedTransaction,
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::SignedTransaction,
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine,
+};
+use prost::Message;
+use serde::Serialize;
+
+use crate::blob_parser::Format;
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Hex- or base64-encoded signed transaction, or a file containing this, or stdin if `-`
+    #[arg(value_name = "TX|PATH")]
+    input: String,
+
+    /// The bech32m prefix used to display the address of the signer
+    #[arg(long, default_value = "astria")]
+    prefix: String,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Decodes a signed sequencer transaction and prints it along with its signer.
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be read or decoded, if the transaction's signature is
+/// invalid, or if the signer's address cannot be constructed with the given prefix.
+pub fn run(
+    Args {
+        input,
+        prefix,
+        format,
+    }: Args,
+) -> Result<()> {
+    let bytes = get_decoded_tx_data(&input)?;
+    let decoded = decode(&bytes, &prefix)?;
+    match format {
+        Format::Display => println!("{decoded}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&decoded).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+fn get_decoded_tx_data(input: &str) -> Result<Vec<u8>> {
+    let encoded = if input == "-" {
+        io::read_to_string(io::stdin().lock()).wrap_err("failed to read stdin")?
+    } else if Path::new(input).is_file() {
+        fs::read_to_string(input).wrap_err_with(|| format!("failed to read file `{input}`"))?
+    } else {
+        input.to_string()
+    };
+    decode_hex_or_base64(encoded.trim())
+}
+
+fn decode_hex_or_base64(encoded: &str) -> Result<Vec<u8>> {
+    let without_prefix = encoded
+        .strip_prefix("0x")
+        .or_else(|| encoded.strip_prefix("0X"))
+        .unwrap_or(encoded);
+    hex::decode(without_prefix)
+        .or_else(|_| BASE64_STANDARD.decode(encoded))
+        .wrap_err("failed to decode transaction as either hex or base64")
+}
+
+fn decode(bytes: &[u8], prefix: &str) -> Result<DecodedTransaction> {
+    let raw = RawSignedTransaction::decode(bytes)
+        .wrap_err("failed to decode bytes as protobuf signed transaction")?;
+    let signed = SignedTransaction::try_from_raw(raw)
+        .wrap_err("failed to verify and convert raw signed transaction")?;
+    let signer = Address::builder()
+        .array(signed.address_bytes())
+        .prefix(prefix)
+        .try_build()
+        .wrap_err("failed to construct signer address with the given prefix")?;
+    let unsigned_transaction = serde_json::to_value(signed.unsigned_transaction().to_raw())
+        .wrap_err("failed to json-encode unsigned transaction")?;
+    Ok(DecodedTransaction {
+        id: hex::encode(signed.id().get()),
+        signer: signer.to_string(),
+        chain_id: signed.chain_id().to_string(),
+        nonce: signed.nonce(),
+        number_of_actions: signed.actions().len(),
+        unsigned_transaction,
+    })
+}
+
+#[derive(Serialize, Debug)]
+struct DecodedTransaction {
+    id: String,
+    signer: String,
+    chain_id: String,
+    nonce: u32,
+    number_of_actions: usize,
+    unsigned_transaction: serde_json::Value,
+}
+
+impl Display for DecodedTransaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "transaction ID: {}", self.id)?;
+        writeln!(f, "signer: {}", self.signer)?;
+        writeln!(f, "chain ID: {}", self.chain_id)?;
+        writeln!(f, "nonce: {}", self.nonce)?;
+        writeln!(f, "number of actions: {}", self.number_of_actions)?;
+        let unsigned_transaction =
+            serde_json::to_string_pretty(&self.unsigned_transaction).map_err(|_| fmt::Error)?;
+        write!(f, "unsigned transaction: {unsigned_transaction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::RollupId,
+        protocol::transaction::v1alpha1::{
+            action::SequenceAction,
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    };
+
+    use super::*;
+
+    fn signed_transaction() -> (SigningKey, Vec<u8>) {
+        let signing_key = SigningKey::from([1; 32]);
+        let unsigned = UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(3)
+                .chain_id("test-1")
+                .build(),
+            actions: vec![SequenceAction {
+                rollup_id: RollupId::new([2; 32]),
+                data: b"hello".to_vec().into(),
+                fee_asset: "nria".parse().unwrap(),
+            }
+            .into()],
+        };
+        let signed = unsigned.into_signed(&signing_key);
+        (signing_key, signed.into_raw().encode_to_vec())
+    }
+
+    #[test]
+    fn hex_and_base64_inputs_are_decoded() {
+        let bytes = vec![0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(bytes, decode_hex_or_base64("deadbeef").unwrap());
+        assert_eq!(bytes, decode_hex_or_base64("0xdeadbeef").unwrap());
+        assert_eq!(
+            bytes,
+            decode_hex_or_base64(&BASE64_STANDARD.encode(&bytes)).unwrap()
+        );
+        assert!(decode_hex_or_base64("not encoded!").is_err());
+    }
+
+    #[test]
+    fn signed_transaction_is_decoded_with_signer() {
+        let (signing_key, bytes) = signed_transaction();
+        let decoded = decode(&bytes, "astria").unwrap();
+        let expected_signer = Address::builder()
+            .array(signing_key.address_bytes())
+            .prefix("astria")
+            .try_build()
+            .unwrap();
+        assert_eq!(expected_signer.to_string(), decoded.signer);
+        assert_eq!("test-1", decoded.chain_id);
+        assert_eq!(3, decoded.nonce);
+        assert_eq!(1, decoded.number_of_actions);
+    }
+
+    #[test]
+    fn transaction_with_bad_signature_is_rejected() {
+        let (_, bytes) = signed_transaction();
+        let mut raw = RawSignedTransaction::decode(&*bytes).unwrap();
+        raw.signature = vec![0; 64].into();
+        assert!(decode(&raw.encode_to_vec(), "astria").is_err());
+    }
+}
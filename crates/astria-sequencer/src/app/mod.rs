@@ -20,6 +20,7 @@ use std::{
 pub(crate) use action_handler::ActionHandler;
 use astria_core::{
     generated::protocol::transactions::v1alpha1 as raw,
+    primitive::v1::ADDRESS_LEN,
     protocol::{
         abci::AbciErrorCode,
         genesis::v1alpha1::GenesisAppState,
@@ -59,7 +60,10 @@ use telemetry::display::json;
 use tendermint::{
     abci::{
         self,
-        types::ExecTxResult,
+        types::{
+            CommitInfo,
+            ExecTxResult,
+        },
         Code,
         Event,
     },
@@ -75,16 +79,10 @@ use tracing::{
 };
 
 use crate::{
-    accounts::{
-        component::AccountsComponent,
-        StateWriteExt as _,
-    },
+    accounts::component::AccountsComponent,
     address::StateWriteExt as _,
     api_state_ext::StateWriteExt as _,
-    assets::{
-        StateReadExt as _,
-        StateWriteExt as _,
-    },
+    assets::StateWriteExt as _,
     authority::{
         component::{
             AuthorityComponent,
@@ -99,6 +97,14 @@ use crate::{
         StateWriteExt as _,
     },
     component::Component as _,
+    evidence::{
+        self,
+        component::EvidenceComponent,
+    },
+    fee_distribution::{
+        self,
+        StateWriteExt as _,
+    },
     ibc::component::IbcComponent,
     mempool::{
         Mempool,
@@ -113,15 +119,20 @@ use crate::{
         },
     },
     sequence::component::SequenceComponent,
+    staking::component::StakingComponent,
     state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
     },
     transaction::InvalidNonce,
+    upgrade::{
+        self,
+        Migrations,
+    },
 };
 
 /// The inter-block state being written to by the application.
-type InterBlockState = Arc<StateDelta<Snapshot>>;
+pub(crate) type InterBlockState = Arc<StateDelta<Snapshot>>;
 
 /// The Sequencer application, written as a bundle of [`Component`]s.
 ///
@@ -179,6 +190,12 @@ pub(crate) struct App {
     #[allow(clippy::struct_field_names)]
     app_hash: AppHash,
 
+    // the migrations of the upgrades supported by this binary.
+    //
+    // an upgrade scheduled through an `UpgradeScheduleAction` without a migration registered
+    // here halts the chain at the upgrade height.
+    migrations: Migrations,
+
     metrics: &'static Metrics,
 }
 
@@ -213,6 +230,7 @@ impl App {
             recost_mempool: false,
             write_batch: None,
             app_hash,
+            migrations: Migrations::supported(),
             metrics,
         })
     }
@@ -247,6 +265,10 @@ impl App {
             state_tx.put_allowed_fee_asset(fee_asset);
         }
 
+        if let Some(fee_distribution) = genesis_state.fee_distribution() {
+            state_tx.put_fee_distribution(fee_distribution);
+        }
+
         // call init_chain on all components
         AccountsComponent::init_chain(&mut state_tx, &genesis_state)
             .await
@@ -269,6 +291,12 @@ impl App {
         SequenceComponent::init_chain(&mut state_tx, &genesis_state)
             .await
             .wrap_err("failed to call init_chain on SequenceComponent")?;
+        StakingComponent::init_chain(&mut state_tx, &genesis_state)
+            .await
+            .wrap_err("failed to call init_chain on StakingComponent")?;
+        EvidenceComponent::init_chain(&mut state_tx, &genesis_state)
+            .await
+            .wrap_err("failed to call init_chain on EvidenceComponent")?;
 
         state_tx.apply();
 
@@ -775,11 +803,6 @@ impl App {
             .get_chain_id()
             .await
             .wrap_err("failed to get chain ID from state")?;
-        let sudo_address = self
-            .state
-            .get_sudo_address()
-            .await
-            .wrap_err("failed to get sudo address from state")?;
 
         // convert tendermint id to astria address; this assumes they are
         // the same address, as they are both ed25519 keys
@@ -791,6 +814,16 @@ impl App {
             bail!("finalized block hash is empty; this should not occur")
         };
 
+        // refuse to finalize the block if an upgrade takes effect that this binary does not
+        // support; the committed state still contains all upgrades pending at this height.
+        upgrade::ensure_upgrades_are_supported(
+            &storage.latest_snapshot(),
+            height.value(),
+            &self.migrations,
+        )
+        .await
+        .wrap_err("refusing to finalize block")?;
+
         // If we previously executed txs in a different proposal than is being processed,
         // reset cached state changes.
         if self.executed_proposal_hash != finalize_block.hash {
@@ -861,7 +894,13 @@ impl App {
             tx_results.extend(execution_results);
         };
 
-        let end_block = self.end_block(height.value(), sudo_address).await?;
+        let end_block = self
+            .end_block(
+                height.value(),
+                proposer_address,
+                &finalize_block.decided_last_commit,
+            )
+            .await?;
 
         // get deposits for this block from state's ephemeral cache and put them to storage.
         let mut state_tx = StateDelta::new(self.state.clone());
@@ -964,11 +1003,25 @@ impl App {
         // store the block time
         state_tx.put_block_timestamp(begin_block.header.time);
 
+        // run the migrations of the upgrades taking effect at this height before any component
+        upgrade::apply_migrations(
+            &mut state_tx,
+            begin_block.header.height.value(),
+            &self.migrations,
+        )
+        .await
+        .wrap_err("failed to apply upgrade migrations")?;
+
         // call begin_block on all components
         let mut arc_state_tx = Arc::new(state_tx);
         AccountsComponent::begin_block(&mut arc_state_tx, begin_block)
             .await
             .wrap_err("failed to call begin_block on AccountsComponent")?;
+        // must run before the authority component, which removes misbehaving validators from
+        // the validator set
+        EvidenceComponent::begin_block(&mut arc_state_tx, begin_block)
+            .await
+            .wrap_err("failed to call begin_block on EvidenceComponent")?;
         AuthorityComponent::begin_block(&mut arc_state_tx, begin_block)
             .await
             .wrap_err("failed to call begin_block on AuthorityComponent")?;
@@ -981,6 +1034,9 @@ impl App {
         SequenceComponent::begin_block(&mut arc_state_tx, begin_block)
             .await
             .wrap_err("failed to call begin_block on SequenceComponent")?;
+        StakingComponent::begin_block(&mut arc_state_tx, begin_block)
+            .await
+            .wrap_err("failed to call begin_block on StakingComponent")?;
 
         let state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components should not retain copies of shared state");
@@ -1023,9 +1079,21 @@ impl App {
     async fn end_block(
         &mut self,
         height: u64,
-        fee_recipient: [u8; 20],
+        proposer_address: account::Id,
+        last_commit: &CommitInfo,
     ) -> Result<abci::response::EndBlock> {
-        let state_tx = StateDelta::new(self.state.clone());
+        let proposer_address: [u8; ADDRESS_LEN] = proposer_address
+            .as_bytes()
+            .try_into()
+            .expect("a cometbft account ID is always 20 bytes");
+        let mut state_tx = StateDelta::new(self.state.clone());
+
+        // jail validators that missed too many blocks; must run before the components, as
+        // jailing records validator updates
+        evidence::track_liveness(&mut state_tx, height, last_commit)
+            .await
+            .wrap_err("failed to track validator liveness")?;
+
         let mut arc_state_tx = Arc::new(state_tx);
 
         let end_block = abci::request::EndBlock {
@@ -1038,6 +1106,11 @@ impl App {
         AccountsComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("failed to call end_block on AccountsComponent")?;
+        // must run before the authority component, which applies the validator updates
+        // recorded by the staking component
+        StakingComponent::end_block(&mut arc_state_tx, &end_block)
+            .await
+            .wrap_err("failed to call end_block on StakingComponent")?;
         AuthorityComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("failed to call end_block on AuthorityComponent")?;
@@ -1050,13 +1123,15 @@ impl App {
         SequenceComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("failed to call end_block on SequenceComponent")?;
+        EvidenceComponent::end_block(&mut arc_state_tx, &end_block)
+            .await
+            .wrap_err("failed to call end_block on EvidenceComponent")?;
 
         let mut state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components should not retain copies of shared state");
 
         // gather and return validator updates
-        let validator_updates = self
-            .state
+        let validator_updates = state_tx
             .get_validator_updates()
             .await
             .expect("failed getting validator updates");
@@ -1064,22 +1139,10 @@ impl App {
         // clear validator updates
         state_tx.clear_validator_updates();
 
-        // gather block fees and transfer them to the block proposer
-        let fees = self
-            .state
-            .get_block_fees()
+        // distribute the block fees according to the fee distribution in state
+        fee_distribution::distribute_block_fees(&mut state_tx, proposer_address, last_commit)
             .await
-            .wrap_err("failed to get block fees")?;
-
-        for (asset, amount) in fees {
-            state_tx
-                .increase_balance(fee_recipient, asset, amount)
-                .await
-                .wrap_err("failed to increase fee recipient balance")?;
-        }
-
-        // clear block fees
-        state_tx.clear_block_fees().await;
+            .wrap_err("failed to distribute block fees")?;
 
         let events = self.apply(state_tx);
         Ok(abci::response::EndBlock {
@@ -174,6 +174,9 @@ pub(crate) fn proto_genesis_state()
         }),
         allowed_fee_assets: vec![crate::test_utils::nria().to_string()],
         fees: Some(default_fees().to_raw()),
+        fee_distribution: None,
+        staking_parameters: None,
+        evidence_parameters: None,
     }
 }
 
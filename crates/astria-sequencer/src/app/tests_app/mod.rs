@@ -19,10 +19,15 @@ use astria_core::{
             TransactionParams,
             UnsignedTransaction,
         },
+        upgrade::v1alpha1::Upgrade,
     },
     sequencerblock::v1alpha1::block::Deposit,
 };
-use cnidarium::StateDelta;
+use cnidarium::{
+    StateDelta,
+    StateWrite as _,
+};
+use futures::future::BoxFuture;
 use prost::{
     bytes::Bytes,
     Message as _,
@@ -64,6 +69,11 @@ use crate::{
         nria,
         verification_key,
     },
+    upgrade::{
+        Migration,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
 };
 
 fn default_tendermint_header() -> Header {
@@ -281,6 +291,113 @@ async fn app_transfer_block_fees_to_sudo() {
     assert_eq!(app.state.get_block_fees().await.unwrap().len(), 0);
 }
 
+#[tokio::test]
+async fn app_finalize_block_distributes_fees_according_to_fee_distribution() {
+    use astria_core::protocol::transaction::v1alpha1::action::FeeDistribution;
+    use tendermint::{
+        abci::types::{
+            BlockSignatureInfo,
+            Validator,
+            VoteInfo,
+        },
+        block::BlockIdFlag,
+    };
+
+    let proposer_address = [99u8; 20];
+    let treasury_address = astria_address(&[100u8; 20]);
+    let validator_a = [101u8; 20];
+    let validator_b = [102u8; 20];
+    let absent_validator = [103u8; 20];
+
+    let mut genesis_state = proto_genesis_state();
+    genesis_state.fee_distribution = Some(
+        FeeDistribution::try_new(40, 40, 10, 10, treasury_address)
+            .unwrap()
+            .into_raw(),
+    );
+    let (mut app, storage) =
+        initialize_app_with_storage(Some(genesis_state.try_into().unwrap()), vec![]).await;
+
+    let alice = get_alice_signing_key();
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            TransferAction {
+                to: astria_address_from_hex_string(BOB_ADDRESS),
+                amount: 333_333,
+                asset: nria().into(),
+                fee_asset: nria().into(),
+            }
+            .into(),
+        ],
+    };
+    let signed_tx = tx.into_signed(&alice);
+    let commitments = generate_rollup_datas_commitment(&[signed_tx.clone()], HashMap::new());
+
+    let vote = |address: [u8; 20], power: u32, flag: BlockIdFlag| VoteInfo {
+        validator: Validator {
+            address,
+            power: power.into(),
+        },
+        sig_info: BlockSignatureInfo::Flag(flag),
+    };
+    let finalize_block = abci::request::FinalizeBlock {
+        hash: Hash::try_from([0u8; 32].to_vec()).unwrap(),
+        height: 1u32.into(),
+        time: Time::now(),
+        next_validators_hash: Hash::default(),
+        proposer_address: account::Id::new(proposer_address),
+        txs: commitments.into_transactions(vec![signed_tx.to_raw().encode_to_vec().into()]),
+        decided_last_commit: CommitInfo {
+            votes: vec![
+                vote(validator_a, 3, BlockIdFlag::Commit),
+                vote(validator_b, 1, BlockIdFlag::Commit),
+                vote(absent_validator, 10, BlockIdFlag::Absent),
+            ],
+            round: Round::default(),
+        },
+        misbehavior: vec![],
+    };
+    let resp = app
+        .finalize_block(finalize_block, storage.clone())
+        .await
+        .unwrap();
+    app.commit(storage).await;
+
+    // the transfer fee of 12 is split as 4 to the validators (weighted 3:1 by power), 1 to the
+    // treasury, 1 burned, and the remaining 6 (including rounding dust) to the proposer
+    assert_eq!(app.state.get_transfer_base_fee().await.unwrap(), 12);
+    let balance = |address: [u8; 20]| {
+        let state = app.state.clone();
+        async move {
+            state
+                .get_account_balance(astria_address(&address), nria())
+                .await
+                .unwrap()
+        }
+    };
+    assert_eq!(balance(validator_a).await, 3);
+    assert_eq!(balance(validator_b).await, 1);
+    assert_eq!(balance(absent_validator).await, 0);
+    assert_eq!(balance(treasury_address.bytes()).await, 1);
+    assert_eq!(balance(proposer_address).await, 6);
+    assert_eq!(
+        balance(astria_address_from_hex_string(JUDY_ADDRESS).bytes()).await,
+        0
+    );
+    assert_eq!(app.state.get_block_fees().await.unwrap().len(), 0);
+
+    let distribution_events = resp
+        .events
+        .iter()
+        .filter(|event| event.kind == "fees.distribution")
+        .count();
+    assert_eq!(distribution_events, 5);
+}
+
 #[tokio::test]
 async fn app_create_sequencer_block_with_sequenced_data_and_deposits() {
     use astria_core::{
@@ -752,7 +869,7 @@ async fn app_end_block_validator_updates() {
     ];
 
     let mut app = initialize_app(None, initial_validator_set).await;
-    let proposer_address = [0u8; 20];
+    let proposer_address = account::Id::new([0u8; 20]);
 
     let validator_updates = vec![
         ValidatorUpdate {
@@ -775,7 +892,17 @@ async fn app_end_block_validator_updates() {
         .unwrap();
     app.apply(state_tx);
 
-    let resp = app.end_block(1, proposer_address).await.unwrap();
+    let resp = app
+        .end_block(
+            1,
+            proposer_address,
+            &CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+        )
+        .await
+        .unwrap();
     // we only assert length here as the ordering of the updates is not guaranteed
     // and validator::Update does not implement Ord
     assert_eq!(resp.validator_updates.len(), validator_updates.len());
@@ -797,3 +924,62 @@ async fn app_end_block_validator_updates() {
     assert_eq!(validator_c.power, 100);
     assert_eq!(app.state.get_validator_updates().await.unwrap().len(), 0);
 }
+
+#[tokio::test]
+async fn app_finalize_block_halts_at_unsupported_upgrade() {
+    fn migration(state: &mut StateDelta<InterBlockState>) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async move {
+            state.put_raw("test/migrated".to_string(), vec![1]);
+            Ok(())
+        })
+    }
+
+    let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx
+        .put_pending_upgrades(vec![Upgrade {
+            name: "upgrade".to_string(),
+            height: 1,
+        }])
+        .unwrap();
+    app.apply(state_tx);
+    app.prepare_commit(storage.clone()).await.unwrap();
+    app.commit(storage.clone()).await;
+
+    let finalize_block = abci::request::FinalizeBlock {
+        hash: Hash::try_from([0u8; 32].to_vec()).unwrap(),
+        height: 1u32.into(),
+        time: Time::now(),
+        next_validators_hash: Hash::default(),
+        proposer_address: [0u8; 20].to_vec().try_into().unwrap(),
+        txs: generate_rollup_datas_commitment(&[], HashMap::new()).into_transactions(vec![]),
+        decided_last_commit: CommitInfo {
+            votes: vec![],
+            round: Round::default(),
+        },
+        misbehavior: vec![],
+    };
+    let error = app
+        .finalize_block(finalize_block.clone(), storage.clone())
+        .await
+        .unwrap_err();
+    assert!(
+        format!("{error:#}").contains("chain halted at height 1 for upgrade `upgrade`"),
+        "{error:#}"
+    );
+
+    // a binary supporting the upgrade runs its migration and finalizes the block
+    app.migrations = Migrations::new([("upgrade", migration as Migration)]);
+    app.finalize_block(finalize_block, storage.clone())
+        .await
+        .unwrap();
+    app.commit(storage.clone()).await;
+
+    assert!(app.state.get_pending_upgrades().await.unwrap().is_empty());
+    assert_eq!(
+        app.state.get_applied_upgrade("upgrade").await.unwrap(),
+        Some(1)
+    );
+    assert!(app.state.get_raw("test/migrated").await.unwrap().is_some());
+}
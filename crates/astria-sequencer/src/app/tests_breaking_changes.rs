@@ -45,6 +45,7 @@ use prost::{
 use tendermint::{
     abci,
     abci::types::CommitInfo,
+    account,
     block::Round,
     Hash,
     Time,
@@ -300,7 +301,16 @@ async fn app_execute_transaction_with_every_action_snapshot() {
     app.execute_transaction(signed_tx).await.unwrap();
 
     let sudo_address = app.state.get_sudo_address().await.unwrap();
-    app.end_block(1, sudo_address).await.unwrap();
+    app.end_block(
+        1,
+        account::Id::new(sudo_address),
+        &CommitInfo {
+            votes: vec![],
+            round: Round::default(),
+        },
+    )
+    .await
+    .unwrap();
 
     app.prepare_commit(storage.clone()).await.unwrap();
     app.commit(storage.clone()).await;
@@ -22,8 +22,10 @@ use crate::{
         StateWriteExt as _,
     },
     bridge::StateWriteExt as _,
+    evidence::StateReadExt as _,
     ibc::StateWriteExt as _,
     sequence::StateWriteExt as _,
+    staking::StateReadExt as _,
     transaction::StateReadExt as _,
 };
 
@@ -44,6 +46,26 @@ impl ActionHandler for ValidatorUpdate {
             .await
             .wrap_err("failed to get sudo address from state")?;
         ensure!(sudo_address == from, "signer is not the sudo key");
+        ensure!(
+            state
+                .get_staking_parameters()
+                .await
+                .wrap_err("failed to get staking parameters from state")?
+                .is_none(),
+            "validator set is derived from stake and cannot be updated by the sudo key",
+        );
+
+        // jailed validators may only be added back once they are released
+        if self.power > 0 {
+            ensure!(
+                state
+                    .get_jailed_until(self.verification_key.address_bytes())
+                    .await
+                    .wrap_err("failed to get jail status of validator from state")?
+                    .is_none(),
+                "validator is jailed",
+            );
+        }
 
         // ensure that we're not removing the last validator or a validator
         // that doesn't exist, these both cause issues in cometBFT
@@ -61,7 +61,7 @@ impl ValidatorSet {
         self.0.get(&address.into())
     }
 
-    pub(super) fn push_update(&mut self, update: ValidatorUpdate) {
+    pub(crate) fn push_update(&mut self, update: ValidatorUpdate) {
         self.0.insert((&update.verification_key).into(), update);
     }
 
@@ -73,7 +73,7 @@ impl ValidatorSet {
     ///
     /// If the power of a validator is set to 0, remove it from the set.
     /// Otherwise, update the validator's power.
-    pub(super) fn apply_updates(&mut self, validator_updates: ValidatorSet) {
+    pub(crate) fn apply_updates(&mut self, validator_updates: ValidatorSet) {
         for (address, update) in validator_updates.0 {
             match update.power {
                 0 => self.0.remove(&address),
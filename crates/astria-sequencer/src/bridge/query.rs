@@ -267,6 +267,86 @@ pub(crate) async fn bridge_account_last_tx_hash_request(
     }
 }
 
+pub(crate) async fn bridge_account_withdrawal_event_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    use astria_core::protocol::bridge::v1alpha1::BridgeAccountWithdrawalEventResponse;
+
+    let address = match preprocess_request(&params) {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+    let Some(event_id) = params
+        .iter()
+        .find_map(|(k, v)| (k == "event_id").then_some(v))
+    else {
+        return error_query_response(
+            None,
+            AbciErrorCode::INVALID_PARAMETER,
+            "path did not contain event_id parameter",
+        );
+    };
+    // The client hex encodes the event ID so that IDs containing `/` or other characters
+    // reserved in paths survive the round trip.
+    let event_id = match hex::decode(event_id)
+        .map_err(|err| eyre!(err))
+        .and_then(|bytes| String::from_utf8(bytes).map_err(|err| eyre!(err)))
+    {
+        Ok(event_id) => event_id,
+        Err(err) => {
+            return error_query_response(
+                Some(err),
+                AbciErrorCode::INVALID_PARAMETER,
+                "event_id parameter was not a hex encoded utf8 string",
+            );
+        }
+    };
+
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => {
+            return error_query_response(
+                Some(eyre!(err)),
+                AbciErrorCode::INTERNAL_ERROR,
+                "failed to get block height",
+            );
+        }
+    };
+
+    let rollup_block_number = match snapshot
+        .get_withdrawal_event_block_for_bridge_account(&address, &event_id)
+        .await
+    {
+        Ok(rollup_block_number) => rollup_block_number,
+        Err(err) => {
+            return error_query_response(
+                Some(err),
+                AbciErrorCode::INTERNAL_ERROR,
+                "failed getting withdrawal event for provided address",
+            );
+        }
+    };
+    let payload = BridgeAccountWithdrawalEventResponse {
+        height,
+        rollup_block_number,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
 fn preprocess_request(params: &[(String, String)]) -> Result<Address, response::Query> {
     let Some(address) = params
         .iter()
@@ -293,9 +373,15 @@ fn preprocess_request(params: &[(String, String)]) -> Result<Address, response::
 #[cfg(test)]
 mod tests {
     use astria_core::{
-        generated::protocol::bridge::v1alpha1::BridgeAccountInfoResponse as RawBridgeAccountInfoResponse,
+        generated::protocol::bridge::v1alpha1::{
+            BridgeAccountInfoResponse as RawBridgeAccountInfoResponse,
+            BridgeAccountWithdrawalEventResponse as RawBridgeAccountWithdrawalEventResponse,
+        },
         primitive::v1::RollupId,
-        protocol::bridge::v1alpha1::BridgeAccountInfoResponse,
+        protocol::bridge::v1alpha1::{
+            BridgeAccountInfoResponse,
+            BridgeAccountWithdrawalEventResponse,
+        },
     };
     use cnidarium::StateDelta;
 
@@ -360,4 +446,55 @@ mod tests {
         };
         assert_eq!(native, expected);
     }
+
+    #[tokio::test]
+    async fn bridge_account_withdrawal_event_request_decodes_hex_event_id() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX);
+
+        let bridge_address = astria_address(&[0u8; 20]);
+        let event_id = "0xabc/1";
+        state.put_block_height(1);
+        state
+            .check_and_set_withdrawal_event_block_for_bridge_account(bridge_address, event_id, 42)
+            .await
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        let query = request::Query {
+            data: vec![].into(),
+            path: "path".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        };
+
+        let params = vec![
+            ("address".to_string(), bridge_address.to_string()),
+            ("event_id".to_string(), hex::encode(event_id)),
+        ];
+        let resp =
+            bridge_account_withdrawal_event_request(storage.clone(), query.clone(), params).await;
+        assert_eq!(resp.code, 0.into(), "{}", resp.log);
+
+        let proto = RawBridgeAccountWithdrawalEventResponse::decode(resp.value).unwrap();
+        let native = BridgeAccountWithdrawalEventResponse::from_raw(proto);
+        let expected = BridgeAccountWithdrawalEventResponse {
+            height: 1,
+            rollup_block_number: Some(42),
+        };
+        assert_eq!(native, expected);
+
+        let params = vec![
+            ("address".to_string(), bridge_address.to_string()),
+            ("event_id".to_string(), event_id.to_string()),
+        ];
+        let resp = bridge_account_withdrawal_event_request(storage, query, params).await;
+        assert_eq!(
+            resp.code,
+            Code::Err(AbciErrorCode::INVALID_PARAMETER.value())
+        );
+    }
 }
@@ -352,6 +352,30 @@ pub(crate) trait StateReadExt: StateRead + address::StateReadExt {
 
         Ok(Some(TransactionId::new(tx_hash)))
     }
+
+    /// Returns the rollup block number recorded when `withdrawal_event_id` was processed for the
+    /// bridge account at `address`, or `None` if it was not processed.
+    #[instrument(skip_all)]
+    async fn get_withdrawal_event_block_for_bridge_account<T: AddressBytes>(
+        &self,
+        address: &T,
+        withdrawal_event_id: &str,
+    ) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(&bridge_account_withdrawal_event_storage_key(
+                address,
+                withdrawal_event_id,
+            ))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw withdrawal event from state")?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(u64::from_be_bytes(bytes.try_into().expect(
+            "all block numbers stored should be 8 bytes; this is a bug",
+        ))))
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -418,28 +442,21 @@ pub(crate) trait StateWriteExt: StateWrite {
         withdrawal_event_id: &str,
         block_num: u64,
     ) -> Result<()> {
-        let key = bridge_account_withdrawal_event_storage_key(&address, withdrawal_event_id);
-
         // Check if the withdrawal ID has already been used, if so return an error.
-        let bytes = self
-            .get_raw(&key)
-            .await
-            .map_err(anyhow_to_eyre)
-            .wrap_err("failed reading raw withdrawal event from state")?;
-        if let Some(bytes) = bytes {
-            let existing_block_num = u64::from_be_bytes(
-                bytes
-                    .try_into()
-                    .expect("all block numbers stored should be 8 bytes; this is a bug"),
-            );
-
+        if let Some(existing_block_num) = self
+            .get_withdrawal_event_block_for_bridge_account(&address, withdrawal_event_id)
+            .await?
+        {
             bail!(
                 "withdrawal event ID {withdrawal_event_id} used by block number \
                  {existing_block_num}"
             );
         }
 
-        self.put_raw(key, block_num.to_be_bytes().to_vec());
+        self.put_raw(
+            bridge_account_withdrawal_event_storage_key(&address, withdrawal_event_id),
+            block_num.to_be_bytes().to_vec(),
+        );
         Ok(())
     }
 
@@ -556,6 +573,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn withdrawal_event_block_is_recorded_once() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        assert_eq!(
+            state
+                .get_withdrawal_event_block_for_bridge_account(&address, "event-0")
+                .await
+                .unwrap(),
+            None,
+            "no block should be recorded for an unprocessed withdrawal event"
+        );
+
+        state
+            .check_and_set_withdrawal_event_block_for_bridge_account(address, "event-0", 7)
+            .await
+            .unwrap();
+        assert_eq!(
+            state
+                .get_withdrawal_event_block_for_bridge_account(&address, "event-0")
+                .await
+                .unwrap(),
+            Some(7),
+            "stored block for withdrawal event not what was expected"
+        );
+        state
+            .check_and_set_withdrawal_event_block_for_bridge_account(address, "event-0", 8)
+            .await
+            .expect_err("reusing a withdrawal event id must fail");
+    }
+
     #[tokio::test]
     async fn put_bridge_account_rollup_id() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use astria_core::protocol::{
+    evidence::v1alpha1::{
+        Misbehaviour,
+        MisbehaviourKind,
+    },
+    genesis::v1alpha1::GenesisAppState,
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use tendermint::abci::{
+    request::{
+        BeginBlock,
+        EndBlock,
+    },
+    types::MisbehaviorKind,
+};
+use tracing::instrument;
+
+use super::{
+    jail,
+    min_misbehaviour_height,
+    StateReadExt as _,
+    StateWriteExt,
+};
+use crate::{
+    component::Component,
+    staking::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+/// Records evidence of validator misbehaviour and jails misbehaving validators.
+///
+/// Misbehaviour is always recorded. Validators are only jailed (and slashed, if staking is
+/// enabled) if evidence parameters were set at genesis.
+#[derive(Default)]
+pub(crate) struct EvidenceComponent;
+
+#[async_trait::async_trait]
+impl Component for EvidenceComponent {
+    type AppState = GenesisAppState;
+
+    #[instrument(name = "EvidenceComponent::init_chain", skip_all)]
+    async fn init_chain<S: StateWriteExt>(mut state: S, app_state: &Self::AppState) -> Result<()> {
+        if let Some(parameters) = app_state.evidence_parameters() {
+            state
+                .put_evidence_parameters(parameters)
+                .wrap_err("failed to put evidence parameters")?;
+        }
+        Ok(())
+    }
+
+    /// Releases the validators whose jail period ends at this height, then records and
+    /// punishes the misbehaviour reported by cometbft.
+    ///
+    /// Must be called before `AuthorityComponent::begin_block`, which removes the misbehaving
+    /// validators from the validator set.
+    #[instrument(name = "EvidenceComponent::begin_block", skip_all)]
+    async fn begin_block<S: StateWriteExt + 'static>(
+        state: &mut Arc<S>,
+        begin_block: &BeginBlock,
+    ) -> Result<()> {
+        let parameters = state
+            .get_evidence_parameters()
+            .await
+            .wrap_err("failed to get evidence parameters")?;
+        let height = begin_block.header.height.value();
+        let state = Arc::get_mut(state)
+            .ok_or_eyre("must only have one reference to the state; this is a bug")?;
+
+        let releases = state
+            .get_releases(height)
+            .await
+            .wrap_err("failed to get jail releases")?;
+        for address in releases {
+            // the jail period was extended if the release height does not match
+            if state
+                .get_jailed_until(address)
+                .await
+                .wrap_err("failed to get jail status of validator")?
+                != Some(height)
+            {
+                continue;
+            }
+            state.delete_jailed_until(address);
+            // rewriting a staked validator recalculates its voting power at the end of the
+            // block, which adds it back to the validator set
+            if let Some(validator) = state
+                .get_validator(address)
+                .await
+                .wrap_err("failed to get validator")?
+            {
+                state
+                    .put_validator(&validator)
+                    .wrap_err("failed to put validator")?;
+            }
+        }
+        state.delete_releases(height);
+
+        for misbehaviour in &begin_block.byzantine_validators {
+            let address = misbehaviour.validator.address;
+            let kind = match misbehaviour.kind {
+                MisbehaviorKind::DuplicateVote => MisbehaviourKind::DuplicateVote,
+                MisbehaviorKind::LightClientAttack => MisbehaviourKind::LightClientAttack,
+                MisbehaviorKind::Unknown => MisbehaviourKind::Unknown,
+            };
+            state
+                .push_misbehaviour(
+                    address,
+                    Misbehaviour {
+                        kind,
+                        height: misbehaviour.height.value(),
+                    },
+                    min_misbehaviour_height(height, parameters.as_ref()),
+                )
+                .await
+                .wrap_err("failed to record misbehaviour")?;
+            if let Some(parameters) = &parameters {
+                jail(state, address, kind, height, parameters)
+                    .await
+                    .wrap_err("failed to jail misbehaving validator")?;
+            }
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "EvidenceComponent::end_block", skip_all)]
+    async fn end_block<S: StateWriteExt + 'static>(
+        _state: &mut Arc<S>,
+        _end_block: &EndBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::{
+        genesis::v1alpha1::{
+            EvidenceParameters,
+            StakingParameters,
+        },
+        transaction::v1alpha1::action::ValidatorUpdate,
+    };
+    use cnidarium::StateDelta;
+    use tendermint::{
+        abci::types,
+        account,
+        block::{
+            header::Version,
+            Header,
+            Height,
+            Round,
+        },
+        AppHash,
+        Hash,
+        Time,
+    };
+
+    use super::*;
+    use crate::{
+        authority::{
+            StateWriteExt as _,
+            ValidatorSet,
+        },
+        staking::Validator,
+        test_utils::verification_key,
+    };
+
+    fn begin_block(height: u32, byzantine_validators: Vec<types::Misbehavior>) -> BeginBlock {
+        BeginBlock {
+            hash: Hash::default(),
+            header: Header {
+                app_hash: AppHash::try_from(vec![]).unwrap(),
+                chain_id: "test".to_string().try_into().unwrap(),
+                consensus_hash: Hash::default(),
+                data_hash: Some(Hash::default()),
+                evidence_hash: Some(Hash::default()),
+                height: height.into(),
+                last_block_id: None,
+                last_commit_hash: Some(Hash::default()),
+                last_results_hash: Some(Hash::default()),
+                next_validators_hash: Hash::default(),
+                proposer_address: account::Id::new([0; 20]),
+                time: Time::unix_epoch(),
+                validators_hash: Hash::default(),
+                version: Version {
+                    app: 0,
+                    block: 0,
+                },
+            },
+            last_commit_info: types::CommitInfo {
+                round: Round::default(),
+                votes: vec![],
+            },
+            byzantine_validators,
+        }
+    }
+
+    #[tokio::test]
+    async fn begin_block_records_misbehaviour_and_releases_jailed_validators() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = verification_key(1).address_bytes();
+        state
+            .put_evidence_parameters(&EvidenceParameters {
+                jail_period_blocks: 10,
+                max_missed_blocks: 0,
+                slash_percent: 0,
+                max_age_blocks: 0,
+            })
+            .unwrap();
+        state
+            .put_staking_parameters(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 1,
+            })
+            .unwrap();
+        state
+            .put_validator(&Validator {
+                verification_key: verification_key(1),
+                stake: 100,
+            })
+            .unwrap();
+        state
+            .put_validator_set(ValidatorSet::new_from_updates(vec![ValidatorUpdate {
+                power: 100,
+                verification_key: verification_key(1),
+            }]))
+            .unwrap();
+        // commit to clear the validators marked as changed in this block
+        storage.commit(state).await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+
+        let misbehaviour = types::Misbehavior {
+            kind: MisbehaviorKind::DuplicateVote,
+            validator: types::Validator {
+                address,
+                power: 100u32.into(),
+            },
+            height: Height::from(4u32),
+            time: Time::unix_epoch(),
+            total_voting_power: 100u32.into(),
+        };
+        let mut state = Arc::new(state);
+        EvidenceComponent::begin_block(&mut state, &begin_block(5, vec![misbehaviour]))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_misbehaviours(address).await.unwrap(),
+            vec![Misbehaviour {
+                kind: MisbehaviourKind::DuplicateVote,
+                height: 4,
+            }]
+        );
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), Some(15));
+        assert!(state.get_changed_validators().is_empty());
+
+        EvidenceComponent::begin_block(&mut state, &begin_block(15, vec![]))
+            .await
+            .unwrap();
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), None);
+        assert!(state.get_releases(15).await.unwrap().is_empty());
+        assert!(
+            state.get_changed_validators().contains(&address),
+            "released validator must be marked as changed to be added back to the set"
+        );
+    }
+
+    #[tokio::test]
+    async fn begin_block_without_parameters_only_records_misbehaviour() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        let address = verification_key(1).address_bytes();
+        let misbehaviour = types::Misbehavior {
+            kind: MisbehaviorKind::Unknown,
+            validator: types::Validator {
+                address,
+                power: 100u32.into(),
+            },
+            height: Height::from(4u32),
+            time: Time::unix_epoch(),
+            total_voting_power: 100u32.into(),
+        };
+        let mut state = Arc::new(state);
+        EvidenceComponent::begin_block(&mut state, &begin_block(5, vec![misbehaviour]))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_misbehaviours(address).await.unwrap(),
+            vec![Misbehaviour {
+                kind: MisbehaviourKind::Unknown,
+                height: 4,
+            }]
+        );
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), None);
+    }
+}
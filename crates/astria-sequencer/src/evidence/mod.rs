@@ -0,0 +1,407 @@
+pub(crate) mod component;
+pub(crate) mod query;
+mod state_ext;
+
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::{
+        evidence::v1alpha1::{
+            Misbehaviour,
+            MisbehaviourKind,
+        },
+        genesis::v1alpha1::EvidenceParameters,
+        transaction::v1alpha1::action::ValidatorUpdate,
+    },
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateWrite;
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+use tendermint::abci::{
+    types::CommitInfo,
+    Event,
+    EventAttributeIndexExt as _,
+};
+use tracing::warn;
+
+use crate::authority::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+/// Jails the validator at `address` until `jail_period_blocks` after `height` and removes it
+/// from the validator set at the end of the block, unless it is the last validator.
+///
+/// If the validator misbehaved (as opposed to being offline) and has stake bonded to it,
+/// `slash_percent` of its stake is burned.
+async fn jail<S: StateWrite>(
+    state: &mut S,
+    address: [u8; ADDRESS_LEN],
+    kind: MisbehaviourKind,
+    height: u64,
+    parameters: &EvidenceParameters,
+) -> Result<()> {
+    let jailed_until = height
+        .checked_add(parameters.jail_period_blocks)
+        .ok_or_eyre("jail release height overflowed u64")?;
+    let previously_jailed_until = state
+        .get_jailed_until(address)
+        .await
+        .wrap_err("failed to get jail status of validator")?;
+    // never shorten an existing jail period
+    if previously_jailed_until.map_or(true, |previous| previous < jailed_until) {
+        state
+            .put_jailed_until(address, jailed_until)
+            .wrap_err("failed to put jail release height")?;
+        state
+            .push_release(jailed_until, address)
+            .await
+            .wrap_err("failed to queue release from jail")?;
+    }
+    state
+        .put_missed_blocks(address, 0)
+        .wrap_err("failed to reset missed blocks")?;
+
+    let slashed = if kind != MisbehaviourKind::Downtime && parameters.slash_percent > 0 {
+        crate::staking::slash(state, address, parameters.slash_percent)
+            .await
+            .wrap_err("failed to slash validator")?
+    } else {
+        0
+    };
+
+    let mut validator_updates = state
+        .get_validator_updates()
+        .await
+        .wrap_err("failed to get validator updates")?;
+    let mut next_set = state
+        .get_validator_set()
+        .await
+        .wrap_err("failed to get validator set")?;
+    next_set.apply_updates(validator_updates.clone());
+    if let Some(validator) = next_set.get(address) {
+        // cometbft rejects removing the last validator
+        if next_set.len() > 1 {
+            validator_updates.push_update(ValidatorUpdate {
+                power: 0,
+                verification_key: validator.verification_key.clone(),
+            });
+            state
+                .put_validator_updates(validator_updates)
+                .wrap_err("failed to put validator updates")?;
+        } else {
+            warn!(
+                validator = %validator.verification_key,
+                "jailed validator is the last validator; not removing it from the validator set",
+            );
+        }
+    }
+
+    state.record(construct_jail_event(address, kind, jailed_until, slashed));
+    Ok(())
+}
+
+/// Returns the lowest height of misbehaviour that is kept in state at `height`.
+///
+/// Misbehaviour older than `max_age_blocks` is pruned; nothing is pruned if no evidence
+/// parameters are set or `max_age_blocks` is 0.
+fn min_misbehaviour_height(height: u64, parameters: Option<&EvidenceParameters>) -> u64 {
+    match parameters {
+        Some(parameters) if parameters.max_age_blocks > 0 => {
+            height.saturating_sub(parameters.max_age_blocks)
+        }
+        _ => 0,
+    }
+}
+
+/// Updates the number of consecutive blocks that each validator in `last_commit` did not
+/// sign, and jails validators that missed `max_missed_blocks` in a row.
+///
+/// Does nothing if no evidence parameters are set or liveness tracking is disabled.
+pub(crate) async fn track_liveness<S: StateWrite>(
+    state: &mut S,
+    height: u64,
+    last_commit: &CommitInfo,
+) -> Result<()> {
+    let Some(parameters) = state
+        .get_evidence_parameters()
+        .await
+        .wrap_err("failed to get evidence parameters")?
+    else {
+        return Ok(());
+    };
+    if parameters.max_missed_blocks == 0 {
+        return Ok(());
+    }
+
+    for vote in &last_commit.votes {
+        let address = vote.validator.address;
+        let missed_blocks = state
+            .get_missed_blocks(address)
+            .await
+            .wrap_err("failed to get missed blocks")?;
+        if vote.sig_info.is_signed() {
+            if missed_blocks > 0 {
+                state
+                    .put_missed_blocks(address, 0)
+                    .wrap_err("failed to reset missed blocks")?;
+            }
+            continue;
+        }
+        if state
+            .get_jailed_until(address)
+            .await
+            .wrap_err("failed to get jail status of validator")?
+            .is_some()
+        {
+            continue;
+        }
+
+        let missed_blocks = missed_blocks.saturating_add(1);
+        if missed_blocks < parameters.max_missed_blocks {
+            state
+                .put_missed_blocks(address, missed_blocks)
+                .wrap_err("failed to put missed blocks")?;
+            continue;
+        }
+        state
+            .push_misbehaviour(
+                address,
+                Misbehaviour {
+                    kind: MisbehaviourKind::Downtime,
+                    height,
+                },
+                min_misbehaviour_height(height, Some(&parameters)),
+            )
+            .await
+            .wrap_err("failed to record misbehaviour")?;
+        jail(
+            state,
+            address,
+            MisbehaviourKind::Downtime,
+            height,
+            &parameters,
+        )
+        .await
+        .wrap_err("failed to jail offline validator")?;
+    }
+    Ok(())
+}
+
+/// Creates `abci::Event` of kind `evidence.jail` for every jailed validator.
+fn construct_jail_event(
+    address: [u8; ADDRESS_LEN],
+    kind: MisbehaviourKind,
+    jailed_until: u64,
+    slashed: u128,
+) -> Event {
+    Event::new(
+        "evidence.jail",
+        [
+            ("validator", hex::encode(address)).index(),
+            ("kind", kind.to_string()).index(),
+            ("jailedUntil", jailed_until.to_string()).index(),
+            ("slashed", slashed.to_string()).index(),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::genesis::v1alpha1::StakingParameters;
+    use cnidarium::StateDelta;
+    use tendermint::{
+        abci::types::{
+            BlockSignatureInfo,
+            Validator,
+            VoteInfo,
+        },
+        block::{
+            BlockIdFlag,
+            Round,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        authority::ValidatorSet,
+        staking::{
+            self,
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+        test_utils::verification_key,
+    };
+
+    const PARAMETERS: EvidenceParameters = EvidenceParameters {
+        jail_period_blocks: 10,
+        max_missed_blocks: 2,
+        slash_percent: 10,
+        max_age_blocks: 100,
+    };
+
+    fn validator_set() -> ValidatorSet {
+        ValidatorSet::new_from_updates(vec![
+            ValidatorUpdate {
+                power: 10,
+                verification_key: verification_key(1),
+            },
+            ValidatorUpdate {
+                power: 10,
+                verification_key: verification_key(2),
+            },
+        ])
+    }
+
+    fn commit(signed: bool) -> CommitInfo {
+        let sig_info = if signed {
+            BlockSignatureInfo::Flag(BlockIdFlag::Commit)
+        } else {
+            BlockSignatureInfo::Flag(BlockIdFlag::Absent)
+        };
+        CommitInfo {
+            round: Round::default(),
+            votes: vec![VoteInfo {
+                validator: Validator {
+                    address: verification_key(1).address_bytes(),
+                    power: 10u32.into(),
+                },
+                sig_info,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn jail_removes_validator_and_slashes_stake() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = verification_key(1).address_bytes();
+        state.put_validator_set(validator_set()).unwrap();
+        state
+            .put_staking_parameters(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 1,
+            })
+            .unwrap();
+        state
+            .put_validator(&staking::Validator {
+                verification_key: verification_key(1),
+                stake: 100,
+            })
+            .unwrap();
+        state.put_delegation(address, address, 100).unwrap();
+
+        jail(
+            &mut state,
+            address,
+            MisbehaviourKind::DuplicateVote,
+            5,
+            &PARAMETERS,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), Some(15));
+        assert_eq!(state.get_releases(15).await.unwrap(), vec![address]);
+        assert_eq!(
+            state.get_validator(address).await.unwrap().unwrap().stake,
+            90
+        );
+        assert_eq!(
+            state
+                .get_validator_updates()
+                .await
+                .unwrap()
+                .get(address)
+                .unwrap()
+                .power,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn jail_does_not_remove_last_validator() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = verification_key(1).address_bytes();
+        state
+            .put_validator_set(ValidatorSet::new_from_updates(vec![ValidatorUpdate {
+                power: 10,
+                verification_key: verification_key(1),
+            }]))
+            .unwrap();
+
+        jail(
+            &mut state,
+            address,
+            MisbehaviourKind::DuplicateVote,
+            5,
+            &PARAMETERS,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), Some(15));
+        assert!(state
+            .get_validator_updates()
+            .await
+            .unwrap()
+            .get(address)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn track_liveness_jails_validator_after_max_missed_blocks() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = verification_key(1).address_bytes();
+        state.put_validator_set(validator_set()).unwrap();
+        state.put_evidence_parameters(&PARAMETERS).unwrap();
+
+        track_liveness(&mut state, 1, &commit(false)).await.unwrap();
+        assert_eq!(state.get_missed_blocks(address).await.unwrap(), 1);
+        track_liveness(&mut state, 2, &commit(true)).await.unwrap();
+        assert_eq!(state.get_missed_blocks(address).await.unwrap(), 0);
+
+        track_liveness(&mut state, 3, &commit(false)).await.unwrap();
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), None);
+        track_liveness(&mut state, 4, &commit(false)).await.unwrap();
+        assert_eq!(state.get_jailed_until(address).await.unwrap(), Some(14));
+        assert_eq!(state.get_missed_blocks(address).await.unwrap(), 0);
+        assert_eq!(
+            state.get_misbehaviours(address).await.unwrap(),
+            vec![Misbehaviour {
+                kind: MisbehaviourKind::Downtime,
+                height: 4,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn track_liveness_is_disabled_without_evidence_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        track_liveness(&mut state, 1, &commit(false)).await.unwrap();
+        assert_eq!(
+            state
+                .get_missed_blocks(verification_key(1).address_bytes())
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
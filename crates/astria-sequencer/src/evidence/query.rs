@@ -0,0 +1,192 @@
+use astria_core::{
+    primitive::v1::Address,
+    protocol::abci::AbciErrorCode,
+};
+use astria_eyre::eyre::WrapErr as _;
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+    Code,
+};
+
+use crate::{
+    evidence::StateReadExt as _,
+    state_ext::StateReadExt as _,
+};
+
+fn error_query_response(
+    err: Option<astria_eyre::eyre::Error>,
+    code: AbciErrorCode,
+    msg: &str,
+) -> response::Query {
+    let log = match err {
+        Some(err) => format!("{msg}: {err:#}"),
+        None => msg.into(),
+    };
+    response::Query {
+        code: Code::Err(code.value()),
+        info: code.info(),
+        log,
+        ..response::Query::default()
+    }
+}
+
+pub(crate) async fn jail_status_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    use astria_core::protocol::evidence::v1alpha1::JailStatusResponse;
+
+    let address = match preprocess_request(&params) {
+        Ok(address) => address,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    // use latest snapshot, as this is a query for the current jail status
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => {
+            return error_query_response(
+                Some(err),
+                AbciErrorCode::INTERNAL_ERROR,
+                "failed to get block height",
+            );
+        }
+    };
+    let jailed_until = match snapshot.get_jailed_until(address).await {
+        Ok(jailed_until) => jailed_until,
+        Err(err) => {
+            return error_query_response(
+                Some(err),
+                AbciErrorCode::INTERNAL_ERROR,
+                "failed getting jail status for provided address",
+            );
+        }
+    };
+    let missed_blocks = match snapshot.get_missed_blocks(address).await {
+        Ok(missed_blocks) => missed_blocks,
+        Err(err) => {
+            return error_query_response(
+                Some(err),
+                AbciErrorCode::INTERNAL_ERROR,
+                "failed getting missed blocks for provided address",
+            );
+        }
+    };
+    let misbehaviours = match snapshot.get_misbehaviours(address).await {
+        Ok(misbehaviours) => misbehaviours,
+        Err(err) => {
+            return error_query_response(
+                Some(err),
+                AbciErrorCode::INTERNAL_ERROR,
+                "failed getting misbehaviour for provided address",
+            );
+        }
+    };
+    let payload = JailStatusResponse {
+        height,
+        jailed_until,
+        missed_blocks,
+        misbehaviours,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+fn preprocess_request(params: &[(String, String)]) -> Result<Address, response::Query> {
+    let Some(address) = params
+        .iter()
+        .find_map(|(k, v)| (k == "address").then_some(v))
+    else {
+        return Err(error_query_response(
+            None,
+            AbciErrorCode::INVALID_PARAMETER,
+            "path did not contain address parameter",
+        ));
+    };
+    let address = address
+        .parse()
+        .wrap_err("failed to parse argument as address")
+        .map_err(|err| response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: format!("address could not be constructed from provided parameter: {err:#}"),
+            ..response::Query::default()
+        })?;
+    Ok(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::protocol::evidence::v1alpha1::JailStatusResponse as RawJailStatusResponse,
+        protocol::evidence::v1alpha1::{
+            JailStatusResponse,
+            Misbehaviour,
+            MisbehaviourKind,
+        },
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        evidence::StateWriteExt as _,
+        state_ext::StateWriteExt as _,
+        test_utils::astria_address,
+    };
+
+    #[tokio::test]
+    async fn jail_status_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = astria_address(&[1u8; 20]);
+        let misbehaviour = Misbehaviour {
+            kind: MisbehaviourKind::DuplicateVote,
+            height: 1,
+        };
+        state.put_block_height(2);
+        state.put_jailed_until(validator, 12).unwrap();
+        state
+            .push_misbehaviour(validator, misbehaviour, 0)
+            .await
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        let query = request::Query {
+            data: vec![].into(),
+            path: "path".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        };
+
+        let params = vec![("address".to_string(), validator.to_string())];
+        let resp = jail_status_request(storage.clone(), query, params).await;
+        assert_eq!(resp.code, 0.into(), "{}", resp.log);
+
+        let proto = RawJailStatusResponse::decode(resp.value).unwrap();
+        let native = JailStatusResponse::try_from_raw(&proto).unwrap();
+        let expected = JailStatusResponse {
+            height: 2,
+            jailed_until: Some(12),
+            missed_blocks: 0,
+            misbehaviours: vec![misbehaviour],
+        };
+        assert_eq!(native, expected);
+    }
+}
@@ -0,0 +1,412 @@
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::{
+        evidence::v1alpha1::Misbehaviour,
+        genesis::v1alpha1::EvidenceParameters,
+    },
+};
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::instrument;
+
+use crate::accounts::AddressBytes;
+
+/// Wrapper to read and write the evidence parameters from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Parameters {
+    jail_period_blocks: u64,
+    max_missed_blocks: u64,
+    slash_percent: u32,
+    max_age_blocks: u64,
+}
+
+/// Newtype wrapper to read and write the misbehaviour of a validator from rocksdb.
+///
+/// Contains the kind and height of every recorded misbehaviour.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Misbehaviours(Vec<(String, u64)>);
+
+/// Newtype wrapper to read and write a block height from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Height(u64);
+
+/// Newtype wrapper to read and write a number of missed blocks from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct MissedBlocks(u64);
+
+/// Newtype wrapper to read and write the validators released from jail at a height from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Releases(Vec<[u8; ADDRESS_LEN]>);
+
+const EVIDENCE_PARAMETERS_STORAGE_KEY: &str = "evidence/params";
+const MISBEHAVIOUR_PREFIX: &str = "evidence/misbehaviour";
+const JAILED_PREFIX: &str = "evidence/jailed";
+const MISSED_BLOCKS_PREFIX: &str = "evidence/missed";
+const RELEASE_PREFIX: &str = "evidence/release";
+
+struct HexAddress<'a, T>(&'a T);
+impl<'a, T: AddressBytes> std::fmt::Display for HexAddress<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.0.address_bytes() {
+            f.write_fmt(format_args!("{byte:02x}"))?;
+        }
+        Ok(())
+    }
+}
+
+fn misbehaviour_storage_key<T: AddressBytes>(validator: &T) -> String {
+    format!("{MISBEHAVIOUR_PREFIX}/{}", HexAddress(validator))
+}
+
+fn jailed_storage_key<T: AddressBytes>(validator: &T) -> String {
+    format!("{JAILED_PREFIX}/{}", HexAddress(validator))
+}
+
+fn missed_blocks_storage_key<T: AddressBytes>(validator: &T) -> String {
+    format!("{MISSED_BLOCKS_PREFIX}/{}", HexAddress(validator))
+}
+
+fn release_storage_key(height: u64) -> String {
+    // zero-padded so that the keys sort by height
+    format!("{RELEASE_PREFIX}/{height:020}")
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the evidence parameters, or `None` if misbehaving validators are not jailed.
+    #[instrument(skip_all)]
+    async fn get_evidence_parameters(&self) -> Result<Option<EvidenceParameters>> {
+        let Some(bytes) = self
+            .get_raw(EVIDENCE_PARAMETERS_STORAGE_KEY)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw evidence parameters from state")?
+        else {
+            // return none because jailing is optional
+            return Ok(None);
+        };
+        let Parameters {
+            jail_period_blocks,
+            max_missed_blocks,
+            slash_percent,
+            max_age_blocks,
+        } = Parameters::try_from_slice(&bytes).wrap_err("invalid evidence parameters bytes")?;
+        Ok(Some(EvidenceParameters {
+            jail_period_blocks,
+            max_missed_blocks,
+            slash_percent,
+            max_age_blocks,
+        }))
+    }
+
+    /// Returns all misbehaviour recorded for the validator, oldest first.
+    #[instrument(skip_all)]
+    async fn get_misbehaviours<T: AddressBytes>(&self, validator: T) -> Result<Vec<Misbehaviour>> {
+        let Some(bytes) = self
+            .get_raw(&misbehaviour_storage_key(&validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw misbehaviour from state")?
+        else {
+            return Ok(vec![]);
+        };
+        let Misbehaviours(misbehaviours) =
+            Misbehaviours::try_from_slice(&bytes).wrap_err("invalid misbehaviour bytes")?;
+        misbehaviours
+            .into_iter()
+            .map(|(kind, height)| {
+                Ok(Misbehaviour {
+                    kind: kind
+                        .parse()
+                        .wrap_err("invalid misbehaviour kind stored in state")?,
+                    height,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the height at which the validator is released from jail, or `None` if it is
+    /// not jailed.
+    #[instrument(skip_all)]
+    async fn get_jailed_until<T: AddressBytes>(&self, validator: T) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(&jailed_storage_key(&validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw jail release height from state")?
+        else {
+            return Ok(None);
+        };
+        let Height(height) =
+            Height::try_from_slice(&bytes).wrap_err("invalid jail release height bytes")?;
+        Ok(Some(height))
+    }
+
+    /// Returns the number of consecutive blocks the validator did not sign.
+    #[instrument(skip_all)]
+    async fn get_missed_blocks<T: AddressBytes>(&self, validator: T) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(&missed_blocks_storage_key(&validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw missed blocks from state")?
+        else {
+            return Ok(0);
+        };
+        let MissedBlocks(missed_blocks) =
+            MissedBlocks::try_from_slice(&bytes).wrap_err("invalid missed blocks bytes")?;
+        Ok(missed_blocks)
+    }
+
+    /// Returns the addresses of the validators that are released from jail at `height`.
+    #[instrument(skip_all)]
+    async fn get_releases(&self, height: u64) -> Result<Vec<[u8; ADDRESS_LEN]>> {
+        let Some(bytes) = self
+            .get_raw(&release_storage_key(height))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw jail releases from state")?
+        else {
+            return Ok(vec![]);
+        };
+        let Releases(releases) =
+            Releases::try_from_slice(&bytes).wrap_err("invalid jail releases bytes")?;
+        Ok(releases)
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_evidence_parameters(&mut self, parameters: &EvidenceParameters) -> Result<()> {
+        let bytes = borsh::to_vec(&Parameters {
+            jail_period_blocks: parameters.jail_period_blocks,
+            max_missed_blocks: parameters.max_missed_blocks,
+            slash_percent: parameters.slash_percent,
+            max_age_blocks: parameters.max_age_blocks,
+        })
+        .wrap_err("failed to serialize evidence parameters")?;
+        self.put_raw(EVIDENCE_PARAMETERS_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Appends `misbehaviour` to the misbehaviour recorded for the validator, dropping all
+    /// recorded misbehaviour below `min_height`.
+    #[instrument(skip_all)]
+    async fn push_misbehaviour<T: AddressBytes>(
+        &mut self,
+        validator: T,
+        misbehaviour: Misbehaviour,
+        min_height: u64,
+    ) -> Result<()> {
+        let mut misbehaviours = self
+            .get_misbehaviours(&validator)
+            .await
+            .wrap_err("failed to get misbehaviour")?;
+        misbehaviours.retain(|recorded| recorded.height >= min_height);
+        misbehaviours.push(misbehaviour);
+        let bytes = borsh::to_vec(&Misbehaviours(
+            misbehaviours
+                .into_iter()
+                .map(|misbehaviour| (misbehaviour.kind.to_string(), misbehaviour.height))
+                .collect(),
+        ))
+        .wrap_err("failed to serialize misbehaviour")?;
+        self.put_raw(misbehaviour_storage_key(&validator), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_jailed_until<T: AddressBytes>(&mut self, validator: T, height: u64) -> Result<()> {
+        let bytes =
+            borsh::to_vec(&Height(height)).wrap_err("failed to serialize jail release height")?;
+        self.put_raw(jailed_storage_key(&validator), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn delete_jailed_until<T: AddressBytes>(&mut self, validator: T) {
+        self.delete(jailed_storage_key(&validator));
+    }
+
+    #[instrument(skip_all)]
+    fn put_missed_blocks<T: AddressBytes>(
+        &mut self,
+        validator: T,
+        missed_blocks: u64,
+    ) -> Result<()> {
+        let key = missed_blocks_storage_key(&validator);
+        if missed_blocks == 0 {
+            self.delete(key);
+            return Ok(());
+        }
+        self.put_raw(
+            key,
+            borsh::to_vec(&MissedBlocks(missed_blocks))
+                .wrap_err("failed to serialize missed blocks")?,
+        );
+        Ok(())
+    }
+
+    /// Queues the validator to be released from jail at `height`.
+    #[instrument(skip_all)]
+    async fn push_release<T: AddressBytes>(&mut self, height: u64, validator: T) -> Result<()> {
+        let mut releases = self
+            .get_releases(height)
+            .await
+            .wrap_err("failed to get jail releases")?;
+        releases.push(validator.address_bytes());
+        let bytes =
+            borsh::to_vec(&Releases(releases)).wrap_err("failed to serialize jail releases")?;
+        self.put_raw(release_storage_key(height), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn delete_releases(&mut self, height: u64) {
+        self.delete(release_storage_key(height));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::{
+        evidence::v1alpha1::{
+            Misbehaviour,
+            MisbehaviourKind,
+        },
+        genesis::v1alpha1::EvidenceParameters,
+    };
+    use cnidarium::StateDelta;
+
+    use super::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn evidence_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(
+            state.get_evidence_parameters().await.unwrap().is_none(),
+            "no evidence parameters should exist at first"
+        );
+
+        let parameters = EvidenceParameters {
+            jail_period_blocks: 10,
+            max_missed_blocks: 5,
+            slash_percent: 1,
+            max_age_blocks: 100,
+        };
+        state.put_evidence_parameters(&parameters).unwrap();
+        assert_eq!(
+            state.get_evidence_parameters().await.unwrap(),
+            Some(parameters),
+            "stored evidence parameters were not what was expected"
+        );
+    }
+
+    #[tokio::test]
+    async fn misbehaviours_are_appended() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = [1; 20];
+        assert!(state.get_misbehaviours(validator).await.unwrap().is_empty());
+
+        let first = Misbehaviour {
+            kind: MisbehaviourKind::DuplicateVote,
+            height: 3,
+        };
+        let second = Misbehaviour {
+            kind: MisbehaviourKind::Downtime,
+            height: 7,
+        };
+        state.push_misbehaviour(validator, first, 0).await.unwrap();
+        state.push_misbehaviour(validator, second, 0).await.unwrap();
+        assert_eq!(
+            state.get_misbehaviours(validator).await.unwrap(),
+            vec![first, second]
+        );
+        assert!(state.get_misbehaviours([2; 20]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn misbehaviours_below_min_height_are_pruned() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = [1; 20];
+        let first = Misbehaviour {
+            kind: MisbehaviourKind::DuplicateVote,
+            height: 3,
+        };
+        let second = Misbehaviour {
+            kind: MisbehaviourKind::Downtime,
+            height: 7,
+        };
+        let third = Misbehaviour {
+            kind: MisbehaviourKind::LightClientAttack,
+            height: 12,
+        };
+        state.push_misbehaviour(validator, first, 0).await.unwrap();
+        state.push_misbehaviour(validator, second, 0).await.unwrap();
+        state.push_misbehaviour(validator, third, 7).await.unwrap();
+        assert_eq!(
+            state.get_misbehaviours(validator).await.unwrap(),
+            vec![second, third]
+        );
+    }
+
+    #[tokio::test]
+    async fn jail_and_liveness_records() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = [1; 20];
+        assert_eq!(state.get_jailed_until(validator).await.unwrap(), None);
+        state.put_jailed_until(validator, 10).unwrap();
+        assert_eq!(state.get_jailed_until(validator).await.unwrap(), Some(10));
+        state.delete_jailed_until(validator);
+        assert_eq!(state.get_jailed_until(validator).await.unwrap(), None);
+
+        assert_eq!(state.get_missed_blocks(validator).await.unwrap(), 0);
+        state.put_missed_blocks(validator, 3).unwrap();
+        assert_eq!(state.get_missed_blocks(validator).await.unwrap(), 3);
+        state.put_missed_blocks(validator, 0).unwrap();
+        assert_eq!(state.get_missed_blocks(validator).await.unwrap(), 0);
+
+        state.push_release(10, validator).await.unwrap();
+        state.push_release(10, [2; 20]).await.unwrap();
+        assert_eq!(
+            state.get_releases(10).await.unwrap(),
+            vec![validator, [2; 20]]
+        );
+        state.delete_releases(10);
+        assert!(state.get_releases(10).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use astria_core::protocol::transaction::v1alpha1::action::FeeDistributionChangeAction;
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+
+use super::StateWriteExt as _;
+use crate::{
+    address::StateReadExt as _,
+    app::ActionHandler,
+    authority::StateReadExt as _,
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for FeeDistributionChangeAction {
+    async fn check_stateless(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let from = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+
+        state
+            .ensure_base_prefix(self.new_distribution.treasury_address())
+            .await
+            .wrap_err("treasury address has an unsupported prefix")?;
+
+        state.put_fee_distribution(&self.new_distribution);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::TransactionId,
+        protocol::transaction::v1alpha1::action::{
+            FeeDistribution,
+            FeeDistributionChangeAction,
+        },
+    };
+    use cnidarium::StateDelta;
+
+    use crate::{
+        address::StateWriteExt as _,
+        app::ActionHandler as _,
+        authority::StateWriteExt as _,
+        fee_distribution::StateReadExt as _,
+        test_utils::{
+            assert_eyre_error,
+            astria_address,
+            ASTRIA_PREFIX,
+        },
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    #[tokio::test]
+    async fn fee_distribution_change_action_executes_as_expected() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX);
+        let sudo_address = astria_address(&[1u8; 20]);
+        state.put_sudo_address(sudo_address).unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: sudo_address.bytes(),
+            transaction_id: TransactionId::new([0; 32]),
+            source_action_index: 0,
+        });
+
+        let new_distribution =
+            FeeDistribution::try_new(40, 40, 10, 10, astria_address(&[2u8; 20])).unwrap();
+        let action = FeeDistributionChangeAction {
+            new_distribution: new_distribution.clone(),
+        };
+        action.check_and_execute(&mut state).await.unwrap();
+
+        assert_eq!(
+            state.get_fee_distribution().await.unwrap(),
+            Some(new_distribution)
+        );
+    }
+
+    #[tokio::test]
+    async fn fee_distribution_change_action_fails_if_signer_is_not_sudo() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX);
+        state.put_sudo_address(astria_address(&[1u8; 20])).unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: [3u8; 20],
+            transaction_id: TransactionId::new([0; 32]),
+            source_action_index: 0,
+        });
+
+        let action = FeeDistributionChangeAction {
+            new_distribution: FeeDistribution::try_new(40, 40, 10, 10, astria_address(&[2u8; 20]))
+                .unwrap(),
+        };
+        assert_eyre_error(
+            &action.check_and_execute(&mut state).await.unwrap_err(),
+            "signer is not the sudo key",
+        );
+        assert!(state.get_fee_distribution().await.unwrap().is_none());
+    }
+}
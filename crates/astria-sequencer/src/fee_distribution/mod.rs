@@ -0,0 +1,366 @@
+mod action;
+mod state_ext;
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1alpha1::action::FeeDistribution,
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateWrite;
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+use tendermint::abci::{
+    types::CommitInfo,
+    Event,
+    EventAttributeIndexExt as _,
+};
+
+use crate::{
+    accounts::StateWriteExt as _,
+    address::StateReadExt as _,
+    assets::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    authority::StateReadExt as _,
+};
+
+/// The recipient of a share of the block fees.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Recipient {
+    /// The sudo address, which receives all fees if no fee distribution is set.
+    Sudo([u8; ADDRESS_LEN]),
+    Proposer([u8; ADDRESS_LEN]),
+    Validator([u8; ADDRESS_LEN]),
+    Treasury([u8; ADDRESS_LEN]),
+    Burn,
+}
+
+impl Recipient {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Sudo(_) => "sudo",
+            Self::Proposer(_) => "proposer",
+            Self::Validator(_) => "validator",
+            Self::Treasury(_) => "treasury",
+            Self::Burn => "burn",
+        }
+    }
+
+    fn address_bytes(&self) -> Option<[u8; ADDRESS_LEN]> {
+        match self {
+            Self::Sudo(address)
+            | Self::Proposer(address)
+            | Self::Validator(address)
+            | Self::Treasury(address) => Some(*address),
+            Self::Burn => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Payout {
+    recipient: Recipient,
+    amount: u128,
+}
+
+/// Pays out the fees collected during the current block and clears them.
+///
+/// If no fee distribution is set in state, all fees are paid to the sudo address. Otherwise
+/// they are split between the block proposer, the validators that signed the last commit
+/// (weighted by their voting power), the treasury and burned. Only the native asset is burned;
+/// the burn share of all other assets is paid to the treasury. Any amount that cannot be split
+/// evenly is paid to the proposer, as is the validators' share if nobody signed the last commit.
+///
+/// An event is recorded for every payout.
+pub(crate) async fn distribute_block_fees<S: StateWrite>(
+    state: &mut S,
+    proposer_address: [u8; ADDRESS_LEN],
+    last_commit: &CommitInfo,
+) -> Result<()> {
+    let fees = state
+        .get_block_fees()
+        .await
+        .wrap_err("failed to get block fees")?;
+    let distribution = state
+        .get_fee_distribution()
+        .await
+        .wrap_err("failed to get fee distribution")?;
+
+    let payouts: Vec<(asset::IbcPrefixed, Payout)> = if let Some(distribution) = distribution {
+        let native_asset = state
+            .get_native_asset()
+            .await
+            .wrap_err("failed to get native asset")?
+            .to_ibc_prefixed();
+        let signers: Vec<([u8; ADDRESS_LEN], u64)> = last_commit
+            .votes
+            .iter()
+            .filter(|vote| vote.sig_info.is_signed())
+            .map(|vote| (vote.validator.address, vote.validator.power.value()))
+            .collect();
+        let mut payouts = vec![];
+        for (asset, amount) in fees {
+            let split = split_fee(
+                &distribution,
+                proposer_address,
+                &signers,
+                amount,
+                asset == native_asset,
+            )
+            .wrap_err_with(|| format!("failed to split block fees of asset `{asset}`"))?;
+            payouts.extend(split.into_iter().map(|payout| (asset, payout)));
+        }
+        payouts
+    } else {
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to get sudo address")?;
+        fees.into_iter()
+            .map(|(asset, amount)| {
+                (
+                    asset,
+                    Payout {
+                        recipient: Recipient::Sudo(sudo_address),
+                        amount,
+                    },
+                )
+            })
+            .collect()
+    };
+
+    for (asset, payout) in payouts {
+        let recipient_address = match payout.recipient.address_bytes() {
+            Some(address_bytes) => {
+                state
+                    .increase_balance(address_bytes, asset, payout.amount)
+                    .await
+                    .wrap_err("failed to increase fee recipient balance")?;
+                let address = state
+                    .try_base_prefixed(&address_bytes)
+                    .await
+                    .wrap_err("failed to construct fee recipient address")?;
+                Some(address.to_string())
+            }
+            None => None,
+        };
+        state.record(construct_fee_distribution_event(
+            payout.recipient.kind(),
+            recipient_address,
+            &asset,
+            payout.amount,
+        ));
+    }
+
+    state.clear_block_fees().await;
+    Ok(())
+}
+
+/// Splits `amount` of a single asset according to `distribution`.
+///
+/// Payouts of zero are omitted.
+fn split_fee(
+    distribution: &FeeDistribution,
+    proposer_address: [u8; ADDRESS_LEN],
+    signers: &[([u8; ADDRESS_LEN], u64)],
+    amount: u128,
+    is_native_asset: bool,
+) -> Result<Vec<Payout>> {
+    let mut payouts = Vec::with_capacity(signers.len().saturating_add(3));
+
+    let total_power = signers
+        .iter()
+        .try_fold(0u64, |total, (_, power)| total.checked_add(*power))
+        .ok_or_eyre("total voting power of last commit signers overflowed u64")?;
+    let validators_amount = percent_of(amount, distribution.validators_percent())?;
+    let mut paid_to_validators = 0u128;
+    if total_power > 0 {
+        for (address, power) in signers {
+            let share = mul_div(validators_amount, *power, total_power)
+                .ok_or_eyre("failed to calculate validator share")?;
+            paid_to_validators = paid_to_validators
+                .checked_add(share)
+                .ok_or_eyre("validator shares overflowed u128")?;
+            payouts.push(Payout {
+                recipient: Recipient::Validator(*address),
+                amount: share,
+            });
+        }
+    }
+
+    let mut treasury_amount = percent_of(amount, distribution.treasury_percent())?;
+    let mut burn_amount = percent_of(amount, distribution.burn_percent())?;
+    if !is_native_asset {
+        treasury_amount = treasury_amount
+            .checked_add(burn_amount)
+            .ok_or_eyre("treasury share overflowed u128")?;
+        burn_amount = 0;
+    }
+    payouts.push(Payout {
+        recipient: Recipient::Treasury(distribution.treasury_address().bytes()),
+        amount: treasury_amount,
+    });
+    payouts.push(Payout {
+        recipient: Recipient::Burn,
+        amount: burn_amount,
+    });
+
+    // all other shares are rounded down and add up to at most 100%, so the proposer receives
+    // its own share plus any remainder
+    let proposer_amount = amount
+        .checked_sub(paid_to_validators)
+        .and_then(|rest| rest.checked_sub(treasury_amount))
+        .and_then(|rest| rest.checked_sub(burn_amount))
+        .ok_or_eyre("fee shares exceeded the total amount")?;
+    payouts.push(Payout {
+        recipient: Recipient::Proposer(proposer_address),
+        amount: proposer_amount,
+    });
+
+    payouts.retain(|payout| payout.amount > 0);
+    Ok(payouts)
+}
+
+fn percent_of(amount: u128, percent: u32) -> Result<u128> {
+    mul_div(amount, percent.into(), 100).ok_or_eyre("failed to calculate percentage of fees")
+}
+
+/// Calculates `amount * numerator / denominator`, rounded down.
+///
+/// Unlike the naive calculation this does not overflow for large amounts as long as
+/// `numerator` is not greater than `denominator`. Returns `None` if `denominator` is zero.
+pub(crate) fn mul_div(amount: u128, numerator: u64, denominator: u64) -> Option<u128> {
+    let numerator = u128::from(numerator);
+    let denominator = u128::from(denominator);
+    let whole = amount.checked_div(denominator)?.checked_mul(numerator)?;
+    let fraction = amount
+        .checked_rem(denominator)?
+        .checked_mul(numerator)?
+        .checked_div(denominator)?;
+    whole.checked_add(fraction)
+}
+
+/// Creates `abci::Event` of kind `fees.distribution` for every payout of block fees.
+fn construct_fee_distribution_event(
+    recipient_kind: &'static str,
+    recipient: Option<String>,
+    asset: &asset::IbcPrefixed,
+    amount: u128,
+) -> Event {
+    let mut attributes = vec![("recipientKind", recipient_kind.to_string()).index()];
+    if let Some(recipient) = recipient {
+        attributes.push(("recipient", recipient).index());
+    }
+    attributes.push(("asset", asset.to_string()).index());
+    attributes.push(("amount", amount.to_string()).index());
+    Event::new("fees.distribution", attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::astria_address;
+
+    const PROPOSER: [u8; ADDRESS_LEN] = [1; ADDRESS_LEN];
+    const TREASURY: [u8; ADDRESS_LEN] = [2; ADDRESS_LEN];
+    const VALIDATOR_A: [u8; ADDRESS_LEN] = [3; ADDRESS_LEN];
+    const VALIDATOR_B: [u8; ADDRESS_LEN] = [4; ADDRESS_LEN];
+
+    fn distribution(proposer: u32, validators: u32, treasury: u32, burn: u32) -> FeeDistribution {
+        FeeDistribution::try_new(
+            proposer,
+            validators,
+            treasury,
+            burn,
+            astria_address(&TREASURY),
+        )
+        .unwrap()
+    }
+
+    fn payout(recipient: Recipient, amount: u128) -> Payout {
+        Payout {
+            recipient,
+            amount,
+        }
+    }
+
+    #[test]
+    fn fees_are_split_by_percentage_and_power() {
+        let payouts = split_fee(
+            &distribution(40, 30, 20, 10),
+            PROPOSER,
+            &[(VALIDATOR_A, 2), (VALIDATOR_B, 1)],
+            1000,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                payout(Recipient::Validator(VALIDATOR_A), 200),
+                payout(Recipient::Validator(VALIDATOR_B), 100),
+                payout(Recipient::Treasury(TREASURY), 200),
+                payout(Recipient::Burn, 100),
+                payout(Recipient::Proposer(PROPOSER), 400),
+            ],
+            payouts,
+        );
+    }
+
+    #[test]
+    fn remainder_is_paid_to_proposer() {
+        let payouts = split_fee(
+            &distribution(0, 100, 0, 0),
+            PROPOSER,
+            &[(VALIDATOR_A, 1), (VALIDATOR_B, 1)],
+            3,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                payout(Recipient::Validator(VALIDATOR_A), 1),
+                payout(Recipient::Validator(VALIDATOR_B), 1),
+                payout(Recipient::Proposer(PROPOSER), 1),
+            ],
+            payouts,
+        );
+    }
+
+    #[test]
+    fn validators_share_is_paid_to_proposer_without_signers() {
+        let payouts = split_fee(&distribution(50, 50, 0, 0), PROPOSER, &[], 100, true).unwrap();
+        assert_eq!(vec![payout(Recipient::Proposer(PROPOSER), 100)], payouts);
+    }
+
+    #[test]
+    fn burn_share_of_non_native_asset_is_paid_to_treasury() {
+        let payouts = split_fee(&distribution(50, 0, 20, 30), PROPOSER, &[], 100, false).unwrap();
+        assert_eq!(
+            vec![
+                payout(Recipient::Treasury(TREASURY), 50),
+                payout(Recipient::Proposer(PROPOSER), 50),
+            ],
+            payouts,
+        );
+    }
+
+    #[test]
+    fn mul_div_does_not_overflow() {
+        assert_eq!(
+            336_879_543_251_729_078_828_740_861_357_450_529_340,
+            percent_of(u128::MAX, 99).unwrap()
+        );
+        assert_eq!(Some(u128::MAX / 3), mul_div(u128::MAX, 1, 3));
+        assert_eq!(None, mul_div(1, 0, 0));
+    }
+}
@@ -0,0 +1,102 @@
+use astria_core::{
+    generated::protocol::transactions::v1alpha1 as raw,
+    protocol::transaction::v1alpha1::action::FeeDistribution,
+    Protobuf as _,
+};
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use prost::Message as _;
+use tracing::instrument;
+
+const FEE_DISTRIBUTION_STORAGE_KEY: &str = "feedistribution";
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    #[instrument(skip_all)]
+    async fn get_fee_distribution(&self) -> Result<Option<FeeDistribution>> {
+        let Some(bytes) = self
+            .get_raw(FEE_DISTRIBUTION_STORAGE_KEY)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw fee distribution from state")?
+        else {
+            // return none because the fee distribution is optional
+            return Ok(None);
+        };
+        let raw = raw::FeeDistribution::decode(bytes.as_slice())
+            .wrap_err("invalid fee distribution bytes")?;
+        let distribution = FeeDistribution::try_from_raw(raw)
+            .wrap_err("failed converting raw fee distribution from state")?;
+        Ok(Some(distribution))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_fee_distribution(&mut self, distribution: &FeeDistribution) {
+        self.put_raw(
+            FEE_DISTRIBUTION_STORAGE_KEY.to_string(),
+            distribution.to_raw().encode_to_vec(),
+        );
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1alpha1::action::FeeDistribution;
+    use cnidarium::StateDelta;
+
+    use super::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+    use crate::test_utils::astria_address;
+
+    #[tokio::test]
+    async fn fee_distribution() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        // doesn't exist at first
+        assert!(
+            state.get_fee_distribution().await.unwrap().is_none(),
+            "no fee distribution should exist at first"
+        );
+
+        // can write new
+        let distribution =
+            FeeDistribution::try_new(50, 30, 20, 0, astria_address(&[1u8; 20])).unwrap();
+        state.put_fee_distribution(&distribution);
+        assert_eq!(
+            state.get_fee_distribution().await.unwrap(),
+            Some(distribution),
+            "stored fee distribution was not what was expected"
+        );
+
+        // can rewrite with new value
+        let distribution =
+            FeeDistribution::try_new(10, 20, 30, 40, astria_address(&[2u8; 20])).unwrap();
+        state.put_fee_distribution(&distribution);
+        assert_eq!(
+            state.get_fee_distribution().await.unwrap(),
+            Some(distribution),
+            "updated fee distribution was not what was expected"
+        );
+    }
+}
@@ -10,7 +10,9 @@ pub(crate) mod bridge;
 mod build_info;
 pub(crate) mod component;
 pub mod config;
+pub(crate) mod evidence;
 pub(crate) mod fee_asset_change;
+pub(crate) mod fee_distribution;
 pub(crate) mod grpc;
 pub(crate) mod ibc;
 mod mempool;
@@ -19,11 +21,13 @@ pub(crate) mod proposal;
 pub(crate) mod sequence;
 mod sequencer;
 pub(crate) mod service;
+pub(crate) mod staking;
 pub(crate) mod state_ext;
 pub(crate) mod storage_keys;
 #[cfg(any(test, feature = "benchmark"))]
 pub(crate) mod test_utils;
 pub(crate) mod transaction;
+pub(crate) mod upgrade;
 mod utils;
 
 pub use build_info::BUILD_INFO;
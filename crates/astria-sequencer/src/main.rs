@@ -22,7 +22,7 @@ async fn main() -> ExitCode {
             .expect("build info is serializable because it contains only unicode fields")
     );
 
-    let cfg: Config = match config::get() {
+    let cfg: Config = match config::get_from_args() {
         Ok(cfg) => cfg,
         Err(e) => {
             eprintln!("failed to read configuration:\n{e:?}");
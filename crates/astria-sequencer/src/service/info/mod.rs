@@ -87,6 +87,24 @@ impl Info {
                 crate::bridge::query::bridge_account_info_request,
             )
             .wrap_err("invalid path: `bridge/account_info/:address`")?;
+        query_router
+            .insert(
+                "bridge/withdrawal_event/:address/:event_id",
+                crate::bridge::query::bridge_account_withdrawal_event_request,
+            )
+            .wrap_err("invalid path: `bridge/withdrawal_event/:address/:event_id`")?;
+        query_router
+            .insert(
+                "evidence/jail_status/:address",
+                crate::evidence::query::jail_status_request,
+            )
+            .wrap_err("invalid path: `evidence/jail_status/:address`")?;
+        query_router
+            .insert(
+                "upgrade/pending",
+                crate::upgrade::query::pending_upgrades_request,
+            )
+            .wrap_err("invalid path: `upgrade/pending`")?;
         Ok(Self {
             storage,
             query_router,
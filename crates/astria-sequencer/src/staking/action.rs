@@ -0,0 +1,379 @@
+use astria_core::{
+    primitive::v1::{
+        asset,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1alpha1::action::{
+        BondAction,
+        UnbondAction,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+
+use super::{
+    StateReadExt as _,
+    StateWriteExt as _,
+    Unbonding,
+    Validator,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    app::ActionHandler,
+    assets::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    state_ext::StateReadExt as _,
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for BondAction {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(self.amount > 0, "amount must be greater than zero");
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let from = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+        ensure!(
+            state
+                .get_staking_parameters()
+                .await
+                .wrap_err("failed to get staking parameters")?
+                .is_some(),
+            "staking is not enabled; the validator set is managed by the sudo address",
+        );
+
+        let validator_address = self.validator.address_bytes();
+        let mut validator = match state
+            .get_validator(validator_address)
+            .await
+            .wrap_err("failed to get validator")?
+        {
+            Some(validator) => validator,
+            None => {
+                ensure!(
+                    from == validator_address,
+                    "validator is not registered; a validator registers by bonding to its own key",
+                );
+                Validator {
+                    verification_key: self.validator.clone(),
+                    stake: 0,
+                }
+            }
+        };
+
+        charge_fee(&mut state, from, &self.fee_asset, BondAction::full_name()).await?;
+
+        let native_asset = state
+            .get_native_asset()
+            .await
+            .wrap_err("failed to get native asset")?;
+        state
+            .decrease_balance(from, &native_asset, self.amount)
+            .await
+            .wrap_err("failed decreasing signer balance by bonded amount")?;
+
+        let delegation = state
+            .get_delegation(validator_address, from)
+            .await
+            .wrap_err("failed to get delegation")?
+            .checked_add(self.amount)
+            .ok_or_eyre("delegation overflowed u128")?;
+        state
+            .put_delegation(validator_address, from, delegation)
+            .wrap_err("failed to put delegation")?;
+
+        validator.stake = validator
+            .stake
+            .checked_add(self.amount)
+            .ok_or_eyre("validator stake overflowed u128")?;
+        state
+            .put_validator(&validator)
+            .wrap_err("failed to put validator")?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ActionHandler for UnbondAction {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(self.amount > 0, "amount must be greater than zero");
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let from = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+        let parameters = state
+            .get_staking_parameters()
+            .await
+            .wrap_err("failed to get staking parameters")?
+            .ok_or_eyre(
+                "staking is not enabled; the validator set is managed by the sudo address",
+            )?;
+
+        let validator_address = self.validator.address_bytes();
+        let mut validator = state
+            .get_validator(validator_address)
+            .await
+            .wrap_err("failed to get validator")?
+            .ok_or_eyre("validator is not registered")?;
+        let delegation = state
+            .get_delegation(validator_address, from)
+            .await
+            .wrap_err("failed to get delegation")?
+            .checked_sub(self.amount)
+            .ok_or_eyre("amount exceeds the amount bonded to the validator by the signer")?;
+
+        charge_fee(&mut state, from, &self.fee_asset, UnbondAction::full_name()).await?;
+
+        state
+            .put_delegation(validator_address, from, delegation)
+            .wrap_err("failed to put delegation")?;
+        validator.stake = validator
+            .stake
+            .checked_sub(self.amount)
+            .ok_or_eyre("amount exceeds the stake of the validator")?;
+        state
+            .put_validator(&validator)
+            .wrap_err("failed to put validator")?;
+
+        let release_height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?
+            .checked_add(parameters.unbonding_period_blocks)
+            .ok_or_eyre("unbonding release height overflowed u64")?;
+        state
+            .push_unbonding(
+                release_height,
+                Unbonding {
+                    owner: from,
+                    amount: self.amount,
+                },
+            )
+            .await
+            .wrap_err("failed to queue unbonding")?;
+        Ok(())
+    }
+}
+
+/// Charges the transfer base fee for bonding and unbonding.
+async fn charge_fee<S: StateWrite>(
+    state: &mut S,
+    from: [u8; ADDRESS_LEN],
+    fee_asset: &asset::Denom,
+    action_name: String,
+) -> Result<()> {
+    ensure!(
+        state
+            .is_allowed_fee_asset(fee_asset)
+            .await
+            .wrap_err("failed to check allowed fee assets in state")?,
+        "invalid fee asset",
+    );
+    let fee = state
+        .get_transfer_base_fee()
+        .await
+        .wrap_err("failed to get transfer base fee")?;
+    state
+        .get_and_increase_block_fees(fee_asset, fee, action_name)
+        .await
+        .wrap_err("failed to add to block fees")?;
+    state
+        .decrease_balance(from, fee_asset, fee)
+        .await
+        .wrap_err("failed decreasing signer balance for fee payment")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::TransactionId,
+        protocol::{
+            genesis::v1alpha1::StakingParameters,
+            transaction::v1alpha1::action::{
+                BondAction,
+                UnbondAction,
+            },
+        },
+    };
+    use cnidarium::StateDelta;
+
+    use crate::{
+        accounts::{
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+        app::ActionHandler as _,
+        assets::StateWriteExt as _,
+        staking::{
+            StateReadExt as _,
+            StateWriteExt as _,
+            Unbonding,
+        },
+        state_ext::StateWriteExt as _,
+        test_utils::{
+            assert_eyre_error,
+            nria,
+        },
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    async fn setup(state: &mut StateDelta<cnidarium::Snapshot>, signer: [u8; 20]) {
+        state
+            .put_staking_parameters(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 100,
+            })
+            .unwrap();
+        state.put_native_asset(&nria());
+        state.put_allowed_fee_asset(&nria());
+        state.put_transfer_base_fee(1).unwrap();
+        state.put_block_height(5);
+        state
+            .increase_balance(signer, &nria(), 1_000)
+            .await
+            .unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: signer,
+            transaction_id: TransactionId::new([0; 32]),
+            source_action_index: 0,
+        });
+    }
+
+    #[tokio::test]
+    async fn bond_registers_validator_and_unbond_queues_release() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = SigningKey::from([1; 32]).verification_key();
+        let validator_address = validator.address_bytes();
+        setup(&mut state, validator_address).await;
+
+        BondAction {
+            validator: validator.clone(),
+            amount: 500,
+            fee_asset: nria().into(),
+        }
+        .check_and_execute(&mut state)
+        .await
+        .unwrap();
+        assert_eq!(
+            state
+                .get_validator(validator_address)
+                .await
+                .unwrap()
+                .unwrap()
+                .stake,
+            500
+        );
+        assert_eq!(
+            state
+                .get_account_balance(validator_address, &nria())
+                .await
+                .unwrap(),
+            499
+        );
+
+        UnbondAction {
+            validator: validator.clone(),
+            amount: 200,
+            fee_asset: nria().into(),
+        }
+        .check_and_execute(&mut state)
+        .await
+        .unwrap();
+        assert_eq!(
+            state
+                .get_validator(validator_address)
+                .await
+                .unwrap()
+                .unwrap()
+                .stake,
+            300
+        );
+        assert_eq!(
+            state
+                .get_delegation(validator_address, validator_address)
+                .await
+                .unwrap(),
+            300
+        );
+        assert_eq!(
+            state.get_unbondings(15).await.unwrap(),
+            vec![Unbonding {
+                owner: validator_address,
+                amount: 200,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn bond_to_unregistered_validator_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        setup(&mut state, [9; 20]).await;
+
+        let action = BondAction {
+            validator: SigningKey::from([1; 32]).verification_key(),
+            amount: 500,
+            fee_asset: nria().into(),
+        };
+        assert_eyre_error(
+            &action.check_and_execute(&mut state).await.unwrap_err(),
+            "validator is not registered",
+        );
+    }
+
+    #[tokio::test]
+    async fn unbond_more_than_delegated_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = SigningKey::from([1; 32]).verification_key();
+        setup(&mut state, validator.address_bytes()).await;
+
+        BondAction {
+            validator: validator.clone(),
+            amount: 100,
+            fee_asset: nria().into(),
+        }
+        .check_and_execute(&mut state)
+        .await
+        .unwrap();
+        let action = UnbondAction {
+            validator,
+            amount: 101,
+            fee_asset: nria().into(),
+        };
+        assert_eyre_error(
+            &action.check_and_execute(&mut state).await.unwrap_err(),
+            "amount exceeds the amount bonded to the validator by the signer",
+        );
+    }
+}
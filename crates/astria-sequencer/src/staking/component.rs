@@ -0,0 +1,357 @@
+use std::sync::Arc;
+
+use astria_core::protocol::{
+    genesis::v1alpha1::GenesisAppState,
+    transaction::v1alpha1::action::ValidatorUpdate,
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use tendermint::abci::request::{
+    BeginBlock,
+    EndBlock,
+};
+use tracing::{
+    instrument,
+    warn,
+};
+
+use super::{
+    StateReadExt as _,
+    StateWriteExt,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    assets::StateReadExt as _,
+    authority::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    component::Component,
+    evidence::StateReadExt as _,
+};
+
+/// Derives the validator set from the native asset bonded to validators.
+///
+/// Only active if staking parameters were set at genesis. Validators from the genesis file
+/// keep their voting power until their stake changes for the first time.
+#[derive(Default)]
+pub(crate) struct StakingComponent;
+
+#[async_trait::async_trait]
+impl Component for StakingComponent {
+    type AppState = GenesisAppState;
+
+    #[instrument(name = "StakingComponent::init_chain", skip_all)]
+    async fn init_chain<S: StateWriteExt>(mut state: S, app_state: &Self::AppState) -> Result<()> {
+        if let Some(parameters) = app_state.staking_parameters() {
+            state
+                .put_staking_parameters(parameters)
+                .wrap_err("failed to put staking parameters")?;
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "StakingComponent::begin_block", skip_all)]
+    async fn begin_block<S: StateWriteExt + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &BeginBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Releases the unbondings that matured at this height and records validator updates for
+    /// all validators whose stake changed in this block, unless they are jailed.
+    ///
+    /// Must be called before `AuthorityComponent::end_block`, which applies the updates to the
+    /// validator set.
+    #[instrument(name = "StakingComponent::end_block", skip_all)]
+    async fn end_block<S: StateWriteExt + 'static>(
+        state: &mut Arc<S>,
+        end_block: &EndBlock,
+    ) -> Result<()> {
+        let Some(parameters) = state
+            .get_staking_parameters()
+            .await
+            .wrap_err("failed to get staking parameters")?
+        else {
+            return Ok(());
+        };
+        let height: u64 = end_block
+            .height
+            .try_into()
+            .wrap_err("block height must be non-negative")?;
+        let native_asset = state
+            .get_native_asset()
+            .await
+            .wrap_err("failed to get native asset")?;
+        let unbondings = state
+            .get_unbondings(height)
+            .await
+            .wrap_err("failed to get unbondings")?;
+
+        let changed_validators = state.get_changed_validators();
+        let mut validators = Vec::with_capacity(changed_validators.len());
+        for address in changed_validators {
+            let validator = state
+                .get_validator(address)
+                .await
+                .wrap_err("failed to get validator")?
+                .ok_or_eyre("changed validator not found in state")?;
+            // jailed validators are kept out of the validator set until they are released
+            if state
+                .get_jailed_until(address)
+                .await
+                .wrap_err("failed to get jail status of validator")?
+                .is_some()
+            {
+                continue;
+            }
+            validators.push(validator);
+        }
+        let current_set = state
+            .get_validator_set()
+            .await
+            .wrap_err("failed to get validator set")?;
+        let mut validator_updates = state
+            .get_validator_updates()
+            .await
+            .wrap_err("failed to get validator updates")?;
+
+        let state = Arc::get_mut(state)
+            .ok_or_eyre("must only have one reference to the state; this is a bug")?;
+
+        for unbonding in unbondings {
+            state
+                .increase_balance(unbonding.owner, &native_asset, unbonding.amount)
+                .await
+                .wrap_err("failed to release unbonded stake")?;
+        }
+        state.delete_unbondings(height);
+
+        let updates: Vec<ValidatorUpdate> = validators
+            .into_iter()
+            .map(|validator| ValidatorUpdate {
+                power: voting_power(validator.stake, parameters.stake_per_power),
+                verification_key: validator.verification_key,
+            })
+            .collect();
+        // cometbft rejects removing the last validator, so count the validators that remain
+        // after all additions before removing any
+        let mut remaining = updates
+            .iter()
+            .filter(|update| {
+                update.power > 0 && current_set.get(&update.verification_key).is_none()
+            })
+            .count()
+            .saturating_add(current_set.len());
+        for update in updates {
+            if update.power == 0 {
+                if current_set.get(&update.verification_key).is_none() {
+                    continue;
+                }
+                if remaining <= 1 {
+                    warn!(
+                        validator = %update.verification_key,
+                        "validator has no voting power left but is the last validator; not \
+                         removing it",
+                    );
+                    continue;
+                }
+                remaining = remaining.saturating_sub(1);
+            }
+            validator_updates.push_update(update);
+        }
+        state
+            .put_validator_updates(validator_updates)
+            .wrap_err("failed to put validator updates")?;
+        Ok(())
+    }
+}
+
+/// Calculates the voting power for `stake`, saturating at `u32::MAX`.
+fn voting_power(stake: u128, stake_per_power: u128) -> u32 {
+    stake
+        .checked_div(stake_per_power)
+        .unwrap_or_default()
+        .try_into()
+        .unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        protocol::genesis::v1alpha1::StakingParameters,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        accounts::StateReadExt as _,
+        assets::StateWriteExt as _,
+        authority::ValidatorSet,
+        evidence::StateWriteExt as _,
+        staking::{
+            Unbonding,
+            Validator,
+        },
+        test_utils::nria,
+    };
+
+    #[test]
+    fn voting_power_is_stake_divided_by_stake_per_power() {
+        assert_eq!(voting_power(999, 100), 9);
+        assert_eq!(voting_power(99, 100), 0);
+        assert_eq!(voting_power(u128::MAX, 1), u32::MAX);
+    }
+
+    #[tokio::test]
+    async fn end_block_releases_unbondings_and_updates_validator_power() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let genesis_validator = SigningKey::from([1; 32]).verification_key();
+        let new_validator = SigningKey::from([2; 32]).verification_key();
+        state
+            .put_staking_parameters(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 100,
+            })
+            .unwrap();
+        state.put_native_asset(&nria());
+        state
+            .put_validator_set(ValidatorSet::new_from_updates(vec![ValidatorUpdate {
+                power: 10,
+                verification_key: genesis_validator.clone(),
+            }]))
+            .unwrap();
+        state
+            .push_unbonding(
+                7,
+                Unbonding {
+                    owner: [9; 20],
+                    amount: 50,
+                },
+            )
+            .await
+            .unwrap();
+        state
+            .put_validator(&Validator {
+                verification_key: new_validator.clone(),
+                stake: 250,
+            })
+            .unwrap();
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 7,
+            },
+        )
+        .await
+        .unwrap();
+        let state = Arc::try_unwrap(state).unwrap();
+
+        assert_eq!(
+            state.get_account_balance([9; 20], &nria()).await.unwrap(),
+            50
+        );
+        assert!(state.get_unbondings(7).await.unwrap().is_empty());
+        let updates = state.get_validator_updates().await.unwrap();
+        assert_eq!(
+            updates.get(&new_validator),
+            Some(&ValidatorUpdate {
+                power: 2,
+                verification_key: new_validator,
+            })
+        );
+        assert!(updates.get(&genesis_validator).is_none());
+    }
+
+    #[tokio::test]
+    async fn end_block_does_not_remove_last_validator() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = SigningKey::from([1; 32]).verification_key();
+        state
+            .put_staking_parameters(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 100,
+            })
+            .unwrap();
+        state.put_native_asset(&nria());
+        state
+            .put_validator_set(ValidatorSet::new_from_updates(vec![ValidatorUpdate {
+                power: 10,
+                verification_key: validator.clone(),
+            }]))
+            .unwrap();
+        state
+            .put_validator(&Validator {
+                verification_key: validator.clone(),
+                stake: 0,
+            })
+            .unwrap();
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+        let updates = state.get_validator_updates().await.unwrap();
+        assert!(updates.get(&validator).is_none());
+    }
+
+    #[tokio::test]
+    async fn end_block_does_not_add_jailed_validator() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = SigningKey::from([1; 32]).verification_key();
+        state
+            .put_staking_parameters(&StakingParameters {
+                unbonding_period_blocks: 10,
+                stake_per_power: 100,
+            })
+            .unwrap();
+        state.put_native_asset(&nria());
+        state
+            .put_validator_set(ValidatorSet::new_from_updates(vec![]))
+            .unwrap();
+        state
+            .put_validator(&Validator {
+                verification_key: validator.clone(),
+                stake: 1_000,
+            })
+            .unwrap();
+        state
+            .put_jailed_until(validator.address_bytes(), 10)
+            .unwrap();
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+        let updates = state.get_validator_updates().await.unwrap();
+        assert!(updates.get(&validator).is_none());
+    }
+}
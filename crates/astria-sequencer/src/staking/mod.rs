@@ -0,0 +1,126 @@
+mod action;
+pub(crate) mod component;
+mod state_ext;
+
+use astria_core::{
+    crypto::VerificationKey,
+    primitive::v1::ADDRESS_LEN,
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateWrite;
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+
+use crate::fee_distribution::mul_div;
+
+/// A validator that registered itself by bonding the native asset to its own key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Validator {
+    pub(crate) verification_key: VerificationKey,
+    /// The total amount of the native asset bonded to the validator, including delegations.
+    pub(crate) stake: u128,
+}
+
+/// An amount of the native asset that was unbonded and is returned to `owner` once the
+/// unbonding period has passed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Unbonding {
+    pub(crate) owner: [u8; ADDRESS_LEN],
+    pub(crate) amount: u128,
+}
+
+/// Burns `percent` of every delegation to the validator at `address` and reduces its stake
+/// accordingly. Stake that is already unbonding is not slashed.
+///
+/// Returns the total amount burned, which is 0 if `address` is not a registered validator.
+pub(crate) async fn slash<S: StateWrite>(
+    state: &mut S,
+    address: [u8; ADDRESS_LEN],
+    percent: u32,
+) -> Result<u128> {
+    let Some(mut validator) = state
+        .get_validator(address)
+        .await
+        .wrap_err("failed to get validator")?
+    else {
+        return Ok(0);
+    };
+    let delegations = state
+        .get_delegations(address)
+        .await
+        .wrap_err("failed to get delegations")?;
+
+    let mut total_slashed: u128 = 0;
+    for (delegator, amount) in delegations {
+        let slashed =
+            mul_div(amount, percent.into(), 100).ok_or_eyre("slashed amount overflowed u128")?;
+        let remaining = amount
+            .checked_sub(slashed)
+            .ok_or_eyre("slashed more than the delegated amount")?;
+        state
+            .put_delegation(address, delegator, remaining)
+            .wrap_err("failed to put delegation")?;
+        total_slashed = total_slashed
+            .checked_add(slashed)
+            .ok_or_eyre("total slashed amount overflowed u128")?;
+    }
+
+    validator.stake = validator
+        .stake
+        .checked_sub(total_slashed)
+        .ok_or_eyre("slashed more than the stake of the validator")?;
+    state
+        .put_validator(&validator)
+        .wrap_err("failed to put validator")?;
+    Ok(total_slashed)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn slash_reduces_every_delegation_and_the_stake() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let verification_key = SigningKey::from([1; 32]).verification_key();
+        let address = verification_key.address_bytes();
+        state
+            .put_validator(&Validator {
+                verification_key,
+                stake: 300,
+            })
+            .unwrap();
+        state.put_delegation(address, address, 100).unwrap();
+        state.put_delegation(address, [2; 20], 200).unwrap();
+
+        assert_eq!(slash(&mut state, address, 10).await.unwrap(), 30);
+        assert_eq!(
+            state.get_validator(address).await.unwrap().unwrap().stake,
+            270
+        );
+        assert_eq!(state.get_delegation(address, address).await.unwrap(), 90);
+        assert_eq!(state.get_delegation(address, [2; 20]).await.unwrap(), 180);
+    }
+
+    #[tokio::test]
+    async fn slash_of_unregistered_validator_is_noop() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(slash(&mut state, [1; 20], 10).await.unwrap(), 0);
+        assert!(state.get_changed_validators().is_empty());
+    }
+}
@@ -0,0 +1,433 @@
+use std::collections::BTreeSet;
+
+use astria_core::{
+    crypto::VerificationKey,
+    primitive::v1::ADDRESS_LEN,
+    protocol::genesis::v1alpha1::StakingParameters,
+};
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        eyre,
+        OptionExt as _,
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use futures::StreamExt as _;
+use tracing::instrument;
+
+use super::{
+    Unbonding,
+    Validator,
+};
+use crate::accounts::AddressBytes;
+
+/// Wrapper to read and write the staking parameters from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Parameters {
+    unbonding_period_blocks: u64,
+    stake_per_power: u128,
+}
+
+/// Wrapper to read and write a validator from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StoredValidator {
+    verification_key: [u8; 32],
+    stake: u128,
+}
+
+/// Newtype wrapper to read and write a delegated amount from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Delegation(u128);
+
+/// Newtype wrapper to read and write the unbondings released at a height from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Unbondings(Vec<([u8; ADDRESS_LEN], u128)>);
+
+const STAKING_PARAMETERS_STORAGE_KEY: &str = "staking/params";
+const VALIDATOR_PREFIX: &str = "staking/validator";
+const DELEGATION_PREFIX: &str = "staking/delegation";
+const UNBONDING_PREFIX: &str = "staking/unbonding";
+const CHANGED_VALIDATORS_EPHEMERAL_KEY: &str = "staking/changedvalidators";
+
+struct HexAddress<'a, T>(&'a T);
+impl<'a, T: AddressBytes> std::fmt::Display for HexAddress<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.0.address_bytes() {
+            f.write_fmt(format_args!("{byte:02x}"))?;
+        }
+        Ok(())
+    }
+}
+
+fn validator_storage_key<T: AddressBytes>(validator: &T) -> String {
+    format!("{VALIDATOR_PREFIX}/{}", HexAddress(validator))
+}
+
+fn delegation_storage_key<TValidator: AddressBytes, TDelegator: AddressBytes>(
+    validator: &TValidator,
+    delegator: &TDelegator,
+) -> String {
+    format!(
+        "{DELEGATION_PREFIX}/{}/{}",
+        HexAddress(validator),
+        HexAddress(delegator)
+    )
+}
+
+fn unbonding_storage_key(release_height: u64) -> String {
+    // zero-padded so that the keys sort by height
+    format!("{UNBONDING_PREFIX}/{release_height:020}")
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the staking parameters, or `None` if the validator set is managed by the
+    /// authority sudo address.
+    #[instrument(skip_all)]
+    async fn get_staking_parameters(&self) -> Result<Option<StakingParameters>> {
+        let Some(bytes) = self
+            .get_raw(STAKING_PARAMETERS_STORAGE_KEY)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw staking parameters from state")?
+        else {
+            // return none because staking is optional
+            return Ok(None);
+        };
+        let Parameters {
+            unbonding_period_blocks,
+            stake_per_power,
+        } = Parameters::try_from_slice(&bytes).wrap_err("invalid staking parameters bytes")?;
+        Ok(Some(StakingParameters {
+            unbonding_period_blocks,
+            stake_per_power,
+        }))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_validator<T: AddressBytes>(&self, address: T) -> Result<Option<Validator>> {
+        let Some(bytes) = self
+            .get_raw(&validator_storage_key(&address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw validator from state")?
+        else {
+            return Ok(None);
+        };
+        let StoredValidator {
+            verification_key,
+            stake,
+        } = StoredValidator::try_from_slice(&bytes).wrap_err("invalid validator bytes")?;
+        let verification_key = VerificationKey::try_from(verification_key)
+            .wrap_err("invalid validator verification key bytes")?;
+        Ok(Some(Validator {
+            verification_key,
+            stake,
+        }))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_delegation<TValidator, TDelegator>(
+        &self,
+        validator: TValidator,
+        delegator: TDelegator,
+    ) -> Result<u128>
+    where
+        TValidator: AddressBytes,
+        TDelegator: AddressBytes,
+    {
+        let Some(bytes) = self
+            .get_raw(&delegation_storage_key(&validator, &delegator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw delegation from state")?
+        else {
+            return Ok(0);
+        };
+        let Delegation(amount) =
+            Delegation::try_from_slice(&bytes).wrap_err("invalid delegation bytes")?;
+        Ok(amount)
+    }
+
+    /// Returns all delegators of the validator and the amounts they delegated to it.
+    #[instrument(skip_all)]
+    async fn get_delegations<T: AddressBytes>(
+        &self,
+        validator: T,
+    ) -> Result<Vec<([u8; ADDRESS_LEN], u128)>> {
+        let prefix = format!("{DELEGATION_PREFIX}/{}/", HexAddress(&validator));
+        let mut delegations = Vec::new();
+        let mut stream = std::pin::pin!(self.prefix_raw(&prefix));
+        while let Some(item) = stream.next().await {
+            let (key, bytes) = item
+                .map_err(anyhow_to_eyre)
+                .wrap_err("failed reading raw delegation from state")?;
+            // if the key isn't of the form `{prefix}{delegator}`, then we have a bug in
+            // `put_delegation`
+            let delegator = key
+                .strip_prefix(&prefix)
+                .ok_or_eyre("delegation key did not start with the validator prefix")?;
+            let delegator = hex::decode(delegator)
+                .wrap_err("delegation key did not end in a hex encoded address")?
+                .try_into()
+                .map_err(|_| eyre!("delegator address was not 20 bytes"))?;
+            let Delegation(amount) =
+                Delegation::try_from_slice(&bytes).wrap_err("invalid delegation bytes")?;
+            delegations.push((delegator, amount));
+        }
+        Ok(delegations)
+    }
+
+    /// Returns the unbondings that are released at `release_height`.
+    #[instrument(skip_all)]
+    async fn get_unbondings(&self, release_height: u64) -> Result<Vec<Unbonding>> {
+        let Some(bytes) = self
+            .get_raw(&unbonding_storage_key(release_height))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw unbondings from state")?
+        else {
+            return Ok(vec![]);
+        };
+        let Unbondings(unbondings) =
+            Unbondings::try_from_slice(&bytes).wrap_err("invalid unbondings bytes")?;
+        Ok(unbondings
+            .into_iter()
+            .map(|(owner, amount)| Unbonding {
+                owner,
+                amount,
+            })
+            .collect())
+    }
+
+    /// Returns the addresses of all validators whose stake changed in the current block.
+    #[instrument(skip_all)]
+    fn get_changed_validators(&self) -> BTreeSet<[u8; ADDRESS_LEN]> {
+        self.object_get(CHANGED_VALIDATORS_EPHEMERAL_KEY)
+            .unwrap_or_default()
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_staking_parameters(&mut self, parameters: &StakingParameters) -> Result<()> {
+        let bytes = borsh::to_vec(&Parameters {
+            unbonding_period_blocks: parameters.unbonding_period_blocks,
+            stake_per_power: parameters.stake_per_power,
+        })
+        .wrap_err("failed to serialize staking parameters")?;
+        self.put_raw(STAKING_PARAMETERS_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Writes the validator to state and marks it as changed in the current block, so that
+    /// its voting power is recalculated in `end_block`.
+    #[instrument(skip_all)]
+    fn put_validator(&mut self, validator: &Validator) -> Result<()> {
+        let address = validator.verification_key.address_bytes();
+        let bytes = borsh::to_vec(&StoredValidator {
+            verification_key: validator.verification_key.to_bytes(),
+            stake: validator.stake,
+        })
+        .wrap_err("failed to serialize validator")?;
+        self.put_raw(validator_storage_key(&address), bytes);
+
+        let mut changed_validators = self.get_changed_validators();
+        changed_validators.insert(address);
+        self.object_put(CHANGED_VALIDATORS_EPHEMERAL_KEY, changed_validators);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_delegation<TValidator, TDelegator>(
+        &mut self,
+        validator: TValidator,
+        delegator: TDelegator,
+        amount: u128,
+    ) -> Result<()>
+    where
+        TValidator: AddressBytes,
+        TDelegator: AddressBytes,
+    {
+        let key = delegation_storage_key(&validator, &delegator);
+        if amount == 0 {
+            self.delete(key);
+            return Ok(());
+        }
+        self.put_raw(
+            key,
+            borsh::to_vec(&Delegation(amount)).wrap_err("failed to serialize delegation")?,
+        );
+        Ok(())
+    }
+
+    /// Queues `unbonding` to be released at `release_height`.
+    #[instrument(skip_all)]
+    async fn push_unbonding(&mut self, release_height: u64, unbonding: Unbonding) -> Result<()> {
+        let mut unbondings = self
+            .get_unbondings(release_height)
+            .await
+            .wrap_err("failed to get unbondings")?;
+        unbondings.push(unbonding);
+        let bytes = borsh::to_vec(&Unbondings(
+            unbondings
+                .into_iter()
+                .map(|unbonding| (unbonding.owner, unbonding.amount))
+                .collect(),
+        ))
+        .wrap_err("failed to serialize unbondings")?;
+        self.put_raw(unbonding_storage_key(release_height), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn delete_unbondings(&mut self, release_height: u64) {
+        self.delete(unbonding_storage_key(release_height));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        protocol::genesis::v1alpha1::StakingParameters,
+    };
+    use cnidarium::StateDelta;
+
+    use super::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+    use crate::staking::{
+        Unbonding,
+        Validator,
+    };
+
+    #[tokio::test]
+    async fn staking_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(
+            state.get_staking_parameters().await.unwrap().is_none(),
+            "no staking parameters should exist at first"
+        );
+
+        let parameters = StakingParameters {
+            unbonding_period_blocks: 10,
+            stake_per_power: 1_000,
+        };
+        state.put_staking_parameters(&parameters).unwrap();
+        assert_eq!(
+            state.get_staking_parameters().await.unwrap(),
+            Some(parameters),
+            "stored staking parameters were not what was expected"
+        );
+    }
+
+    #[tokio::test]
+    async fn put_validator_marks_it_as_changed() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let verification_key = SigningKey::from([1; 32]).verification_key();
+        let address = verification_key.address_bytes();
+        assert!(state.get_validator(address).await.unwrap().is_none());
+        assert!(state.get_changed_validators().is_empty());
+
+        let validator = Validator {
+            verification_key,
+            stake: 100,
+        };
+        state.put_validator(&validator).unwrap();
+        assert_eq!(state.get_validator(address).await.unwrap(), Some(validator));
+        assert!(state.get_changed_validators().contains(&address));
+    }
+
+    #[tokio::test]
+    async fn delegations() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = [1; 20];
+        let delegator = [2; 20];
+        assert_eq!(state.get_delegation(validator, delegator).await.unwrap(), 0);
+
+        state.put_delegation(validator, delegator, 50).unwrap();
+        assert_eq!(
+            state.get_delegation(validator, delegator).await.unwrap(),
+            50
+        );
+        assert_eq!(
+            state.get_delegation(delegator, validator).await.unwrap(),
+            0,
+            "delegation must be keyed by validator and delegator"
+        );
+
+        state.put_delegation(validator, delegator, 0).unwrap();
+        assert_eq!(state.get_delegation(validator, delegator).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn get_delegations_returns_all_delegators_of_a_validator() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let validator = [1; 20];
+        assert!(state.get_delegations(validator).await.unwrap().is_empty());
+
+        state.put_delegation(validator, [2; 20], 50).unwrap();
+        state.put_delegation(validator, [3; 20], 70).unwrap();
+        state.put_delegation([4; 20], [2; 20], 90).unwrap();
+        assert_eq!(
+            state.get_delegations(validator).await.unwrap(),
+            vec![([2; 20], 50), ([3; 20], 70)]
+        );
+    }
+
+    #[tokio::test]
+    async fn unbondings_are_queued_by_release_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let first = Unbonding {
+            owner: [1; 20],
+            amount: 10,
+        };
+        let second = Unbonding {
+            owner: [2; 20],
+            amount: 20,
+        };
+        state.push_unbonding(5, first).await.unwrap();
+        state.push_unbonding(5, second).await.unwrap();
+        state.push_unbonding(6, first).await.unwrap();
+
+        assert_eq!(state.get_unbondings(5).await.unwrap(), vec![first, second]);
+        assert_eq!(state.get_unbondings(6).await.unwrap(), vec![first]);
+
+        state.delete_unbondings(5);
+        assert!(state.get_unbondings(5).await.unwrap().is_empty());
+        assert_eq!(state.get_unbondings(6).await.unwrap(), vec![first]);
+    }
+}
@@ -26,6 +26,7 @@ use tracing::instrument;
 use crate::{
     accounts::StateReadExt as _,
     address::StateReadExt as _,
+    assets::StateReadExt as _,
     bridge::StateReadExt as _,
     ibc::StateReadExt as _,
     state_ext::StateReadExt as _,
@@ -96,6 +97,12 @@ pub(crate) async fn get_fees_for_transaction<S: StateRead>(
             Action::Transfer(act) => {
                 transfer_update_fees(&act.fee_asset, &mut fees_by_asset, transfer_fee);
             }
+            Action::Bond(act) => {
+                transfer_update_fees(&act.fee_asset, &mut fees_by_asset, transfer_fee);
+            }
+            Action::Unbond(act) => {
+                transfer_update_fees(&act.fee_asset, &mut fees_by_asset, transfer_fee);
+            }
             Action::Sequence(act) => {
                 sequence_update_fees(state, &act.fee_asset, &mut fees_by_asset, &act.data).await?;
             }
@@ -134,7 +141,9 @@ pub(crate) async fn get_fees_for_transaction<S: StateRead>(
             | Action::Ibc(_)
             | Action::IbcRelayerChange(_)
             | Action::FeeAssetChange(_)
-            | Action::FeeChange(_) => {
+            | Action::FeeChange(_)
+            | Action::FeeDistributionChange(_)
+            | Action::UpgradeSchedule(_) => {
                 continue;
             }
         }
@@ -201,6 +210,16 @@ pub(crate) async fn get_total_transaction_cost<S: StateRead>(
                     .and_modify(|amt| *amt = amt.saturating_add(act.amount))
                     .or_insert(act.amount);
             }
+            Action::Bond(act) => {
+                let native_asset = state
+                    .get_native_asset()
+                    .await
+                    .wrap_err("failed to get native asset")?;
+                cost_by_asset
+                    .entry(native_asset.to_ibc_prefixed())
+                    .and_modify(|amt| *amt = amt.saturating_add(act.amount))
+                    .or_insert(act.amount);
+            }
             Action::BridgeUnlock(act) => {
                 let asset = state
                     .get_bridge_account_ibc_asset(tx)
@@ -220,7 +239,10 @@ pub(crate) async fn get_total_transaction_cost<S: StateRead>(
             | Action::Ibc(_)
             | Action::IbcRelayerChange(_)
             | Action::FeeAssetChange(_)
-            | Action::FeeChange(_) => {
+            | Action::FeeChange(_)
+            | Action::FeeDistributionChange(_)
+            | Action::UpgradeSchedule(_)
+            | Action::Unbond(_) => {
                 continue;
             }
         }
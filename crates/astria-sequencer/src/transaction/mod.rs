@@ -95,6 +95,14 @@ impl ActionHandler for SignedTransaction {
                     .check_stateless()
                     .await
                     .wrap_err("stateless check failed for SequenceAction")?,
+                Action::Bond(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for BondAction")?,
+                Action::Unbond(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for UnbondAction")?,
                 Action::ValidatorUpdate(act) => act
                     .check_stateless()
                     .await
@@ -111,6 +119,14 @@ impl ActionHandler for SignedTransaction {
                     .check_stateless()
                     .await
                     .wrap_err("stateless check failed for FeeChangeAction")?,
+                Action::FeeDistributionChange(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for FeeDistributionChangeAction")?,
+                Action::UpgradeSchedule(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for UpgradeScheduleAction")?,
                 Action::Ibc(act) => {
                     let action = act
                         .clone()
@@ -221,6 +237,14 @@ impl ActionHandler for SignedTransaction {
                     .check_and_execute(&mut state)
                     .await
                     .wrap_err("executing sequence action failed")?,
+                Action::Bond(act) => act
+                    .check_and_execute(&mut state)
+                    .await
+                    .wrap_err("executing bond action failed")?,
+                Action::Unbond(act) => act
+                    .check_and_execute(&mut state)
+                    .await
+                    .wrap_err("executing unbond action failed")?,
                 Action::ValidatorUpdate(act) => act
                     .check_and_execute(&mut state)
                     .await
@@ -237,6 +261,14 @@ impl ActionHandler for SignedTransaction {
                     .check_and_execute(&mut state)
                     .await
                     .wrap_err("executing fee change failed")?,
+                Action::FeeDistributionChange(act) => act
+                    .check_and_execute(&mut state)
+                    .await
+                    .wrap_err("executing fee distribution change failed")?,
+                Action::UpgradeSchedule(act) => act
+                    .check_and_execute(&mut state)
+                    .await
+                    .wrap_err("executing upgrade schedule failed")?,
                 Action::Ibc(act) => {
                     // FIXME: this check should be moved to check_and_execute, as it now has
                     // access to the the signer through state. However, what's the correct
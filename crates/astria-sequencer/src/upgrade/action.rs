@@ -0,0 +1,221 @@
+use astria_core::protocol::{
+    transaction::v1alpha1::action::UpgradeScheduleAction,
+    upgrade::v1alpha1::Upgrade,
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+
+use super::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+use crate::{
+    app::ActionHandler,
+    authority::StateReadExt as _,
+    state_ext::StateReadExt as _,
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for UpgradeScheduleAction {
+    async fn check_stateless(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let from = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+
+        let current_height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height from state")?;
+        ensure!(
+            self.height > current_height,
+            "upgrade height {} must be greater than the current height {current_height}",
+            self.height,
+        );
+
+        ensure!(
+            state
+                .get_applied_upgrade(&self.name)
+                .await
+                .wrap_err("failed to get applied upgrade from state")?
+                .is_none(),
+            "upgrade `{}` was already applied",
+            self.name,
+        );
+        let mut pending_upgrades = state
+            .get_pending_upgrades()
+            .await
+            .wrap_err("failed to get pending upgrades from state")?;
+        ensure!(
+            pending_upgrades
+                .iter()
+                .all(|upgrade| upgrade.name != self.name),
+            "upgrade `{}` is already scheduled",
+            self.name,
+        );
+        pending_upgrades.push(Upgrade {
+            name: self.name.clone(),
+            height: self.height,
+        });
+        state
+            .put_pending_upgrades(pending_upgrades)
+            .wrap_err("failed to put pending upgrades")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::TransactionId,
+        protocol::{
+            transaction::v1alpha1::action::UpgradeScheduleAction,
+            upgrade::v1alpha1::Upgrade,
+        },
+    };
+    use cnidarium::StateDelta;
+
+    use crate::{
+        app::ActionHandler as _,
+        authority::StateWriteExt as _,
+        state_ext::StateWriteExt as _,
+        test_utils::{
+            assert_eyre_error,
+            astria_address,
+        },
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+        upgrade::{
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+    };
+
+    fn setup(state: &mut StateDelta<cnidarium::Snapshot>, signer: [u8; 20]) {
+        state.put_block_height(10);
+        state.put_sudo_address(astria_address(&[1u8; 20])).unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: signer,
+            transaction_id: TransactionId::new([0; 32]),
+            source_action_index: 0,
+        });
+    }
+
+    fn action(name: &str, height: u64) -> UpgradeScheduleAction {
+        UpgradeScheduleAction {
+            name: name.to_string(),
+            height,
+        }
+    }
+
+    #[tokio::test]
+    async fn upgrade_schedule_action_executes_as_expected() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, [1u8; 20]);
+
+        action("second", 30)
+            .check_and_execute(&mut state)
+            .await
+            .unwrap();
+        action("first", 20)
+            .check_and_execute(&mut state)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            state.get_pending_upgrades().await.unwrap(),
+            vec![
+                Upgrade {
+                    name: "first".to_string(),
+                    height: 20,
+                },
+                Upgrade {
+                    name: "second".to_string(),
+                    height: 30,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn upgrade_schedule_action_fails_if_signer_is_not_sudo() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, [3u8; 20]);
+
+        assert_eyre_error(
+            &action("upgrade", 20)
+                .check_and_execute(&mut state)
+                .await
+                .unwrap_err(),
+            "signer is not the sudo key",
+        );
+        assert!(state.get_pending_upgrades().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn upgrade_schedule_action_fails_if_height_is_not_in_the_future() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, [1u8; 20]);
+
+        assert_eyre_error(
+            &action("upgrade", 10)
+                .check_and_execute(&mut state)
+                .await
+                .unwrap_err(),
+            "must be greater than the current height",
+        );
+    }
+
+    #[tokio::test]
+    async fn upgrade_schedule_action_fails_if_upgrade_is_known() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, [1u8; 20]);
+
+        action("pending", 20)
+            .check_and_execute(&mut state)
+            .await
+            .unwrap();
+        assert_eyre_error(
+            &action("pending", 30)
+                .check_and_execute(&mut state)
+                .await
+                .unwrap_err(),
+            "is already scheduled",
+        );
+
+        state.put_applied_upgrade("applied", 5).unwrap();
+        assert_eyre_error(
+            &action("applied", 30)
+                .check_and_execute(&mut state)
+                .await
+                .unwrap_err(),
+            "was already applied",
+        );
+    }
+}
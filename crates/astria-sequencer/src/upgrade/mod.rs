@@ -0,0 +1,214 @@
+mod action;
+pub(crate) mod query;
+mod state_ext;
+
+use std::collections::BTreeMap;
+
+use astria_eyre::eyre::{
+    bail,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateDelta,
+    StateRead,
+    StateWrite as _,
+};
+use futures::future::BoxFuture;
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+use tendermint::abci::{
+    Event,
+    EventAttributeIndexExt as _,
+};
+use tracing::info;
+
+use crate::app::InterBlockState;
+
+/// A migration of the chain state that takes effect in the block at the height of its upgrade.
+pub(crate) type Migration =
+    for<'a> fn(&'a mut StateDelta<InterBlockState>) -> BoxFuture<'a, Result<()>>;
+
+/// The migrations supported by this binary, keyed by the name of the upgrade they belong to.
+///
+/// Upgrades that only change the binary but not the chain state are registered with a
+/// migration that does nothing.
+const SUPPORTED_MIGRATIONS: &[(&str, Migration)] = &[];
+
+/// The registry of migrations a node runs when reaching the height of a scheduled upgrade.
+pub(crate) struct Migrations(BTreeMap<&'static str, Migration>);
+
+impl Migrations {
+    pub(crate) fn new(migrations: impl IntoIterator<Item = (&'static str, Migration)>) -> Self {
+        Self(migrations.into_iter().collect())
+    }
+
+    /// Returns the migrations of all upgrades supported by this binary.
+    pub(crate) fn supported() -> Self {
+        Self::new(SUPPORTED_MIGRATIONS.iter().copied())
+    }
+
+    fn get(&self, name: &str) -> Option<Migration> {
+        self.0.get(name).copied()
+    }
+}
+
+/// Returns an error if an upgrade takes effect at or before `height` that this binary has no
+/// migration for.
+///
+/// Must be called with the state committed at the previous height, so that the node halts
+/// before finalizing the block at the upgrade height.
+pub(crate) async fn ensure_upgrades_are_supported<S: StateRead>(
+    state: &S,
+    height: u64,
+    migrations: &Migrations,
+) -> Result<()> {
+    let pending_upgrades = state
+        .get_pending_upgrades()
+        .await
+        .wrap_err("failed to get pending upgrades")?;
+    if let Some(upgrade) = pending_upgrades
+        .iter()
+        .find(|upgrade| upgrade.height <= height && migrations.get(&upgrade.name).is_none())
+    {
+        bail!(
+            "chain halted at height {height} for upgrade `{}` scheduled at height {}; restart the \
+             node with a binary that supports the upgrade",
+            upgrade.name,
+            upgrade.height,
+        );
+    }
+    Ok(())
+}
+
+/// Runs the migrations of all pending upgrades that take effect at or before `height` and
+/// marks them as applied.
+///
+/// Upgrades without a registered migration are left pending; `App::finalize_block` refuses to
+/// finalize the block in that case.
+pub(crate) async fn apply_migrations(
+    state: &mut StateDelta<InterBlockState>,
+    height: u64,
+    migrations: &Migrations,
+) -> Result<()> {
+    let pending_upgrades = state
+        .get_pending_upgrades()
+        .await
+        .wrap_err("failed to get pending upgrades")?;
+    if pending_upgrades
+        .iter()
+        .all(|upgrade| upgrade.height > height)
+    {
+        return Ok(());
+    }
+
+    let mut still_pending = Vec::with_capacity(pending_upgrades.len());
+    for upgrade in pending_upgrades {
+        let Some(migration) = migrations
+            .get(&upgrade.name)
+            .filter(|_| upgrade.height <= height)
+        else {
+            still_pending.push(upgrade);
+            continue;
+        };
+        migration(state)
+            .await
+            .wrap_err_with(|| format!("failed to run migration of upgrade `{}`", upgrade.name))?;
+        state
+            .put_applied_upgrade(&upgrade.name, height)
+            .wrap_err("failed to put applied upgrade")?;
+        info!(name = %upgrade.name, height, "applied upgrade");
+        state.record(construct_upgrade_event(&upgrade.name, height));
+    }
+    state
+        .put_pending_upgrades(still_pending)
+        .wrap_err("failed to put pending upgrades")?;
+    Ok(())
+}
+
+/// Creates `abci::Event` of kind `upgrade.applied` for every applied upgrade.
+fn construct_upgrade_event(name: &str, height: u64) -> Event {
+    Event::new(
+        "upgrade.applied",
+        [
+            ("name", name.to_string()).index(),
+            ("height", height.to_string()).index(),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use astria_core::protocol::upgrade::v1alpha1::Upgrade;
+
+    use super::*;
+
+    const MIGRATED_KEY: &str = "test/migrated";
+
+    fn migration(state: &mut StateDelta<InterBlockState>) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async move {
+            state.put_raw(MIGRATED_KEY.to_string(), vec![1]);
+            Ok(())
+        })
+    }
+
+    fn upgrade(name: &str, height: u64) -> Upgrade {
+        Upgrade {
+            name: name.to_string(),
+            height,
+        }
+    }
+
+    #[tokio::test]
+    async fn ensure_upgrades_are_supported_halts_at_unsupported_upgrade() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        state
+            .put_pending_upgrades(vec![upgrade("supported", 5), upgrade("unsupported", 10)])
+            .unwrap();
+        let migrations = Migrations::new([("supported", migration as Migration)]);
+
+        ensure_upgrades_are_supported(&state, 9, &migrations)
+            .await
+            .unwrap();
+        let error = ensure_upgrades_are_supported(&state, 10, &migrations)
+            .await
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("chain halted at height 10 for upgrade `unsupported`"),
+            "{error:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_migrations_runs_due_migrations() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(Arc::new(StateDelta::new(snapshot)));
+        state
+            .put_pending_upgrades(vec![upgrade("now", 10), upgrade("later", 20)])
+            .unwrap();
+        let migrations = Migrations::new([
+            ("now", migration as Migration),
+            ("later", migration as Migration),
+        ]);
+
+        apply_migrations(&mut state, 9, &migrations).await.unwrap();
+        assert!(state.get_raw(MIGRATED_KEY).await.unwrap().is_none());
+
+        apply_migrations(&mut state, 10, &migrations).await.unwrap();
+        assert!(state.get_raw(MIGRATED_KEY).await.unwrap().is_some());
+        assert_eq!(state.get_applied_upgrade("now").await.unwrap(), Some(10));
+        assert_eq!(
+            state.get_pending_upgrades().await.unwrap(),
+            vec![upgrade("later", 20)]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use astria_core::protocol::{
+    abci::AbciErrorCode,
+    upgrade::v1alpha1::PendingUpgradesResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+    Code,
+};
+
+use crate::{
+    state_ext::StateReadExt as _,
+    upgrade::StateReadExt as _,
+};
+
+pub(crate) async fn pending_upgrades_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    // use latest snapshot, as this is a query for the currently pending upgrades
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting block height: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+    let upgrades = match snapshot.get_pending_upgrades().await {
+        Ok(upgrades) => upgrades,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed to retrieve pending upgrades: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = PendingUpgradesResponse {
+        height,
+        upgrades,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: tendermint::abci::Code::Ok,
+        key: request.path.into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::protocol::upgrade::v1alpha1::PendingUpgradesResponse as RawPendingUpgradesResponse,
+        protocol::upgrade::v1alpha1::Upgrade,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        state_ext::StateWriteExt as _,
+        upgrade::StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn pending_upgrades_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let upgrade = Upgrade {
+            name: "upgrade".to_string(),
+            height: 10,
+        };
+        state.put_block_height(2);
+        state.put_pending_upgrades(vec![upgrade.clone()]).unwrap();
+        storage.commit(state).await.unwrap();
+
+        let query = request::Query {
+            data: vec![].into(),
+            path: "path".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        };
+
+        let resp = pending_upgrades_request(storage.clone(), query, vec![]).await;
+        assert_eq!(resp.code, 0.into(), "{}", resp.log);
+
+        let proto = RawPendingUpgradesResponse::decode(resp.value).unwrap();
+        let expected = PendingUpgradesResponse {
+            height: 2,
+            upgrades: vec![upgrade],
+        };
+        assert_eq!(PendingUpgradesResponse::from_raw(&proto), expected);
+    }
+}
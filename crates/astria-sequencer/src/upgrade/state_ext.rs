@@ -0,0 +1,157 @@
+use astria_core::protocol::upgrade::v1alpha1::Upgrade;
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::instrument;
+
+/// Newtype wrapper to read and write the pending upgrades from rocksdb.
+///
+/// Contains the name and height of every pending upgrade, ordered by height.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct PendingUpgrades(Vec<(String, u64)>);
+
+/// Newtype wrapper to read and write a block height from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Height(u64);
+
+const PENDING_UPGRADES_STORAGE_KEY: &str = "upgrade/pending";
+const APPLIED_UPGRADE_PREFIX: &str = "upgrade/applied";
+
+fn applied_upgrade_storage_key(name: &str) -> String {
+    format!("{APPLIED_UPGRADE_PREFIX}/{name}")
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the upgrades that were scheduled but not yet applied, ordered by height.
+    #[instrument(skip_all)]
+    async fn get_pending_upgrades(&self) -> Result<Vec<Upgrade>> {
+        let Some(bytes) = self
+            .get_raw(PENDING_UPGRADES_STORAGE_KEY)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw pending upgrades from state")?
+        else {
+            return Ok(vec![]);
+        };
+        let PendingUpgrades(upgrades) =
+            PendingUpgrades::try_from_slice(&bytes).wrap_err("invalid pending upgrades bytes")?;
+        Ok(upgrades
+            .into_iter()
+            .map(|(name, height)| Upgrade {
+                name,
+                height,
+            })
+            .collect())
+    }
+
+    /// Returns the height at which the upgrade named `name` was applied, or `None` if it was
+    /// not applied.
+    #[instrument(skip_all)]
+    async fn get_applied_upgrade(&self, name: &str) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(&applied_upgrade_storage_key(name))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw applied upgrade height from state")?
+        else {
+            return Ok(None);
+        };
+        let Height(height) =
+            Height::try_from_slice(&bytes).wrap_err("invalid applied upgrade height bytes")?;
+        Ok(Some(height))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    /// Writes the pending upgrades, sorted by height.
+    #[instrument(skip_all)]
+    fn put_pending_upgrades(&mut self, mut upgrades: Vec<Upgrade>) -> Result<()> {
+        upgrades.sort_by_key(|upgrade| upgrade.height);
+        let bytes = borsh::to_vec(&PendingUpgrades(
+            upgrades
+                .into_iter()
+                .map(|upgrade| (upgrade.name, upgrade.height))
+                .collect(),
+        ))
+        .wrap_err("failed to serialize pending upgrades")?;
+        self.put_raw(PENDING_UPGRADES_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_applied_upgrade(&mut self, name: &str, height: u64) -> Result<()> {
+        let bytes = borsh::to_vec(&Height(height))
+            .wrap_err("failed to serialize applied upgrade height")?;
+        self.put_raw(applied_upgrade_storage_key(name), bytes);
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::upgrade::v1alpha1::Upgrade;
+    use cnidarium::StateDelta;
+
+    use super::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+
+    fn upgrade(name: &str, height: u64) -> Upgrade {
+        Upgrade {
+            name: name.to_string(),
+            height,
+        }
+    }
+
+    #[tokio::test]
+    async fn pending_upgrades_are_sorted_by_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_pending_upgrades().await.unwrap().is_empty());
+
+        state
+            .put_pending_upgrades(vec![upgrade("second", 20), upgrade("first", 10)])
+            .unwrap();
+        assert_eq!(
+            state.get_pending_upgrades().await.unwrap(),
+            vec![upgrade("first", 10), upgrade("second", 20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn applied_upgrade() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_applied_upgrade("upgrade").await.unwrap(), None);
+        state.put_applied_upgrade("upgrade", 10).unwrap();
+        assert_eq!(
+            state.get_applied_upgrade("upgrade").await.unwrap(),
+            Some(10)
+        );
+        assert_eq!(state.get_applied_upgrade("other").await.unwrap(), None);
+    }
+}
@@ -17,7 +17,13 @@
 //!     .expect("must be able to initialize telemetry");
 //! tracing::info!("telemetry initialized");
 //! ```
-use std::io::IsTerminal as _;
+use std::{
+    io::IsTerminal as _,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
 
 pub use metrics::Metrics;
 use opentelemetry::{
@@ -35,11 +41,13 @@ use tracing_subscriber::{
         ParseError,
     },
     layer::SubscriberExt as _,
+    reload,
     util::{
         SubscriberInitExt as _,
         TryInitError,
     },
     EnvFilter,
+    Registry,
 };
 
 #[cfg(feature = "display")]
@@ -65,6 +73,10 @@ impl Error {
     fn init_subscriber(source: TryInitError) -> Self {
         Self(ErrorKind::InitSubscriber(source))
     }
+
+    fn reload_filter(source: reload::Error) -> Self {
+        Self(ErrorKind::ReloadFilter(source))
+    }
 }
 
 impl From<metrics::Error> for Error {
@@ -81,6 +93,8 @@ enum ErrorKind {
     FilterDirectives(#[source] ParseError),
     #[error("failed installing global tracing subscriber")]
     InitSubscriber(#[source] TryInitError),
+    #[error("failed replacing the filter of the global tracing subscriber")]
+    ReloadFilter(#[source] reload::Error),
     #[error(transparent)]
     Metrics(#[from] metrics::Error),
 }
@@ -202,12 +216,8 @@ impl Config {
             metrics_config_builder,
         } = self;
 
-        let env_filter = {
-            let builder = EnvFilter::builder().with_default_directive(LevelFilter::INFO.into());
-            builder
-                .parse(filter_directives)
-                .map_err(Error::filter_directives)?
-        };
+        let (env_filter, filter_handle) =
+            reload::Layer::new(parse_filter_directives(&filter_directives)?);
 
         let mut tracer_provider = TracerProvider::builder();
         if !no_otel {
@@ -250,9 +260,9 @@ impl Config {
 
         let otel_layer = tracing_opentelemetry::layer().with_tracer(tracer);
         tracing_subscriber::registry()
+            .with(env_filter)
             .with(otel_layer)
             .with(pretty_printer)
-            .with(env_filter)
             .try_init()
             .map_err(Error::init_subscriber)?;
 
@@ -263,6 +273,10 @@ impl Config {
 
         let guard = Guard {
             run_otel_shutdown: !no_otel,
+            filter_handle: FilterHandle {
+                inner: filter_handle,
+                filter_directives: Arc::new(Mutex::new(filter_directives)),
+            },
         };
 
         Ok((Box::leak(Box::new(metrics)), guard))
@@ -275,6 +289,60 @@ impl Config {
 /// indefinitely.
 pub struct Guard {
     run_otel_shutdown: bool,
+    filter_handle: FilterHandle,
+}
+
+impl Guard {
+    /// Returns a handle to replace the filter directives of the installed subscriber.
+    #[must_use]
+    pub fn filter_handle(&self) -> FilterHandle {
+        self.filter_handle.clone()
+    }
+}
+
+/// A handle to replace the filter directives of the global tracing subscriber at runtime,
+/// e.g. to change the log level without restarting a service.
+#[derive(Clone)]
+pub struct FilterHandle {
+    inner: reload::Handle<EnvFilter, Registry>,
+    filter_directives: Arc<Mutex<String>>,
+}
+
+impl FilterHandle {
+    /// Replaces the filter of the global tracing subscriber by `filter_directives`.
+    ///
+    /// The active filter is left untouched if `filter_directives` cannot be parsed.
+    ///
+    /// # Errors
+    /// Fails if the filter directives could not be parsed or if the subscriber was dropped.
+    pub fn set_filter_directives(&self, filter_directives: &str) -> Result<(), Error> {
+        let env_filter = parse_filter_directives(filter_directives)?;
+        let mut active = self
+            .filter_directives
+            .lock()
+            .expect("the lock is only held to replace the filter and must not be poisoned");
+        self.inner
+            .reload(env_filter)
+            .map_err(Error::reload_filter)?;
+        filter_directives.clone_into(&mut active);
+        Ok(())
+    }
+
+    /// Returns the filter directives of the global tracing subscriber.
+    #[must_use]
+    pub fn filter_directives(&self) -> String {
+        self.filter_directives
+            .lock()
+            .expect("the lock is only held to replace the filter and must not be poisoned")
+            .clone()
+    }
+}
+
+fn parse_filter_directives(filter_directives: &str) -> Result<EnvFilter, Error> {
+    EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .parse(filter_directives)
+        .map_err(Error::filter_directives)
 }
 
 impl Drop for Guard {